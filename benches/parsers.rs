@@ -0,0 +1,114 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use scraper::Html;
+use unipa_ex::parsers::page::PageKind;
+
+mod support;
+
+use support::alloc::{Allocations, CountingAllocator};
+use support::fixtures;
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// ベンチマーク対象のフィクスチャ
+struct Fixture {
+    kind: PageKind,
+    /// ページに含まれる行数（スループットの単位）
+    rows: u64,
+    html: String,
+}
+
+/// 実際の利用で想定される最大規模のページ群
+fn fixtures() -> Vec<Fixture> {
+    vec![
+        Fixture {
+            kind: PageKind::PortalAllNotifications,
+            rows: 4 * 500,
+            html: fixtures::all_notifications(500),
+        },
+        Fixture {
+            kind: PageKind::SyllabusSearchResult,
+            rows: 500,
+            html: fixtures::syllabus_search_result(500),
+        },
+        Fixture {
+            kind: PageKind::GradeInquiry,
+            rows: 4 * 24,
+            html: fixtures::grade_inquiry(4),
+        },
+        Fixture {
+            kind: PageKind::AssignmentList,
+            rows: 300,
+            html: fixtures::assignment_list(300),
+        },
+        Fixture {
+            kind: PageKind::QuestionnaireList,
+            rows: 300,
+            html: fixtures::questionnaire_list(300),
+        },
+        Fixture {
+            kind: PageKind::GraduationPrediction,
+            rows: 2 * 40 + 20,
+            html: fixtures::graduation_prediction(40),
+        },
+    ]
+}
+
+/// 解析時間の計測
+///
+/// `parse`は構築済みのDOMに対するパーサー単体の時間、
+/// `parse_html`はHTML文字列からのDOM構築を含めた時間です。
+fn bench_parse_time(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_time");
+
+    for fixture in fixtures() {
+        let document = Html::parse_document(&fixture.html);
+        // フィクスチャがパーサーの想定する構造から外れていないことを確認しておく
+        fixture.kind.parse(&document).unwrap();
+
+        group.throughput(Throughput::Elements(fixture.rows));
+        group.bench_with_input(
+            BenchmarkId::new("parse", fixture.kind.as_str()),
+            &document,
+            |b, document| b.iter(|| fixture.kind.parse(black_box(document)).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parse_html", fixture.kind.as_str()),
+            &fixture.html,
+            |b, html| {
+                b.iter(|| {
+                    let document = Html::parse_document(black_box(html));
+                    fixture.kind.parse(&document).unwrap()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// 1回の解析あたりのヒープ割り当て回数の計測
+fn bench_parse_allocations(c: &mut Criterion<Allocations>) {
+    let mut group = c.benchmark_group("parse_allocations");
+
+    for fixture in fixtures() {
+        let document = Html::parse_document(&fixture.html);
+
+        group.throughput(Throughput::Elements(fixture.rows));
+        group.bench_with_input(
+            BenchmarkId::new("parse", fixture.kind.as_str()),
+            &document,
+            |b, document| b.iter(|| fixture.kind.parse(black_box(document)).unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(time, bench_parse_time);
+criterion_group! {
+    name = allocations;
+    config = Criterion::default().with_measurement(Allocations);
+    targets = bench_parse_allocations
+}
+criterion_main!(time, allocations);
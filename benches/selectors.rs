@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use scraper::{Html, Selector};
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::portal::builder::PortalParserBuilder;
+
+mod support;
+
+/// セクションごとの件数
+const ROWS_PER_SECTION: usize = 500;
+
+fn bench_all_notifications(c: &mut Criterion) {
+    let document = Html::parse_document(&support::fixtures::all_notifications(ROWS_PER_SECTION));
+    let parser = PortalParserBuilder::new()
+        .build_all_notifications()
+        .unwrap();
+
+    c.bench_function("portal_all_notifications/parse_document", |b| {
+        b.iter(|| parser.parse_document(black_box(&document)).unwrap())
+    });
+}
+
+/// 行ごとにセレクターを構築していた場合に1行あたりで発生していたコスト
+fn bench_per_row_compile(c: &mut Criterion) {
+    c.bench_function("selector/compile_per_row", |b| {
+        b.iter(|| {
+            for selector in ["tr", "td", "img"] {
+                black_box(Selector::parse(black_box(selector)).unwrap());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_all_notifications, bench_per_row_compile);
+criterion_main!(benches);
@@ -0,0 +1,104 @@
+//! ヒープ割り当て回数の計測
+//!
+//! [`CountingAllocator`]をグローバルアロケーターとして登録したベンチマークでは、
+//! [`Allocations`]をCriterionの計測方法に指定することで、実行時間の代わりに
+//! 1回の解析あたりの割り当て回数を比較できます。
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::measurement::{Measurement, ValueFormatter};
+use criterion::Throughput;
+
+/// これまでに行われた割り当て回数
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// 割り当て回数を数えながらシステムアロケーターに委譲するアロケーター
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// 割り当て回数を計測値とするCriterionの計測方法
+///
+/// 計測はプロセス全体で共有するカウンターを使用するため、
+/// ベンチマーク対象の処理は単一スレッドで実行される必要があります。
+pub struct Allocations;
+
+impl Measurement for Allocations {
+    type Intermediate = usize;
+    type Value = usize;
+
+    fn start(&self) -> Self::Intermediate {
+        ALLOCATIONS.load(Ordering::SeqCst)
+    }
+
+    fn end(&self, start: Self::Intermediate) -> Self::Value {
+        ALLOCATIONS.load(Ordering::SeqCst) - start
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        v1 + v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &AllocationFormatter
+    }
+}
+
+/// 割り当て回数の表示形式
+struct AllocationFormatter;
+
+impl ValueFormatter for AllocationFormatter {
+    fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+
+    fn scale_throughputs(
+        &self,
+        _typical_value: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        match throughput {
+            Throughput::Bytes(bytes) | Throughput::BytesDecimal(bytes) => {
+                values.iter_mut().for_each(|v| *v /= *bytes as f64);
+                "allocs/byte"
+            }
+            Throughput::Elements(elements) => {
+                values.iter_mut().for_each(|v| *v /= *elements as f64);
+                "allocs/element"
+            }
+        }
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+}
@@ -0,0 +1,204 @@
+//! 実際の画面構造を模した大規模なフィクスチャHTMLの生成
+
+/// 成績評価の候補（順番に割り当てる）
+const GRADES: [&str; 7] = ["秀", "優", "良", "可", "不可", "合格", "-"];
+
+/// 曜日の候補（順番に割り当てる）
+const WEEKDAYS: [&str; 6] = ["月", "火", "水", "木", "金", "土"];
+
+/// 各セクションを全件表示したお知らせ全表示ページを生成する
+pub fn all_notifications(rows_per_section: usize) -> String {
+    let mut html = String::from(
+        r#"<html><body><form id="form1">
+<table id="form1:Poa00101A:htmlCalendarTable"><tbody><tr><td>1</td><td>2</td></tr></tbody></table>
+<table id="form1:Poa00201A:htmlParentTable"><tbody>"#,
+    );
+
+    for section in 0..4 {
+        html.push_str(&format!(
+            r#"<tr><td><table id="form1:Poa00201A:htmlParentTable:{section}:htmlDetailTbl"><tbody>"#
+        ));
+        for row in 0..rows_per_section {
+            html.push_str(&format!(
+                r#"<tr><td><img src="/img/unread.gif"></td><td>お知らせ {section}-{row}</td><td>教務課</td><td>2025/04/01</td></tr>"#
+            ));
+        }
+        html.push_str("</tbody></table></td></tr>");
+    }
+
+    html.push_str("</tbody></table></form></body></html>");
+    html
+}
+
+/// 指定した件数の科目を1ページに表示したシラバス検索結果ページを生成する
+pub fn syllabus_search_result(rows: usize) -> String {
+    let mut html = String::from(
+        r#"<html><body><form id="form1" method="post" action="/up/faces/up/km/Kmh00601A.jsp">
+<input type="hidden" name="form1" value="form1">
+<input type="hidden" name="com.sun.faces.VIEW" value="_id0:_id1">
+<div id="form1:htmlKensakuJyoken">開講年度／学期：2025年度　前期<br>科目名：<br>学科・コース／専攻：情報工学科</div>
+<table id="form1:htmlKekkatable">
+<thead><tr><th>曜日時限</th><th>科目</th><th>教員</th><th>区分</th><th>学年</th><th>学期</th><th>単位</th></tr></thead>
+<tbody>"#,
+    );
+
+    for row in 0..rows {
+        let weekday = WEEKDAYS[row % WEEKDAYS.len()];
+        let period = row % 5 + 1;
+        let grade = row % 4 + 1;
+        html.push_str(&format!(
+            r##"<tr class="rowClass1">
+<td class="yobi"><span>{weekday}{period}<br></span></td>
+<td class="kamokuName"><a id="form1:htmlKekkatable:{row}:linkKamoku" href="#" onclick="return submitSyllabus('{row}');"><span>K{row:05} 情報科学特論{row}</span></a></td>
+<td class="kyoin"><span>教員 {row}</span></td>
+<td class="kubun"><span>講義<br></span></td>
+<td class="gakunen"><span>{grade}</span></td>
+<td class="gakki"><span>前期<br></span></td>
+<td class="tani"><span>2</span></td>
+</tr>"##
+        ));
+    }
+
+    html.push_str(&format!(
+        r#"</tbody></table>
+<span id="form1:htmlKekkatable:htmlGokeiKensu">{rows}件</span>
+<span id="form1:htmlKekkatable:deluxe1__pagerText">1 / 1 ページ</span>
+<span id="form1:htmlKekkatable:web1"><strong>1</strong></span>
+</form></body></html>"#
+    ));
+    html
+}
+
+/// 指定した年数分の履修科目を含む成績照会ページを生成する
+///
+/// 1年あたり前期・後期で合わせて24科目を履修し、最終年度は評価前（`-`）の科目を含みます。
+pub fn grade_inquiry(years: usize) -> String {
+    let mut html = String::from(
+        r#"<html><body><div class="gpaScore">GPA: 2.85</div>
+<table class="listTable">
+<thead><tr><th>科目名</th><th>単位</th><th>評価</th><th>担当教員</th><th>備考</th></tr></thead>
+<tbody>"#,
+    );
+
+    for year in 0..years {
+        for index in 0..24 {
+            let grade = if year + 1 == years && index >= 12 {
+                "-"
+            } else {
+                GRADES[(year * 24 + index) % (GRADES.len() - 1)]
+            };
+            html.push_str(&format!(
+                "<tr><td>専門科目 {year}-{index}</td><td>2</td><td>{grade}</td><td>教員 {index}</td><td>-</td></tr>"
+            ));
+        }
+    }
+
+    html.push_str(
+        r#"</tbody></table>
+<table class="creditSummary">
+<tr><th>区分</th><th>要件</th><th>修得</th><th>履修中</th></tr>
+<tr><td>共通教育</td><td>40</td><td>38</td><td>2</td></tr>
+<tr><td>専門教育</td><td>84</td><td>70</td><td>10</td></tr>
+<tr><td>自由選択</td><td>0</td><td>4</td><td>0</td></tr>
+<tr><td>合計</td><td>124</td><td>112</td><td>12</td></tr>
+</table></body></html>"#,
+    );
+    html
+}
+
+/// 指定した件数の課題を含む課題提出一覧ページを生成する
+pub fn assignment_list(rows: usize) -> String {
+    let mut html = String::from(
+        r#"<html><body><table class="listTable">
+<thead><tr><th>授業科目</th><th>課題名</th><th>提出期限</th><th>提出状況</th></tr></thead>
+<tbody>"#,
+    );
+
+    for row in 0..rows {
+        let status = if row % 3 == 0 {
+            "未提出"
+        } else {
+            "提出済"
+        };
+        html.push_str(&format!(
+            r##"<tr><td>授業 {row}</td><td><a href="#">第{row}回レポート</a></td><td>2025/07/{day:02} 23:59</td><td>{status}</td></tr>"##,
+            day = row % 28 + 1
+        ));
+    }
+
+    html.push_str("</tbody></table></body></html>");
+    html
+}
+
+/// 指定した件数のアンケートを含むアンケート一覧ページを生成する
+pub fn questionnaire_list(rows: usize) -> String {
+    let mut html = String::from(
+        r#"<html><body><table class="listTable">
+<thead><tr><th>アンケート名</th><th>授業科目</th><th>回答期限</th><th>回答状況</th></tr></thead>
+<tbody>"#,
+    );
+
+    for row in 0..rows {
+        let status = if row % 2 == 0 {
+            "未回答"
+        } else {
+            "回答済"
+        };
+        html.push_str(&format!(
+            "<tr><td>授業評価アンケート {row}</td><td>授業 {row}</td><td>2025/07/31</td><td>{status}</td></tr>"
+        ));
+    }
+
+    html.push_str("</tbody></table></body></html>");
+    html
+}
+
+/// 指定した数の要件区分を含む卒業見込判定ページを生成する
+///
+/// 各要件区分は下位の要件を1つ持ちます。奇数番目の区分は修得単位数が8.5単位で1.5単位不足し、
+/// 要件不足項目の一覧に1件ずつ表示されます。
+pub fn graduation_prediction(categories: usize) -> String {
+    let mut requirements = String::new();
+    let mut shortfalls = String::new();
+    let mut completed_total = 0.0;
+
+    for category in 0..categories {
+        let completed = if category % 2 == 0 { 10.0 } else { 8.5 };
+        completed_total += completed;
+        let judgement = if category % 2 == 0 { "○" } else { "×" };
+        requirements.push_str(&format!(
+            "<tr><td>　区分{category}</td><td>10</td><td>{completed}</td><td>2</td><td>{judgement}</td></tr>\n\
+             <tr><td>　　区分{category}必修</td><td>4</td><td>4</td><td>0</td><td>○</td></tr>\n"
+        ));
+        if category % 2 == 1 {
+            shortfalls.push_str(&format!(
+                "<tr><td>S{category:02}</td><td>{category}</td><td>区分{category}の単位が不足しています</td><td>1.5単位</td></tr>\n"
+            ));
+        }
+    }
+
+    let (result, judgement) = if shortfalls.is_empty() {
+        ("卒業見込あり", "○")
+    } else {
+        ("卒業見込なし", "×")
+    };
+    format!(
+        r#"<html><body><form id="form1">
+<table class="outputTable">
+<tr><th>学籍番号</th><td>21X0001</td></tr>
+<tr><th>判定結果</th><td>{result}</td></tr>
+</table>
+<table class="listTable">
+<thead><tr><th>要件名</th><th>必要単位数</th><th>修得単位数</th><th>履修中単位数</th><th>判定</th></tr></thead>
+<tbody>
+<tr><td>卒業要件</td><td>{required}</td><td>{completed_total}</td><td>{enrolled}</td><td>{judgement}</td></tr>
+{requirements}</tbody></table>
+<table class="listTable">
+<thead><tr><th>条件コード</th><th>要素番号</th><th>不足内容</th><th>不足量</th></tr></thead>
+<tbody>
+{shortfalls}</tbody></table>
+</form></body></html>"#,
+        required = categories * 10,
+        enrolled = categories * 2,
+    )
+}
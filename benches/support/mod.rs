@@ -0,0 +1,8 @@
+//! ベンチマーク間で共有する補助モジュール
+//!
+//! ベンチマークごとに使用する生成関数が異なるため、未使用の警告は抑制しています。
+
+#![allow(dead_code)]
+
+pub mod alloc;
+pub mod fixtures;
@@ -0,0 +1,109 @@
+#!/usr/bin/env bash
+# ブラウザ・Node.js・Deno・バンドラー向けのnpmパッケージをまとめてビルドする
+#
+# 使い方:
+#   scripts/build-packages.sh                 # pkg/ に全ターゲットを出力
+#   scripts/build-packages.sh -- --no-default-features --features portal
+#
+# `--`以降の引数はそのままcargoに渡します。
+#
+# 出力されるpkg/の構成:
+#   bundler/  webpack・Vite・Rollupなど（wasm-pack --target bundler）
+#   node/     Node.js（CommonJSのunipa_ex.jsと、ESMから読み込むindex.mjs）
+#   web/      ブラウザで直接読み込むESM（init()で初期化）
+#   deno/     Deno
+#   package.json  実行環境ごとの`exports`条件で上記を振り分ける
+
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+OUT=pkg
+CARGO_ARGS=()
+if [ "${1:-}" = "--" ]; then
+    shift
+    CARGO_ARGS=("$@")
+fi
+
+NAME=unipa_ex
+VERSION=$(sed -n 's/^version = "\(.*\)"/\1/p' Cargo.toml | head -1)
+
+rm -rf "$OUT"
+
+for target in bundler nodejs web deno; do
+    dir="$target"
+    [ "$target" = nodejs ] && dir=node
+    wasm-pack build --release --target "$target" --out-dir "$OUT/$dir" --out-name "$NAME" \
+        -- ${CARGO_ARGS[@]+"${CARGO_ARGS[@]}"}
+    # 個別のpackage.jsonやREADMEは最上位のpackage.jsonに統合する
+    rm -f "$OUT/$dir/package.json" "$OUT/$dir/README.md" "$OUT/$dir/LICENSE" "$OUT/$dir/.gitignore"
+done
+
+# Node.jsのESMからはCommonJSの出力をそのまま再エクスポートする
+# （wasm-bindgenの出力は`module.exports.X = X`の形なので名前付きインポートができる）
+cat >"$OUT/node/index.mjs" <<EOF
+export * from "./$NAME.js";
+EOF
+cat >"$OUT/node/index.d.mts" <<EOF
+export * from "./$NAME.js";
+EOF
+
+cat >"$OUT/package.json" <<EOF
+{
+  "name": "$NAME",
+  "version": "$VERSION",
+  "description": "UNIVERSAL PASSPORT EXのHTMLページを解析するRust + WebAssemblyライブラリ",
+  "license": "Apache-2.0",
+  "repository": {
+    "type": "git",
+    "url": "https://github.com/nusu-github/UNIPA-EX-rs"
+  },
+  "files": ["bundler", "node", "web", "deno"],
+  "main": "./node/$NAME.js",
+  "module": "./bundler/$NAME.js",
+  "types": "./bundler/$NAME.d.ts",
+  "sideEffects": ["./bundler/$NAME.js", "./bundler/snippets/*"],
+  "exports": {
+    ".": {
+      "deno": {
+        "types": "./deno/$NAME.d.ts",
+        "default": "./deno/$NAME.js"
+      },
+      "node": {
+        "import": {
+          "types": "./node/index.d.mts",
+          "default": "./node/index.mjs"
+        },
+        "require": {
+          "types": "./node/$NAME.d.ts",
+          "default": "./node/$NAME.js"
+        }
+      },
+      "types": "./bundler/$NAME.d.ts",
+      "default": "./bundler/$NAME.js"
+    },
+    "./web": {
+      "types": "./web/$NAME.d.ts",
+      "default": "./web/$NAME.js"
+    },
+    "./package.json": "./package.json"
+  }
+}
+EOF
+
+cp README.md LICENSE "$OUT/"
+
+# Node.js・Denoがあれば、各形式で読み込んでパーサークラスが公開されていることを確認する
+# （どのクラスが含まれるかは有効なフィーチャーによって変わるため、`Parser`で終わる名前を探す）
+CHECK='if (!Object.keys(mod).some((name) => name.endsWith("Parser"))) throw new Error("no parser classes exported");'
+
+if command -v node >/dev/null 2>&1; then
+    node -e "const mod = require('./$OUT'); $CHECK"
+    node --input-type=module -e "import * as mod from './$OUT/node/index.mjs'; $CHECK"
+    echo "Node.js (CommonJS / ESM) から読み込めることを確認しました"
+fi
+
+if command -v deno >/dev/null 2>&1; then
+    deno eval "import * as mod from './$OUT/deno/$NAME.js'; $CHECK"
+    echo "Denoから読み込めることを確認しました"
+fi
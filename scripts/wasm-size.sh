@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+# フィーチャーの組み合わせごとにWASMバイナリのサイズを計測する
+#
+# 使い方:
+#   scripts/wasm-size.sh                      # 既定の組み合わせを計測
+#   scripts/wasm-size.sh portal "portal wee_alloc" full
+#
+# 各引数は`--features`に渡すフィーチャーの組み合わせで、`--no-default-features`を付けて
+# リリースプロファイルでビルドします。wasm-optがインストールされている場合は最適化後のサイズも表示します。
+
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+TARGET=wasm32-unknown-unknown
+ARTIFACT="target/$TARGET/release/unipa_ex.wasm"
+
+if [ "$#" -eq 0 ]; then
+    set -- portal grades syllabus timetable classroom questionnaire tests student-info \
+        assignments course-evaluation class-profile full "full console_error_panic_hook" "full har"
+fi
+
+has_wasm_opt=false
+if command -v wasm-opt >/dev/null 2>&1; then
+    has_wasm_opt=true
+fi
+
+size_of() {
+    wc -c <"$1" | tr -d ' '
+}
+
+printf '%-40s %12s %12s %12s\n' "features" "wasm" "gzip" "wasm-opt"
+
+for features in "$@"; do
+    cargo build --quiet --lib --release --target "$TARGET" \
+        --no-default-features --features "$features"
+
+    raw=$(size_of "$ARTIFACT")
+    gzipped=$(gzip -9 -c "$ARTIFACT" | wc -c | tr -d ' ')
+    optimized="-"
+    if [ "$has_wasm_opt" = true ]; then
+        wasm-opt -Oz "$ARTIFACT" -o "$ARTIFACT.opt"
+        optimized=$(size_of "$ARTIFACT.opt")
+        rm -f "$ARTIFACT.opt"
+    fi
+
+    printf '%-40s %12s %12s %12s\n' "${features:-(none)}" "$raw" "$gzipped" "$optimized"
+done
@@ -0,0 +1,772 @@
+//! UNIVERSAL PASSPORT EXの保存済みHTMLを解析してJSONとして出力するコマンドラインツール
+//!
+//! `cli`フィーチャーを有効にした場合のみビルドされます。
+//!
+//! ```bash
+//! cargo run --features cli -- parse --format pretty portal.html
+//! cat grades.html | cargo run --features cli -- parse --kind grade-inquiry
+//! cargo run --features cli -- parse --trace grades.html
+//! cargo run --features cli -- parse --strict grades.html
+//! cargo run --features cli -- parse --envelope grades.html > stored.json
+//! cargo run --features cli -- migrate stored.json
+//! cargo run --features cli -- har --fixtures fixtures/ report.har
+//! cargo run --features cli -- schema --kind grade-inquiry --format pretty
+//! cargo run --features cli -- schema --out-dir schemas/
+//! cargo run --features cli -- ical --term-start 2025-04-07 --term-end 2025-07-25 \
+//!     --classes classes.json tests.html assignments.html > unipa.ics
+//! cargo run --features cli -- csv grades.html > grades.csv
+//! cargo run --features cli -- csv --sheet credits --tsv --columns category_name,completed_credits grades.html
+//! ```
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use scraper::Html;
+use serde::Serialize;
+use unipa_ex::common::datetime::{JstDate, JstRange};
+use unipa_ex::common::diagnostics::ParseDiagnostics;
+use unipa_ex::common::options::ParseOptions;
+use unipa_ex::common::outcome::{ParseOutcome, ParseWarning};
+use unipa_ex::export::csv::{CreditColumn, CsvFormat, GradeInquiryCsv, SubjectColumn};
+use unipa_ex::export::ical::{IcalBuilder, TimetableClass};
+use unipa_ex::har::{Har, HarEntryOutcome, HarParser, HarReport};
+use unipa_ex::parsers::envelope::{migrate, Envelope};
+use unipa_ex::parsers::page::{parse_page_with_options, PageData, PageKind};
+use unipa_ex::parsers::schema::{json_schema, json_schemas};
+use unipa_ex::utils::encoding::decode_html;
+use unipa_ex::utils::error::ParseError;
+
+/// UNIVERSAL PASSPORT EXのHTMLを解析するツール
+#[derive(Parser)]
+#[command(name = "unipa-ex", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 保存済みHTMLを解析してJSONとして出力する
+    Parse(ParseArgs),
+    /// HARファイル内のHTMLレスポンスを一括解析してレポートを出力する
+    Har(HarArgs),
+    /// 解析結果のJSON Schemaを出力する
+    Schema(SchemaArgs),
+    /// 保存済みの解析結果のJSONを現在のスキーマバージョンのエンベロープに変換する
+    Migrate(MigrateArgs),
+    /// 時間割・テスト・課題の締切・ポータルの予定をiCalendar形式で出力する
+    Ical(IcalArgs),
+    /// 成績照会をCSV・TSVとして出力する
+    Csv(CsvArgs),
+}
+
+#[derive(clap::Args)]
+struct ParseArgs {
+    /// ページ種別（省略時はHTMLから自動判定）
+    #[arg(short, long, value_parser = parse_kind)]
+    kind: Option<PageKind>,
+
+    /// 出力形式
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+
+    /// 試行したセレクターと一致件数、読み飛ばした行を標準エラー出力に表示する
+    #[arg(long)]
+    trace: bool,
+
+    /// 既定値による補完や必要な要素の欠落を警告ではなくエラーとして扱う
+    #[arg(long)]
+    strict: bool,
+
+    /// 解析結果をページ種別・スキーマバージョン・パーサーのバージョン・解析日時とあわせて出力する
+    #[arg(long)]
+    envelope: bool,
+
+    /// 入力HTMLファイル（省略時または`-`で標準入力）
+    files: Vec<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct HarArgs {
+    /// 出力形式（`ndjson`の場合はエントリーごとに1行）
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+
+    /// デコード済みのHTMLをフィクスチャとして書き出すディレクトリ
+    #[arg(long, value_name = "DIR")]
+    fixtures: Option<PathBuf>,
+
+    /// 入力HARファイル（`-`で標準入力）
+    file: PathBuf,
+}
+
+#[derive(clap::Args)]
+struct SchemaArgs {
+    /// ページ種別（省略時はすべてのページ種別をページ種別名をキーとするオブジェクトで出力）
+    #[arg(short, long, value_parser = parse_kind)]
+    kind: Option<PageKind>,
+
+    /// 出力形式（`ndjson`の場合はページ種別ごとに1行）
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+
+    /// 標準出力の代わりに`<ページ種別>.schema.json`として書き出すディレクトリ
+    #[arg(long, value_name = "DIR")]
+    out_dir: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct MigrateArgs {
+    /// モデルのみのJSON（エンベロープ導入前の`parse`の出力）のページ種別
+    #[arg(short, long, value_parser = parse_kind)]
+    kind: Option<PageKind>,
+
+    /// 出力形式（`ndjson`の場合は入力の各行を変換して1行ずつ出力）
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+
+    /// 入力JSONファイル（省略時または`-`で標準入力）
+    file: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct IcalArgs {
+    /// 授業期間の最初の日（`--classes`を指定する場合は必須）
+    #[arg(long, value_name = "DATE", value_parser = parse_date, requires = "term_end")]
+    term_start: Option<JstDate>,
+
+    /// 授業期間の最後の日
+    #[arg(long, value_name = "DATE", value_parser = parse_date, requires = "term_start")]
+    term_end: Option<JstDate>,
+
+    /// カレンダー名
+    #[arg(long)]
+    name: Option<String>,
+
+    /// 毎週の授業（`TimetableClass`の配列のJSON）のファイル
+    #[arg(long, value_name = "FILE")]
+    classes: Option<PathBuf>,
+
+    /// 予定を取り出すHTMLファイル（シラバス詳細・テスト解答状況・課題提出一覧・ポータル）
+    files: Vec<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct CsvArgs {
+    /// 出力するシート
+    #[arg(short, long, value_enum, default_value_t = Sheet::Subjects)]
+    sheet: Sheet,
+
+    /// カンマ区切りの代わりにタブ区切りで出力する
+    #[arg(long)]
+    tsv: bool,
+
+    /// 先頭にUTF-8のBOMを付けない
+    #[arg(long)]
+    no_bom: bool,
+
+    /// 出力する列（カンマ区切り、省略時はすべての列）
+    #[arg(long, value_delimiter = ',')]
+    columns: Vec<String>,
+
+    /// 成績照会のHTMLファイル（省略時または`-`で標準入力）
+    file: Option<PathBuf>,
+}
+
+/// `csv`で出力するシート
+#[derive(Clone, Copy, ValueEnum)]
+enum Sheet {
+    /// 1行1科目の成績
+    Subjects,
+    /// カテゴリ別の単位修得状況
+    Credits,
+}
+
+/// 出力形式
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// 1行のJSON
+    Json,
+    /// 整形済みJSON
+    Pretty,
+    /// 入力ごとに1行のJSON（改行区切り）
+    Ndjson,
+}
+
+/// 複数入力時やNDJSON出力時の1件分のレコード
+///
+/// `data`は`--envelope`の指定に応じて解析結果そのものか、エンベロープで包んだものになる。
+#[derive(Serialize)]
+struct Record<'a, D> {
+    source: &'a str,
+    kind: PageKind,
+    data: D,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    warnings: &'a [ParseWarning],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diagnostics: Option<&'a ParseDiagnostics>,
+}
+
+/// `parse`の出力する解析結果
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Output {
+    Data(PageData),
+    Envelope(Envelope<PageData>),
+}
+
+/// 入力元と解析エラー
+enum Failure {
+    Io(String, io::Error),
+    Parse(String, ParseError),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Parse(args) => run_parse(args),
+        Command::Har(args) => run_har(args),
+        Command::Schema(args) => run_schema(args),
+        Command::Migrate(args) => run_migrate(args),
+        Command::Ical(args) => run_ical(args),
+        Command::Csv(args) => run_csv(args),
+    }
+}
+
+fn run_parse(args: ParseArgs) -> ExitCode {
+    let sources = if args.files.is_empty() {
+        vec![PathBuf::from("-")]
+    } else {
+        args.files
+    };
+    let single = sources.len() == 1;
+    let options = ParseOptions::new()
+        .with_strict_mode(args.strict)
+        .with_debug_mode(args.trace);
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut records = Vec::new();
+    let mut failed = false;
+
+    for source in &sources {
+        let name = source.display().to_string();
+        let result = read_input(source)
+            .map_err(|e| Failure::Io(name.clone(), e))
+            .and_then(|bytes| {
+                decode_html(&bytes, None, &name)
+                    .and_then(|html| parse_html(&html, args.kind, options.clone()))
+                    .map_err(|e| Failure::Parse(name.clone(), e))
+            });
+
+        let (
+            kind,
+            ParseOutcome {
+                data,
+                warnings,
+                diagnostics,
+            },
+        ) = match result {
+            Ok(parsed) => parsed,
+            Err(failure) => {
+                report(&failure);
+                failed = true;
+                continue;
+            }
+        };
+        for warning in &warnings {
+            eprintln!("unipa-ex: {name}: warning: {warning}");
+        }
+        if let Some(diagnostics) = &diagnostics {
+            print_trace(&name, diagnostics);
+        }
+
+        let data = if args.envelope {
+            Output::Envelope(Envelope::new(kind, data))
+        } else {
+            Output::Data(data)
+        };
+        let written = match args.format {
+            OutputFormat::Ndjson => write_json(
+                &mut out,
+                &Record {
+                    source: &name,
+                    kind,
+                    data: &data,
+                    warnings: &warnings,
+                    diagnostics: diagnostics.as_ref(),
+                },
+                false,
+            ),
+            OutputFormat::Json | OutputFormat::Pretty if single => {
+                write_json(&mut out, &data, matches!(args.format, OutputFormat::Pretty))
+            }
+            OutputFormat::Json | OutputFormat::Pretty => {
+                records.push((name, kind, data, warnings, diagnostics));
+                Ok(())
+            }
+        };
+        if let Err(e) = written {
+            eprintln!("unipa-ex: failed to write output: {e}");
+            return ExitCode::from(2);
+        }
+    }
+
+    if !records.is_empty() {
+        let records: Vec<_> = records
+            .iter()
+            .map(|(source, kind, data, warnings, diagnostics)| Record {
+                source,
+                kind: *kind,
+                data,
+                warnings,
+                diagnostics: diagnostics.as_ref(),
+            })
+            .collect();
+        if let Err(e) = write_json(
+            &mut out,
+            &records,
+            matches!(args.format, OutputFormat::Pretty),
+        ) {
+            eprintln!("unipa-ex: failed to write output: {e}");
+            return ExitCode::from(2);
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_har(args: HarArgs) -> ExitCode {
+    let name = args.file.display().to_string();
+    let har = match read_input(&args.file) {
+        Ok(bytes) => String::from_utf8(bytes)
+            .map_err(|_| ParseError::InvalidFileEncoding { path: name.clone() })
+            .and_then(|json| Har::from_json(&json))
+            .map_err(|e| Failure::Parse(name.clone(), e)),
+        Err(e) => Err(Failure::Io(name.clone(), e)),
+    };
+    let har = match har {
+        Ok(har) => har,
+        Err(failure) => {
+            report(&failure);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report_data = HarParser::new().parse_har(&har);
+
+    if let Some(dir) = &args.fixtures {
+        if let Err(e) = write_fixtures(dir, &har, &report_data) {
+            eprintln!("unipa-ex: {}: {e}", dir.display());
+            return ExitCode::from(2);
+        }
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let written = match args.format {
+        OutputFormat::Ndjson => report_data
+            .entries
+            .iter()
+            .try_for_each(|entry| write_json(&mut out, entry, false)),
+        OutputFormat::Json => write_json(&mut out, &report_data, false),
+        OutputFormat::Pretty => write_json(&mut out, &report_data, true),
+    };
+    if let Err(e) = written {
+        eprintln!("unipa-ex: failed to write output: {e}");
+        return ExitCode::from(2);
+    }
+
+    for entry in &report_data.entries {
+        if let HarEntryOutcome::Failed { error, .. } = &entry.outcome {
+            eprintln!(
+                "unipa-ex: {name}: entry {} ({}): {error}",
+                entry.index, entry.url
+            );
+        }
+    }
+
+    if report_data.failed_count() > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_schema(args: SchemaArgs) -> ExitCode {
+    let schemas = match args.kind {
+        Some(kind) => match json_schema(kind) {
+            Ok(schema) => vec![(kind, schema)],
+            Err(e) => {
+                eprintln!("unipa-ex: {kind}: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => json_schemas(),
+    };
+    let pretty = matches!(args.format, OutputFormat::Pretty);
+
+    if let Some(dir) = &args.out_dir {
+        let written = fs::create_dir_all(dir).and_then(|()| {
+            schemas.iter().try_for_each(|(kind, schema)| {
+                let mut file = fs::File::create(dir.join(format!("{kind}.schema.json")))?;
+                write_json(&mut file, schema, pretty)
+            })
+        });
+        if let Err(e) = written {
+            eprintln!("unipa-ex: {}: {e}", dir.display());
+            return ExitCode::from(2);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let written = match (args.format, args.kind) {
+        (OutputFormat::Ndjson, _) => schemas.iter().try_for_each(|(kind, schema)| {
+            write_json(
+                &mut out,
+                &serde_json::json!({ "kind": kind, "schema": schema }),
+                false,
+            )
+        }),
+        (_, Some(_)) => write_json(&mut out, &schemas[0].1, pretty),
+        (_, None) => {
+            let schemas: serde_json::Map<_, _> = schemas
+                .iter()
+                .map(|(kind, schema)| (kind.to_string(), schema.as_value().clone()))
+                .collect();
+            write_json(&mut out, &schemas, pretty)
+        }
+    };
+    if let Err(e) = written {
+        eprintln!("unipa-ex: failed to write output: {e}");
+        return ExitCode::from(2);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn run_migrate(args: MigrateArgs) -> ExitCode {
+    let source = args.file.unwrap_or_else(|| PathBuf::from("-"));
+    let name = source.display().to_string();
+    let input = match read_input(&source) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            report(&Failure::Io(name, e));
+            return ExitCode::FAILURE;
+        }
+    };
+    let invalid = |e: serde_json::Error| ParseError::InvalidDataFormat {
+        data_type: "JSON".to_string(),
+        details: e.to_string(),
+    };
+    let pretty = matches!(args.format, OutputFormat::Pretty);
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut failed = false;
+
+    // NDJSONは行ごと、それ以外は入力全体を1つのJSONとして変換する
+    // （複数入力時の`parse`の出力のような配列は要素ごとに変換する）
+    let values: Vec<(String, Result<serde_json::Value, ParseError>)> = match args.format {
+        OutputFormat::Ndjson => String::from_utf8_lossy(&input)
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                (
+                    format!("{name}:{}", i + 1),
+                    serde_json::from_str(line).map_err(invalid),
+                )
+            })
+            .collect(),
+        OutputFormat::Json | OutputFormat::Pretty => {
+            vec![(
+                name.clone(),
+                serde_json::from_slice(&input).map_err(invalid),
+            )]
+        }
+    };
+
+    for (source, value) in values {
+        let migrated = value.and_then(|value| match value {
+            serde_json::Value::Array(items) => items
+                .into_iter()
+                .map(|item| migrate(item, args.kind))
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|envelopes| serde_json::to_value(envelopes).map_err(invalid)),
+            value => migrate(value, args.kind)
+                .and_then(|envelope| serde_json::to_value(envelope).map_err(invalid)),
+        });
+        match migrated {
+            Ok(migrated) => {
+                if let Err(e) = write_json(&mut out, &migrated, pretty) {
+                    eprintln!("unipa-ex: failed to write output: {e}");
+                    return ExitCode::from(2);
+                }
+            }
+            Err(e) => {
+                report(&Failure::Parse(source, e));
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_ical(args: IcalArgs) -> ExitCode {
+    let mut builder = IcalBuilder::new();
+    if let (Some(start), Some(end)) = (args.term_start, args.term_end) {
+        match JstRange::new(start, end) {
+            Ok(term) => builder = builder.with_term_dates(term),
+            Err(e) => {
+                eprintln!("unipa-ex: --term-end: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Some(name) = args.name {
+        builder = builder.with_calendar_name(name);
+    }
+
+    if let Some(source) = &args.classes {
+        let name = source.display().to_string();
+        let classes = read_input(source)
+            .map_err(|e| Failure::Io(name.clone(), e))
+            .and_then(|bytes| {
+                serde_json::from_slice::<Vec<TimetableClass>>(&bytes).map_err(|e| {
+                    Failure::Parse(
+                        name.clone(),
+                        ParseError::InvalidDataFormat {
+                            data_type: "JSON".to_string(),
+                            details: e.to_string(),
+                        },
+                    )
+                })
+            });
+        match classes {
+            Ok(classes) => builder = builder.add_classes(classes),
+            Err(failure) => {
+                report(&failure);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    for source in &args.files {
+        let name = source.display().to_string();
+        let added = read_input(source)
+            .map_err(|e| Failure::Io(name.clone(), e))
+            .and_then(|bytes| {
+                decode_html(&bytes, None, &name)
+                    .and_then(|html| parse_html(&html, None, ParseOptions::new()))
+                    .and_then(|(_, outcome)| builder.clone().add_page(&outcome.data))
+                    .map_err(|e| Failure::Parse(name.clone(), e))
+            });
+        match added {
+            Ok(added) => builder = added,
+            Err(failure) => {
+                report(&failure);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let ics = match builder.build() {
+        Ok(ics) => ics,
+        Err(e) => {
+            eprintln!("unipa-ex: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = io::stdout().lock().write_all(ics.as_bytes()) {
+        eprintln!("unipa-ex: failed to write output: {e}");
+        return ExitCode::from(2);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn run_csv(args: CsvArgs) -> ExitCode {
+    let mut csv = GradeInquiryCsv::new()
+        .with_format(if args.tsv {
+            CsvFormat::Tsv
+        } else {
+            CsvFormat::Csv
+        })
+        .with_byte_order_mark(!args.no_bom);
+    if !args.columns.is_empty() {
+        let columns = match args.sheet {
+            Sheet::Subjects => args
+                .columns
+                .iter()
+                .map(|column| column.parse::<SubjectColumn>())
+                .collect::<Result<Vec<_>, _>>()
+                .map(|columns| csv.clone().with_subject_columns(columns)),
+            Sheet::Credits => args
+                .columns
+                .iter()
+                .map(|column| column.parse::<CreditColumn>())
+                .collect::<Result<Vec<_>, _>>()
+                .map(|columns| csv.clone().with_credit_columns(columns)),
+        };
+        match columns {
+            Ok(with_columns) => csv = with_columns,
+            Err(e) => {
+                eprintln!("unipa-ex: --columns: {e}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let source = args.file.unwrap_or_else(|| PathBuf::from("-"));
+    let name = source.display().to_string();
+    let parsed = read_input(&source)
+        .map_err(|e| Failure::Io(name.clone(), e))
+        .and_then(|bytes| {
+            decode_html(&bytes, None, &name)
+                .and_then(|html| {
+                    parse_html(&html, Some(PageKind::GradeInquiry), ParseOptions::new())
+                })
+                .map_err(|e| Failure::Parse(name.clone(), e))
+        });
+    let grades = match parsed {
+        Ok((_, outcome)) => match outcome.data {
+            PageData::GradeInquiry(grades) => grades,
+            _ => unreachable!("parsed as grade inquiry"),
+        },
+        Err(failure) => {
+            report(&failure);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let text = match args.sheet {
+        Sheet::Subjects => csv.subjects(&grades),
+        Sheet::Credits => csv.credit_summary(&grades),
+    };
+    if let Err(e) = io::stdout().lock().write_all(text.as_bytes()) {
+        eprintln!("unipa-ex: failed to write output: {e}");
+        return ExitCode::from(2);
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// 解析対象となったHTMLレスポンスを`<番号>-<種別>.html`として書き出す
+fn write_fixtures(dir: &Path, har: &Har, report: &HarReport) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+
+    for entry_report in &report.entries {
+        let entry = &har.log.entries[entry_report.index];
+        let Ok(Some(html)) = entry.decode_body() else {
+            continue;
+        };
+
+        let label = match &entry_report.outcome {
+            HarEntryOutcome::Parsed { kind, .. }
+            | HarEntryOutcome::Failed {
+                kind: Some(kind), ..
+            } => kind.as_str(),
+            _ => entry_report.screen_id.as_deref().unwrap_or("unknown"),
+        };
+        fs::write(
+            dir.join(format!("{:03}-{label}.html", entry_report.index)),
+            html,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// ファイルまたは標準入力から読み込む
+fn read_input(source: &Path) -> io::Result<Vec<u8>> {
+    if source.as_os_str() == "-" {
+        let mut buf = Vec::new();
+        io::stdin().read_to_end(&mut buf)?;
+        Ok(buf)
+    } else {
+        fs::read(source)
+    }
+}
+
+/// ページ種別を判定（または指定された種別を使用）して警告付きで解析する
+///
+/// `debug_mode`が有効な場合は診断情報もあわせて収集する。
+fn parse_html(
+    html: &str,
+    kind: Option<PageKind>,
+    options: ParseOptions,
+) -> Result<(PageKind, ParseOutcome<PageData>), ParseError> {
+    if html.trim().is_empty() {
+        return Err(ParseError::empty_html_content());
+    }
+
+    let document = Html::parse_document(html);
+    match kind {
+        Some(kind) => Ok((kind, kind.parse_with_options(&document, options)?)),
+        None => parse_page_with_options(&document, options),
+    }
+}
+
+/// 診断情報を1行ずつ標準エラー出力に表示する
+fn print_trace(source: &str, diagnostics: &ParseDiagnostics) {
+    for trace in &diagnostics.selectors {
+        let via = if trace.matched_alternatives.is_empty() {
+            String::new()
+        } else {
+            format!(" via {}", trace.matched_alternatives.join(", "))
+        };
+        eprintln!(
+            "unipa-ex: {source}: trace: [{}] `{}` matched {}{via}",
+            trace.section, trace.selector, trace.match_count
+        );
+    }
+    for row in &diagnostics.skipped_rows {
+        eprintln!(
+            "unipa-ex: {source}: trace: [{}] skipped row {} ({} cells): {}",
+            row.section, row.row, row.cell_count, row.reason
+        );
+    }
+}
+
+fn write_json<T: Serialize>(out: &mut impl Write, value: &T, pretty: bool) -> io::Result<()> {
+    if pretty {
+        serde_json::to_writer_pretty(&mut *out, value)?;
+    } else {
+        serde_json::to_writer(&mut *out, value)?;
+    }
+    writeln!(out)
+}
+
+fn report(failure: &Failure) {
+    match failure {
+        Failure::Io(source, e) => eprintln!("unipa-ex: {source}: {e}"),
+        Failure::Parse(source, e) => eprintln!("unipa-ex: {source}: {e}"),
+    }
+}
+
+fn parse_kind(value: &str) -> Result<PageKind, String> {
+    value.parse().map_err(|_| {
+        let known: Vec<_> = PageKind::ALL.iter().map(PageKind::as_str).collect();
+        format!("unknown page kind (expected one of: {})", known.join(", "))
+    })
+}
+
+fn parse_date(value: &str) -> Result<JstDate, String> {
+    JstDate::parse(value).map_err(|e| e.to_string())
+}
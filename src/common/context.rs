@@ -0,0 +1,228 @@
+//! # 解析コンテキスト
+//!
+//! 1回の解析処理の間だけ存在し、パーサーの各段階から警告を集約します。
+//! 診断モードではセレクターの試行記録もあわせて収集します。
+//! 厳密モードでは、警告を記録する代わりにエラーとして返します。
+
+use std::str::FromStr;
+
+use super::diagnostics::ParseDiagnostics;
+use super::dom::DomElement;
+use super::options::ParseOptions;
+use super::outcome::{ParseOutcome, ParseWarning};
+use super::period::{ClassTime, PeriodTable};
+use super::selectors::CompiledSelector;
+use crate::utils::error::ParseError;
+
+/// 1回の解析処理で共有される状態
+#[derive(Debug, Default)]
+pub struct ParseContext {
+    warnings: Vec<ParseWarning>,
+    diagnostics: Option<ParseDiagnostics>,
+    strict: bool,
+    period_table: PeriodTable,
+}
+
+impl ParseContext {
+    /// 新しいコンテキストを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 解析オプションに従ってコンテキストを作成
+    ///
+    /// `debug_mode`が有効な場合は診断情報を収集し、`strict_mode`が有効な場合は
+    /// 警告をエラーとして扱います。曜日・時限の時刻は`period_table`から求めます。
+    pub fn from_options(options: &ParseOptions) -> Self {
+        Self::new()
+            .with_diagnostics(options.debug_mode)
+            .with_strict(options.strict_mode)
+            .with_period_table(options.period_table.clone())
+    }
+
+    /// 厳密モードを有効または無効にする
+    pub fn with_strict(mut self, enabled: bool) -> Self {
+        self.strict = enabled;
+        self
+    }
+
+    /// 厳密モードかどうか
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// 曜日・時限の開始・終了時刻を求める時限表を設定する
+    pub fn with_period_table(mut self, table: PeriodTable) -> Self {
+        self.period_table = table;
+        self
+    }
+
+    /// 曜日・時限の開始・終了時刻を求める時限表
+    pub fn period_table(&self) -> &PeriodTable {
+        &self.period_table
+    }
+
+    /// 診断情報の収集を有効または無効にする
+    pub fn with_diagnostics(mut self, enabled: bool) -> Self {
+        self.diagnostics = enabled.then(ParseDiagnostics::default);
+        self
+    }
+
+    /// 診断情報を収集しているかどうか
+    pub fn is_tracing(&self) -> bool {
+        self.diagnostics.is_some()
+    }
+
+    /// `scope`配下でのセレクターの照合結果を記録する
+    ///
+    /// 記録されるセレクター文字列は、解析に使用する[`CompiledSelector`]の元の文字列です。
+    /// 診断モードでない場合は何もしません。照合は記録のために改めて行われるため、
+    /// 通常の解析には影響しません。
+    pub fn trace_selector<E: DomElement>(
+        &mut self,
+        section: &str,
+        selector: &CompiledSelector,
+        scope: E,
+    ) {
+        if let Some(diagnostics) = &mut self.diagnostics {
+            diagnostics.record_selector(section, selector.source(), scope);
+        }
+    }
+
+    /// 行を読み飛ばしたことを記録する（診断モードのみ）
+    pub fn skip_row(&mut self, section: &str, row: usize, cell_count: usize, reason: &str) {
+        if let Some(diagnostics) = &mut self.diagnostics {
+            diagnostics.record_skipped_row(section, row, cell_count, reason);
+        }
+    }
+
+    /// セル数が足りない行を読み飛ばしたことを記録する（診断モードのみ）
+    pub fn skip_short_row(
+        &mut self,
+        section: &str,
+        row: usize,
+        cell_count: usize,
+        required: usize,
+    ) {
+        if self.is_tracing() {
+            let reason = format!("expected at least {required} cells");
+            self.skip_row(section, row, cell_count, &reason);
+        }
+    }
+
+    /// 警告を記録する
+    ///
+    /// 厳密モードでは記録せず、警告に対応するエラーを返します。
+    pub fn warn(&mut self, warning: ParseWarning) -> Result<(), ParseError> {
+        if self.strict {
+            return Err(warning.into());
+        }
+        self.warnings.push(warning);
+        Ok(())
+    }
+
+    /// 任意のセクションが見つからなかったことを記録する
+    ///
+    /// 厳密モードでは`ElementNotFound`になります。
+    pub fn missing_section(&mut self, section: &str, selector: &str) -> Result<(), ParseError> {
+        self.warn(ParseWarning::MissingSection {
+            section: section.to_string(),
+            selector: selector.to_string(),
+        })
+    }
+
+    /// セルの値を解釈できなかったことを記録する
+    ///
+    /// 厳密モードでは`DataParsingFailed`になります。
+    pub fn unparseable_cell(
+        &mut self,
+        section: &str,
+        row: usize,
+        column: usize,
+        value: &str,
+    ) -> Result<(), ParseError> {
+        self.warn(ParseWarning::UnparseableCell {
+            section: section.to_string(),
+            row,
+            column,
+            value: value.to_string(),
+        })
+    }
+
+    /// セルの値を解釈する
+    ///
+    /// 空欄（`&nbsp;`のみの場合を含む）は`None`になります。解釈できない値は
+    /// [`unparseable_cell`](Self::unparseable_cell)として記録したうえで`None`になります。
+    pub fn parse_cell<T: FromStr>(
+        &mut self,
+        section: &str,
+        row: usize,
+        column: usize,
+        value: &str,
+    ) -> Result<Option<T>, ParseError> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() || trimmed == "&nbsp;" {
+            return Ok(None);
+        }
+        match trimmed.parse() {
+            Ok(parsed) => Ok(Some(parsed)),
+            Err(_) => {
+                self.unparseable_cell(section, row, column, trimmed)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// 曜日・時限のセルを時限表に従って解釈する
+    ///
+    /// 空欄は`None`になります。解釈できない値は
+    /// [`unparseable_cell`](Self::unparseable_cell)として記録したうえで`None`になります。
+    pub fn parse_class_time(
+        &mut self,
+        section: &str,
+        row: usize,
+        column: usize,
+        value: &str,
+    ) -> Result<Option<ClassTime>, ParseError> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() || trimmed == "&nbsp;" {
+            return Ok(None);
+        }
+        match ClassTime::parse(trimmed, &self.period_table) {
+            Ok(class_time) => Ok(Some(class_time)),
+            Err(_) => {
+                self.unparseable_cell(section, row, column, trimmed)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// 既定値や代替の算出方法を使用したことを記録する
+    ///
+    /// 厳密モードでは`MissingRequiredField`になります。
+    pub fn fallback_used(&mut self, field: &str, fallback: &str) -> Result<(), ParseError> {
+        self.warn(ParseWarning::FallbackUsed {
+            field: field.to_string(),
+            fallback: fallback.to_string(),
+        })
+    }
+
+    /// 記録済みの警告
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+
+    /// 記録済みの診断情報（診断モードでない場合は`None`）
+    pub fn diagnostics(&self) -> Option<&ParseDiagnostics> {
+        self.diagnostics.as_ref()
+    }
+
+    /// 解析結果と記録済みの警告・診断情報をまとめる
+    pub fn finish<T>(self, data: T) -> ParseOutcome<T> {
+        ParseOutcome {
+            data,
+            warnings: self.warnings,
+            diagnostics: self.diagnostics,
+        }
+    }
+}
@@ -0,0 +1,620 @@
+//! # 日付・日時
+//!
+//! UNIPAの画面に表示される日付・日時を日本標準時（JST, UTC+9）の値として表す型と、
+//! その表記を解釈するパーサーです。[`parse`]は次の表記を受け付けます。
+//!
+//! - 日付: `2025年06月15日`・`2025/06/15`・`2025-06-15`・`2025.6.15`（続く`(日)`などの曜日は無視）
+//! - 日時: `2025/06/15 23:59`・`2025年6月15日 9時05分`・`2025-06-15T23:59:00+09:00`
+//! - 和暦: `令和7年6月15日`・`R7.6.15`・`平成元年1月8日`（令和・平成・昭和・大正）
+//! - 期間: `2025/06/15 10:00～12:00`・`2025/06/01～2025/06/15`（`～`・`〜`・`~`区切り）
+//!
+//! 全角の数字・記号は半角と同じに扱います。オフセットのない日時は日本標準時とみなし、
+//! 他のオフセットを持つ日時は日本標準時に変換します。
+//!
+//! シリアライズ時は、日付を`YYYY-MM-DD`、日時を`YYYY-MM-DDTHH:MM:SS+09:00`
+//! （RFC 3339）の文字列にします。日付を持たない時刻（[`ClockTime`]）は`HH:MM`です。
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use regex_lite::{Captures, Regex};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::common::period::Weekday;
+use crate::utils::error::ParseError;
+use crate::utils::time::{civil_from_days, days_from_civil};
+
+/// 日本標準時のUTCからのオフセット（秒）
+const JST_OFFSET_SECONDS: i64 = 9 * 3600;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// 日本標準時の日付
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl JstDate {
+    /// 年月日から作成する（存在しない日付は`ValueOutOfRange`エラー）
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self, ParseError> {
+        check_range("year", year, 1..=9999)?;
+        check_range("month", month, 1..=12)?;
+        check_range("day", day, 1..=days_in_month(year, month))?;
+        Ok(Self {
+            year,
+            month: month as u8,
+            day: day as u8,
+        })
+    }
+
+    /// 日付の表記を解釈する
+    ///
+    /// 時刻を含む表記や期間は`InvalidDataFormat`エラーになります。
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        match parse(text)? {
+            JstValue::Date(date) => Ok(date),
+            _ => Err(invalid("date", text, "expected a date without time")),
+        }
+    }
+
+    /// 年
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// 月（1〜12）
+    pub fn month(&self) -> u32 {
+        u32::from(self.month)
+    }
+
+    /// 日（1〜31）
+    pub fn day(&self) -> u32 {
+        u32::from(self.day)
+    }
+
+    /// 時刻を指定して日時にする
+    pub fn at(self, hour: u32, minute: u32, second: u32) -> Result<JstDateTime, ParseError> {
+        JstDateTime::new(self, hour, minute, second)
+    }
+
+    /// 曜日
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01は木曜日
+        Weekday::ALL[(self.days_since_epoch() + 3).rem_euclid(7) as usize]
+    }
+
+    /// `days`日後（負の場合は前）の日付
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// 1970-01-01からの日数
+    fn days_since_epoch(&self) -> i64 {
+        days_from_civil(i64::from(self.year), self.month(), self.day())
+    }
+
+    fn from_days_since_epoch(days: i64) -> Self {
+        let (year, month, day) = civil_from_days(days);
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+/// 日本標準時の日時（秒単位）
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstDateTime {
+    date: JstDate,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl JstDateTime {
+    /// 日付と時刻から作成する（範囲外の時刻は`ValueOutOfRange`エラー）
+    pub fn new(date: JstDate, hour: u32, minute: u32, second: u32) -> Result<Self, ParseError> {
+        check_range("hour", hour, 0..=23)?;
+        check_range("minute", minute, 0..=59)?;
+        check_range("second", second, 0..=59)?;
+        Ok(Self {
+            date,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+        })
+    }
+
+    /// 日時の表記を解釈する
+    ///
+    /// 時刻を含まない表記や期間は`InvalidDataFormat`エラーになります。
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        match parse(text)? {
+            JstValue::DateTime(datetime) => Ok(datetime),
+            _ => Err(invalid("datetime", text, "expected a date with time")),
+        }
+    }
+
+    /// UNIX時刻（秒）から作成する
+    pub fn from_unix_seconds(unix_seconds: i64) -> Self {
+        let local = unix_seconds + JST_OFFSET_SECONDS;
+        let seconds_of_day = local.rem_euclid(SECONDS_PER_DAY);
+        Self {
+            date: JstDate::from_days_since_epoch(local.div_euclid(SECONDS_PER_DAY)),
+            hour: (seconds_of_day / 3600) as u8,
+            minute: (seconds_of_day % 3600 / 60) as u8,
+            second: (seconds_of_day % 60) as u8,
+        }
+    }
+
+    /// UNIX時刻（秒）
+    pub fn unix_seconds(&self) -> i64 {
+        self.date.days_since_epoch() * SECONDS_PER_DAY
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+            - JST_OFFSET_SECONDS
+    }
+
+    /// 日付
+    pub fn date(&self) -> JstDate {
+        self.date
+    }
+
+    /// 時（0〜23）
+    pub fn hour(&self) -> u32 {
+        u32::from(self.hour)
+    }
+
+    /// 分（0〜59）
+    pub fn minute(&self) -> u32 {
+        u32::from(self.minute)
+    }
+
+    /// 秒（0〜59）
+    pub fn second(&self) -> u32 {
+        u32::from(self.second)
+    }
+}
+
+/// 日付を持たない時刻（分単位）
+///
+/// 時限の開始・終了時刻のように、毎週繰り返す時刻を表します。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClockTime {
+    hour: u8,
+    minute: u8,
+}
+
+impl ClockTime {
+    /// 時・分から作成する（範囲外の値は`ValueOutOfRange`エラー）
+    pub fn new(hour: u32, minute: u32) -> Result<Self, ParseError> {
+        check_range("hour", hour, 0..=23)?;
+        check_range("minute", minute, 0..=59)?;
+        Ok(Self {
+            hour: hour as u8,
+            minute: minute as u8,
+        })
+    }
+
+    /// 時刻の表記（`9:00`・`09:00`・`9時00分`）を解釈する
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let normalized = normalize(text);
+        let captures = time_regex()
+            .captures(normalized.trim())
+            .ok_or_else(|| invalid("time", text, "unrecognized time notation"))?;
+        match time_from(&captures)? {
+            (hour, minute, 0, None) => Self::new(hour, minute),
+            _ => Err(invalid("time", text, "expected hours and minutes only")),
+        }
+    }
+
+    /// 時（0〜23）
+    pub fn hour(&self) -> u32 {
+        u32::from(self.hour)
+    }
+
+    /// 分（0〜59）
+    pub fn minute(&self) -> u32 {
+        u32::from(self.minute)
+    }
+
+    /// 指定した日付のこの時刻
+    pub fn on(self, date: JstDate) -> JstDateTime {
+        JstDateTime {
+            date,
+            hour: self.hour,
+            minute: self.minute,
+            second: 0,
+        }
+    }
+}
+
+/// 開始と終了からなる期間（両端を含む）
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct JstRange<T> {
+    /// 開始
+    pub start: T,
+    /// 終了
+    pub end: T,
+}
+
+impl<T: PartialOrd + fmt::Display> JstRange<T> {
+    /// 開始と終了から作成する（終了が開始より前の場合は`ValueOutOfRange`エラー）
+    pub fn new(start: T, end: T) -> Result<Self, ParseError> {
+        if end < start {
+            return Err(ParseError::ValueOutOfRange {
+                field: "end".to_string(),
+                value: end.to_string(),
+                range: format!("{start} or later"),
+            });
+        }
+        Ok(Self { start, end })
+    }
+
+    /// 値が期間内にあるか
+    pub fn contains(&self, value: &T) -> bool {
+        &self.start <= value && value <= &self.end
+    }
+}
+
+impl JstRange<JstDate> {
+    /// 日付の期間の表記を解釈する
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        match parse(text)? {
+            JstValue::DateRange(range) => Ok(range),
+            _ => Err(invalid("date range", text, "expected a range of dates")),
+        }
+    }
+}
+
+impl JstRange<JstDateTime> {
+    /// 日時の期間の表記を解釈する
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        match parse(text)? {
+            JstValue::DateTimeRange(range) => Ok(range),
+            _ => Err(invalid(
+                "datetime range",
+                text,
+                "expected a range of datetimes",
+            )),
+        }
+    }
+}
+
+/// [`parse`]の解析結果（表記に応じた日付・日時・期間）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JstValue {
+    /// 日付
+    Date(JstDate),
+    /// 日時
+    DateTime(JstDateTime),
+    /// 日付の期間
+    DateRange(JstRange<JstDate>),
+    /// 日時の期間
+    DateTimeRange(JstRange<JstDateTime>),
+}
+
+/// 日付・日時・期間の表記を解釈する
+///
+/// 期間の終了側は時刻のみ（`2025/06/15 10:00～12:00`）でも構いません。その場合は
+/// 開始と同じ日付とみなし、開始より前の時刻であれば翌日として扱います。
+pub fn parse(text: &str) -> Result<JstValue, ParseError> {
+    let normalized = normalize(text);
+    let mut parts = normalized.split('~').map(str::trim);
+    let start = parts.next().unwrap_or_default();
+    let end = parts.next();
+    if parts.next().is_some() {
+        return Err(invalid("date", text, "more than one range separator"));
+    }
+
+    let start = parse_point(start).ok_or_else(|| unrecognized(text))??;
+    let Some(end) = end else {
+        return match start {
+            Point::Date(date) => Ok(JstValue::Date(date)),
+            Point::DateTime(datetime) => Ok(JstValue::DateTime(datetime)),
+            Point::Time(..) => Err(unrecognized(text)),
+        };
+    };
+    let end = parse_point(end).ok_or_else(|| unrecognized(text))??;
+
+    match (start, end) {
+        (Point::Date(start), Point::Date(end)) => {
+            Ok(JstValue::DateRange(JstRange::new(start, end)?))
+        }
+        (Point::DateTime(start), Point::DateTime(end)) => {
+            Ok(JstValue::DateTimeRange(JstRange::new(start, end)?))
+        }
+        (Point::DateTime(start), Point::Time(hour, minute, second, offset)) => {
+            let mut end = with_offset(start.date.at(hour, minute, second)?, offset);
+            if end < start {
+                end = JstDateTime::from_unix_seconds(end.unix_seconds() + SECONDS_PER_DAY);
+            }
+            Ok(JstValue::DateTimeRange(JstRange::new(start, end)?))
+        }
+        _ => Err(invalid(
+            "date",
+            text,
+            "range must have both ends as dates or as datetimes",
+        )),
+    }
+}
+
+/// 期間の片側の表記
+enum Point {
+    Date(JstDate),
+    DateTime(JstDateTime),
+    /// 時・分・秒・UTCからのオフセット（秒）
+    Time(u32, u32, u32, Option<i64>),
+}
+
+const DATE_PATTERN: &str = r"(?:(?P<era>令和|平成|昭和|大正|[RHST])\s*(?P<era_year>\d{1,2}|元)|(?P<year>\d{4}))\s*[年/.\-]\s*(?P<month>\d{1,2})\s*[月/.\-]\s*(?P<day>\d{1,2})\s*日?(?:\s*\([^)]*\))?";
+
+const TIME_PATTERN: &str = r"(?P<hour>\d{1,2})\s*[:時]\s*(?P<minute>\d{1,2})\s*分?(?::(?P<second>\d{1,2})|\s*(?P<second_ja>\d{1,2})\s*秒)?(?:\s*(?P<offset>Z|[+-]\d{2}:?\d{2}))?";
+
+fn point_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(&format!(r"^{DATE_PATTERN}(?:\s*T?\s*{TIME_PATTERN})?$"))
+            .expect("date pattern is valid")
+    })
+}
+
+fn time_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(&format!(r"^{TIME_PATTERN}$")).expect("time pattern is valid"))
+}
+
+/// 期間の片側を解釈する（該当する表記でない場合は`None`）
+fn parse_point(text: &str) -> Option<Result<Point, ParseError>> {
+    if let Some(captures) = point_regex().captures(text) {
+        return Some(point_from(&captures));
+    }
+    let captures = time_regex().captures(text)?;
+    Some(time_from(&captures).map(|(h, m, s, offset)| Point::Time(h, m, s, offset)))
+}
+
+fn point_from(captures: &Captures) -> Result<Point, ParseError> {
+    let year = match (captures.name("era"), captures.name("era_year")) {
+        (Some(era), Some(era_year)) => western_year(era.as_str(), era_year.as_str())?,
+        _ => number(&captures["year"]) as i32,
+    };
+    let date = JstDate::new(year, number(&captures["month"]), number(&captures["day"]))?;
+
+    if captures.name("hour").is_none() {
+        return Ok(Point::Date(date));
+    }
+    let (hour, minute, second, offset) = time_from(captures)?;
+    Ok(Point::DateTime(with_offset(
+        date.at(hour, minute, second)?,
+        offset,
+    )))
+}
+
+fn time_from(captures: &Captures) -> Result<(u32, u32, u32, Option<i64>), ParseError> {
+    let second = captures
+        .name("second")
+        .or_else(|| captures.name("second_ja"))
+        .map_or(0, |m| number(m.as_str()));
+    let offset = match captures.name("offset").map(|m| m.as_str()) {
+        None => None,
+        Some("Z") => Some(0),
+        Some(offset) => {
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let digits: String = offset.chars().filter(char::is_ascii_digit).collect();
+            let hours = i64::from(number(&digits[..2]));
+            let minutes = i64::from(number(&digits[2..]));
+            Some(sign * (hours * 3600 + minutes * 60))
+        }
+    };
+    Ok((
+        number(&captures["hour"]),
+        number(&captures["minute"]),
+        second,
+        offset,
+    ))
+}
+
+/// 表記上のオフセットの時刻を日本標準時に変換する
+fn with_offset(local: JstDateTime, offset: Option<i64>) -> JstDateTime {
+    match offset {
+        Some(offset) if offset != JST_OFFSET_SECONDS => {
+            JstDateTime::from_unix_seconds(local.unix_seconds() + JST_OFFSET_SECONDS - offset)
+        }
+        _ => local,
+    }
+}
+
+/// 和暦の元号（`令和`・`R`など）と年（数字または`元`）を西暦にする
+pub(crate) fn western_year(era: &str, era_year: &str) -> Result<i32, ParseError> {
+    let era_year = match era_year {
+        "元" => 1,
+        digits => number(digits),
+    };
+    check_range("era_year", era_year, 1..=99)?;
+    Ok(era_base_year(era) + era_year as i32)
+}
+
+/// 和暦の元年の前年（西暦）
+fn era_base_year(era: &str) -> i32 {
+    match era {
+        "令和" | "R" => 2018,
+        "平成" | "H" => 1988,
+        "昭和" | "S" => 1925,
+        _ => 1911,
+    }
+}
+
+/// 全角の数字・記号を半角にそろえ、期間の区切りを`~`に統一する
+pub(crate) fn normalize(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .chars()
+        .map(|c| match c {
+            '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap_or(c),
+            '～' | '〜' => '~',
+            '／' => '/',
+            '：' => ':',
+            '．' => '.',
+            '－' | '−' => '-',
+            '＋' => '+',
+            '（' => '(',
+            '）' => ')',
+            '　' => ' ',
+            _ => c,
+        })
+        .collect()
+}
+
+/// 正規表現で数字のみと確認済みの文字列を数値にする
+fn number(digits: &str) -> u32 {
+    digits.parse().unwrap_or_default()
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn check_range<T: PartialOrd + fmt::Display>(
+    field: &str,
+    value: T,
+    range: RangeInclusive<T>,
+) -> Result<(), ParseError> {
+    if range.contains(&value) {
+        return Ok(());
+    }
+    Err(ParseError::ValueOutOfRange {
+        field: field.to_string(),
+        value: value.to_string(),
+        range: format!("{}..={}", range.start(), range.end()),
+    })
+}
+
+fn invalid(data_type: &str, text: &str, reason: &str) -> ParseError {
+    ParseError::InvalidDataFormat {
+        data_type: data_type.to_string(),
+        details: format!("{reason}: {text:?}"),
+    }
+}
+
+fn unrecognized(text: &str) -> ParseError {
+    invalid("date", text, "unrecognized date notation")
+}
+
+impl fmt::Display for JstDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for JstDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}+09:00",
+            self.date, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl fmt::Display for ClockTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl FromStr for JstDate {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl FromStr for JstDateTime {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl FromStr for ClockTime {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+/// 文字列としてシリアライズし、デシリアライズ時は各型の`parse`が受け付ける表記をすべて受け付ける
+macro_rules! string_serde {
+    ($($ty:ident => $decl:literal, $pattern:literal $(, $format:literal)?;)*) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let text = String::deserialize(deserializer)?;
+                text.parse().map_err(de::Error::custom)
+            }
+        }
+
+        const _: () = {
+            #[wasm_bindgen(typescript_custom_section)]
+            const TS_APPEND_CONTENT: &'static str = $decl;
+        };
+
+        impl Tsify for $ty {
+            type JsType = JsValue;
+            const DECL: &'static str = $decl;
+        }
+
+        #[cfg(feature = "schema")]
+        impl schemars::JsonSchema for $ty {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                stringify!($ty).into()
+            }
+
+            fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                #[allow(unused_mut)]
+                let mut schema = schemars::json_schema!({
+                    "type": "string",
+                    "pattern": $pattern
+                });
+                $(schema.insert("format".into(), $format.into());)?
+                schema
+            }
+        }
+    )*};
+}
+
+string_serde! {
+    JstDate => "/**\n * 日本標準時の日付（`YYYY-MM-DD`形式）\n */\nexport type JstDate = string;",
+        r"^\d{4}-\d{2}-\d{2}$", "date";
+    JstDateTime => "/**\n * 日本標準時の日時（`YYYY-MM-DDTHH:MM:SS+09:00`形式）\n */\nexport type JstDateTime = string;",
+        r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}\+09:00$", "date-time";
+    ClockTime => "/**\n * 時刻（`HH:MM`形式）\n */\nexport type ClockTime = string;",
+        r"^\d{2}:\d{2}$";
+}
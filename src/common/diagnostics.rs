@@ -0,0 +1,130 @@
+//! # 診断トレース
+//!
+//! 診断モードで解析した際に、試行したセレクターとその一致件数、
+//! カンマ区切りの候補のうち実際に一致したもの、読み飛ばした行を記録します。
+//! UNIPA側のDOM構造が変わった際に、どのセレクターが外れたかを特定するために使用します。
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use super::dom::DomElement;
+
+/// 1回の解析で記録された診断情報
+#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ParseDiagnostics {
+    /// 試行したセレクター（試行順）
+    pub selectors: Vec<SelectorTrace>,
+    /// 読み飛ばした行
+    pub skipped_rows: Vec<SkippedRow>,
+}
+
+/// セレクター1件分の試行結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct SelectorTrace {
+    /// 解析中のセクション名
+    pub section: String,
+    /// 試行したセレクター
+    pub selector: String,
+    /// 一致した要素数
+    pub match_count: usize,
+    /// 最初に一致した要素に一致する候補（`a, b, c`形式のセレクターのうち実際に使われたもの）
+    ///
+    /// 一致する要素がない場合は空になります。
+    pub matched_alternatives: Vec<String>,
+}
+
+/// 解析対象から外した行
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct SkippedRow {
+    /// 解析中のセクション名
+    pub section: String,
+    /// 行番号（0始まり）
+    pub row: usize,
+    /// 行に含まれていたセル数
+    pub cell_count: usize,
+    /// 読み飛ばした理由
+    pub reason: String,
+}
+
+impl ParseDiagnostics {
+    /// `scope`配下でセレクターを照合した結果を記録する
+    ///
+    /// 解析できないセレクターは一致件数0として記録します。
+    pub fn record_selector<E: DomElement>(&mut self, section: &str, selector: &str, scope: E) {
+        let matches = scope.select_all_css(selector);
+        let match_count = matches.len();
+
+        let matched_alternatives = matches
+            .first()
+            .map(|element| {
+                split_selector_list(selector)
+                    .into_iter()
+                    .filter(|alternative| element.matches_css(alternative))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.selectors.push(SelectorTrace {
+            section: section.to_string(),
+            selector: selector.to_string(),
+            match_count,
+            matched_alternatives,
+        });
+    }
+
+    /// 行を読み飛ばしたことを記録する
+    pub fn record_skipped_row(
+        &mut self,
+        section: &str,
+        row: usize,
+        cell_count: usize,
+        reason: &str,
+    ) {
+        self.skipped_rows.push(SkippedRow {
+            section: section.to_string(),
+            row,
+            cell_count,
+            reason: reason.to_string(),
+        });
+    }
+
+    /// 一致する要素がなかったセレクター
+    pub fn unmatched_selectors(&self) -> impl Iterator<Item = &SelectorTrace> {
+        self.selectors.iter().filter(|trace| trace.match_count == 0)
+    }
+}
+
+/// セレクターリストをトップレベルのカンマで分割する
+///
+/// 括弧や引用符の内側のカンマ（`:is(a, b)`や`[title='a,b']`）では分割しません。
+fn split_selector_list(selector: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+
+    for (index, c) in selector.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                parts.push(selector[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(selector[start..].trim());
+
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
@@ -0,0 +1,123 @@
+//! # DOMアクセスの抽象化
+//!
+//! 抽出ロジックを`scraper`で解析したHTMLとブラウザのライブDOM（`web_sys::Element`）の
+//! 両方で共有するためのトレイトを定義します。
+//!
+//! ブラウザ拡張機能のように既にページが読み込まれている環境では、
+//! `outerHTML`を文字列化して再解析する代わりに、DOMを直接たどって同じ結果を得られます。
+//! セレクターは[`CompiledSelector`]の元の文字列を`querySelectorAll`に渡して照合します。
+
+use scraper::{ElementRef, Selector};
+
+use super::selectors::CompiledSelector;
+
+/// 抽出処理から参照される要素の操作
+pub trait DomElement: Clone {
+    /// 子孫要素のうちセレクターに一致するものを文書順に返す
+    fn select_all(&self, selector: &CompiledSelector) -> Vec<Self>;
+
+    /// 子孫要素のうちセレクターに最初に一致するものを返す
+    fn select_first(&self, selector: &CompiledSelector) -> Option<Self>;
+
+    /// 構築済みでないセレクター文字列で子孫要素を照合する
+    ///
+    /// 診断情報の記録など、レジストリにないセレクターを扱う場合に使用します。
+    /// 解析できないセレクターの場合は空を返します。
+    fn select_all_css(&self, selector: &str) -> Vec<Self>;
+
+    /// 要素自身がセレクター文字列に一致するか
+    fn matches_css(&self, selector: &str) -> bool;
+
+    /// 属性値を取得する
+    fn attr(&self, name: &str) -> Option<String>;
+
+    /// 要素の内部HTMLを取得する
+    fn inner_html(&self) -> String;
+}
+
+impl DomElement for ElementRef<'_> {
+    fn select_all(&self, selector: &CompiledSelector) -> Vec<Self> {
+        self.select(selector).collect()
+    }
+
+    fn select_first(&self, selector: &CompiledSelector) -> Option<Self> {
+        self.select(selector).next()
+    }
+
+    fn select_all_css(&self, selector: &str) -> Vec<Self> {
+        Selector::parse(selector)
+            .map(|parsed| self.select(&parsed).collect())
+            .unwrap_or_default()
+    }
+
+    fn matches_css(&self, selector: &str) -> bool {
+        Selector::parse(selector)
+            .map(|parsed| parsed.matches(self))
+            .unwrap_or(false)
+    }
+
+    fn attr(&self, name: &str) -> Option<String> {
+        self.value().attr(name).map(str::to_string)
+    }
+
+    fn inner_html(&self) -> String {
+        ElementRef::inner_html(self)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub use web::document_root;
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use wasm_bindgen::JsCast;
+    use web_sys::{Document, Element, NodeList};
+
+    use super::DomElement;
+    use crate::common::selectors::CompiledSelector;
+    use crate::utils::error::ParseError;
+
+    /// ドキュメントのルート要素（`<html>`）を取得する
+    pub fn document_root(document: &Document) -> Result<Element, ParseError> {
+        document.document_element().ok_or_else(|| {
+            ParseError::element_not_found("html", "ドキュメントにルート要素がありません")
+        })
+    }
+
+    /// `querySelectorAll`の結果から要素のみを取り出す
+    fn elements(list: Option<NodeList>) -> Vec<Element> {
+        let Some(list) = list else {
+            return Vec::new();
+        };
+        (0..list.length())
+            .filter_map(|index| list.item(index))
+            .filter_map(|node| node.dyn_into::<Element>().ok())
+            .collect()
+    }
+
+    impl DomElement for Element {
+        fn select_all(&self, selector: &CompiledSelector) -> Vec<Self> {
+            self.select_all_css(selector.source())
+        }
+
+        fn select_first(&self, selector: &CompiledSelector) -> Option<Self> {
+            self.query_selector(selector.source()).ok().flatten()
+        }
+
+        fn select_all_css(&self, selector: &str) -> Vec<Self> {
+            elements(self.query_selector_all(selector).ok())
+        }
+
+        fn matches_css(&self, selector: &str) -> bool {
+            self.matches(selector).unwrap_or(false)
+        }
+
+        fn attr(&self, name: &str) -> Option<String> {
+            self.get_attribute(name)
+        }
+
+        fn inner_html(&self) -> String {
+            Element::inner_html(self)
+        }
+    }
+}
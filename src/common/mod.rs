@@ -3,4 +3,13 @@
 //! This module provides shared traits, interfaces, and utilities used across
 //! different parser modules in the Universal Passport EX library.
 
+pub mod context;
+pub mod datetime;
+pub mod diagnostics;
+pub mod dom;
+pub mod options;
+pub mod outcome;
+pub mod period;
+pub mod selectors;
+pub mod term;
 pub mod traits;
@@ -0,0 +1,63 @@
+//! # 解析オプション
+//!
+//! すべてのパーサーで共通の動作設定です。各ビルダーの`with_options`に渡すか、
+//! `with_strict_mode`・`with_debug_mode`などの個別のメソッドで設定します。
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::common::period::PeriodTable;
+
+/// パーサー共通の解析オプション
+#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ParseOptions {
+    /// 厳密モード
+    ///
+    /// 有効な場合、通常は警告として記録される既定値の使用や期待した要素の欠落を
+    /// エラー（`ElementNotFound`・`MissingRequiredField`など）として扱います。
+    #[serde(default)]
+    pub strict_mode: bool,
+    /// デバッグモード
+    ///
+    /// 有効な場合、警告付きの解析結果に診断情報を含めます。
+    #[serde(default)]
+    pub debug_mode: bool,
+    /// 時限表
+    ///
+    /// 授業の曜日・時限（`ClassTime`）の開始・終了時刻を
+    /// 求めるために使います。省略時は1限 9:00〜10:30から始まる1コマ90分の時限表です。
+    #[serde(default)]
+    pub period_table: PeriodTable,
+}
+
+impl ParseOptions {
+    /// 既定のオプション（厳密モード・デバッグモードともに無効）を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 厳密モードを有効にしたオプションを作成
+    pub fn strict() -> Self {
+        Self::new().with_strict_mode(true)
+    }
+
+    /// 厳密モードを設定する
+    pub fn with_strict_mode(mut self, enabled: bool) -> Self {
+        self.strict_mode = enabled;
+        self
+    }
+
+    /// デバッグモードを設定する
+    pub fn with_debug_mode(mut self, enabled: bool) -> Self {
+        self.debug_mode = enabled;
+        self
+    }
+
+    /// 時限表を設定する
+    pub fn with_period_table(mut self, table: PeriodTable) -> Self {
+        self.period_table = table;
+        self
+    }
+}
@@ -0,0 +1,130 @@
+//! # 解析結果と警告
+//!
+//! 解析は成功したものの、一部のセクションが欠落していたりセルを解釈できずに
+//! 既定値で補った場合に、その内容を構造化された警告として呼び出し元へ返します。
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use super::diagnostics::ParseDiagnostics;
+use crate::utils::error::ParseError;
+
+/// 警告付きの解析結果
+///
+/// `warnings`が空でない場合、`data`の一部は既定値や推定値で補われています。
+#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ParseOutcome<T> {
+    /// 解析されたデータ
+    pub data: T,
+    /// 解析中に発生した警告
+    pub warnings: Vec<ParseWarning>,
+    /// 診断モードで解析した場合のセレクターの試行記録
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub diagnostics: Option<ParseDiagnostics>,
+}
+
+impl<T> ParseOutcome<T> {
+    /// 警告なしの解析結果を作成
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            warnings: Vec::new(),
+            diagnostics: None,
+        }
+    }
+
+    /// 警告が1件もないかどうか
+    pub fn is_complete(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// 警告を保持したままデータを変換する
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ParseOutcome<U> {
+        ParseOutcome {
+            data: f(self.data),
+            warnings: self.warnings,
+            diagnostics: self.diagnostics,
+        }
+    }
+}
+
+/// 解析中に発生した警告
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ParseWarning {
+    /// 任意のセクションが見つからなかった
+    MissingSection {
+        /// セクション名
+        section: String,
+        /// 探索に使用したセレクター
+        selector: String,
+    },
+    /// セルの値を解釈できなかった
+    UnparseableCell {
+        /// セクション名
+        section: String,
+        /// 行番号（0始まり）
+        row: usize,
+        /// 列番号（0始まり）
+        column: usize,
+        /// セルの値
+        value: String,
+    },
+    /// 既定値や代替の算出方法を使用した
+    FallbackUsed {
+        /// 対象のフィールド
+        field: String,
+        /// 使用した代替値または算出方法
+        fallback: String,
+    },
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseWarning::MissingSection { section, selector } => {
+                write!(f, "section `{section}` not found (selector: {selector})")
+            }
+            ParseWarning::UnparseableCell {
+                section,
+                row,
+                column,
+                value,
+            } => write!(
+                f,
+                "unparseable cell in `{section}` at row {row}, column {column}: {value:?}"
+            ),
+            ParseWarning::FallbackUsed { field, fallback } => {
+                write!(f, "`{field}` fell back to {fallback:?}")
+            }
+        }
+    }
+}
+
+/// 厳密モードでは警告をそのままエラーとして返す
+impl From<ParseWarning> for ParseError {
+    fn from(warning: ParseWarning) -> Self {
+        match warning {
+            ParseWarning::MissingSection { section, selector } => {
+                ParseError::element_not_found(&selector, &section)
+            }
+            ParseWarning::UnparseableCell {
+                section,
+                row,
+                column,
+                value,
+            } => ParseError::data_parsing_failed(
+                &format!("{section} (row {row}, column {column})"),
+                &value,
+            ),
+            ParseWarning::FallbackUsed { field, .. } => ParseError::missing_required_field(&field),
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! # 曜日・時限
+//!
+//! 時間割やシラバス、ポータルの本日の時限などに`月3`・`火曜3限 火曜4限`・`集中`のような
+//! 表記で表示される授業の曜日・時限を[`ClassTime`]として表します。
+//! 時限の開始・終了時刻は[`PeriodTable`]（解析オプションの`period_table`）から求めます。
+//!
+//! [`ClassTime::parse`]は次の表記を受け付けます。
+//!
+//! - 曜日と時限: `月3`・`月 3`・`月曜3限`・`月曜日 3時限`・`4/14(月) 3限`
+//! - 複数の時限: `月3-4`・`月3・4`・`月3〜5`・`火曜3限 火曜4限`・`月1, 水2`
+//! - 時限のみ: `1限`・`3時限`・`3`（数字のみの表記を含む。曜日は`None`）
+//! - 集中講義: `集中`・`集中講義`など`集中`を含む表記
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use regex_lite::Regex;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::common::datetime::{normalize, ClockTime};
+use crate::utils::error::ParseError;
+
+/// 曜日
+#[derive(
+    Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Weekday {
+    /// 月曜日
+    Monday,
+    /// 火曜日
+    Tuesday,
+    /// 水曜日
+    Wednesday,
+    /// 木曜日
+    Thursday,
+    /// 金曜日
+    Friday,
+    /// 土曜日
+    Saturday,
+    /// 日曜日
+    Sunday,
+}
+
+impl Weekday {
+    /// 月曜日から順に並べたすべての曜日
+    pub const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// 曜日の1文字表記（`月`など）から取得する
+    pub fn from_kanji(kanji: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|day| day.kanji() == kanji)
+    }
+
+    /// 曜日の1文字表記
+    pub fn kanji(&self) -> &'static str {
+        match self {
+            Self::Monday => "月",
+            Self::Tuesday => "火",
+            Self::Wednesday => "水",
+            Self::Thursday => "木",
+            Self::Friday => "金",
+            Self::Saturday => "土",
+            Self::Sunday => "日",
+        }
+    }
+
+    /// 月曜日を0とした番号
+    pub fn days_from_monday(&self) -> u32 {
+        *self as u32
+    }
+}
+
+/// 1コマ分の曜日・時限と、その開始・終了時刻
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ClassSlot {
+    /// 曜日（`1限`のように曜日が表記されていない場合は`None`）
+    pub weekday: Option<Weekday>,
+    /// 時限
+    pub period: u32,
+    /// 開始時刻（時限表にない時限の場合は`None`）
+    pub start: Option<ClockTime>,
+    /// 終了時刻（時限表にない時限の場合は`None`）
+    pub end: Option<ClockTime>,
+}
+
+impl ClassSlot {
+    /// 曜日と時限から、時限表の時刻を補って作成する
+    pub fn new(weekday: Option<Weekday>, period: u32, table: &PeriodTable) -> Self {
+        let time = table.get(period);
+        Self {
+            weekday,
+            period,
+            start: time.map(|time| time.start),
+            end: time.map(|time| time.end),
+        }
+    }
+}
+
+/// 授業の曜日・時限
+#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ClassTime {
+    /// 集中講義など、週ごとの曜日・時限を持たない授業か
+    pub is_intensive: bool,
+    /// 曜日・時限（連続する時限も1コマずつ、表記の順に並ぶ）
+    pub slots: Vec<ClassSlot>,
+}
+
+impl ClassTime {
+    /// 曜日・時限の表記を解釈し、時限表から開始・終了時刻を補う
+    ///
+    /// 曜日・時限も`集中`も含まない表記は`InvalidDataFormat`エラーになります。
+    pub fn parse(text: &str, table: &PeriodTable) -> Result<Self, ParseError> {
+        let normalized = normalize(&text.replace("<br>", " ").replace("<BR>", " "));
+        let is_intensive = normalized.contains("集中");
+        if let Ok(period) = normalized.trim().parse::<u32>() {
+            return Ok(Self {
+                is_intensive,
+                slots: vec![ClassSlot::new(None, period, table)],
+            });
+        }
+
+        let mut slots = Vec::new();
+        for captures in slot_regex().captures_iter(&normalized) {
+            let weekday = captures
+                .name("weekday")
+                .and_then(|m| Weekday::from_kanji(m.as_str()));
+            // 曜日のない数字は「限」が続く場合のみ時限とみなす（日付などの数字を除く）
+            if weekday.is_none() && !captures["periods"].contains('限') {
+                continue;
+            }
+            for period in periods(&captures["periods"])? {
+                let slot = ClassSlot::new(weekday, period, table);
+                if !slots.contains(&slot) {
+                    slots.push(slot);
+                }
+            }
+        }
+
+        if slots.is_empty() && !is_intensive {
+            return Err(ParseError::InvalidDataFormat {
+                data_type: "class time".to_string(),
+                details: format!("unrecognized weekday and period notation: {text:?}"),
+            });
+        }
+        Ok(Self {
+            is_intensive,
+            slots,
+        })
+    }
+}
+
+/// 時限ごとの開始・終了時刻
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct PeriodTime {
+    /// 時限
+    pub period: u32,
+    /// 開始時刻
+    pub start: ClockTime,
+    /// 終了時刻
+    pub end: ClockTime,
+}
+
+/// 時限表（各時限の開始・終了時刻）
+///
+/// 既定は1コマ90分の時限表（1限 9:00〜10:30、2限 10:40〜12:10、3限 13:00〜14:30、
+/// 4限 14:40〜16:10、5限 16:20〜17:50、6限 18:00〜19:30、7限 19:40〜21:10）です。
+/// 大学によって異なる場合は解析オプションの`period_table`で指定します。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct PeriodTable {
+    /// 各時限の開始・終了時刻
+    pub periods: Vec<PeriodTime>,
+}
+
+impl PeriodTable {
+    /// 時限ごとの開始・終了時刻から作成する
+    pub fn new(periods: Vec<PeriodTime>) -> Self {
+        Self { periods }
+    }
+
+    /// 時限の開始・終了時刻
+    pub fn get(&self, period: u32) -> Option<PeriodTime> {
+        self.periods
+            .iter()
+            .find(|time| time.period == period)
+            .copied()
+    }
+}
+
+impl Default for PeriodTable {
+    fn default() -> Self {
+        const STANDARD: [((u32, u32), (u32, u32)); 7] = [
+            ((9, 0), (10, 30)),
+            ((10, 40), (12, 10)),
+            ((13, 0), (14, 30)),
+            ((14, 40), (16, 10)),
+            ((16, 20), (17, 50)),
+            ((18, 0), (19, 30)),
+            ((19, 40), (21, 10)),
+        ];
+        let periods = (1..)
+            .zip(STANDARD)
+            .filter_map(|(period, (start, end))| {
+                Some(PeriodTime {
+                    period,
+                    start: ClockTime::new(start.0, start.1).ok()?,
+                    end: ClockTime::new(end.0, end.1).ok()?,
+                })
+            })
+            .collect();
+        Self { periods }
+    }
+}
+
+fn slot_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"(?:(?P<weekday>[月火水木金土日])(?:曜日?)?\)?\s*)?(?P<periods>\d{1,2}(?:\s*時?限)?(?:\s*[-~〜・,、]\s*\d{1,2}(?:\s*時?限)?)*)",
+        )
+        .expect("class slot pattern is valid")
+    })
+}
+
+/// `3-5`・`3・4`のような時限の並びを展開する
+fn periods(text: &str) -> Result<Vec<u32>, ParseError> {
+    let mut periods = Vec::new();
+    for part in text.split(['・', ',', '、']) {
+        let mut bounds = part
+            .split(['-', '~', '〜'])
+            .map(|bound| bound.trim().trim_end_matches(['限', '時']).trim())
+            .map(|bound| bound.parse::<u32>().unwrap_or_default());
+        let first = bounds.next().unwrap_or_default();
+        let last = bounds.next_back().unwrap_or(first);
+        if last < first {
+            return Err(ParseError::ValueOutOfRange {
+                field: "period".to_string(),
+                value: text.to_string(),
+                range: format!("{first} or later"),
+            });
+        }
+        periods.extend(first..=last);
+    }
+    Ok(periods)
+}
+
+impl fmt::Display for ClassSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(weekday) = self.weekday {
+            f.write_str(weekday.kanji())?;
+        }
+        write!(f, "{}限", self.period)
+    }
+}
@@ -0,0 +1,128 @@
+//! # セレクターレジストリ
+//!
+//! 各ページのパーサーが使用するCSSセレクターを、プロセス内で一度だけ構築して再利用するための仕組みです。
+//! 行ごとに`Selector::parse`を呼び出すと、数千ページを一括で解析する際に無視できないコストになるため、
+//! ページ種別ごとに[`selector_registry!`]でセレクター群を宣言し、初回アクセス時にまとめて構築します。
+//!
+//! 不正なセレクターが含まれていた場合は初回の構築時に`SelectorCreationFailed`となり、
+//! 以降の呼び出しでも同じエラーを返します。
+//!
+//! レジストリの各セレクターは元のセレクター文字列も保持しているため、
+//! ブラウザのDOMに対して`querySelectorAll`で同じ照合を行うこともできます（[`crate::common::dom`]）。
+
+use std::ops::Deref;
+
+use scraper::Selector;
+
+use crate::utils::error::ParseError;
+
+/// セレクター文字列を構築する
+///
+/// 構築に失敗した場合は`SelectorCreationFailed`エラーを返します。
+pub fn compile(selector: &str) -> Result<Selector, ParseError> {
+    Selector::parse(selector)
+        .map_err(|e| ParseError::selector_creation_failed(selector, &e.to_string()))
+}
+
+/// 構築済みのセレクターと、その元になったセレクター文字列の組
+///
+/// `Deref`により`&Selector`が必要な箇所へそのまま渡せます。
+#[derive(Debug, Clone)]
+pub struct CompiledSelector {
+    source: &'static str,
+    selector: Selector,
+}
+
+impl CompiledSelector {
+    /// セレクター文字列を構築する
+    pub fn new(source: &'static str) -> Result<Self, ParseError> {
+        Ok(Self {
+            source,
+            selector: compile(source)?,
+        })
+    }
+
+    /// 元のセレクター文字列
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+}
+
+impl Deref for CompiledSelector {
+    type Target = Selector;
+
+    fn deref(&self) -> &Selector {
+        &self.selector
+    }
+}
+
+/// ページ種別ごとのセレクター群を宣言する
+///
+/// 各フィールドに対応するセレクター文字列を指定すると、構築済みの`Selector`を
+/// フィールドとして持つ構造体と、遅延初期化されたインスタンスを返す`get()`を生成します。
+/// 各フィールドは[`CompiledSelector`]で、優先順位付きで試行する候補は`[...]`で列挙でき、
+/// その場合のフィールドは`Vec<CompiledSelector>`になります。
+///
+/// ```ignore
+/// selector_registry! {
+///     /// 成績照会画面のセレクター
+///     struct GradeSelectors {
+///         row: "table.listTable tbody tr",
+///         cell: "td",
+///         title: [".title", "h1", "h2"],
+///     }
+/// }
+///
+/// let selectors = GradeSelectors::get()?;
+/// for row in document.select(&selectors.row) { /* ... */ }
+/// ```
+macro_rules! selector_registry {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$field_meta:meta])* $field:ident: $selector:tt ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: $crate::common::selectors::selector_registry!(@type $selector),
+            )*
+        }
+
+        impl $name {
+            /// 構築済みのセレクター群を取得する
+            ///
+            /// 初回呼び出し時にすべてのセレクターを構築し、以降は同じインスタンスを返します。
+            $vis fn get() -> Result<&'static Self, $crate::utils::error::ParseError> {
+                static REGISTRY: ::std::sync::OnceLock<
+                    Result<$name, $crate::utils::error::ParseError>,
+                > = ::std::sync::OnceLock::new();
+
+                REGISTRY
+                    .get_or_init(|| {
+                        Ok(Self {
+                            $(
+                                $field: $crate::common::selectors::selector_registry!(
+                                    @compile $selector
+                                ),
+                            )*
+                        })
+                    })
+                    .as_ref()
+                    .map_err(Clone::clone)
+            }
+        }
+    };
+    (@type [$($selector:expr),* $(,)?]) => {
+        ::std::vec::Vec<$crate::common::selectors::CompiledSelector>
+    };
+    (@type $selector:expr) => { $crate::common::selectors::CompiledSelector };
+    (@compile [$($selector:expr),* $(,)?]) => {
+        vec![$( $crate::common::selectors::CompiledSelector::new($selector)? ),*]
+    };
+    (@compile $selector:expr) => { $crate::common::selectors::CompiledSelector::new($selector)? };
+}
+
+pub(crate) use selector_registry;
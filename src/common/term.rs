@@ -0,0 +1,249 @@
+//! # 年度・学期
+//!
+//! 成績・時間割・シラバス・教室予約など、ページごとにさまざまな表記で表示される
+//! 開講年度と学期を[`AcademicTerm`]として表します。[`AcademicTerm::parse`]は次の表記を受け付けます。
+//!
+//! - 年度: `2025年度`・`2025年`・`2025`・`令和7年度`・`R7`（年度の後の空白や`/`は無視）
+//! - 学期: `前期`・`後期`・`前学期`・`後学期`・`1学期`・`2学期`・`春学期`・`秋学期`・`春`・`秋`
+//! - 通年・集中: `通年`・`集中`・`前期集中`・`集中講義`など`集中`を含む表記
+//! - クォーター: `第1クォーター`・`1Q`・`Q1`・`1ターム`
+//!
+//! 学期のみの表記（検索結果の各行など）は、同じページの年度とあわせて
+//! [`AcademicTerm::parse_in_year`]で解釈します。
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use regex_lite::Regex;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::common::datetime::{normalize, western_year};
+use crate::utils::error::ParseError;
+
+/// 開講年度と学期
+///
+/// 年度・学期の順に並ぶため、ソートすると時系列順になります。
+#[derive(
+    Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct AcademicTerm {
+    /// 年度（西暦）
+    pub year: u32,
+    /// 学期
+    pub term: Term,
+}
+
+/// 学期の種類
+#[derive(
+    Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Term {
+    /// 前期（前学期・1学期）
+    FirstHalf,
+    /// 春学期
+    Spring,
+    /// 第1クォーター
+    Q1,
+    /// 第2クォーター
+    Q2,
+    /// 後期（後学期・2学期）
+    SecondHalf,
+    /// 秋学期
+    Fall,
+    /// 第3クォーター
+    Q3,
+    /// 第4クォーター
+    Q4,
+    /// 通年
+    FullYear,
+    /// 集中
+    Intensive,
+}
+
+impl AcademicTerm {
+    /// 年度と学期から作成する
+    pub const fn new(year: u32, term: Term) -> Self {
+        Self { year, term }
+    }
+
+    /// 年度と学期の表記を解釈する（年度を含まない表記は`InvalidDataFormat`エラー）
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let normalized = normalize_term(text);
+        let (year, rest) = split_year(&normalized)?
+            .ok_or_else(|| invalid("academic term", text, "missing academic year"))?;
+        Ok(Self::new(year, Term::parse(rest)?))
+    }
+
+    /// 年度を含まない表記を`year`年度の学期として解釈する
+    ///
+    /// 表記に年度が含まれる場合はそちらを優先します。
+    pub fn parse_in_year(text: &str, year: u32) -> Result<Self, ParseError> {
+        let normalized = normalize_term(text);
+        let (year, rest) = split_year(&normalized)?.unwrap_or((year, normalized.as_str()));
+        Ok(Self::new(year, Term::parse(rest)?))
+    }
+
+    /// 同じ年度で期間が重なるか
+    ///
+    /// 前期・春学期は第1・第2クォーター、後期・秋学期は第3・第4クォーターと重なります。
+    /// 通年と集中（開講時期を特定できない）は同じ年度のすべての学期と重なります。
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.year == other.year && self.term.quarters() & other.term.quarters() != 0
+    }
+}
+
+impl Term {
+    /// 学期の表記を解釈する
+    ///
+    /// `FirstHalf`などの列挙子名（JSONでの表記）も受け付けます。
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let label: String = normalize_term(text)
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let label = label.trim_start_matches(['/', '・']);
+        let term = match label {
+            "前期" | "前学期" | "1学期" | "第1学期" | "1" | "FirstHalf" => {
+                Self::FirstHalf
+            }
+            "後期" | "後学期" | "2学期" | "第2学期" | "2" | "SecondHalf" => {
+                Self::SecondHalf
+            }
+            "春学期" | "春期" | "春" | "Spring" => Self::Spring,
+            "秋学期" | "秋期" | "秋" | "Fall" => Self::Fall,
+            "通年" | "FullYear" => Self::FullYear,
+            "Q1" => Self::Q1,
+            "Q2" => Self::Q2,
+            "Q3" => Self::Q3,
+            "Q4" => Self::Q4,
+            "Intensive" => Self::Intensive,
+            label if label.contains("集中") => Self::Intensive,
+            label => {
+                let quarter = quarter_regex()
+                    .captures(label)
+                    .and_then(|captures| captures.name("quarter"))
+                    .ok_or_else(|| invalid("term", text, "unrecognized term notation"))?;
+                match quarter.as_str() {
+                    "1" => Self::Q1,
+                    "2" => Self::Q2,
+                    "3" => Self::Q3,
+                    _ => Self::Q4,
+                }
+            }
+        };
+        Ok(term)
+    }
+
+    /// 表示用の名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::FirstHalf => "前期",
+            Self::Spring => "春学期",
+            Self::Q1 => "第1クォーター",
+            Self::Q2 => "第2クォーター",
+            Self::SecondHalf => "後期",
+            Self::Fall => "秋学期",
+            Self::Q3 => "第3クォーター",
+            Self::Q4 => "第4クォーター",
+            Self::FullYear => "通年",
+            Self::Intensive => "集中",
+        }
+    }
+
+    /// 学期が含むクォーター（第1クォーターを最下位ビットとするビット集合）
+    fn quarters(&self) -> u8 {
+        match self {
+            Self::Q1 => 0b0001,
+            Self::Q2 => 0b0010,
+            Self::Q3 => 0b0100,
+            Self::Q4 => 0b1000,
+            Self::FirstHalf | Self::Spring => 0b0011,
+            Self::SecondHalf | Self::Fall => 0b1100,
+            Self::FullYear | Self::Intensive => 0b1111,
+        }
+    }
+}
+
+/// 先頭の年度を取り出す（年度がない場合は`None`）
+fn split_year(text: &str) -> Result<Option<(u32, &str)>, ParseError> {
+    let Some(captures) = year_regex().captures(text) else {
+        return Ok(None);
+    };
+    let year = match (captures.name("era"), captures.name("era_year")) {
+        (Some(era), Some(era_year)) => western_year(era.as_str(), era_year.as_str())? as u32,
+        _ => captures["year"].parse().unwrap_or_default(),
+    };
+    let rest = &text[captures.get(0).map_or(0, |m| m.end())..];
+    Ok(Some((year, rest)))
+}
+
+fn year_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"^(?:(?P<era>令和|平成|昭和|大正|[RHST])(?P<era_year>\d{1,2}|元)|(?P<year>\d{4}))(?:年度|年)?")
+            .expect("year pattern is valid")
+    })
+}
+
+fn quarter_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"^第?(?P<quarter>[1-4])(?:クォーター|クオーター|Q|ターム)$")
+            .expect("quarter pattern is valid")
+    })
+}
+
+/// 全角英数字・空白を半角にそろえ、前後の空白を取り除く
+fn normalize_term(text: &str) -> String {
+    normalize(text.trim())
+        .trim()
+        .chars()
+        .map(|c| match c {
+            'Ａ'..='Ｚ' | 'ａ'..='ｚ' => {
+                char::from_u32(c as u32 - 'Ａ' as u32 + 'A' as u32).unwrap_or(c)
+            }
+            _ => c,
+        })
+        .collect()
+}
+
+fn invalid(data_type: &str, text: &str, reason: &str) -> ParseError {
+    ParseError::InvalidDataFormat {
+        data_type: data_type.to_string(),
+        details: format!("{reason}: {text:?}"),
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl fmt::Display for AcademicTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}年度 {}", self.year, self.term)
+    }
+}
+
+impl FromStr for Term {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl FromStr for AcademicTerm {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
@@ -5,6 +5,8 @@
 
 use scraper::Html;
 
+use super::outcome::ParseOutcome;
+use crate::utils::encoding::decode_html;
 use crate::utils::error::ParseError;
 
 /// 汎用的なパース処理インターフェース
@@ -20,6 +22,33 @@ pub trait PageParser<T> {
     /// 既にパース済みの`Html`ドキュメントがある場合、このメソッドを使用することで
     /// 重複したパース処理を回避できます。
     fn parse_document(&self, document: &Html) -> Result<T, ParseError>;
+
+    /// 警告付きでパースを行う
+    ///
+    /// 欠落したセクションや解釈できなかったセルを既定値で補った場合、
+    /// その内容を`ParseOutcome::warnings`として返します。
+    /// 警告を収集しないパーサーでは常に空の警告リストを返します。
+    fn parse_with_warnings(&self, document: &Html) -> Result<ParseOutcome<T>, ParseError> {
+        self.parse_document(document).map(ParseOutcome::new)
+    }
+
+    /// 診断情報付きでパースを行う
+    ///
+    /// 試行したセレクターと一致件数、読み飛ばした行を`ParseOutcome::diagnostics`に記録します。
+    /// 診断に対応していないパーサーでは`parse_with_warnings`と同じ結果を返します。
+    fn parse_with_diagnostics(&self, document: &Html) -> Result<ParseOutcome<T>, ParseError> {
+        self.parse_with_warnings(document)
+    }
+
+    /// 生のバイト列からパースを行う
+    ///
+    /// 文字コードはBOM、`content_type`（HTTPのContent-Typeヘッダー値）のcharset、
+    /// `<meta>`タグの宣言、バイト列からの推定の順に判定します。
+    /// デコードできない場合は`InvalidFileEncoding`エラーを返します。
+    fn parse_bytes(&self, bytes: &[u8], content_type: Option<&str>) -> Result<T, ParseError> {
+        let html = decode_html(bytes, content_type, Self::PAGE_TYPE)?;
+        self.parse_document(&Html::parse_document(&html))
+    }
 }
 
 /// セクション特化型パースインターフェース
@@ -0,0 +1,475 @@
+//! # CSV・TSV出力
+//!
+//! 成績照会（[`GradeInquiry`]）を表計算ソフトで開けるCSV・TSVに変換します。
+//!
+//! - 科目シート（[`GradeInquiryCsv::subjects`]）: 1行1科目。カリキュラム・大分類・中分類・小分類の列で
+//!   科目カテゴリの階層を表します。
+//! - 単位シート（[`GradeInquiryCsv::credit_summary`]）: 1行1カテゴリ。共通教育・専門教育・
+//!   `CreditSummary::category_breakdown`の各カテゴリ・全体の順に出力します。
+//!
+//! 出力する列とその順序は[`CsvOptions`]で選べます。既定では先頭にUTF-8のBOMを付け、
+//! Excelでダブルクリックして開いても文字化けしないようにします。
+//!
+//! ```no_run
+//! # use unipa_ex::export::csv::{CsvFormat, GradeInquiryCsv, SubjectColumn};
+//! # use unipa_ex::parsers::grades::grade_inquiry::GradeInquiry;
+//! # fn example(grades: &GradeInquiry) {
+//! let csv = GradeInquiryCsv::new()
+//!     .with_format(CsvFormat::Tsv)
+//!     .with_subject_columns(vec![SubjectColumn::Name, SubjectColumn::Grade])
+//!     .subjects(grades);
+//! # }
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::parsers::grades::grade_inquiry::{CreditDetails, GradeInquiry, Subject};
+use crate::utils::error::ParseError;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// UTF-8のBOM
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// 区切り文字の形式
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvFormat {
+    /// カンマ区切り（RFC 4180）
+    #[default]
+    Csv,
+    /// タブ区切り
+    Tsv,
+}
+
+/// 科目シートの列
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectColumn {
+    /// 履修年度
+    Year,
+    /// 履修学期
+    Term,
+    /// カリキュラム名
+    CurriculumName,
+    /// 大分類名
+    MajorCategoryName,
+    /// 中分類名
+    MiddleCategoryName,
+    /// 小分類名
+    SubCategoryName,
+    /// 必修・選択の区分
+    RequirementType,
+    /// 科目名
+    Name,
+    /// 単位数
+    CreditCount,
+    /// 成績評価
+    Grade,
+    /// 素点
+    NumericScore,
+    /// 担当教員名
+    InstructorName,
+    /// 履修中かどうか
+    IsCurrentlyEnrolled,
+}
+
+impl SubjectColumn {
+    /// すべての列（既定の出力順）
+    pub const ALL: [SubjectColumn; 13] = [
+        SubjectColumn::Year,
+        SubjectColumn::Term,
+        SubjectColumn::CurriculumName,
+        SubjectColumn::MajorCategoryName,
+        SubjectColumn::MiddleCategoryName,
+        SubjectColumn::SubCategoryName,
+        SubjectColumn::RequirementType,
+        SubjectColumn::Name,
+        SubjectColumn::CreditCount,
+        SubjectColumn::Grade,
+        SubjectColumn::NumericScore,
+        SubjectColumn::InstructorName,
+        SubjectColumn::IsCurrentlyEnrolled,
+    ];
+
+    /// 列名（シリアライズ時の表記と同じ）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubjectColumn::Year => "year",
+            SubjectColumn::Term => "term",
+            SubjectColumn::CurriculumName => "curriculum_name",
+            SubjectColumn::MajorCategoryName => "major_category_name",
+            SubjectColumn::MiddleCategoryName => "middle_category_name",
+            SubjectColumn::SubCategoryName => "sub_category_name",
+            SubjectColumn::RequirementType => "requirement_type",
+            SubjectColumn::Name => "name",
+            SubjectColumn::CreditCount => "credit_count",
+            SubjectColumn::Grade => "grade",
+            SubjectColumn::NumericScore => "numeric_score",
+            SubjectColumn::InstructorName => "instructor_name",
+            SubjectColumn::IsCurrentlyEnrolled => "is_currently_enrolled",
+        }
+    }
+
+    /// 見出し行に出力する名前
+    pub fn header(&self) -> &'static str {
+        match self {
+            SubjectColumn::Year => "年度",
+            SubjectColumn::Term => "学期",
+            SubjectColumn::CurriculumName => "カリキュラム",
+            SubjectColumn::MajorCategoryName => "大分類",
+            SubjectColumn::MiddleCategoryName => "中分類",
+            SubjectColumn::SubCategoryName => "小分類",
+            SubjectColumn::RequirementType => "必修・選択",
+            SubjectColumn::Name => "科目名",
+            SubjectColumn::CreditCount => "単位数",
+            SubjectColumn::Grade => "評価",
+            SubjectColumn::NumericScore => "素点",
+            SubjectColumn::InstructorName => "担当教員",
+            SubjectColumn::IsCurrentlyEnrolled => "履修中",
+        }
+    }
+
+    fn value(&self, subject: &Subject) -> String {
+        let category = &subject.category;
+        match self {
+            SubjectColumn::Year => optional(subject.term.map(|term| term.year)),
+            SubjectColumn::Term => subject
+                .term
+                .map_or("", |term| term.term.label())
+                .to_string(),
+            SubjectColumn::CurriculumName => category.curriculum_name.clone(),
+            SubjectColumn::MajorCategoryName => category.major_category_name.clone(),
+            SubjectColumn::MiddleCategoryName => category.middle_category_name.clone(),
+            SubjectColumn::SubCategoryName => category.sub_category_name.clone(),
+            SubjectColumn::RequirementType => category.requirement_type.label().to_string(),
+            SubjectColumn::Name => subject.name.clone(),
+            SubjectColumn::CreditCount => optional(subject.credit_count),
+            SubjectColumn::Grade => subject
+                .grade
+                .as_ref()
+                .map_or("", |grade| grade.label())
+                .to_string(),
+            SubjectColumn::NumericScore => optional(subject.numeric_score),
+            SubjectColumn::InstructorName => subject.instructor_name.clone(),
+            SubjectColumn::IsCurrentlyEnrolled if subject.is_currently_enrolled => "○".to_string(),
+            SubjectColumn::IsCurrentlyEnrolled => String::new(),
+        }
+    }
+}
+
+impl fmt::Display for SubjectColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SubjectColumn {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SubjectColumn::ALL
+            .into_iter()
+            .find(|column| column.as_str() == s)
+            .ok_or_else(|| ParseError::InvalidConfiguration {
+                config_name: "subject column".to_string(),
+                value: s.to_string(),
+            })
+    }
+}
+
+/// 単位シートの列
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "snake_case")]
+pub enum CreditColumn {
+    /// カテゴリ名
+    CategoryName,
+    /// 卒業要件単位数
+    RequiredForGraduation,
+    /// 修得済み単位数
+    CompletedCredits,
+    /// 履修中単位数
+    CurrentlyEnrolledCredits,
+    /// 合計単位数
+    TotalCredits,
+}
+
+impl CreditColumn {
+    /// すべての列（既定の出力順）
+    pub const ALL: [CreditColumn; 5] = [
+        CreditColumn::CategoryName,
+        CreditColumn::RequiredForGraduation,
+        CreditColumn::CompletedCredits,
+        CreditColumn::CurrentlyEnrolledCredits,
+        CreditColumn::TotalCredits,
+    ];
+
+    /// 列名（シリアライズ時の表記と同じ）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CreditColumn::CategoryName => "category_name",
+            CreditColumn::RequiredForGraduation => "required_for_graduation",
+            CreditColumn::CompletedCredits => "completed_credits",
+            CreditColumn::CurrentlyEnrolledCredits => "currently_enrolled_credits",
+            CreditColumn::TotalCredits => "total_credits",
+        }
+    }
+
+    /// 見出し行に出力する名前
+    pub fn header(&self) -> &'static str {
+        match self {
+            CreditColumn::CategoryName => "カテゴリ",
+            CreditColumn::RequiredForGraduation => "卒業要件単位数",
+            CreditColumn::CompletedCredits => "修得済み単位数",
+            CreditColumn::CurrentlyEnrolledCredits => "履修中単位数",
+            CreditColumn::TotalCredits => "合計単位数",
+        }
+    }
+
+    fn value(&self, category_name: &str, details: &CreditDetails) -> String {
+        match self {
+            CreditColumn::CategoryName => category_name.to_string(),
+            CreditColumn::RequiredForGraduation => optional(details.required_for_graduation),
+            CreditColumn::CompletedCredits => details.completed_credits.to_string(),
+            CreditColumn::CurrentlyEnrolledCredits => {
+                details.currently_enrolled_credits.to_string()
+            }
+            CreditColumn::TotalCredits => details.total_credits.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for CreditColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CreditColumn {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CreditColumn::ALL
+            .into_iter()
+            .find(|column| column.as_str() == s)
+            .ok_or_else(|| ParseError::InvalidConfiguration {
+                config_name: "credit column".to_string(),
+                value: s.to_string(),
+            })
+    }
+}
+
+/// CSV・TSV出力の設定
+///
+/// 省略した項目は既定値（CSV、BOMあり、すべての列）になります。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(default)]
+pub struct CsvOptions {
+    /// 区切り文字の形式
+    pub format: CsvFormat,
+    /// 先頭にUTF-8のBOMを付けるかどうか
+    pub byte_order_mark: bool,
+    /// 科目シートに出力する列（この順で出力）
+    pub subject_columns: Vec<SubjectColumn>,
+    /// 単位シートに出力する列（この順で出力）
+    pub credit_columns: Vec<CreditColumn>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            format: CsvFormat::Csv,
+            byte_order_mark: true,
+            subject_columns: SubjectColumn::ALL.to_vec(),
+            credit_columns: CreditColumn::ALL.to_vec(),
+        }
+    }
+}
+
+/// 成績照会のCSV・TSVへの変換
+#[derive(Clone, Default, Debug)]
+pub struct GradeInquiryCsv {
+    options: CsvOptions,
+}
+
+impl GradeInquiryCsv {
+    /// 既定の設定で作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 出力の設定をまとめて指定する
+    pub fn with_options(mut self, options: CsvOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 区切り文字の形式を設定する
+    pub fn with_format(mut self, format: CsvFormat) -> Self {
+        self.options.format = format;
+        self
+    }
+
+    /// 先頭にBOMを付けるかどうかを設定する
+    pub fn with_byte_order_mark(mut self, byte_order_mark: bool) -> Self {
+        self.options.byte_order_mark = byte_order_mark;
+        self
+    }
+
+    /// 科目シートに出力する列を設定する
+    pub fn with_subject_columns(mut self, columns: Vec<SubjectColumn>) -> Self {
+        self.options.subject_columns = columns;
+        self
+    }
+
+    /// 単位シートに出力する列を設定する
+    pub fn with_credit_columns(mut self, columns: Vec<CreditColumn>) -> Self {
+        self.options.credit_columns = columns;
+        self
+    }
+
+    /// 1行1科目の科目シートを作成する
+    pub fn subjects(&self, grades: &GradeInquiry) -> String {
+        let columns = &self.options.subject_columns;
+        self.table(
+            columns.iter().map(SubjectColumn::header),
+            grades
+                .subjects
+                .iter()
+                .map(|subject| columns.iter().map(|column| column.value(subject)).collect()),
+        )
+    }
+
+    /// 1行1カテゴリの単位シートを作成する
+    ///
+    /// 共通教育・専門教育、カテゴリ別の各カテゴリ、全体の順に出力します。
+    /// 共通教育・専門教育・全体は、単位数がすべて空または0の場合（単位状況の表にない場合）は省きます。
+    pub fn credit_summary(&self, grades: &GradeInquiry) -> String {
+        let columns = &self.options.credit_columns;
+        let summary = &grades.credit_summary;
+        let is_shown = |details: &CreditDetails| {
+            details.required_for_graduation.is_some()
+                || details.completed_credits > 0
+                || details.currently_enrolled_credits > 0
+                || details.total_credits > 0
+        };
+
+        let categories = [
+            ("共通教育", &summary.common_education),
+            ("専門教育", &summary.specialized_education),
+        ]
+        .into_iter()
+        .filter(|(_, details)| is_shown(details))
+        .chain(
+            summary
+                .category_breakdown
+                .iter()
+                .map(|category| (category.category_name.as_str(), &category.credit_details)),
+        )
+        .chain(Some(("全体", &summary.overall)).filter(|(_, details)| is_shown(details)));
+
+        self.table(
+            columns.iter().map(CreditColumn::header),
+            categories.map(|(name, details)| {
+                columns
+                    .iter()
+                    .map(|column| column.value(name, details))
+                    .collect()
+            }),
+        )
+    }
+
+    /// 見出し行とデータ行を区切り文字でつなぎ、`CRLF`で改行する
+    fn table<'a>(
+        &self,
+        headers: impl Iterator<Item = &'a str>,
+        rows: impl Iterator<Item = Vec<String>>,
+    ) -> String {
+        let mut out = String::new();
+        if self.options.byte_order_mark {
+            out.push(BYTE_ORDER_MARK);
+        }
+
+        let headers: Vec<String> = headers.map(str::to_string).collect();
+        for row in std::iter::once(headers).chain(rows) {
+            let cells: Vec<String> = row.iter().map(|cell| self.cell(cell)).collect();
+            out.push_str(&cells.join(self.delimiter()));
+            out.push_str("\r\n");
+        }
+        out
+    }
+
+    fn delimiter(&self) -> &'static str {
+        match self.options.format {
+            CsvFormat::Csv => ",",
+            CsvFormat::Tsv => "\t",
+        }
+    }
+
+    /// セルの値を書き出せる形にする
+    ///
+    /// CSVでは区切り文字・引用符・改行を含む値を引用符で囲みます。
+    /// TSVには引用の仕組みがないため、タブと改行を空白に置き換えます。
+    fn cell(&self, value: &str) -> String {
+        match self.options.format {
+            CsvFormat::Csv if value.contains([',', '"', '\r', '\n']) => {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            }
+            CsvFormat::Csv => value.to_string(),
+            CsvFormat::Tsv => value.replace(['\t', '\r', '\n'], " "),
+        }
+    }
+}
+
+fn optional(value: Option<u32>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// JavaScriptから成績照会をCSV・TSVに変換するクラス
+///
+/// ```text
+/// const exporter = new GradeInquiryCsvExporter({ format: "tsv", subject_columns: ["name", "grade"] });
+/// const grades = new GradeInquiryParser().parse(html);
+/// const subjects = exporter.subjects(grades);
+/// const credits = exporter.credit_summary(grades);
+/// ```
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub struct GradeInquiryCsvExporter {
+    csv: GradeInquiryCsv,
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl GradeInquiryCsvExporter {
+    /// 出力の設定を指定して作成する
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: Option<CsvOptions>) -> Self {
+        Self {
+            csv: GradeInquiryCsv::new().with_options(options.unwrap_or_default()),
+        }
+    }
+
+    /// 1行1科目の科目シートを作成する
+    pub fn subjects(&self, grades: GradeInquiry) -> String {
+        self.csv.subjects(&grades)
+    }
+
+    /// 1行1カテゴリの単位シートを作成する（共通教育・専門教育・全体の行を含む）
+    pub fn credit_summary(&self, grades: GradeInquiry) -> String {
+        self.csv.credit_summary(&grades)
+    }
+}
@@ -0,0 +1,659 @@
+//! # iCalendar出力
+//!
+//! 時間割の授業、テストの実施期間、課題の提出期限、ポータルの今日の時限割を
+//! iCalendar（RFC 5545）の`VCALENDAR`テキストに変換し、スマートフォンなどのカレンダーに
+//! 取り込めるようにします。
+//!
+//! - 日時はすべて日本標準時（`TZID=Asia/Tokyo`、`VTIMEZONE`を含む）で出力します。
+//! - 毎週の授業は授業期間（[`IcalOptions::term_dates`]）の最初の該当曜日から始まり、
+//!   授業期間の終了日までの`RRULE:FREQ=WEEKLY`で繰り返します。連続する時限は1つの予定にまとめます。
+//! - `UID`は授業コードや科目名・タイトルから決まるため、同じ学期の時間割を出力し直して
+//!   取り込んでも予定が重複しません。
+//!
+//! ```no_run
+//! # use unipa_ex::common::datetime::{JstDate, JstRange};
+//! # use unipa_ex::export::ical::{IcalBuilder, TimetableClass};
+//! # fn example(classes: Vec<TimetableClass>) -> Result<(), unipa_ex::utils::error::ParseError> {
+//! let term = JstRange::new(JstDate::new(2025, 4, 7)?, JstDate::new(2025, 7, 25)?)?;
+//! let ics = IcalBuilder::new()
+//!     .with_term_dates(term)
+//!     .add_classes(classes)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::common::datetime::{ClockTime, JstDate, JstDateTime, JstRange};
+use crate::common::period::{ClassSlot, ClassTime, Weekday};
+use crate::parsers::page::PageData;
+use crate::utils::error::ParseError;
+use crate::utils::time::{format_rfc3339, now_unix_seconds};
+
+#[cfg(feature = "assignments")]
+use crate::parsers::assignment_submit::model::{AssignmentList, SubmissionStatus};
+#[cfg(feature = "portal")]
+use crate::parsers::portal::model::Portal;
+#[cfg(feature = "syllabus")]
+use crate::parsers::syllabus::view::LessonInfo;
+#[cfg(feature = "tests")]
+use crate::parsers::test_answer_status::model::{TestAnswerStatus, TestDetails};
+#[cfg(feature = "timetable")]
+use crate::parsers::timetable::BaseClassInfo;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// `UID`のドメイン部
+const UID_DOMAIN: &str = "unipa-ex";
+
+/// iCalendar出力の設定
+#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct IcalOptions {
+    /// 授業期間（毎週の授業を繰り返す最初と最後の日）
+    ///
+    /// 時間割の授業を含める場合は必須です。
+    #[serde(default)]
+    pub term_dates: Option<JstRange<JstDate>>,
+    /// カレンダー名（`X-WR-CALNAME`）
+    #[serde(default)]
+    pub calendar_name: Option<String>,
+}
+
+/// 毎週の予定として出力する授業
+#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct TimetableClass {
+    /// 授業コード（`UID`に使用）
+    pub class_code: String,
+    /// 科目名
+    pub subject_name: String,
+    /// 担当教員名
+    pub teacher_name: Option<String>,
+    /// 教室名
+    pub classroom: Option<String>,
+    /// 曜日・時限と開始・終了時刻
+    pub class_time: ClassTime,
+}
+
+impl TimetableClass {
+    /// 時間割の一覧表示の1行から作成する（曜日・時限を解釈できなかった場合は`None`）
+    #[cfg(feature = "timetable")]
+    pub fn from_timetable(base: &BaseClassInfo) -> Option<Self> {
+        Some(Self {
+            class_code: base.class_code.clone(),
+            subject_name: base.subject_name.clone(),
+            teacher_name: Some(base.teacher_name.clone()).filter(|name| !name.is_empty()),
+            classroom: base.classroom.clone(),
+            class_time: base.class_time.clone()?,
+        })
+    }
+
+    /// シラバス詳細から作成する（曜日時限を解釈できなかった場合は`None`）
+    #[cfg(feature = "syllabus")]
+    pub fn from_lesson(lesson: &LessonInfo) -> Option<Self> {
+        Some(Self {
+            class_code: lesson.lesson_code.to_string(),
+            subject_name: lesson.subject_name.clone(),
+            teacher_name: Some(lesson.instructor.clone()).filter(|name| !name.is_empty()),
+            classroom: lesson.classroom.clone(),
+            class_time: lesson.class_time.clone()?,
+        })
+    }
+}
+
+/// 1件の予定（`VEVENT`）
+#[derive(Clone, Debug)]
+struct Event {
+    uid: String,
+    summary: String,
+    start: JstDateTime,
+    end: Option<JstDateTime>,
+    rrule: Option<String>,
+    location: Option<String>,
+    description: Option<String>,
+    category: &'static str,
+}
+
+/// iCalendarテキストのビルダー
+///
+/// 授業・テスト・課題・ポータルの予定を追加し、[`build`](Self::build)で`VCALENDAR`を作成します。
+#[derive(Clone, Default, Debug)]
+pub struct IcalBuilder {
+    options: IcalOptions,
+    timestamp: Option<i64>,
+    classes: Vec<TimetableClass>,
+    events: Vec<Event>,
+}
+
+impl IcalBuilder {
+    /// 新しいビルダーを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 出力の設定をまとめて指定する
+    pub fn with_options(mut self, options: IcalOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 授業期間を設定する
+    pub fn with_term_dates(mut self, term_dates: JstRange<JstDate>) -> Self {
+        self.options.term_dates = Some(term_dates);
+        self
+    }
+
+    /// カレンダー名を設定する
+    pub fn with_calendar_name(mut self, name: impl Into<String>) -> Self {
+        self.options.calendar_name = Some(name.into());
+        self
+    }
+
+    /// `DTSTAMP`に使うUNIX時刻（秒）を指定する（省略時は現在時刻）
+    pub fn with_timestamp(mut self, unix_seconds: i64) -> Self {
+        self.timestamp = Some(unix_seconds);
+        self
+    }
+
+    /// 毎週の授業を追加する
+    ///
+    /// 時刻が分からないコマ（時限表にない時限や曜日のない表記）と集中講義は出力されません。
+    pub fn add_class(mut self, class: TimetableClass) -> Self {
+        self.classes.push(class);
+        self
+    }
+
+    /// 毎週の授業をまとめて追加する
+    pub fn add_classes(mut self, classes: impl IntoIterator<Item = TimetableClass>) -> Self {
+        self.classes.extend(classes);
+        self
+    }
+
+    /// 時間割の一覧表示の各行を毎週の授業として追加する
+    #[cfg(feature = "timetable")]
+    pub fn add_timetable<'a>(self, entries: impl IntoIterator<Item = &'a BaseClassInfo>) -> Self {
+        self.add_classes(
+            entries
+                .into_iter()
+                .filter_map(TimetableClass::from_timetable),
+        )
+    }
+
+    /// シラバス詳細の授業を毎週の授業として追加する
+    #[cfg(feature = "syllabus")]
+    pub fn add_lesson(self, lesson: &LessonInfo) -> Self {
+        self.add_classes(TimetableClass::from_lesson(lesson))
+    }
+
+    /// 未実施のテストの実施期間を追加する
+    ///
+    /// 開始・終了日時の片方のみが分かる場合は、その日時の予定になります。
+    #[cfg(feature = "tests")]
+    pub fn add_tests(mut self, status: &TestAnswerStatus) -> Self {
+        for test in &status.tests {
+            let TestDetails::NotImplemented(details) = &test.details else {
+                continue;
+            };
+            let (start, end) = match (details.start_date, details.end_date) {
+                (Some(start), end) => (start, end),
+                (None, Some(end)) => (end, None),
+                (None, None) => continue,
+            };
+            self.events.push(Event {
+                uid: hashed_uid("test", &[&test.course_name, &test.title]),
+                summary: format!("{}（{}）", test.title, test.course_name),
+                start,
+                end,
+                rrule: None,
+                location: None,
+                description: details
+                    .time_limit_minutes
+                    .map(|minutes| format!("制限時間: {minutes}分")),
+                category: "テスト",
+            });
+        }
+        self
+    }
+
+    /// 提出していない課題の提出期限を追加する
+    ///
+    /// 提出済み・評価済みの課題と、提出期限を解釈できなかった課題は出力されません。
+    #[cfg(feature = "assignments")]
+    pub fn add_assignments(mut self, list: &AssignmentList) -> Self {
+        for assignment in &list.assignments {
+            if matches!(
+                assignment.submission_status,
+                SubmissionStatus::Submitted | SubmissionStatus::Evaluated
+            ) {
+                continue;
+            }
+            let Some(due_date) = assignment.due_date else {
+                continue;
+            };
+            self.events.push(Event {
+                uid: hashed_uid(
+                    "assignment",
+                    &[&assignment.subject_name, &assignment.assignment_title],
+                ),
+                summary: format!(
+                    "{}（{}）",
+                    assignment.assignment_title, assignment.subject_name
+                ),
+                start: due_date,
+                end: None,
+                rrule: None,
+                location: None,
+                description: assignment.description.clone(),
+                category: "課題",
+            });
+        }
+        self
+    }
+
+    /// ポータルのカレンダーの日付（`current_date`）の今日の時限割を追加する
+    ///
+    /// カレンダーの日付が分からない場合や、時刻が分からない時限は出力されません。
+    #[cfg(feature = "portal")]
+    pub fn add_portal(mut self, portal: &Portal) -> Self {
+        let Some(date) = portal.calendar.current_date else {
+            return self;
+        };
+        for entry in &portal.schedule.entries {
+            let Some(class_time) = &entry.class_time else {
+                continue;
+            };
+            if entry.class_content.trim().is_empty() {
+                continue;
+            }
+            for block in blocks(&class_time.slots) {
+                self.events.push(Event {
+                    uid: format!(
+                        "portal-{}-{}@{UID_DOMAIN}",
+                        date_value(date),
+                        block.first_period
+                    ),
+                    summary: entry.class_content.clone(),
+                    start: block.start.on(date),
+                    end: Some(block.end.on(date)),
+                    rrule: None,
+                    location: None,
+                    description: Some(entry.date.clone()).filter(|text| !text.is_empty()),
+                    category: "授業",
+                });
+            }
+        }
+        self
+    }
+
+    /// 解析結果をページ種別に応じて追加する
+    ///
+    /// シラバス詳細・テスト解答状況・課題提出一覧・ポータルに対応しています。
+    /// それ以外のページは`NotImplemented`エラーになります。
+    pub fn add_page(self, data: &PageData) -> Result<Self, ParseError> {
+        #[allow(unreachable_patterns)]
+        match data {
+            #[cfg(feature = "syllabus")]
+            PageData::SyllabusView(lesson) => Ok(self.add_lesson(lesson)),
+            #[cfg(feature = "tests")]
+            PageData::TestAnswerStatus(status) => Ok(self.add_tests(status)),
+            #[cfg(feature = "assignments")]
+            PageData::AssignmentList(list) => Ok(self.add_assignments(list)),
+            #[cfg(feature = "portal")]
+            PageData::Portal(portal) => Ok(self.add_portal(portal)),
+            _ => Err(ParseError::NotImplemented {
+                operation: "iCalendar export of this page kind".to_string(),
+            }),
+        }
+    }
+
+    /// `VCALENDAR`テキスト（改行は`CRLF`）を作成する
+    ///
+    /// 授業を追加した場合に授業期間が設定されていないと`MissingRequiredField`エラーになります。
+    pub fn build(&self) -> Result<String, ParseError> {
+        let mut class_events = Vec::new();
+        if !self.classes.is_empty() {
+            let term = self
+                .options
+                .term_dates
+                .ok_or_else(|| ParseError::MissingRequiredField {
+                    field_name: "term_dates".to_string(),
+                })?;
+            for class in &self.classes {
+                class_events.extend(weekly_events(class, &term)?);
+            }
+        }
+
+        let stamp = utc_value(self.timestamp.unwrap_or_else(now_unix_seconds));
+        let mut out = String::new();
+        let mut line = |text: &str| fold(text, &mut out);
+
+        line("BEGIN:VCALENDAR");
+        line("VERSION:2.0");
+        line(&format!(
+            "PRODID:-//{UID_DOMAIN}//{UID_DOMAIN} {}//JA",
+            env!("CARGO_PKG_VERSION")
+        ));
+        line("CALSCALE:GREGORIAN");
+        line("METHOD:PUBLISH");
+        if let Some(name) = &self.options.calendar_name {
+            line(&format!("X-WR-CALNAME:{}", escape(name)));
+        }
+        line("X-WR-TIMEZONE:Asia/Tokyo");
+        for text in [
+            "BEGIN:VTIMEZONE",
+            "TZID:Asia/Tokyo",
+            "BEGIN:STANDARD",
+            "DTSTART:19700101T000000",
+            "TZOFFSETFROM:+0900",
+            "TZOFFSETTO:+0900",
+            "TZNAME:JST",
+            "END:STANDARD",
+            "END:VTIMEZONE",
+        ] {
+            line(text);
+        }
+
+        for event in class_events.iter().chain(&self.events) {
+            line("BEGIN:VEVENT");
+            line(&format!("UID:{}", event.uid));
+            line(&format!("DTSTAMP:{stamp}"));
+            line(&format!(
+                "DTSTART;TZID=Asia/Tokyo:{}",
+                datetime_value(event.start)
+            ));
+            if let Some(end) = event.end {
+                line(&format!("DTEND;TZID=Asia/Tokyo:{}", datetime_value(end)));
+            }
+            if let Some(rrule) = &event.rrule {
+                line(&format!("RRULE:{rrule}"));
+            }
+            line(&format!("SUMMARY:{}", escape(&event.summary)));
+            if let Some(location) = &event.location {
+                line(&format!("LOCATION:{}", escape(location)));
+            }
+            if let Some(description) = &event.description {
+                line(&format!("DESCRIPTION:{}", escape(description)));
+            }
+            line(&format!("CATEGORIES:{}", event.category));
+            line("END:VEVENT");
+        }
+
+        line("END:VCALENDAR");
+        Ok(out)
+    }
+}
+
+/// 同じ曜日の連続する時限をまとめた1回分の授業
+struct Block {
+    weekday: Option<Weekday>,
+    first_period: u32,
+    last_period: u32,
+    start: ClockTime,
+    end: ClockTime,
+}
+
+/// 時刻の分かるコマを、同じ曜日で時限が連続するものごとにまとめる
+fn blocks(slots: &[ClassSlot]) -> Vec<Block> {
+    let mut blocks: Vec<Block> = Vec::new();
+    for slot in slots {
+        let (Some(start), Some(end)) = (slot.start, slot.end) else {
+            continue;
+        };
+        match blocks.last_mut() {
+            Some(block)
+                if block.weekday == slot.weekday && block.last_period + 1 == slot.period =>
+            {
+                block.last_period = slot.period;
+                block.end = end;
+            }
+            _ => blocks.push(Block {
+                weekday: slot.weekday,
+                first_period: slot.period,
+                last_period: slot.period,
+                start,
+                end,
+            }),
+        }
+    }
+    blocks
+}
+
+/// 授業期間中の毎週の授業
+fn weekly_events(
+    class: &TimetableClass,
+    term: &JstRange<JstDate>,
+) -> Result<Vec<Event>, ParseError> {
+    // 授業期間の終了日の終わり（日本標準時）までを繰り返しの範囲とする
+    let until = utc_value(term.end.at(23, 59, 59)?.unix_seconds());
+    let code = uid_part(&class.class_code, &class.subject_name);
+
+    let mut events = Vec::new();
+    for block in blocks(&class.class_time.slots) {
+        let Some(weekday) = block.weekday else {
+            continue;
+        };
+        let offset = (weekday.days_from_monday() + 7 - term.start.weekday().days_from_monday()) % 7;
+        let first = term.start.add_days(i64::from(offset));
+        if !term.contains(&first) {
+            continue;
+        }
+
+        let periods = if block.first_period == block.last_period {
+            format!("{}{}限", weekday.kanji(), block.first_period)
+        } else {
+            format!(
+                "{}{}-{}限",
+                weekday.kanji(),
+                block.first_period,
+                block.last_period
+            )
+        };
+        let mut description = vec![periods];
+        if let Some(teacher) = &class.teacher_name {
+            description.push(format!("担当: {teacher}"));
+        }
+        if !class.class_code.is_empty() {
+            description.push(format!("授業コード: {}", class.class_code));
+        }
+
+        events.push(Event {
+            uid: format!(
+                "class-{code}-{}{}@{UID_DOMAIN}",
+                byday(weekday).to_ascii_lowercase(),
+                block.first_period
+            ),
+            summary: class.subject_name.clone(),
+            start: block.start.on(first),
+            end: Some(block.end.on(first)),
+            rrule: Some(format!(
+                "FREQ=WEEKLY;UNTIL={until};BYDAY={}",
+                byday(weekday)
+            )),
+            location: class.classroom.clone().filter(|room| !room.is_empty()),
+            description: Some(description.join("\n")),
+            category: "授業",
+        });
+    }
+    Ok(events)
+}
+
+/// `RRULE`の`BYDAY`に使う曜日の表記
+fn byday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+/// `UID`に使える英数字の授業コード（英数字を含まない場合は科目名のハッシュ）
+fn uid_part(code: &str, fallback: &str) -> String {
+    let part: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+    if part.is_empty() {
+        format!("{:016x}", fnv1a(&[fallback]))
+    } else {
+        part
+    }
+}
+
+/// 識別子を持たない予定の`UID`（内容から決まるハッシュ）
+#[cfg(any(feature = "tests", feature = "assignments"))]
+fn hashed_uid(prefix: &str, parts: &[&str]) -> String {
+    format!("{prefix}-{:016x}@{UID_DOMAIN}", fnv1a(parts))
+}
+
+/// 実行環境やRustのバージョンによらず同じ値になるハッシュ（FNV-1a 64bit）
+fn fnv1a(parts: &[&str]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for (i, part) in parts.iter().enumerate() {
+        // 区切りを含めて`["ab", "c"]`と`["a", "bc"]`を区別する
+        let separator: &[u8] = if i == 0 { &[] } else { &[0x1f] };
+        for byte in separator.iter().chain(part.as_bytes()) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+fn date_value(date: JstDate) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month(), date.day())
+}
+
+fn datetime_value(datetime: JstDateTime) -> String {
+    format!(
+        "{}T{:02}{:02}{:02}",
+        date_value(datetime.date()),
+        datetime.hour(),
+        datetime.minute(),
+        datetime.second()
+    )
+}
+
+/// UTCの日時（`20250401T000000Z`）
+fn utc_value(unix_seconds: i64) -> String {
+    format_rfc3339(unix_seconds).replace(['-', ':'], "")
+}
+
+/// TEXT値のエスケープ（RFC 5545 3.3.11）
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 75オクテットごとに折り返して1行を書き出す（RFC 5545 3.1）
+fn fold(line: &str, out: &mut String) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// JavaScriptから時間割・テスト・課題・ポータルの予定をiCalendarに書き出すクラス
+///
+/// ```text
+/// const exporter = new IcalExporter({ term_dates: { start: "2025-04-07", end: "2025-07-25" } });
+/// exporter.add_classes([{ class_code: "K00001", subject_name: "情報科学", class_time, ... }]);
+/// exporter.add_tests(new TestAnswerStatusParser().parse(html));
+/// const ics = exporter.to_ical();
+/// ```
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub struct IcalExporter {
+    builder: IcalBuilder,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl IcalExporter {
+    fn update(&mut self, f: impl FnOnce(IcalBuilder) -> IcalBuilder) {
+        self.builder = f(std::mem::take(&mut self.builder));
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl IcalExporter {
+    /// 出力の設定を指定して作成する
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: Option<IcalOptions>) -> Self {
+        Self {
+            builder: IcalBuilder::new().with_options(options.unwrap_or_default()),
+        }
+    }
+
+    /// 毎週の授業を追加する
+    pub fn add_classes(&mut self, classes: Vec<TimetableClass>) {
+        self.update(|builder| builder.add_classes(classes));
+    }
+
+    /// `VCALENDAR`テキストを作成する
+    pub fn to_ical(&self) -> Result<String, ParseError> {
+        self.builder.build()
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "syllabus"))]
+#[wasm_bindgen]
+impl IcalExporter {
+    /// シラバス詳細の授業を毎週の授業として追加する
+    pub fn add_lesson(&mut self, lesson: LessonInfo) {
+        self.update(|builder| builder.add_lesson(&lesson));
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "tests"))]
+#[wasm_bindgen]
+impl IcalExporter {
+    /// 未実施のテストの実施期間を追加する
+    pub fn add_tests(&mut self, status: TestAnswerStatus) {
+        self.update(|builder| builder.add_tests(&status));
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "assignments"))]
+#[wasm_bindgen]
+impl IcalExporter {
+    /// 提出していない課題の提出期限を追加する
+    pub fn add_assignments(&mut self, list: AssignmentList) {
+        self.update(|builder| builder.add_assignments(&list));
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "portal"))]
+#[wasm_bindgen]
+impl IcalExporter {
+    /// ポータルの今日の時限割を追加する
+    pub fn add_portal(&mut self, portal: Portal) {
+        self.update(|builder| builder.add_portal(&portal));
+    }
+}
@@ -0,0 +1,11 @@
+//! # 解析結果の書き出し
+//!
+//! 解析結果をほかのアプリケーションで読み込める形式に変換します。
+
+/// 時間割・テスト・課題の締切・ポータルの予定のiCalendar（RFC 5545）形式への変換
+#[cfg(feature = "ical")]
+pub mod ical;
+
+/// 成績照会の表計算ソフト向けCSV・TSV形式への変換
+#[cfg(feature = "csv")]
+pub mod csv;
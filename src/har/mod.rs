@@ -0,0 +1,15 @@
+//! ブラウザのHARエクスポートの取り込みと一括解析
+//!
+//! 利用者から送られてきたHARファイルを読み込み、各レスポンス本文を文字コードに従って
+//! デコードしたうえで、画面IDやページ判定により対応するパーサーで解析します。
+//! 不具合報告を再現可能なフィクスチャへ変換する用途を想定しています。
+
+/// HARファイルと解析レポートのデータモデル
+pub mod model;
+
+/// HARエントリーの分類と解析の実装
+pub mod parser;
+
+// 公開API
+pub use model::*;
+pub use parser::HarParser;
@@ -0,0 +1,234 @@
+//! HARファイルと解析レポートのデータモデル
+//!
+//! HAR 1.2のうち、ページ解析に必要な項目のみを読み込みます。
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::parsers::page::{PageData, PageKind};
+use crate::utils::encoding::decode_html;
+use crate::utils::error::ParseError;
+
+/// HARファイルのルート
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct Har {
+    /// ログ本体
+    pub log: HarLog,
+}
+
+/// HARのログ
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct HarLog {
+    /// 記録されたリクエストの一覧
+    #[serde(default)]
+    pub entries: Vec<HarEntry>,
+}
+
+/// 1件のリクエストとレスポンス
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HarEntry {
+    /// リクエスト開始日時（ISO 8601）
+    #[serde(default)]
+    pub started_date_time: String,
+    /// リクエスト
+    pub request: HarRequest,
+    /// レスポンス
+    pub response: HarResponse,
+}
+
+/// リクエスト情報
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct HarRequest {
+    /// HTTPメソッド
+    #[serde(default)]
+    pub method: String,
+    /// リクエストURL
+    #[serde(default)]
+    pub url: String,
+}
+
+/// レスポンス情報
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct HarResponse {
+    /// HTTPステータスコード
+    #[serde(default)]
+    pub status: i32,
+    /// レスポンスヘッダー
+    #[serde(default)]
+    pub headers: Vec<HarHeader>,
+    /// レスポンス本文
+    #[serde(default)]
+    pub content: HarContent,
+}
+
+/// HTTPヘッダー
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct HarHeader {
+    /// ヘッダー名
+    pub name: String,
+    /// ヘッダー値
+    pub value: String,
+}
+
+/// レスポンス本文
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HarContent {
+    /// MIMEタイプ（charsetを含む場合あり）
+    #[serde(default)]
+    pub mime_type: String,
+    /// 本文（`encoding`が`base64`の場合はエンコード済み）
+    pub text: Option<String>,
+    /// 本文のエンコーディング
+    pub encoding: Option<String>,
+}
+
+impl Har {
+    /// HARファイルのJSON文字列を読み込む
+    pub fn from_json(json: &str) -> Result<Self, ParseError> {
+        serde_json::from_str(json).map_err(|e| ParseError::InvalidDataFormat {
+            data_type: "HAR".to_string(),
+            details: e.to_string(),
+        })
+    }
+}
+
+impl HarEntry {
+    /// レスポンスのContent-Type（ヘッダーがなければ`mimeType`）
+    pub fn content_type(&self) -> &str {
+        self.response
+            .headers
+            .iter()
+            .find(|header| header.name.eq_ignore_ascii_case("content-type"))
+            .map(|header| header.value.as_str())
+            .unwrap_or(&self.response.content.mime_type)
+    }
+
+    /// レスポンスがHTMLかどうか
+    pub fn is_html(&self) -> bool {
+        let mime_type = self.content_type().split(';').next().unwrap_or("").trim();
+        mime_type.eq_ignore_ascii_case("text/html")
+            || mime_type.eq_ignore_ascii_case("application/xhtml+xml")
+    }
+
+    /// URLに含まれる画面ID（例: `Stb00101A`）
+    pub fn screen_id(&self) -> Option<&str> {
+        // 画面IDは「英大文字1字 + 英小文字2字 + 数字5桁 + 英大文字1字」の9文字
+        fn is_screen_id(bytes: &[u8]) -> bool {
+            bytes[0].is_ascii_uppercase()
+                && bytes[1..3].iter().all(u8::is_ascii_lowercase)
+                && bytes[3..8].iter().all(u8::is_ascii_digit)
+                && bytes[8].is_ascii_uppercase()
+        }
+
+        let url = &self.request.url;
+        url.as_bytes()
+            .windows(9)
+            .position(is_screen_id)
+            .map(|start| &url[start..start + 9])
+    }
+
+    /// レスポンス本文をデコードする
+    ///
+    /// base64エンコードされた本文はContent-Typeのcharset、`<meta>`宣言、
+    /// バイト列からの推定の順に文字コードを判定してデコードします。
+    /// 本文が記録されていない場合は`None`を返します。
+    pub fn decode_body(&self) -> Result<Option<String>, ParseError> {
+        let Some(text) = &self.response.content.text else {
+            return Ok(None);
+        };
+
+        if self.response.content.encoding.as_deref() != Some("base64") {
+            return Ok(Some(text.clone()));
+        }
+
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(text.trim())
+            .map_err(|e| ParseError::InvalidDataFormat {
+                data_type: "HAR response body".to_string(),
+                details: e.to_string(),
+            })?;
+        decode_html(&bytes, Some(self.content_type()), &self.request.url).map(Some)
+    }
+}
+
+/// HAR全体の解析レポート
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct HarReport {
+    /// HTMLレスポンスごとの解析結果
+    pub entries: Vec<HarEntryReport>,
+    /// HTML以外のため解析対象外としたエントリー数
+    pub skipped_entries: usize,
+}
+
+impl HarReport {
+    /// 解析に成功したエントリー数
+    pub fn parsed_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.outcome, HarEntryOutcome::Parsed { .. }))
+            .count()
+    }
+
+    /// 解析に失敗したエントリー数
+    pub fn failed_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.outcome, HarEntryOutcome::Failed { .. }))
+            .count()
+    }
+}
+
+/// 1件のHTMLレスポンスの解析結果
+#[derive(Serialize, Clone, Debug)]
+pub struct HarEntryReport {
+    /// HAR内でのエントリー番号（0始まり）
+    pub index: usize,
+    /// リクエスト開始日時
+    pub started_date_time: String,
+    /// HTTPメソッド
+    pub method: String,
+    /// リクエストURL
+    pub url: String,
+    /// HTTPステータスコード
+    pub status: i32,
+    /// URLから取得した画面ID
+    pub screen_id: Option<String>,
+    /// ページ種別の判定方法
+    pub classified_by: Option<Classification>,
+    /// 解析結果
+    pub outcome: HarEntryOutcome,
+}
+
+/// ページ種別の判定方法
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Classification {
+    /// URLの画面IDから判定
+    ScreenId,
+    /// HTMLの構造から判定
+    Detection,
+}
+
+/// エントリーごとの解析結果
+#[derive(Serialize, Clone, Debug)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum HarEntryOutcome {
+    /// 解析に成功
+    Parsed {
+        /// ページ種別
+        kind: PageKind,
+        /// 解析結果
+        data: Box<PageData>,
+    },
+    /// デコードまたは解析に失敗
+    Failed {
+        /// ページ種別（判定前に失敗した場合は`None`）
+        kind: Option<PageKind>,
+        /// 発生したエラー
+        error: ParseError,
+    },
+    /// 対応するページ種別を判定できなかった
+    Unclassified,
+}
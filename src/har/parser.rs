@@ -0,0 +1,105 @@
+//! HARエントリーの分類と解析の実装
+
+use scraper::Html;
+
+use super::model::*;
+use crate::parsers::page::PageKind;
+use crate::utils::error::ParseError;
+
+/// HARファイルの一括解析パーサー
+///
+/// HTMLレスポンスごとに画面IDを優先してページ種別を判定し、
+/// 判定できない場合はHTMLの構造から推定します。
+#[derive(Default)]
+pub struct HarParser;
+
+impl HarParser {
+    /// 新しいパーサーインスタンスを作成
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// HARファイルのJSON文字列を読み込んで解析する
+    ///
+    /// HAR自体の読み込みに失敗した場合のみエラーを返し、
+    /// 個々のエントリーの失敗はレポートに記録されます。
+    pub fn parse_str(&self, json: &str) -> Result<HarReport, ParseError> {
+        let har = Har::from_json(json)?;
+        Ok(self.parse_har(&har))
+    }
+
+    /// 読み込み済みのHARを解析する
+    pub fn parse_har(&self, har: &Har) -> HarReport {
+        let mut report = HarReport::default();
+
+        for (index, entry) in har.log.entries.iter().enumerate() {
+            if !entry.is_html() {
+                report.skipped_entries += 1;
+                continue;
+            }
+
+            let (classified_by, outcome) = self.parse_entry(entry);
+            report.entries.push(HarEntryReport {
+                index,
+                started_date_time: entry.started_date_time.clone(),
+                method: entry.request.method.clone(),
+                url: entry.request.url.clone(),
+                status: entry.response.status,
+                screen_id: entry.screen_id().map(str::to_string),
+                classified_by,
+                outcome,
+            });
+        }
+
+        report
+    }
+
+    /// 1件のエントリーを分類して解析する
+    fn parse_entry(&self, entry: &HarEntry) -> (Option<Classification>, HarEntryOutcome) {
+        let html = match entry.decode_body() {
+            Ok(Some(html)) if !html.trim().is_empty() => html,
+            Ok(_) => {
+                return (
+                    None,
+                    HarEntryOutcome::Failed {
+                        kind: None,
+                        error: ParseError::empty_html_content(),
+                    },
+                )
+            }
+            Err(e) => {
+                return (
+                    None,
+                    HarEntryOutcome::Failed {
+                        kind: None,
+                        error: e,
+                    },
+                )
+            }
+        };
+
+        let document = Html::parse_document(&html);
+        let classified = entry
+            .screen_id()
+            .and_then(PageKind::from_screen_id)
+            .map(|kind| (Classification::ScreenId, kind))
+            .or_else(|| PageKind::detect(&document).map(|kind| (Classification::Detection, kind)));
+
+        let Some((classified_by, kind)) = classified else {
+            return (None, HarEntryOutcome::Unclassified);
+        };
+
+        let outcome = match kind.parse(&document) {
+            Ok(data) => HarEntryOutcome::Parsed {
+                kind,
+                data: Box::new(data),
+            },
+            Err(e) => HarEntryOutcome::Failed {
+                kind: Some(kind),
+                error: e,
+            },
+        };
+
+        (Some(classified_by), outcome)
+    }
+}
@@ -1,4 +1,7 @@
 pub mod common;
+pub mod export;
+#[cfg(feature = "har")]
+pub mod har;
 pub mod parsers;
 pub mod utils;
 
@@ -6,47 +6,57 @@ use scraper::Html;
 
 use super::model::AssignmentList;
 use super::parser::AssignmentListParserImpl;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 
 /// 課題提出一覧パーサービルダー
 pub struct AssignmentListParserBuilder {
-    /// デバッグモード
-    debug_mode: bool,
-    /// 厳密なパースモード
-    strict_mode: bool,
+    /// 解析オプション
+    options: ParseOptions,
 }
 
 impl AssignmentListParserBuilder {
     /// 新しいパーサービルダーインスタンスを作成
     pub fn new() -> Self {
         Self {
-            debug_mode: false,
-            strict_mode: false,
+            options: ParseOptions::default(),
         }
     }
 
+    /// 解析オプションを設定
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// デバッグモードを設定
     pub fn with_debug_mode(mut self, debug_mode: bool) -> Self {
-        self.debug_mode = debug_mode;
+        self.options.debug_mode = debug_mode;
         self
     }
 
     /// 厳密なパースモードを設定
     pub fn with_strict_mode(mut self, strict_mode: bool) -> Self {
-        self.strict_mode = strict_mode;
+        self.options.strict_mode = strict_mode;
         self
     }
 
     /// 課題提出一覧パーサーを構築
     pub fn build(&self) -> Result<AssignmentListParserImpl, ParseError> {
-        Ok(AssignmentListParserImpl::new_with_config(
-            self.debug_mode,
-            self.strict_mode,
+        Ok(AssignmentListParserImpl::new_with_options(
+            self.options.clone(),
         ))
     }
 }
 
+impl Default for AssignmentListParserBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PageParser<AssignmentList> for AssignmentListParserBuilder {
     const PAGE_TYPE: &'static str = "課題提出一覧";
 
@@ -54,4 +64,19 @@ impl PageParser<AssignmentList> for AssignmentListParserBuilder {
         let parser = self.build()?;
         parser.parse_document(document)
     }
+
+    fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<AssignmentList>, ParseError> {
+        self.build()?.parse_with_warnings(document)
+    }
+
+    fn parse_with_diagnostics(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<AssignmentList>, ParseError> {
+        AssignmentListParserImpl::new_with_options(self.options.clone().with_debug_mode(true))
+            .parse_with_warnings(document)
+    }
 }
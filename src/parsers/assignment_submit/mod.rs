@@ -17,32 +17,3 @@ pub mod assignment_list {
     pub use super::builder::AssignmentListParserBuilder as AssignmentListParser;
     pub use super::model::*;
 }
-
-use crate::common::traits::PageParser;
-use scraper::Html;
-
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen::prelude::*;
-
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-pub struct AssignmentSubmitParser {
-    assignment_list: builder::AssignmentListParserBuilder,
-}
-
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-impl AssignmentSubmitParser {
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Self {
-        Self {
-            assignment_list: builder::AssignmentListParserBuilder::new(),
-        }
-    }
-
-    pub fn assignment_list(&self, html_content: &str) -> Result<model::AssignmentList, JsError> {
-        Ok(self
-            .assignment_list
-            .parse_document(&Html::parse_document(html_content))?)
-    }
-}
@@ -6,8 +6,11 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::datetime::JstDateTime;
+
 /// 課題提出一覧全体を表現する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct AssignmentList {
     /// 課題一覧
@@ -18,14 +21,15 @@ pub struct AssignmentList {
 
 /// 個別の課題情報を表現する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Assignment {
     /// 科目名
     pub subject_name: String,
     /// 課題タイトル
     pub assignment_title: String,
-    /// 提出期限（例：「2025-06-15 23:59」）
-    pub due_date: String,
+    /// 提出期限（表記を解釈できない場合はNone）
+    pub due_date: Option<JstDateTime>,
     /// 提出状況
     pub submission_status: SubmissionStatus,
     /// 課題の詳細説明
@@ -35,7 +39,7 @@ pub struct Assignment {
     /// 提出済みファイル名（提出済みの場合）
     pub submitted_file_name: Option<String>,
     /// 提出日時（提出済みの場合）
-    pub submission_date: Option<String>,
+    pub submission_date: Option<JstDateTime>,
     /// 教員からのコメント（評価済みの場合）
     pub teacher_comment: Option<String>,
     /// 評価点（評価済みの場合）
@@ -44,6 +48,7 @@ pub struct Assignment {
 
 /// 提出状況を表現する列挙型
 #[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum SubmissionStatus {
     /// 未提出
@@ -64,6 +69,7 @@ impl Default for SubmissionStatus {
 
 /// ページネーション情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct PaginationInfo {
     /// 現在のページ番号
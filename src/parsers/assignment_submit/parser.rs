@@ -2,32 +2,75 @@
 //!
 //! HTMLドキュメントから課題提出一覧情報を抽出するパースロジックを提供します。
 
-use scraper::{Html, Selector};
+use scraper::Html;
 
 use super::model::{Assignment, AssignmentList, PaginationInfo, SubmissionStatus};
+use crate::common::context::ParseContext;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::selectors::selector_registry;
 use crate::utils::error::ParseError;
 
+selector_registry! {
+    /// 課題提出一覧画面で使用するセレクター
+    struct AssignmentListSelectors {
+        assignment_table: "table.listTable",
+        pagination: ".pagination, .pager, .page-nav",
+        active_page: ".active, .current",
+        count_info: ".result-count, .count-info, .total",
+        body_row: "tbody tr",
+        cell: "td",
+        link: "a",
+    }
+}
+
 /// 課題提出一覧パーサー実装
 pub struct AssignmentListParserImpl {
-    /// デバッグモード
-    debug_mode: bool,
-    /// 厳密なパースモード
-    strict_mode: bool,
+    /// 解析オプション
+    options: ParseOptions,
 }
 
 impl AssignmentListParserImpl {
     /// 新しいパーサーインスタンスを作成
     pub fn new_with_config(debug_mode: bool, strict_mode: bool) -> Self {
-        Self {
-            debug_mode,
-            strict_mode,
-        }
+        Self::new_with_options(
+            ParseOptions::new()
+                .with_debug_mode(debug_mode)
+                .with_strict_mode(strict_mode),
+        )
+    }
+
+    /// 解析オプションを指定してパーサーインスタンスを作成
+    pub fn new_with_options(options: ParseOptions) -> Self {
+        Self { options }
     }
 
     /// HTMLドキュメントから課題提出一覧を解析する
     pub fn parse_document(&self, document: &Html) -> Result<AssignmentList, ParseError> {
-        let assignments = self.parse_assignments(document)?;
-        let pagination = self.parse_pagination(document)?;
+        let mut ctx = ParseContext::new().with_strict(self.options.strict_mode);
+        self.parse_with_context(document, &mut ctx)
+    }
+
+    /// 課題提出一覧を解析し、警告とあわせて返す
+    ///
+    /// デバッグモードの場合は、試行したセレクターの記録も`diagnostics`に含めます。
+    pub fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<AssignmentList>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+
+    /// 警告や診断情報をコンテキストに記録しながら課題提出一覧を解析する
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<AssignmentList, ParseError> {
+        let assignments = self.parse_assignments(document, ctx)?;
+        let pagination = self.parse_pagination(document, ctx)?;
 
         Ok(AssignmentList {
             assignments,
@@ -36,29 +79,37 @@ impl AssignmentListParserImpl {
     }
 
     /// 課題一覧を解析する
-    fn parse_assignments(&self, document: &Html) -> Result<Vec<Assignment>, ParseError> {
-        let table_selector = Selector::parse("table.listTable")
-            .map_err(|e| ParseError::selector_creation_failed("table.listTable", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tbody tr")
-            .map_err(|e| ParseError::selector_creation_failed("tbody tr", &e.to_string()))?;
-
-        let cell_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
+    fn parse_assignments(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<Assignment>, ParseError> {
+        let selectors = AssignmentListSelectors::get()?;
         let mut assignments = Vec::new();
 
-        if let Some(table) = document.select(&table_selector).next() {
-            for row in table.select(&row_selector) {
-                let cells: Vec<_> = row.select(&cell_selector).collect();
+        ctx.trace_selector(
+            "assignments",
+            &selectors.assignment_table,
+            document.root_element(),
+        );
+        if let Some(table) = document.select(&selectors.assignment_table).next() {
+            for (row_index, row) in table.select(&selectors.body_row).enumerate() {
+                let cells: Vec<_> = row.select(&selectors.cell).collect();
 
                 if cells.len() >= 4 {
                     let subject_name = cells[0].inner_html().trim().to_string();
                     let assignment_title = cells[1].inner_html().trim().to_string();
-                    let due_date = self.format_due_date(cells[2].inner_html().trim())?;
+                    let due_date =
+                        ctx.parse_cell("assignments", row_index, 2, &cells[2].inner_html())?;
                     let status_html = cells[3].inner_html();
                     let status_text = status_html.trim();
-                    let submission_status = self.parse_submission_status(status_text)?;
+                    let submission_status = match self.parse_submission_status(status_text)? {
+                        Some(status) => status,
+                        None => {
+                            ctx.unparseable_cell("assignments", row_index, 3, status_text)?;
+                            SubmissionStatus::NotSubmitted
+                        }
+                    };
 
                     assignments.push(Assignment {
                         subject_name,
@@ -72,78 +123,58 @@ impl AssignmentListParserImpl {
                         teacher_comment: None,
                         score: None,
                     });
+                } else {
+                    ctx.skip_short_row("assignments", row_index, cells.len(), 4);
                 }
             }
+        } else {
+            ctx.missing_section("assignments", selectors.assignment_table.source())?;
         }
 
         Ok(assignments)
     }
 
-    /// 提出期限を標準形式に変換する
-    fn format_due_date(&self, date_str: &str) -> Result<String, ParseError> {
-        if date_str.is_empty() {
-            return Ok(String::new());
-        }
-
-        // Convert Japanese date format to ISO format
-        if let Some(captures) =
-            regex_lite::Regex::new(r"(\d{4})年(\d{1,2})月(\d{1,2})日\s*(\d{1,2}):(\d{2})")
-                .map_err(|e| ParseError::data_parsing_failed("date regex", &e.to_string()))?
-                .captures(date_str)
-        {
-            let year = &captures[1];
-            let month = format!("{:02}", captures[2].parse::<u32>().unwrap_or(1));
-            let day = format!("{:02}", captures[3].parse::<u32>().unwrap_or(1));
-            let hour = format!("{:02}", captures[4].parse::<u32>().unwrap_or(0));
-            let minute = format!("{:02}", captures[5].parse::<u32>().unwrap_or(0));
-            Ok(format!("{}-{}-{} {}:{}", year, month, day, hour, minute))
-        } else {
-            Ok(date_str.to_string())
-        }
-    }
-
-    /// 提出状況を解析する
-    fn parse_submission_status(&self, status_text: &str) -> Result<SubmissionStatus, ParseError> {
+    /// 提出状況を解析する（該当しない表記の場合は`None`）
+    fn parse_submission_status(
+        &self,
+        status_text: &str,
+    ) -> Result<Option<SubmissionStatus>, ParseError> {
         match status_text {
-            s if s.contains("未提出") => Ok(SubmissionStatus::NotSubmitted),
-            s if s.contains("提出済") => Ok(SubmissionStatus::Submitted),
-            s if s.contains("期限切れ") => Ok(SubmissionStatus::Overdue),
-            s if s.contains("評価済") => Ok(SubmissionStatus::Evaluated),
-            _ => Ok(SubmissionStatus::NotSubmitted), // Default to not submitted
+            s if s.contains("未提出") => Ok(Some(SubmissionStatus::NotSubmitted)),
+            s if s.contains("提出済") => Ok(Some(SubmissionStatus::Submitted)),
+            s if s.contains("期限切れ") => Ok(Some(SubmissionStatus::Overdue)),
+            s if s.contains("評価済") => Ok(Some(SubmissionStatus::Evaluated)),
+            _ => Ok(None),
         }
     }
 
     /// ページネーション情報を解析する
-    fn parse_pagination(&self, _document: &Html) -> Result<Option<PaginationInfo>, ParseError> {
-        // ページネーション要素を探す
-        let pagination_selector =
-            Selector::parse(".pagination, .pager, .page-nav").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    ".pagination, .pager, .page-nav",
-                    &e.to_string(),
-                )
-            })?;
+    fn parse_pagination(
+        &self,
+        _document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<Option<PaginationInfo>, ParseError> {
+        let selectors = AssignmentListSelectors::get()?;
 
-        if let Some(pagination_element) = _document.select(&pagination_selector).next() {
+        // ページネーション要素を探す
+        ctx.trace_selector(
+            "pagination",
+            &selectors.pagination,
+            _document.root_element(),
+        );
+        if let Some(pagination_element) = _document.select(&selectors.pagination).next() {
             let mut pagination_info = PaginationInfo::default();
 
             // ページ番号リンクを探す
-            let link_selector = Selector::parse("a")
-                .map_err(|e| ParseError::selector_creation_failed("a", &e.to_string()))?;
-
             let mut page_numbers = Vec::new();
-            for link in pagination_element.select(&link_selector) {
+            for link in pagination_element.select(&selectors.link) {
                 if let Ok(text) = link.inner_html().trim().parse::<u32>() {
                     page_numbers.push(text);
                 }
             }
 
             // 現在のページ番号を特定（active class などから）
-            let active_selector = Selector::parse(".active, .current").map_err(|e| {
-                ParseError::selector_creation_failed(".active, .current", &e.to_string())
-            })?;
-
-            if let Some(active_element) = pagination_element.select(&active_selector).next() {
+            if let Some(active_element) = pagination_element.select(&selectors.active_page).next() {
                 if let Ok(current) = active_element.inner_html().trim().parse::<u32>() {
                     pagination_info.current_page = current;
                 }
@@ -162,24 +193,24 @@ impl AssignmentListParserImpl {
         }
 
         // ページネーションが見つからない場合は、総件数などから推測
-        self.parse_pagination_from_content(_document)
+        self.parse_pagination_from_content(_document, ctx)
     }
 
     /// コンテンツから間接的にページネーション情報を推測する
     fn parse_pagination_from_content(
         &self,
         _document: &Html,
+        ctx: &mut ParseContext,
     ) -> Result<Option<PaginationInfo>, ParseError> {
-        // 結果件数表示を探す（例：「1-20件 / 全50件」）
-        let count_info_selector =
-            Selector::parse(".result-count, .count-info, .total").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    ".result-count, .count-info, .total",
-                    &e.to_string(),
-                )
-            })?;
+        let selectors = AssignmentListSelectors::get()?;
 
-        if let Some(count_element) = _document.select(&count_info_selector).next() {
+        // 結果件数表示を探す（例：「1-20件 / 全50件」）
+        ctx.trace_selector(
+            "pagination",
+            &selectors.count_info,
+            _document.root_element(),
+        );
+        if let Some(count_element) = _document.select(&selectors.count_info).next() {
             let count_text = count_element.inner_html();
 
             // 件数情報から総数を抽出
@@ -192,11 +223,12 @@ impl AssignmentListParserImpl {
                     let items_per_page = 20; // 一般的な1ページあたりの件数
                     let total_pages = total.div_ceil(items_per_page);
 
-                    let mut pagination_info = PaginationInfo::default();
-                    pagination_info.current_page = 1;
-                    pagination_info.total_pages = total_pages;
-                    pagination_info.has_previous = false;
-                    pagination_info.has_next = total_pages > 1;
+                    let pagination_info = PaginationInfo {
+                        current_page: 1,
+                        total_pages,
+                        has_previous: false,
+                        has_next: total_pages > 1,
+                    };
 
                     return Ok(Some(pagination_info));
                 }
@@ -6,47 +6,57 @@ use scraper::Html;
 
 use super::model::ClassroomReservationStatus;
 use super::parser::ClassroomReservationStatusParserImpl;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 
 /// 教室予約状況パーサービルダー
 pub struct ClassroomReservationStatusParserBuilder {
-    /// デバッグモード
-    debug_mode: bool,
-    /// 厳密なパースモード
-    strict_mode: bool,
+    /// 解析オプション
+    options: ParseOptions,
 }
 
 impl ClassroomReservationStatusParserBuilder {
     /// 新しいパーサービルダーインスタンスを作成
     pub fn new() -> Self {
         Self {
-            debug_mode: false,
-            strict_mode: false,
+            options: ParseOptions::default(),
         }
     }
 
+    /// 解析オプションを設定
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// デバッグモードを設定
     pub fn with_debug_mode(mut self, debug_mode: bool) -> Self {
-        self.debug_mode = debug_mode;
+        self.options.debug_mode = debug_mode;
         self
     }
 
     /// 厳密なパースモードを設定
     pub fn with_strict_mode(mut self, strict_mode: bool) -> Self {
-        self.strict_mode = strict_mode;
+        self.options.strict_mode = strict_mode;
         self
     }
 
     /// 教室予約状況パーサーを構築
     pub fn build(&self) -> Result<ClassroomReservationStatusParserImpl, ParseError> {
-        Ok(ClassroomReservationStatusParserImpl::new_with_config(
-            self.debug_mode,
-            self.strict_mode,
+        Ok(ClassroomReservationStatusParserImpl::new_with_options(
+            self.options.clone(),
         ))
     }
 }
 
+impl Default for ClassroomReservationStatusParserBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PageParser<ClassroomReservationStatus> for ClassroomReservationStatusParserBuilder {
     const PAGE_TYPE: &'static str = "教室予約状況";
 
@@ -54,4 +64,21 @@ impl PageParser<ClassroomReservationStatus> for ClassroomReservationStatusParser
         let parser = self.build()?;
         parser.parse_document(document)
     }
+
+    fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<ClassroomReservationStatus>, ParseError> {
+        self.build()?.parse_with_warnings(document)
+    }
+
+    fn parse_with_diagnostics(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<ClassroomReservationStatus>, ParseError> {
+        ClassroomReservationStatusParserImpl::new_with_options(
+            self.options.clone().with_debug_mode(true),
+        )
+        .parse_with_warnings(document)
+    }
 }
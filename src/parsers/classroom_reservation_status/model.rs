@@ -6,8 +6,13 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::datetime::JstDateTime;
+use crate::common::period::ClassTime;
+use crate::common::term::AcademicTerm;
+
 /// 教室予約状況ページ全体から抽出されるデータを格納するメイン構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ClassroomReservationStatus {
     /// ページ上部の検索条件
@@ -26,12 +31,11 @@ pub struct ClassroomReservationStatus {
 
 /// ページ上部の検索条件を格納する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct SearchParams {
-    /// 検索対象年度
-    pub academic_year: String,
-    /// 検索対象学期
-    pub semester: String,
+    /// 検索対象の年度・学期（年度と学期の両方が指定されている場合）
+    pub term: Option<AcademicTerm>,
     /// 検索対象曜日
     pub day_of_week: String,
     /// 検索対象時限
@@ -48,10 +52,13 @@ pub struct SearchParams {
 
 /// 教室予約状況表示テーブルの各行のデータを格納する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ClassroomReservationTableData {
     /// 時限
     pub period: String,
+    /// 時限の開始・終了時刻（曜日は各列で表すため`None`）
+    pub class_time: Option<ClassTime>,
     /// 月曜日の予約状況
     pub monday: ClassroomReservationCell,
     /// 火曜日の予約状況
@@ -70,6 +77,7 @@ pub struct ClassroomReservationTableData {
 
 /// 教室予約状況テーブルの各セル（曜日×時限）の情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ClassroomReservationCell {
     /// 予約状況のタイプ
@@ -88,6 +96,7 @@ pub struct ClassroomReservationCell {
 
 /// 予約状況のタイプを表現する列挙型
 #[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum ReservationType {
     /// 予約なし（空き状況）
@@ -112,6 +121,7 @@ impl Default for ReservationType {
 
 /// 予約詳細ポップアップから抽出されるデータを格納する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct PopupReservationDetail {
     /// 予約ID
@@ -124,10 +134,10 @@ pub struct PopupReservationDetail {
     pub instructor_name: String,
     /// 教室名
     pub classroom_name: String,
-    /// 開始日時（ISO 8601形式: YYYY-MM-DDTHH:MM:SS+hh:mm）
-    pub start_datetime: String,
-    /// 終了日時（ISO 8601形式: YYYY-MM-DDTHH:MM:SS+hh:mm）
-    pub end_datetime: String,
+    /// 開始日時
+    pub start_datetime: Option<JstDateTime>,
+    /// 終了日時
+    pub end_datetime: Option<JstDateTime>,
     /// 履修者数
     pub enrollment_count: Option<u32>,
     /// 備考
@@ -136,6 +146,7 @@ pub struct PopupReservationDetail {
 
 /// 授業詳細ポップアップから抽出されるデータを格納する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct PopupClassDetail {
     /// 授業ID
@@ -150,10 +161,12 @@ pub struct PopupClassDetail {
     pub credit_count: u32,
     /// 履修者数
     pub enrollment_count: u32,
-    /// 開講学期
-    pub semester: String,
+    /// 開講年度・学期
+    pub term: Option<AcademicTerm>,
     /// 曜日・時限
     pub schedule: String,
+    /// 曜日・時限から求めた開始・終了時刻（解釈できない場合はNone）
+    pub class_time: Option<ClassTime>,
     /// 教室名
     pub classroom_name: String,
     /// 授業概要
@@ -162,6 +175,7 @@ pub struct PopupClassDetail {
 
 /// 重複授業詳細ポップアップから抽出されるデータを格納する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct PopupDuplicateClassDetail {
     /// 重複している授業のリスト
@@ -174,6 +188,7 @@ pub struct PopupDuplicateClassDetail {
 
 /// 重複している個別の授業情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ConflictingClass {
     /// 授業ID
@@ -190,6 +205,7 @@ pub struct ConflictingClass {
 
 /// 教室詳細ポップアップから抽出されるデータを格納する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct PopupClassroomDetail {
     /// 教室ID
@@ -2,30 +2,58 @@
 //!
 //! HTMLドキュメントから教室予約状況情報を抽出するパースロジックを提供します。
 
-use scraper::{Html, Selector};
+use scraper::Html;
 
 use super::model::{
     ClassroomReservationCell, ClassroomReservationStatus, ClassroomReservationTableData,
-    ConflictingClass, PopupClassDetail, PopupClassroomDetail, PopupDuplicateClassDetail,
-    PopupReservationDetail, ReservationType, SearchParams,
+    PopupClassDetail, PopupClassroomDetail, PopupDuplicateClassDetail, PopupReservationDetail,
+    ReservationType, SearchParams,
 };
+use crate::common::context::ParseContext;
+use crate::common::datetime::{JstDateTime, JstRange};
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::selectors::selector_registry;
+use crate::common::term::AcademicTerm;
 use crate::utils::error::ParseError;
 
+selector_registry! {
+    /// 教室予約状況画面で使用するセレクター
+    struct ClassroomReservationSelectors {
+        search_form: "form, .search-form, .condition-form",
+        input: "input",
+        select: "select",
+        option: "option",
+        reservation_table: "table.reservationTable, table.timeTable, table",
+        row: "tbody tr, tr",
+        cell: "td",
+        link: "a",
+        reservation_popup: ".popup-reservation, .reservation-detail, #reservationDetail",
+        class_popup: ".popup-class, .class-detail, #classDetail",
+        duplicate_popup: ".popup-duplicate, .duplicate-detail, #duplicateDetail",
+        classroom_popup: ".popup-classroom, .classroom-detail, #classroomDetail",
+    }
+}
+
 /// 教室予約状況パーサー実装
 pub struct ClassroomReservationStatusParserImpl {
-    /// デバッグモード
-    debug_mode: bool,
-    /// 厳密なパースモード
-    strict_mode: bool,
+    /// 解析オプション
+    options: ParseOptions,
 }
 
 impl ClassroomReservationStatusParserImpl {
     /// 新しいパーサーインスタンスを作成
     pub fn new_with_config(debug_mode: bool, strict_mode: bool) -> Self {
-        Self {
-            debug_mode,
-            strict_mode,
-        }
+        Self::new_with_options(
+            ParseOptions::new()
+                .with_debug_mode(debug_mode)
+                .with_strict_mode(strict_mode),
+        )
+    }
+
+    /// 解析オプションを指定してパーサーインスタンスを作成
+    pub fn new_with_options(options: ParseOptions) -> Self {
+        Self { options }
     }
 
     /// HTMLドキュメントから教室予約状況を解析する
@@ -33,12 +61,37 @@ impl ClassroomReservationStatusParserImpl {
         &self,
         document: &Html,
     ) -> Result<ClassroomReservationStatus, ParseError> {
-        let search_params = self.parse_search_params(document)?;
-        let reservation_table_data = self.parse_reservation_table_data(document)?;
-        let popup_reservation_detail = self.parse_popup_reservation_detail(document)?;
-        let popup_class_detail = self.parse_popup_class_detail(document)?;
-        let popup_duplicate_class_detail = self.parse_popup_duplicate_class_detail(document)?;
-        let popup_classroom_detail = self.parse_popup_classroom_detail(document)?;
+        let mut ctx = ParseContext::new()
+            .with_strict(self.options.strict_mode)
+            .with_period_table(self.options.period_table.clone());
+        self.parse_with_context(document, &mut ctx)
+    }
+
+    /// 教室予約状況を解析し、警告とあわせて返す
+    ///
+    /// デバッグモードでは、予約表やポップアップの探索に使ったセレクターの記録を含めます。
+    pub fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<ClassroomReservationStatus>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+
+    /// 警告や診断情報をコンテキストに記録しながら教室予約状況を解析する
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<ClassroomReservationStatus, ParseError> {
+        let search_params = self.parse_search_params(document, ctx)?;
+        let reservation_table_data = self.parse_reservation_table_data(document, ctx)?;
+        let popup_reservation_detail = self.parse_popup_reservation_detail(document, ctx)?;
+        let popup_class_detail = self.parse_popup_class_detail(document, ctx)?;
+        let popup_duplicate_class_detail =
+            self.parse_popup_duplicate_class_detail(document, ctx)?;
+        let popup_classroom_detail = self.parse_popup_classroom_detail(document, ctx)?;
 
         Ok(ClassroomReservationStatus {
             search_params,
@@ -51,36 +104,34 @@ impl ClassroomReservationStatusParserImpl {
     }
 
     /// ページ上部の検索条件を抽出する
-    fn parse_search_params(&self, document: &Html) -> Result<SearchParams, ParseError> {
-        let form_selector =
-            Selector::parse("form, .search-form, .condition-form").map_err(|e| {
-                ParseError::selector_creation_failed("form search selector", &e.to_string())
-            })?;
-
-        let input_selector = Selector::parse("input")
-            .map_err(|e| ParseError::selector_creation_failed("input", &e.to_string()))?;
-
-        let select_selector = Selector::parse("select")
-            .map_err(|e| ParseError::selector_creation_failed("select", &e.to_string()))?;
-
-        let option_selector = Selector::parse("option")
-            .map_err(|e| ParseError::selector_creation_failed("option", &e.to_string()))?;
-
+    fn parse_search_params(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<SearchParams, ParseError> {
+        let selectors = ClassroomReservationSelectors::get()?;
         let mut search_params = SearchParams::default();
+        let mut academic_year = String::new();
+        let mut semester = String::new();
 
         // フォーム要素を探す
-        for form_element in document.select(&form_selector) {
+        ctx.trace_selector(
+            "search_params",
+            &selectors.search_form,
+            document.root_element(),
+        );
+        for form_element in document.select(&selectors.search_form) {
             // input要素の処理
-            for input in form_element.select(&input_selector) {
+            for input in form_element.select(&selectors.input) {
                 if let Some(name) = input.value().attr("name") {
                     let value = input.value().attr("value").unwrap_or("").to_string();
 
                     match name {
                         n if n.contains("year") || n.contains("nendo") => {
-                            search_params.academic_year = value;
+                            academic_year = value;
                         }
                         n if n.contains("semester") || n.contains("gakki") => {
-                            search_params.semester = value;
+                            semester = value;
                         }
                         n if n.contains("dayofweek") || n.contains("youbi") => {
                             search_params.day_of_week = value;
@@ -100,11 +151,11 @@ impl ClassroomReservationStatusParserImpl {
             }
 
             // select要素の処理
-            for select in form_element.select(&select_selector) {
+            for select in form_element.select(&selectors.select) {
                 if let Some(name) = select.value().attr("name") {
                     // 選択されているoptionを探す
                     let selected_value = select
-                        .select(&option_selector)
+                        .select(&selectors.option)
                         .find(|option| option.value().attr("selected").is_some())
                         .and_then(|option| option.value().attr("value"))
                         .unwrap_or("")
@@ -118,10 +169,10 @@ impl ClassroomReservationStatusParserImpl {
                             search_params.classroom = selected_value;
                         }
                         n if n.contains("year") || n.contains("nendo") => {
-                            search_params.academic_year = selected_value;
+                            academic_year = selected_value;
                         }
                         n if n.contains("semester") || n.contains("gakki") => {
-                            search_params.semester = selected_value;
+                            semester = selected_value;
                         }
                         n if n.contains("dayofweek") || n.contains("youbi") => {
                             search_params.day_of_week = selected_value;
@@ -135,6 +186,23 @@ impl ClassroomReservationStatusParserImpl {
             }
         }
 
+        // 学期の値は年度を含まないことが多いため、年度と組み合わせて解釈する
+        if !academic_year.is_empty() && !semester.is_empty() {
+            search_params.term = academic_year
+                .trim()
+                .parse()
+                .ok()
+                .and_then(|year| AcademicTerm::parse_in_year(&semester, year).ok());
+            if search_params.term.is_none() {
+                ctx.unparseable_cell(
+                    "search_params",
+                    0,
+                    0,
+                    &format!("{academic_year} {semester}"),
+                )?;
+            }
+        }
+
         Ok(search_params)
     }
 
@@ -142,28 +210,29 @@ impl ClassroomReservationStatusParserImpl {
     fn parse_reservation_table_data(
         &self,
         document: &Html,
+        ctx: &mut ParseContext,
     ) -> Result<Vec<ClassroomReservationTableData>, ParseError> {
-        let table_selector = Selector::parse("table.reservationTable, table.timeTable, table")
-            .map_err(|e| ParseError::selector_creation_failed("table", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tbody tr, tr")
-            .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-
-        let cell_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
+        let selectors = ClassroomReservationSelectors::get()?;
         let mut table_data = Vec::new();
 
-        if let Some(table) = document.select(&table_selector).next() {
-            for row in table.select(&row_selector) {
-                let cells: Vec<_> = row.select(&cell_selector).collect();
+        ctx.trace_selector(
+            "reservation_table_data",
+            &selectors.reservation_table,
+            document.root_element(),
+        );
+        if let Some(table) = document.select(&selectors.reservation_table).next() {
+            for (row_index, row) in table.select(&selectors.row).enumerate() {
+                let cells: Vec<_> = row.select(&selectors.cell).collect();
 
                 // 時限+7曜日のセルが存在する場合
                 if cells.len() >= 8 {
                     let period = cells[0].inner_html().trim().to_string();
+                    let class_time =
+                        ctx.parse_class_time("reservation_table_data", row_index, 0, &period)?;
 
                     let reservation_row = ClassroomReservationTableData {
                         period,
+                        class_time,
                         monday: self.parse_reservation_cell(&cells[1])?,
                         tuesday: self.parse_reservation_cell(&cells[2])?,
                         wednesday: self.parse_reservation_cell(&cells[3])?,
@@ -174,8 +243,15 @@ impl ClassroomReservationStatusParserImpl {
                     };
 
                     table_data.push(reservation_row);
+                } else {
+                    ctx.skip_short_row("reservation_table_data", row_index, cells.len(), 8);
                 }
             }
+        } else {
+            ctx.missing_section(
+                "reservation_table_data",
+                selectors.reservation_table.source(),
+            )?;
         }
 
         Ok(table_data)
@@ -186,6 +262,7 @@ impl ClassroomReservationStatusParserImpl {
         &self,
         cell: &scraper::ElementRef,
     ) -> Result<ClassroomReservationCell, ParseError> {
+        let selectors = ClassroomReservationSelectors::get()?;
         let _cell_html = cell.inner_html();
         let cell_text = self.extract_cell_text_with_breaks(cell);
 
@@ -197,11 +274,8 @@ impl ClassroomReservationStatusParserImpl {
             .map(|s| s.to_string());
 
         // リンク要素があるかチェック
-        let link_selector = Selector::parse("a")
-            .map_err(|e| ParseError::selector_creation_failed("a", &e.to_string()))?;
-
         let detail_link_url = cell
-            .select(&link_selector)
+            .select(&selectors.link)
             .next()
             .and_then(|link| link.value().attr("href"))
             .map(|href| href.to_string());
@@ -314,14 +388,16 @@ impl ClassroomReservationStatusParserImpl {
     fn parse_popup_reservation_detail(
         &self,
         document: &Html,
+        ctx: &mut ParseContext,
     ) -> Result<Option<PopupReservationDetail>, ParseError> {
-        let popup_selector =
-            Selector::parse(".popup-reservation, .reservation-detail, #reservationDetail")
-                .map_err(|e| {
-                    ParseError::selector_creation_failed("popup-reservation", &e.to_string())
-                })?;
-
-        if let Some(popup) = document.select(&popup_selector).next() {
+        let selectors = ClassroomReservationSelectors::get()?;
+
+        ctx.trace_selector(
+            "popup_reservation_detail",
+            &selectors.reservation_popup,
+            document.root_element(),
+        );
+        if let Some(popup) = document.select(&selectors.reservation_popup).next() {
             let mut detail = PopupReservationDetail::default();
 
             // ポップアップ内のテキストを解析
@@ -336,6 +412,16 @@ impl ClassroomReservationStatusParserImpl {
                     detail.subject_name = self.extract_value_after_colon(line);
                 } else if line.contains("担当教員") {
                     detail.instructor_name = self.extract_value_after_colon(line);
+                } else if let Some((_, value)) = line.split_once("日時") {
+                    // 時刻の「:」と区別するため、ラベル直後の区切りのみを取り除く
+                    let value = value.trim_start_matches([':', '：', ' ', '　']);
+                    match JstRange::<JstDateTime>::parse(value) {
+                        Ok(range) => {
+                            detail.start_datetime = Some(range.start);
+                            detail.end_datetime = Some(range.end);
+                        }
+                        Err(_) => ctx.unparseable_cell("popup_reservation_detail", 0, 0, value)?,
+                    }
                 } else if line.contains("教室") {
                     detail.classroom_name = self.extract_value_after_colon(line);
                 }
@@ -351,11 +437,16 @@ impl ClassroomReservationStatusParserImpl {
     fn parse_popup_class_detail(
         &self,
         document: &Html,
+        ctx: &mut ParseContext,
     ) -> Result<Option<PopupClassDetail>, ParseError> {
-        let popup_selector = Selector::parse(".popup-class, .class-detail, #classDetail")
-            .map_err(|e| ParseError::selector_creation_failed("popup-class", &e.to_string()))?;
-
-        if let Some(_popup) = document.select(&popup_selector).next() {
+        let selectors = ClassroomReservationSelectors::get()?;
+
+        ctx.trace_selector(
+            "popup_class_detail",
+            &selectors.class_popup,
+            document.root_element(),
+        );
+        if let Some(_popup) = document.select(&selectors.class_popup).next() {
             // 実装省略 - 実際のHTMLに合わせて実装
             return Ok(None);
         }
@@ -367,13 +458,16 @@ impl ClassroomReservationStatusParserImpl {
     fn parse_popup_duplicate_class_detail(
         &self,
         document: &Html,
+        ctx: &mut ParseContext,
     ) -> Result<Option<PopupDuplicateClassDetail>, ParseError> {
-        let popup_selector = Selector::parse(
-            ".popup-duplicate, .duplicate-detail, #duplicateDetail",
-        )
-        .map_err(|e| ParseError::selector_creation_failed("popup-duplicate", &e.to_string()))?;
-
-        if let Some(_popup) = document.select(&popup_selector).next() {
+        let selectors = ClassroomReservationSelectors::get()?;
+
+        ctx.trace_selector(
+            "popup_duplicate_class_detail",
+            &selectors.duplicate_popup,
+            document.root_element(),
+        );
+        if let Some(_popup) = document.select(&selectors.duplicate_popup).next() {
             // 実装省略 - 実際のHTMLに合わせて実装
             return Ok(None);
         }
@@ -385,13 +479,16 @@ impl ClassroomReservationStatusParserImpl {
     fn parse_popup_classroom_detail(
         &self,
         document: &Html,
+        ctx: &mut ParseContext,
     ) -> Result<Option<PopupClassroomDetail>, ParseError> {
-        let popup_selector = Selector::parse(
-            ".popup-classroom, .classroom-detail, #classroomDetail",
-        )
-        .map_err(|e| ParseError::selector_creation_failed("popup-classroom", &e.to_string()))?;
-
-        if let Some(_popup) = document.select(&popup_selector).next() {
+        let selectors = ClassroomReservationSelectors::get()?;
+
+        ctx.trace_selector(
+            "popup_classroom_detail",
+            &selectors.classroom_popup,
+            document.root_element(),
+        );
+        if let Some(_popup) = document.select(&selectors.classroom_popup).next() {
             // 実装省略 - 実際のHTMLに合わせて実装
             return Ok(None);
         }
@@ -409,32 +506,4 @@ impl ClassroomReservationStatusParserImpl {
             text.trim().to_string()
         }
     }
-
-    /// 日付文字列をISO 8601形式に変換する
-    fn format_datetime(&self, datetime_str: &str) -> Result<String, ParseError> {
-        if datetime_str.is_empty() {
-            return Ok(String::new());
-        }
-
-        // 日本語の日付形式を解析してISO 8601形式に変換
-        // 例: 「2025年1月15日 14:30」→「2025-01-15T14:30:00+09:00」
-        let datetime_regex =
-            regex_lite::Regex::new(r"(\d{4})年(\d{1,2})月(\d{1,2})日\s*(\d{1,2}):(\d{2})")
-                .map_err(|e| ParseError::data_parsing_failed("datetime regex", &e.to_string()))?;
-
-        if let Some(captures) = datetime_regex.captures(datetime_str) {
-            let year = &captures[1];
-            let month = format!("{:02}", captures[2].parse::<u32>().unwrap_or(1));
-            let day = format!("{:02}", captures[3].parse::<u32>().unwrap_or(1));
-            let hour = format!("{:02}", captures[4].parse::<u32>().unwrap_or(0));
-            let minute = format!("{:02}", captures[5].parse::<u32>().unwrap_or(0));
-
-            Ok(format!(
-                "{}-{}-{}T{}:{}:00+09:00",
-                year, month, day, hour, minute
-            ))
-        } else {
-            Ok(datetime_str.to_string())
-        }
-    }
 }
@@ -0,0 +1,701 @@
+//! 解析結果のエンベロープとスキーマバージョン
+//!
+//! 解析結果を長期間保存する呼び出し元のために、モデルをページ種別・スキーマバージョン・
+//! パーサーのバージョン・解析日時とあわせて[`Envelope`]で包んで出力します。
+//!
+//! モデルの形が変わる変更を行う場合は、[`schema_version`]の該当するページ種別の値を上げ、
+//! 旧バージョンのJSONを変換する手順を`MIGRATIONS`に追加します。保存済みのJSONは
+//! [`migrate`]で現在の形に変換できます（`schema`フィーチャー）。
+
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::utils::error::ParseError;
+use crate::utils::time::now_rfc3339;
+
+use super::page::{PageData, PageKind};
+
+/// このクレートのバージョン（`Envelope::parser_version`に記録される値）
+pub const PARSER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// ページ種別ごとの現在のスキーマバージョン
+///
+/// エンベロープ導入前に出力されたモデルのみのJSONはバージョン`0`として扱います。
+pub fn schema_version(kind: PageKind) -> u32 {
+    match kind {
+        PageKind::NotificationDetail | PageKind::LicensePrediction => 1,
+        // 2: 日付・日時を`JstDate`・`JstDateTime`に変更
+        PageKind::AssignmentList
+        | PageKind::QuestionnaireList
+        | PageKind::StudentInfo
+        | PageKind::TestAnswerStatus => 2,
+        // 2: 年度・学期を`AcademicTerm`に変更
+        PageKind::PromotionPrediction => 2,
+        // 2: 判定結果`hantei_status`と要件の階層`yoken_tree`を追加し、不足量を`FusokuRyo`に変更
+        PageKind::GraduationPrediction => 2,
+        // 4: 年度・学期ごとの集計`term_summaries`を追加
+        PageKind::GradeInquiry => 4,
+        // 3: 曜日・時限の`class_time`を追加
+        PageKind::SyllabusSearchResult | PageKind::SyllabusView => 3,
+        // 4: 曜日・時限の`class_time`を追加
+        PageKind::Portal
+        | PageKind::PortalAllNotifications
+        | PageKind::PortalClassContact
+        | PageKind::PortalAllClassContact
+        | PageKind::ClassroomReservationStatus => 4,
+    }
+}
+
+/// バージョン情報付きの解析結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Envelope<T> {
+    /// ページ種別
+    pub kind: PageKind,
+    /// `data`の形を表すスキーマバージョン（ページ種別ごとに管理）
+    pub schema_version: u32,
+    /// 解析したパーサーのクレートバージョン（エンベロープ導入前のデータを変換した場合は`null`）
+    pub parser_version: Option<String>,
+    /// 解析日時（UTCのRFC 3339形式、エンベロープ導入前のデータを変換した場合は`null`）
+    pub parsed_at: Option<String>,
+    /// 解析結果
+    pub data: T,
+}
+
+impl<T> Envelope<T> {
+    /// 現在のスキーマバージョンとパーサーのバージョン、現在時刻で解析結果を包む
+    pub fn new(kind: PageKind, data: T) -> Self {
+        Self {
+            kind,
+            schema_version: schema_version(kind),
+            parser_version: Some(PARSER_VERSION.to_string()),
+            parsed_at: Some(now_rfc3339()),
+            data,
+        }
+    }
+
+    /// 解析日時を指定する
+    pub fn with_parsed_at(mut self, parsed_at: impl Into<String>) -> Self {
+        self.parsed_at = Some(parsed_at.into());
+        self
+    }
+
+    /// 解析結果を変換する
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Envelope<U> {
+        Envelope {
+            kind: self.kind,
+            schema_version: self.schema_version,
+            parser_version: self.parser_version,
+            parsed_at: self.parsed_at,
+            data: f(self.data),
+        }
+    }
+
+    /// スキーマバージョンが現在のものと一致するか
+    pub fn is_current(&self) -> bool {
+        self.schema_version == schema_version(self.kind)
+    }
+}
+
+impl PageKind {
+    /// 対応するパーサーでドキュメントを解析し、エンベロープで包んで返す
+    pub fn parse_envelope(&self, document: &Html) -> Result<Envelope<PageData>, ParseError> {
+        self.parse_envelope_with_options(document, ParseOptions::default())
+            .map(|outcome| outcome.data)
+    }
+
+    /// 解析オプションを指定して解析し、警告付きの結果の`data`をエンベロープで包んで返す
+    // ページ種別を持つパーサー群がすべて無効な場合、`PageData`は値を持たない
+    #[cfg_attr(
+        not(any(
+            feature = "portal",
+            feature = "grades",
+            feature = "syllabus",
+            feature = "assignments",
+            feature = "classroom",
+            feature = "questionnaire",
+            feature = "student-info",
+            feature = "tests"
+        )),
+        allow(unreachable_code)
+    )]
+    pub fn parse_envelope_with_options(
+        &self,
+        document: &Html,
+        options: ParseOptions,
+    ) -> Result<ParseOutcome<Envelope<PageData>>, ParseError> {
+        let outcome = self.parse_with_options(document, options)?;
+        Ok(outcome.map(|data| Envelope::new(*self, data)))
+    }
+}
+
+#[cfg(feature = "json")]
+pub use migration::{migrate, Migration};
+
+#[cfg(feature = "json")]
+mod migration {
+    use serde::de::DeserializeOwned;
+    use serde_json::{Map, Value};
+
+    use super::{schema_version, Envelope, PageKind};
+    use crate::common::datetime::{normalize, JstDate, JstDateTime};
+    use crate::common::period::{ClassTime, PeriodTable};
+    use crate::common::term::AcademicTerm;
+    use crate::utils::error::ParseError;
+
+    /// 旧スキーマバージョンの`data`を1つ新しいバージョンの形に変換する手順
+    pub struct Migration {
+        /// 対象のページ種別
+        pub kind: PageKind,
+        /// 変換元のスキーマバージョン（変換後は`from + 1`になる）
+        pub from: u32,
+        /// `data`を書き換える処理
+        pub apply: fn(&mut Value) -> Result<(), ParseError>,
+    }
+
+    /// 登録済みの変換手順
+    ///
+    /// モデルの形を変えた場合は、[`schema_version`]を上げるとともにここへ手順を追加します。
+    const MIGRATIONS: &[Migration] = &[
+        Migration {
+            kind: PageKind::Portal,
+            from: 1,
+            apply: portal_typed_dates,
+        },
+        Migration {
+            kind: PageKind::PortalAllNotifications,
+            from: 1,
+            apply: portal_typed_dates,
+        },
+        Migration {
+            kind: PageKind::PortalClassContact,
+            from: 1,
+            apply: portal_typed_dates,
+        },
+        Migration {
+            kind: PageKind::PortalAllClassContact,
+            from: 1,
+            apply: portal_typed_dates,
+        },
+        Migration {
+            kind: PageKind::AssignmentList,
+            from: 1,
+            apply: |data| {
+                retype(data, "assignments.*.due_date", datetime);
+                retype(data, "assignments.*.submission_date", datetime);
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::ClassroomReservationStatus,
+            from: 1,
+            apply: |data| {
+                retype(data, "popup_reservation_detail.start_datetime", datetime);
+                retype(data, "popup_reservation_detail.end_datetime", datetime);
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::QuestionnaireList,
+            from: 1,
+            apply: |data| {
+                retype(data, "questionnaires.*.deadline", date);
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::StudentInfo,
+            from: 1,
+            apply: |data| {
+                for field in [
+                    "date_of_birth",
+                    "enrollment_date",
+                    "withdrawal_date",
+                    "completion_date",
+                ] {
+                    retype(data, &format!("basic_info.{field}"), date);
+                }
+                retype(data, "advisor_info.advisor_start_date", date);
+                retype(data, "advisor_info.advisor_end_date", date);
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::Portal,
+            from: 2,
+            apply: portal_typed_term,
+        },
+        Migration {
+            kind: PageKind::PortalAllNotifications,
+            from: 2,
+            apply: portal_typed_term,
+        },
+        Migration {
+            kind: PageKind::PortalClassContact,
+            from: 2,
+            apply: portal_typed_term,
+        },
+        Migration {
+            kind: PageKind::PortalAllClassContact,
+            from: 2,
+            apply: portal_typed_term,
+        },
+        Migration {
+            kind: PageKind::ClassroomReservationStatus,
+            from: 2,
+            apply: |data| {
+                // 授業詳細の開講学期は検索条件の年度の学期とみなす
+                let mut year = None;
+                if let Some(params) = data.get_mut("search_params") {
+                    year = take_string(params, "academic_year");
+                    let term = join_term(year.as_deref(), take_string(params, "semester"));
+                    insert_term(params, term);
+                }
+                if let Some(detail) = data.get_mut("popup_class_detail") {
+                    let term = join_term(year.as_deref(), take_string(detail, "semester"));
+                    insert_term(detail, term);
+                }
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::GradeInquiry,
+            from: 1,
+            apply: |data| {
+                // 旧形式の年度・学期は画面から読み取った値ではなく、全科目に固定の
+                // 2025年度春学期を入れていたため、引き継がずに不明（null）とする
+                for subject in items(data, "subjects") {
+                    if let Some(fields) = subject.as_object_mut() {
+                        fields.remove("academic_year");
+                        fields.remove("semester");
+                    }
+                    insert_term(subject, None);
+                }
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::PromotionPrediction,
+            from: 1,
+            apply: |data| {
+                let year = take_string(data, "academic_year");
+                let term = join_term(year.as_deref(), take_string(data, "semester"));
+                insert_term(data, term);
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::SyllabusSearchResult,
+            from: 1,
+            apply: |data| {
+                let mut term = None;
+                if let Some(conditions) = data.get_mut("search_conditions") {
+                    let text = take_string(conditions, "academic_year_semester");
+                    term = text.and_then(|text| AcademicTerm::parse(&text).ok());
+                    insert_term(conditions, term);
+                }
+                for entry in items(data, "course_entries") {
+                    let semester = take_string(entry, "semester");
+                    let entry_term = semester.and_then(|semester| match term {
+                        Some(term) => AcademicTerm::parse_in_year(&semester, term.year).ok(),
+                        None => AcademicTerm::parse(&semester).ok(),
+                    });
+                    insert_term(entry, entry_term);
+                }
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::SyllabusView,
+            from: 1,
+            apply: |data| {
+                if data.get("term").is_some() {
+                    return Ok(());
+                }
+                // 年度学期を解釈できない場合は不明（null）とする
+                let text = take_string(data, "academic_year_semester");
+                let term = text.and_then(|text| AcademicTerm::parse(&text).ok());
+                insert_term(data, term);
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::Portal,
+            from: 3,
+            apply: portal_class_time,
+        },
+        Migration {
+            kind: PageKind::PortalAllNotifications,
+            from: 3,
+            apply: portal_class_time,
+        },
+        Migration {
+            kind: PageKind::PortalClassContact,
+            from: 3,
+            apply: portal_class_time,
+        },
+        Migration {
+            kind: PageKind::PortalAllClassContact,
+            from: 3,
+            apply: portal_class_time,
+        },
+        Migration {
+            kind: PageKind::ClassroomReservationStatus,
+            from: 3,
+            apply: |data| {
+                for row in items(data, "reservation_table_data") {
+                    insert_class_time(row, "period");
+                }
+                if let Some(detail) = data.get_mut("popup_class_detail") {
+                    insert_class_time(detail, "schedule");
+                }
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::SyllabusSearchResult,
+            from: 2,
+            apply: |data| {
+                for entry in items(data, "course_entries") {
+                    insert_class_time(entry, "schedule_day_period");
+                }
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::SyllabusView,
+            from: 2,
+            apply: |data| {
+                insert_class_time(data, "day_period");
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::TestAnswerStatus,
+            from: 1,
+            apply: |data| {
+                for path in [
+                    "tests.*.details.NotImplemented.start_date",
+                    "tests.*.details.NotImplemented.end_date",
+                    "tests.*.details.InProgress.latest_attempt_date",
+                    "tests.*.details.Completed.latest_attempt_date",
+                ] {
+                    retype(data, path, datetime);
+                }
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::GradeInquiry,
+            from: 2,
+            // 以前の認定は評価なしとして保存されており区別できないため、そのまま引き継ぐ
+            apply: |_| Ok(()),
+        },
+        Migration {
+            kind: PageKind::GradeInquiry,
+            from: 3,
+            // 画面に表示された学期GPAは保存されていないため、空の一覧にする
+            apply: |data| {
+                if let Some(object) = data.as_object_mut() {
+                    object
+                        .entry("term_summaries")
+                        .or_insert(Value::Array(Vec::new()));
+                }
+                Ok(())
+            },
+        },
+        Migration {
+            kind: PageKind::GraduationPrediction,
+            from: 1,
+            apply: |data| {
+                let status = data
+                    .get("hantei_message")
+                    .and_then(Value::as_str)
+                    .and_then(hantei_status);
+                if let Some(object) = data.as_object_mut() {
+                    object
+                        .entry("hantei_status")
+                        .or_insert(status.map_or(Value::Null, Value::from));
+                    // 要件の一覧は以前は解析していなかった
+                    object
+                        .entry("yoken_tree")
+                        .or_insert(Value::Array(Vec::new()));
+                }
+                for item in items(data, "fusoku_items") {
+                    if let Some(text) = item.get("fusoku_ryo").and_then(Value::as_str) {
+                        item["fusoku_ryo"] = fusoku_ryo(text).unwrap_or(Value::Null);
+                    }
+                }
+                Ok(())
+            },
+        },
+    ];
+
+    /// 判定メッセージから`HanteiStatus`のJSON表現を読み取る
+    ///
+    /// `grades`フィーチャーなしでも移行できるよう、`HanteiStatus::parse`と同じ規則をここに持ちます。
+    fn hantei_status(text: &str) -> Option<&'static str> {
+        let text = text.replace("見込み", "見込");
+        let contains = |patterns: &[&str]| patterns.iter().any(|pattern| text.contains(pattern));
+        if text.contains("対象外") {
+            Some("TaishoGai")
+        } else if contains(&["見込なし", "見込無", "見込がありません", "見込はありません"])
+        {
+            Some("MikomiNashi")
+        } else if contains(&["見込あり", "見込有", "見込です"]) {
+            Some("MikomiAri")
+        } else {
+            None
+        }
+    }
+
+    /// 不足量の表記から`FusokuRyo`のJSON表現を作る（`FusokuRyo::parse`と同じ規則）
+    fn fusoku_ryo(text: &str) -> Option<Value> {
+        let text = normalize(text);
+        let text = text.trim();
+        let digits = text
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(text.len());
+        let amount = text[..digits]
+            .parse::<f64>()
+            .ok()
+            .filter(|amount| amount.is_finite() && *amount >= 0.0)?;
+        let unit = match text[digits..].trim() {
+            "" | "単位" => "Credits",
+            "科目" => "Subjects",
+            _ => return None,
+        };
+        Some(serde_json::json!({ "amount": amount, "unit": unit }))
+    }
+
+    fn portal_typed_dates(data: &mut Value) -> Result<(), ParseError> {
+        retype(data, "calendar.current_date", date);
+        retype(data, "notifications.sections.*.entries.*.insert_date", date);
+        Ok(())
+    }
+
+    /// カレンダーの年表示・月表示（`"2025"`・`"春学期"`）を年度・学期にまとめる
+    fn portal_typed_term(data: &mut Value) -> Result<(), ParseError> {
+        if let Some(calendar) = data.get_mut("calendar") {
+            let year = take_string(calendar, "year");
+            let term = join_term(year.as_deref(), take_string(calendar, "month"));
+            insert_term(calendar, term);
+        }
+        Ok(())
+    }
+
+    fn portal_class_time(data: &mut Value) -> Result<(), ParseError> {
+        if let Some(schedule) = data.get_mut("schedule") {
+            for entry in items(schedule, "entries") {
+                insert_class_time(entry, "date");
+            }
+        }
+        Ok(())
+    }
+
+    /// `text_key`の曜日・時限の表記から`class_time`フィールドを追加する
+    ///
+    /// 解析時の時限表は保存されていないため、既定の時限表で開始・終了時刻を求めます。
+    /// 既に`class_time`がある場合はそのままにします。
+    fn insert_class_time(value: &mut Value, text_key: &str) {
+        let class_time = value
+            .get(text_key)
+            .and_then(Value::as_str)
+            .and_then(|text| ClassTime::parse(text, &PeriodTable::default()).ok())
+            .and_then(|class_time| serde_json::to_value(class_time).ok());
+        if let Some(object) = value.as_object_mut() {
+            object
+                .entry("class_time")
+                .or_insert(class_time.unwrap_or(Value::Null));
+        }
+    }
+
+    /// 別々のフィールドに保存されていた年度と学期を解釈する（どちらかが欠けていれば`None`）
+    fn join_term(year: Option<&str>, term: Option<String>) -> Option<AcademicTerm> {
+        let year = year?.trim().parse().ok()?;
+        AcademicTerm::parse_in_year(&term?, year).ok()
+    }
+
+    /// `term`フィールドを追加する（既にある場合は現在の形のデータとみなしてそのままにする）
+    fn insert_term(value: &mut Value, term: Option<AcademicTerm>) {
+        if let Some(object) = value.as_object_mut() {
+            let term = term.and_then(|term| serde_json::to_value(term).ok());
+            object.entry("term").or_insert(term.unwrap_or(Value::Null));
+        }
+    }
+
+    /// オブジェクトからフィールドを取り除き、文字列（数値の場合は文字列にしたもの）を返す
+    fn take_string(value: &mut Value, key: &str) -> Option<String> {
+        match value.as_object_mut()?.remove(key)? {
+            Value::String(text) => Some(text),
+            Value::Number(number) => Some(number.to_string()),
+            _ => None,
+        }
+    }
+
+    /// オブジェクトの配列フィールドの各要素
+    fn items<'a>(data: &'a mut Value, key: &str) -> impl Iterator<Item = &'a mut Value> {
+        data.get_mut(key)
+            .and_then(Value::as_array_mut)
+            .into_iter()
+            .flatten()
+    }
+
+    /// `path`（`.`区切り、`*`は配列の各要素）の文字列を`convert`の結果に置き換える
+    ///
+    /// 空欄や解釈できない表記は、パーサーと同じく`null`にします。
+    fn retype(data: &mut Value, path: &str, convert: fn(&str) -> Option<String>) {
+        let segments: Vec<_> = path.split('.').collect();
+        retype_at(data, &segments, convert);
+    }
+
+    fn retype_at(value: &mut Value, segments: &[&str], convert: fn(&str) -> Option<String>) {
+        let Some((segment, rest)) = segments.split_first() else {
+            if let Value::String(text) = value {
+                *value = convert(text).map_or(Value::Null, Value::String);
+            }
+            return;
+        };
+        match (value, *segment) {
+            (Value::Array(items), "*") => {
+                for item in items {
+                    retype_at(item, rest, convert);
+                }
+            }
+            (Value::Object(object), key) => {
+                if let Some(child) = object.get_mut(key) {
+                    retype_at(child, rest, convert);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn date(text: &str) -> Option<String> {
+        JstDate::parse(text).ok().map(|value| value.to_string())
+    }
+
+    fn datetime(text: &str) -> Option<String> {
+        JstDateTime::parse(text).ok().map(|value| value.to_string())
+    }
+
+    /// 保存済みのJSONを現在のスキーマバージョンのエンベロープに変換する
+    ///
+    /// 次の形式を受け付けます。
+    ///
+    /// - エンベロープ: 記録されたスキーマバージョンから現在のバージョンまで順に変換します。
+    /// - CLIの複数入力・NDJSON出力のレコード（`kind`と`data`を持つオブジェクト）
+    /// - モデルのみのJSON: ページ種別を含まないため`kind`の指定が必要です。
+    ///
+    /// 現在より新しいスキーマバージョンのデータは`ValueOutOfRange`エラーになります。
+    pub fn migrate(value: Value, kind: Option<PageKind>) -> Result<Envelope<Value>, ParseError> {
+        let mut envelope = into_envelope(value, kind)?;
+        let current = schema_version(envelope.kind);
+
+        if envelope.schema_version > current {
+            return Err(ParseError::ValueOutOfRange {
+                field: "schema_version".to_string(),
+                value: envelope.schema_version.to_string(),
+                range: format!("0..={current} for {}", envelope.kind),
+            });
+        }
+
+        while envelope.schema_version < current {
+            if envelope.schema_version > 0 {
+                let migration = MIGRATIONS
+                    .iter()
+                    .find(|m| m.kind == envelope.kind && m.from == envelope.schema_version)
+                    .ok_or_else(|| ParseError::NotImplemented {
+                        operation: format!(
+                            "migration of {} from schema version {}",
+                            envelope.kind, envelope.schema_version
+                        ),
+                    })?;
+                (migration.apply)(&mut envelope.data)?;
+            }
+            // バージョン0はエンベロープで包むだけで、モデルの形はバージョン1と同じ
+            envelope.schema_version += 1;
+        }
+
+        Ok(envelope)
+    }
+
+    /// 保存形式を判別してエンベロープにする（スキーマバージョンは記録された値のまま）
+    fn into_envelope(value: Value, kind: Option<PageKind>) -> Result<Envelope<Value>, ParseError> {
+        let Value::Object(mut object) = value else {
+            return Err(invalid("expected a JSON object"));
+        };
+
+        if object.contains_key("schema_version") {
+            let envelope: Envelope<Value> = serde_json::from_value(Value::Object(object))
+                .map_err(|e| invalid(&e.to_string()))?;
+            return match kind {
+                Some(kind) if kind != envelope.kind => Err(invalid(&format!(
+                    "envelope is {}, not {kind}",
+                    envelope.kind
+                ))),
+                _ => Ok(envelope),
+            };
+        }
+
+        let (kind, data) = match legacy_record(&mut object) {
+            Some((recorded, data)) => (recorded, data),
+            None => {
+                let kind = kind.ok_or_else(|| ParseError::MissingRequiredField {
+                    field_name: "kind".to_string(),
+                })?;
+                (kind, Value::Object(object))
+            }
+        };
+
+        Ok(Envelope {
+            kind,
+            schema_version: 0,
+            parser_version: None,
+            parsed_at: None,
+            data,
+        })
+    }
+
+    /// CLIのレコード形式（`source`・`kind`・`data`など）から種別とデータを取り出す
+    fn legacy_record(object: &mut Map<String, Value>) -> Option<(PageKind, Value)> {
+        let kind = object.get("kind")?.as_str()?.parse().ok()?;
+        let data = object.remove("data")?;
+        Some((kind, data))
+    }
+
+    fn invalid(details: &str) -> ParseError {
+        ParseError::InvalidDataFormat {
+            data_type: "envelope".to_string(),
+            details: details.to_string(),
+        }
+    }
+
+    impl Envelope<serde_json::Value> {
+        /// `data`をモデルの型に変換する
+        pub fn into_typed<T: DeserializeOwned>(self) -> Result<Envelope<T>, ParseError> {
+            let Envelope {
+                kind,
+                schema_version,
+                parser_version,
+                parsed_at,
+                data,
+            } = self;
+            let data = serde_json::from_value(data).map_err(|e| ParseError::InvalidDataFormat {
+                data_type: kind.to_string(),
+                details: e.to_string(),
+            })?;
+            Ok(Envelope {
+                kind,
+                schema_version,
+                parser_version,
+                parsed_at,
+                data,
+            })
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! GPAの計算
+//!
+//! 成績照会の科目一覧から、学期ごとのGPAと通算GPAを計算します。
+//! 評価ごとのグレードポイント（GP）や、合格・認定・履修中の科目をGPAに含めるかどうかは
+//! 大学によって異なるため、[`GpaRules`]で指定します。
+//!
+//! GPAは「GP × 単位数」の合計をGPA対象の単位数の合計で割った値です。
+//! 素点からGPを求める方式（f-GPA）にも対応しており、[`GpaMethod::NumericScore`]を指定すると
+//! 素点のある科目は[`NumericScale`]で連続的なGPに変換します。
+//!
+//! 計算結果の[`GpaReport::subjects`]には、すべての科目について使用したGPと
+//! GPAに含めなかった理由が記録されるため、表示されているGPAとの違いを確認できます。
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::common::term::AcademicTerm;
+use crate::utils::error::ParseError;
+
+use super::grade_inquiry::{Grade, GradeInquiry, Subject};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// 評価ごとのグレードポイント
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct GradePoint {
+    /// 評価
+    pub grade: Grade,
+    /// グレードポイント
+    pub point: f64,
+}
+
+/// 評価からグレードポイントへの対応表
+///
+/// 対応表にない評価（合格・認定など）の科目はGPAの対象外です。
+/// 既定は秀=4、優=3、良=2、可=1、不可=0です。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct GradeScale {
+    /// 評価ごとのグレードポイント
+    pub points: Vec<GradePoint>,
+}
+
+impl GradeScale {
+    /// 対応表を作成
+    pub fn new(points: Vec<GradePoint>) -> Self {
+        Self { points }
+    }
+
+    /// 評価のグレードポイント
+    pub fn point(&self, grade: &Grade) -> Option<f64> {
+        self.points
+            .iter()
+            .find(|point| &point.grade == grade)
+            .map(|point| point.point)
+    }
+}
+
+impl Default for GradeScale {
+    fn default() -> Self {
+        Self::new(
+            [
+                (Grade::AA, 4.0),
+                (Grade::A, 3.0),
+                (Grade::B, 2.0),
+                (Grade::C, 1.0),
+                (Grade::D, 0.0),
+            ]
+            .into_iter()
+            .map(|(grade, point)| GradePoint { grade, point })
+            .collect(),
+        )
+    }
+}
+
+/// 素点からグレードポイントへの変換式（f-GPA）
+///
+/// 合格点以上の素点は`(素点 - base_score) / step`、合格点未満は0になります。
+/// 既定は合格点60点、`(素点 - 55) / 10`（100点で4.5、60点で0.5）です。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(default)]
+pub struct NumericScale {
+    /// 合格点
+    pub passing_score: u32,
+    /// 基準点
+    pub base_score: f64,
+    /// グレードポイント1あたりの点数
+    pub step: f64,
+    /// グレードポイントの上限
+    pub max_point: Option<f64>,
+}
+
+impl NumericScale {
+    /// 変換式が有効かを確認する
+    ///
+    /// `step`が正の有限値でない場合や、`base_score`・`max_point`が有限値でない場合は
+    /// グレードポイントが無限大やNaNになるため`InvalidDataFormat`エラーを返します。
+    pub fn validate(&self) -> Result<(), ParseError> {
+        let invalid = |details: &str| {
+            Err(ParseError::InvalidDataFormat {
+                data_type: "numeric scale".to_string(),
+                details: details.to_string(),
+            })
+        };
+        if !(self.step.is_finite() && self.step > 0.0) {
+            return invalid(&format!(
+                "step must be a positive number, got {}",
+                self.step
+            ));
+        }
+        if !self.base_score.is_finite() {
+            return invalid(&format!(
+                "base_score must be finite, got {}",
+                self.base_score
+            ));
+        }
+        if let Some(max) = self.max_point.filter(|max| !max.is_finite()) {
+            return invalid(&format!("max_point must be finite, got {max}"));
+        }
+        Ok(())
+    }
+
+    /// 素点のグレードポイント
+    pub fn point(&self, score: u32) -> f64 {
+        if score < self.passing_score {
+            return 0.0;
+        }
+        let point = ((f64::from(score) - self.base_score) / self.step).max(0.0);
+        self.max_point.map_or(point, |max| point.min(max))
+    }
+}
+
+impl Default for NumericScale {
+    fn default() -> Self {
+        Self {
+            passing_score: 60,
+            base_score: 55.0,
+            step: 10.0,
+            max_point: None,
+        }
+    }
+}
+
+/// グレードポイントの求め方
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum GpaMethod {
+    /// 評価から対応表で求める
+    #[default]
+    Grade,
+    /// 素点から変換式で求める（素点のない科目は評価から求める）
+    NumericScore,
+}
+
+/// GPAの計算規則
+///
+/// 省略した項目は既定値になります。既定では合格・認定・履修中の科目をGPAに含めず、
+/// 不可の科目はGP 0として含めます。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(default)]
+pub struct GpaRules {
+    /// グレードポイントの求め方
+    pub method: GpaMethod,
+    /// 評価からグレードポイントへの対応表
+    pub grade_scale: GradeScale,
+    /// 素点からグレードポイントへの変換式
+    pub numeric_scale: NumericScale,
+    /// 合格の科目をGPAに含めない
+    pub exclude_pass: bool,
+    /// 認定の科目をGPAに含めない
+    pub exclude_recognized: bool,
+    /// 履修中の科目をGPAに含めない
+    pub exclude_currently_enrolled: bool,
+    /// 不可の科目をGPAに含めない
+    pub exclude_failed: bool,
+}
+
+impl Default for GpaRules {
+    fn default() -> Self {
+        Self {
+            method: GpaMethod::Grade,
+            grade_scale: GradeScale::default(),
+            numeric_scale: NumericScale::default(),
+            exclude_pass: true,
+            exclude_recognized: true,
+            exclude_currently_enrolled: true,
+            exclude_failed: false,
+        }
+    }
+}
+
+impl GpaRules {
+    /// 既定の計算規則を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// グレードポイントの求め方を設定する
+    pub fn with_method(mut self, method: GpaMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// 評価からグレードポイントへの対応表を設定する
+    pub fn with_grade_scale(mut self, grade_scale: GradeScale) -> Self {
+        self.grade_scale = grade_scale;
+        self
+    }
+
+    /// 素点からグレードポイントへの変換式を設定する
+    pub fn with_numeric_scale(mut self, numeric_scale: NumericScale) -> Self {
+        self.numeric_scale = numeric_scale;
+        self
+    }
+
+    /// 計算規則が有効かを確認する（素点からの変換式を[`NumericScale::validate`]で検証）
+    pub fn validate(&self) -> Result<(), ParseError> {
+        self.numeric_scale.validate()
+    }
+
+    /// 成績照会の科目からGPAを計算する
+    ///
+    /// 計算規則が無効な場合は[`GpaRules::validate`]の`InvalidDataFormat`エラーを返します。
+    pub fn calculate(&self, grades: &GradeInquiry) -> Result<GpaReport, ParseError> {
+        self.calculate_subjects(&grades.subjects)
+    }
+
+    /// 科目の一覧からGPAを計算する
+    ///
+    /// 計算規則が無効な場合は[`GpaRules::validate`]の`InvalidDataFormat`エラーを返します。
+    pub fn calculate_subjects(&self, subjects: &[Subject]) -> Result<GpaReport, ParseError> {
+        self.validate()?;
+        let subjects: Vec<SubjectGpa> = subjects
+            .iter()
+            .map(|subject| self.subject_gpa(subject))
+            .collect();
+
+        let mut terms: Vec<AcademicTerm> =
+            subjects.iter().filter_map(|subject| subject.term).collect();
+        terms.sort();
+        terms.dedup();
+        let terms = terms
+            .into_iter()
+            .map(|term| {
+                GpaSummary::sum(
+                    Some(term),
+                    subjects.iter().filter(|subject| subject.term == Some(term)),
+                )
+            })
+            .collect();
+
+        Ok(GpaReport {
+            cumulative: GpaSummary::sum(None, subjects.iter()),
+            terms,
+            subjects,
+        })
+    }
+
+    /// 1科目のGPAへの寄与
+    fn subject_gpa(&self, subject: &Subject) -> SubjectGpa {
+        let (grade_point, basis) = match (self.method, subject.numeric_score) {
+            (GpaMethod::NumericScore, Some(score)) => (
+                Some(self.numeric_scale.point(score)),
+                Some(GpaMethod::NumericScore),
+            ),
+            _ => {
+                let point = subject
+                    .grade
+                    .as_ref()
+                    .and_then(|grade| self.grade_scale.point(grade));
+                (point, point.map(|_| GpaMethod::Grade))
+            }
+        };
+
+        let excluded = if subject.is_currently_enrolled && self.exclude_currently_enrolled {
+            Some(GpaExclusion::CurrentlyEnrolled)
+        } else {
+            match subject.grade {
+                Some(Grade::Pass) if self.exclude_pass => Some(GpaExclusion::Pass),
+                Some(Grade::Recognized) if self.exclude_recognized => {
+                    Some(GpaExclusion::Recognized)
+                }
+                Some(Grade::D) if self.exclude_failed => Some(GpaExclusion::Failed),
+                _ if grade_point.is_none() => Some(GpaExclusion::NoGradePoint),
+                _ if subject.credit_count.unwrap_or(0) == 0 => Some(GpaExclusion::NoCredits),
+                _ => None,
+            }
+        };
+
+        let quality_points = match (excluded, grade_point, subject.credit_count) {
+            (None, Some(point), Some(credits)) => point * f64::from(credits),
+            _ => 0.0,
+        };
+
+        SubjectGpa {
+            name: subject.name.clone(),
+            term: subject.term,
+            credit_count: subject.credit_count,
+            grade: subject.grade.clone(),
+            numeric_score: subject.numeric_score,
+            grade_point,
+            basis,
+            quality_points,
+            excluded,
+        }
+    }
+}
+
+/// GPAに含めなかった理由
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum GpaExclusion {
+    /// 合格の科目（`exclude_pass`）
+    Pass,
+    /// 認定の科目（`exclude_recognized`）
+    Recognized,
+    /// 履修中の科目（`exclude_currently_enrolled`）
+    CurrentlyEnrolled,
+    /// 不可の科目（`exclude_failed`）
+    Failed,
+    /// 評価がないか、評価が対応表にない
+    NoGradePoint,
+    /// 単位数が不明または0
+    NoCredits,
+}
+
+/// 1科目のGPAへの寄与
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct SubjectGpa {
+    /// 科目名
+    pub name: String,
+    /// 履修年度・学期（不明な場合は`None`）
+    pub term: Option<AcademicTerm>,
+    /// 単位数
+    pub credit_count: Option<u32>,
+    /// 成績評価
+    pub grade: Option<Grade>,
+    /// 素点
+    pub numeric_score: Option<u32>,
+    /// グレードポイント
+    pub grade_point: Option<f64>,
+    /// グレードポイントを求めた方法
+    pub basis: Option<GpaMethod>,
+    /// GPAの分子に加えた値（GP × 単位数、対象外の場合は0）
+    pub quality_points: f64,
+    /// GPAに含めなかった理由（含めた場合は`None`）
+    pub excluded: Option<GpaExclusion>,
+}
+
+/// 学期ごと、または通算のGPA
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct GpaSummary {
+    /// 対象の年度・学期（通算の場合は`None`）
+    pub term: Option<AcademicTerm>,
+    /// GPA（GPA対象の科目がない場合は`None`）
+    pub gpa: Option<f64>,
+    /// GPA対象の単位数の合計
+    pub gpa_credits: u32,
+    /// GP × 単位数の合計
+    pub quality_points: f64,
+}
+
+impl GpaSummary {
+    fn sum<'a>(term: Option<AcademicTerm>, subjects: impl Iterator<Item = &'a SubjectGpa>) -> Self {
+        let mut gpa_credits = 0;
+        let mut quality_points = 0.0;
+        for subject in subjects.filter(|subject| subject.excluded.is_none()) {
+            gpa_credits += subject.credit_count.unwrap_or(0);
+            quality_points += subject.quality_points;
+        }
+        Self {
+            term,
+            gpa: (gpa_credits > 0).then(|| quality_points / f64::from(gpa_credits)),
+            gpa_credits,
+            quality_points,
+        }
+    }
+}
+
+/// GPAの計算結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct GpaReport {
+    /// 通算GPA
+    pub cumulative: GpaSummary,
+    /// 学期ごとのGPA（年度・学期の順、年度・学期が不明な科目は通算のみに含める）
+    pub terms: Vec<GpaSummary>,
+    /// 科目ごとの内訳（成績照会と同じ順）
+    pub subjects: Vec<SubjectGpa>,
+}
+
+/// JavaScriptから計算規則を指定してGPAを計算するクラス
+///
+/// ```text
+/// const calculator = new GpaCalculator({ method: "NumericScore", exclude_failed: true });
+/// const report = calculator.calculate(new GradeInquiryParser().parse(html));
+/// report.cumulative.gpa;
+/// ```
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub struct GpaCalculator {
+    rules: GpaRules,
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl GpaCalculator {
+    /// 計算規則を指定して作成する（省略時は既定の規則）
+    ///
+    /// 計算規則が無効な場合（`numeric_scale.step`が0以下など）は`InvalidDataFormat`エラーを返します。
+    #[wasm_bindgen(constructor)]
+    pub fn new(rules: Option<GpaRules>) -> Result<GpaCalculator, ParseError> {
+        let rules = rules.unwrap_or_default();
+        rules.validate()?;
+        Ok(Self { rules })
+    }
+
+    /// 成績照会の科目からGPAを計算する
+    pub fn calculate(&self, grades: GradeInquiry) -> Result<GpaReport, ParseError> {
+        self.rules.calculate(&grades)
+    }
+}
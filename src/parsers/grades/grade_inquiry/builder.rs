@@ -2,6 +2,9 @@
 //!
 //! 成績照会パーサーの設定を管理し、パーサーインスタンスを生成します。
 
+use crate::common::context::ParseContext;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 use scraper::Html;
@@ -13,6 +16,7 @@ use super::parser::GradeInquiryParserImpl;
 pub struct GradeInquiryParserBuilder {
     display_pattern: DisplayPattern,
     display_settings: DisplaySettings,
+    options: ParseOptions,
 }
 
 impl GradeInquiryParserBuilder {
@@ -21,9 +25,22 @@ impl GradeInquiryParserBuilder {
         Self {
             display_pattern: DisplayPattern::default(),
             display_settings: DisplaySettings::default(),
+            options: ParseOptions::default(),
         }
     }
 
+    /// 解析オプションを設定
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 厳密なパースモードを設定
+    pub fn with_strict_mode(mut self, strict_mode: bool) -> Self {
+        self.options.strict_mode = strict_mode;
+        self
+    }
+
     /// 表示パターンを設定
     pub fn with_display_pattern(mut self, display_pattern: DisplayPattern) -> Self {
         self.display_pattern = display_pattern;
@@ -49,8 +66,26 @@ impl PageParser<GradeInquiry> for GradeInquiryParserBuilder {
     const PAGE_TYPE: &'static str = "成績照会";
 
     fn parse_document(&self, document: &Html) -> Result<GradeInquiry, ParseError> {
-        let parser = self.build()?;
-        parser.parse_document(document)
+        let mut ctx = ParseContext::new().with_strict(self.options.strict_mode);
+        self.build()?.parse_with_context(document, &mut ctx)
+    }
+
+    fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<GradeInquiry>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.build()?.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+
+    fn parse_with_diagnostics(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<GradeInquiry>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options).with_diagnostics(true);
+        let data = self.build()?.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
     }
 }
 
@@ -10,7 +10,7 @@ pub mod parser;
 pub use builder::GradeInquiryParserBuilder;
 pub use model::{
     CategoryCredits, CreditDetails, CreditSummary, DisplayPattern, DisplaySettings, Grade,
-    GradeInquiry, RequirementType, Semester, Subject, SubjectCategory, ViewType,
+    GradeInquiry, RequirementType, Subject, SubjectCategory, ViewType,
 };
 pub use parser::GradeInquiryParserImpl;
 
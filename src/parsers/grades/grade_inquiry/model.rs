@@ -6,8 +6,11 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::term::AcademicTerm;
+
 /// 成績照会の全体情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct GradeInquiry {
     /// 表示パターン
@@ -16,14 +19,17 @@ pub struct GradeInquiry {
     pub display_settings: DisplaySettings,
     /// 科目リスト
     pub subjects: Vec<Subject>,
-    /// GPA得点
-    pub gpa_score: f64,
+    /// GPA得点（GPAの表示がなく、GPA対象の科目もない場合はNone）
+    pub gpa_score: Option<f64>,
+    /// 年度・学期ごとのGPAと単位数（科目の年度・学期の出現順）
+    pub term_summaries: Vec<TermSummary>,
     /// 単位取得状況サマリー
     pub credit_summary: CreditSummary,
 }
 
 /// 表示パターン設定
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct DisplayPattern {
     /// 表示タイプ
@@ -32,6 +38,7 @@ pub struct DisplayPattern {
 
 /// 表示タイプの種類
 #[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum ViewType {
     /// 通常表示
@@ -48,6 +55,7 @@ impl Default for ViewType {
 
 /// 表示設定の詳細
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct DisplaySettings {
     /// 評価名称の表示可否
@@ -67,7 +75,8 @@ pub struct DisplaySettings {
 }
 
 /// 科目情報
-#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Subject {
     /// 科目名
@@ -78,10 +87,8 @@ pub struct Subject {
     pub grade: Option<Grade>,
     /// 素点
     pub numeric_score: Option<u32>,
-    /// 履修年度
-    pub academic_year: u32,
-    /// 履修学期
-    pub semester: Semester,
+    /// 履修年度・学期（画面から読み取れない場合はNone）
+    pub term: Option<AcademicTerm>,
     /// 担当教員名
     pub instructor_name: String,
     /// 現在履修中かどうか
@@ -91,7 +98,8 @@ pub struct Subject {
 }
 
 /// 成績評価の種類
-#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum Grade {
     /// 秀
@@ -106,6 +114,8 @@ pub enum Grade {
     D,
     /// 合格
     Pass,
+    /// 認定（他大学等での修得単位の認定など）
+    Recognized,
     /// 評価なし
     NoEvaluation,
 }
@@ -116,24 +126,42 @@ impl Default for Grade {
     }
 }
 
-/// 学期の種類
-#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
-#[tsify(into_wasm_abi, from_wasm_abi)]
-pub enum Semester {
-    /// 春学期
-    Spring,
-    /// 秋学期
-    Fall,
+impl Grade {
+    /// 成績照会画面での表記（評価なしは空文字列）
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::AA => "秀",
+            Self::A => "優",
+            Self::B => "良",
+            Self::C => "可",
+            Self::D => "不可",
+            Self::Pass => "合格",
+            Self::Recognized => "認定",
+            Self::NoEvaluation => "",
+        }
+    }
 }
 
-impl Default for Semester {
-    fn default() -> Self {
-        Self::Spring
-    }
+/// 年度・学期ごとの成績の集計
+///
+/// 年度学期別表示で画面に表示された値を優先し、表示されていない値は科目の成績から計算します。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct TermSummary {
+    /// 年度・学期
+    pub term: AcademicTerm,
+    /// 学期GPA（対象となる科目がない場合は`None`）
+    pub gpa: Option<f64>,
+    /// 修得単位数
+    pub earned_credits: u32,
+    /// 履修単位数
+    pub registered_credits: u32,
 }
 
 /// 科目カテゴリ情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct SubjectCategory {
     /// カリキュラム名
@@ -152,6 +180,7 @@ pub struct SubjectCategory {
 
 /// 必修・選択の区分
 #[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum RequirementType {
     /// 必修
@@ -166,8 +195,19 @@ impl Default for RequirementType {
     }
 }
 
+impl RequirementType {
+    /// 成績照会画面での表記
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Required => "必修",
+            Self::Elective => "選択",
+        }
+    }
+}
+
 /// 単位取得状況のサマリー
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct CreditSummary {
     /// 全体の単位状況
@@ -182,10 +222,12 @@ pub struct CreditSummary {
 
 /// 単位の詳細情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct CreditDetails {
     /// 卒業要件単位数
-    pub required_for_graduation: u32,
+    /// 単位状況の表に表示されていない場合（科目一覧から計算した場合を含む）は`None`になります。
+    pub required_for_graduation: Option<u32>,
     /// 修得済み単位数
     pub completed_credits: u32,
     /// 履修中単位数
@@ -196,6 +238,7 @@ pub struct CreditDetails {
 
 /// カテゴリ別の単位情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct CategoryCredits {
     /// カテゴリ名
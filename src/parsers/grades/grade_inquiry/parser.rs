@@ -2,16 +2,44 @@
 //!
 //! HTMLドキュメントから成績照会データを解析する機能を提供します。
 
-use regex_lite;
-use scraper::{Html, Selector};
+use std::sync::OnceLock;
 
+use regex_lite::Regex;
+use scraper::{ElementRef, Html};
+
+use crate::common::context::ParseContext;
+use crate::common::selectors::selector_registry;
+use crate::common::term::AcademicTerm;
+use crate::parsers::grades::gpa::GpaRules;
 use crate::utils::error::ParseError;
 
 use super::model::{
     CategoryCredits, CreditDetails, CreditSummary, DisplayPattern, DisplaySettings, Grade,
-    GradeInquiry, Semester, Subject, SubjectCategory,
+    GradeInquiry, RequirementType, Subject, SubjectCategory, TermSummary, ViewType,
 };
 
+selector_registry! {
+    /// 成績照会画面で使用するセレクター
+    struct GradeInquirySelectors {
+        subject_table: "table.listTable",
+        caption: "caption",
+        footer: "tfoot",
+        checkbox: "input[type=checkbox]",
+        radio: "input[type=radio]",
+        label: "label[for]",
+        gpa: ".gpaScore, .gpa, #gpaValue",
+        credit_summary_table: "table.creditSummary, .creditTable, .unitTable",
+        body_row: "tbody tr",
+        row: "tr",
+        header: "th",
+        cell: "td",
+        header_or_cell: "td, th",
+    }
+}
+
+/// 評価欄・備考欄で履修中の科目を表す表記
+const CURRENTLY_ENROLLED: &str = "履修中";
+
 /// 成績照会パーサー実装
 pub struct GradeInquiryParserImpl {
     display_pattern: DisplayPattern,
@@ -20,6 +48,8 @@ pub struct GradeInquiryParserImpl {
 
 impl GradeInquiryParserImpl {
     /// 新しいパーサーインスタンスを作成
+    ///
+    /// 表示パターンと表示設定は、ページに表示切り替えの項目がない場合に使用します。
     pub fn new(display_pattern: DisplayPattern, display_settings: DisplaySettings) -> Self {
         Self {
             display_pattern,
@@ -29,60 +59,226 @@ impl GradeInquiryParserImpl {
 
     /// HTMLドキュメントから成績照会を解析する
     pub fn parse_document(&self, document: &Html) -> Result<GradeInquiry, ParseError> {
-        let subjects = self.parse_subjects(document)?;
-        let gpa_score = self.parse_gpa(document)?;
-        let credit_summary = self.parse_credit_summary(document)?;
+        self.parse_with_context(document, &mut ParseContext::new())
+    }
+
+    /// 警告をコンテキストに記録しながら成績照会を解析する
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<GradeInquiry, ParseError> {
+        let display_settings = self.parse_display_settings(document)?;
+        let SubjectList { subjects, blocks } = self.parse_subjects(document, ctx)?;
+        let display_pattern = self.parse_display_pattern(document, !blocks.is_empty())?;
+        let gpa_score = self.parse_gpa(document, &subjects, ctx)?;
+        let term_summaries = self.summarize_terms(&subjects, &blocks, ctx)?;
+        let credit_summary = self.parse_credit_summary(document, &subjects, ctx)?;
 
         Ok(GradeInquiry {
-            display_pattern: self.display_pattern.clone(),
-            display_settings: self.display_settings.clone(),
+            display_pattern,
+            display_settings,
             subjects,
             gpa_score,
+            term_summaries,
             credit_summary,
         })
     }
 
+    /// 表示設定のチェックボックスから表示設定を読み取る
+    ///
+    /// チェックボックスのない項目はパーサーに設定された値のままにします。
+    fn parse_display_settings(&self, document: &Html) -> Result<DisplaySettings, ParseError> {
+        let selectors = GradeInquirySelectors::get()?;
+        let mut settings = self.display_settings.clone();
+
+        for input in document.select(&selectors.checkbox) {
+            let label = control_label(document, input, selectors);
+            if let Some(visible) = display_setting(&mut settings, &label) {
+                *visible = input.value().attr("checked").is_some();
+            }
+        }
+
+        Ok(settings)
+    }
+
+    /// 表示パターンを判定する
+    ///
+    /// 選択されている表示切り替えのラジオボタンを優先し、なければ学期ごとのまとまりの有無、
+    /// それもなければパーサーに設定された値を使用します。
+    fn parse_display_pattern(
+        &self,
+        document: &Html,
+        has_term_blocks: bool,
+    ) -> Result<DisplayPattern, ParseError> {
+        let selectors = GradeInquirySelectors::get()?;
+        let selected = document
+            .select(&selectors.radio)
+            .filter(|input| input.value().attr("checked").is_some())
+            .find_map(|input| view_type(&control_label(document, input, selectors)));
+
+        let view_type = match selected {
+            Some(view_type) => view_type,
+            None if has_term_blocks => ViewType::BySemester,
+            None => self.display_pattern.view_type.clone(),
+        };
+        Ok(DisplayPattern { view_type })
+    }
+
     /// 科目一覧を解析する
-    fn parse_subjects(&self, document: &Html) -> Result<Vec<Subject>, ParseError> {
-        let table_selector = Selector::parse("table.listTable")
-            .map_err(|e| ParseError::selector_creation_failed("table.listTable", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tbody tr")
-            .map_err(|e| ParseError::selector_creation_failed("tbody tr", &e.to_string()))?;
-
-        let cell_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
-        let mut subjects = Vec::new();
-
-        if let Some(table) = document.select(&table_selector).next() {
-            for row in table.select(&row_selector) {
-                let cells: Vec<_> = row.select(&cell_selector).collect();
-
-                if cells.len() >= 5 {
-                    let name = cells[0].inner_html().trim().to_string();
-                    let credit_count = cells[1].inner_html().trim().parse::<u32>().ok();
-                    let grade_html = cells[2].inner_html();
-                    let grade_text = grade_html.trim();
-                    let grade = self.parse_grade(grade_text)?;
-                    let instructor_name = cells[3].inner_html().trim().to_string();
-
-                    subjects.push(Subject {
-                        name,
-                        credit_count,
-                        grade: Some(grade),
-                        numeric_score: None, // Would need additional parsing
-                        academic_year: 2025, // Would need to be extracted
-                        semester: Semester::Spring, // Would need to be extracted
-                        instructor_name,
-                        is_currently_enrolled: false, // Would need to be determined
-                        category: SubjectCategory::default(),
-                    });
+    ///
+    /// 列の並びは見出し行（`th`）の名前から判定し、見出し行がない場合は
+    /// 科目名・単位・評価・担当教員・備考の順とみなします。
+    /// 値のあるセルが1つだけの短い行は科目区分の見出し行として扱い、以降の科目のカテゴリにします。
+    ///
+    /// 年度学期別表示では、学期ごとの表（表題または直前の見出しが「2024年度 前期」など）か、
+    /// 表の中の学期の見出し行で科目がまとめられます。年度・学期の列がない科目にはまとまりの
+    /// 学期を使用し、まとまりに表示された学期GPA・単位数も読み取ります。
+    fn parse_subjects(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<SubjectList, ParseError> {
+        let selectors = GradeInquirySelectors::get()?;
+        let mut list = SubjectList::default();
+
+        ctx.trace_selector(
+            "subjects",
+            &selectors.subject_table,
+            document.root_element(),
+        );
+        let tables: Vec<_> = document.select(&selectors.subject_table).collect();
+        if tables.is_empty() {
+            ctx.missing_section("subjects", selectors.subject_table.source())?;
+            return Ok(list);
+        }
+
+        let mut columns = SubjectColumns::default();
+        let mut categories = CategoryStack::default();
+        let mut row_offset = 0;
+
+        for table in tables {
+            // 見出し行のない表は、前の表と同じ列の並びとみなす
+            if let Some(labels) = table
+                .select(&selectors.row)
+                .map(|row| {
+                    row.select(&selectors.header)
+                        .map(cell_text)
+                        .collect::<Vec<_>>()
+                })
+                .find(|labels| !labels.is_empty())
+            {
+                columns = SubjectColumns::from_labels(&labels);
+            }
+
+            if let Some((term, heading)) = table_term(table, selectors) {
+                list.start_block(term, &heading);
+                categories.close_levels();
+            }
+
+            let rows: Vec<_> = table.select(&selectors.body_row).collect();
+            for (index, row) in rows.iter().enumerate() {
+                let row_index = row_offset + index;
+                let cells: Vec<_> = row.select(&selectors.cell).collect();
+
+                if cells.len() < columns.len {
+                    let row_text = cells
+                        .iter()
+                        .map(|cell| cell_text(*cell))
+                        .filter(|text| !text.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    if let Some(term) = heading_term(&row_text) {
+                        list.start_block(term, &row_text);
+                        categories.close_levels();
+                        continue;
+                    }
+                    match list.blocks.last_mut() {
+                        Some(block) if is_term_summary(&row_text) => block.read_summary(&row_text),
+                        _ => match category_header(*row, &cells) {
+                            Some((level, text)) => categories.push(level, &text),
+                            None => {
+                                ctx.skip_short_row("subjects", row_index, cells.len(), columns.len)
+                            }
+                        },
+                    }
+                    continue;
+                }
+
+                let text = |column: Option<usize>| {
+                    column
+                        .map(|column| cell_text(cells[column]))
+                        .unwrap_or_default()
+                };
+
+                let name = text(Some(columns.name));
+                let credit_text = text(Some(columns.credits));
+                let credit_count = credit_text.parse::<u32>().ok();
+                if credit_count.is_none() {
+                    ctx.unparseable_cell("subjects", row_index, columns.credits, &credit_text)?;
+                }
+
+                let grade_text = text(Some(columns.grade));
+                let grade = self.parse_grade(&grade_text)?;
+                if matches!(grade, Grade::NoEvaluation)
+                    && !is_blank(&grade_text)
+                    && grade_text != CURRENTLY_ENROLLED
+                {
+                    ctx.unparseable_cell("subjects", row_index, columns.grade, &grade_text)?;
                 }
+
+                let score_text = text(columns.score);
+                let numeric_score = if is_blank(&score_text) {
+                    None
+                } else {
+                    let score = score_text.parse::<u32>().ok();
+                    if score.is_none() {
+                        let column = columns.score.unwrap_or_default();
+                        ctx.unparseable_cell("subjects", row_index, column, &score_text)?;
+                    }
+                    score
+                };
+
+                // 年度・学期の列、学期のまとまりの順に決める（どちらもなければ不明）
+                let block_term = list.blocks.last().map(|block| block.term);
+                let parsed_term = match columns.term_text(&cells) {
+                    Some((column, term_text)) => match AcademicTerm::parse(&term_text) {
+                        Ok(term) => Some(term),
+                        Err(_) => {
+                            ctx.unparseable_cell("subjects", row_index, column, &term_text)?;
+                            None
+                        }
+                    },
+                    None => None,
+                };
+                let term = parsed_term.or(block_term);
+
+                let is_currently_enrolled = grade_text == CURRENTLY_ENROLLED
+                    || text(columns.status).contains(CURRENTLY_ENROLLED);
+                let requirement_type = requirement_type(&text(columns.requirement));
+
+                list.subjects.push(Subject {
+                    name,
+                    credit_count,
+                    grade: Some(grade),
+                    numeric_score,
+                    term,
+                    instructor_name: text(columns.instructor),
+                    is_currently_enrolled,
+                    category: categories.category(requirement_type),
+                });
+            }
+            row_offset += rows.len();
+
+            if let (Some(block), Some(footer)) = (
+                list.blocks.last_mut(),
+                table.select(&selectors.footer).next(),
+            ) {
+                block.read_summary(&cell_text(footer));
             }
         }
 
-        Ok(subjects)
+        Ok(list)
     }
 
     /// 成績評価を解析する
@@ -94,119 +290,163 @@ impl GradeInquiryParserImpl {
             "可" => Ok(Grade::C),
             "不可" => Ok(Grade::D),
             "合格" => Ok(Grade::Pass),
+            "認定" => Ok(Grade::Recognized),
             _ => Ok(Grade::NoEvaluation),
         }
     }
 
     /// GPA得点を解析する
-    fn parse_gpa(&self, document: &Html) -> Result<f64, ParseError> {
-        // GPA表示エリアを探す
-        let gpa_selector = Selector::parse(".gpaScore, .gpa, #gpaValue").map_err(|e| {
-            ParseError::selector_creation_failed(".gpaScore, .gpa, #gpaValue", &e.to_string())
-        })?;
+    fn parse_gpa(
+        &self,
+        document: &Html,
+        subjects: &[Subject],
+        ctx: &mut ParseContext,
+    ) -> Result<Option<f64>, ParseError> {
+        let selectors = GradeInquirySelectors::get()?;
 
-        if let Some(gpa_element) = document.select(&gpa_selector).next() {
+        // GPA表示エリアを探す
+        ctx.trace_selector("gpa_score", &selectors.gpa, document.root_element());
+        if let Some(gpa_element) = document.select(&selectors.gpa).next() {
             let gpa_text = gpa_element.inner_html();
             let gpa_text_trimmed = gpa_text.trim();
 
             // GPAの数値部分を抽出（例：「GPA: 3.45」から「3.45」を抽出）
-            let gpa_regex = regex_lite::Regex::new(r"(\d+\.\d+)")
-                .map_err(|e| ParseError::data_parsing_failed("gpa regex", &e.to_string()))?;
-
-            if let Some(captures) = gpa_regex.captures(gpa_text_trimmed) {
+            if let Some(captures) = gpa_regex().captures(gpa_text_trimmed) {
                 return captures[1]
                     .parse::<f64>()
+                    .map(Some)
                     .map_err(|e| ParseError::data_parsing_failed("gpa parsing", &e.to_string()));
             }
         }
 
-        // GPAの表示が見つからない場合は、成績表から計算
-        self.calculate_gpa_from_grades(document)
+        // GPAの表示が見つからない場合は、既定の計算規則で科目の成績から計算
+        ctx.fallback_used("gpa_score", "calculated from subject grades")?;
+        Ok(GpaRules::default()
+            .calculate_subjects(subjects)?
+            .cumulative
+            .gpa)
     }
 
-    /// 成績から GPA を計算する
-    fn calculate_gpa_from_grades(&self, document: &Html) -> Result<f64, ParseError> {
-        let table_selector = Selector::parse("table.listTable")
-            .map_err(|e| ParseError::selector_creation_failed("table.listTable", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tbody tr")
-            .map_err(|e| ParseError::selector_creation_failed("tbody tr", &e.to_string()))?;
-
-        let cell_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
-        let mut total_points = 0.0;
-        let mut total_credits = 0;
-
-        if let Some(table) = document.select(&table_selector).next() {
-            for row in table.select(&row_selector) {
-                let cells: Vec<_> = row.select(&cell_selector).collect();
-
-                if cells.len() >= 3 {
-                    if let Ok(credits) = cells[1].inner_html().trim().parse::<u32>() {
-                        let grade_html = cells[2].inner_html();
-                        let grade_text = grade_html.trim();
-                        let grade_point = self.grade_to_point(grade_text);
-
-                        if grade_point > 0.0 {
-                            total_points += grade_point * credits as f64;
-                            total_credits += credits;
-                        }
-                    }
-                }
+    /// 年度・学期ごとのGPAと単位数をまとめる
+    ///
+    /// 学期のまとまりに表示された値を優先し、表示されていない値は既定の計算規則で
+    /// 科目の成績から計算します。
+    fn summarize_terms(
+        &self,
+        subjects: &[Subject],
+        blocks: &[TermBlock],
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<TermSummary>, ParseError> {
+        let report = GpaRules::default().calculate_subjects(subjects)?;
+        let mut terms: Vec<AcademicTerm> = Vec::new();
+        for term in blocks
+            .iter()
+            .map(|block| block.term)
+            .chain(subjects.iter().filter_map(|subject| subject.term))
+        {
+            if !terms.contains(&term) {
+                terms.push(term);
             }
         }
 
-        if total_credits > 0 {
-            Ok(total_points / total_credits as f64)
-        } else {
-            Ok(0.0)
-        }
-    }
+        let mut gpa_fallback = false;
+        let summaries = terms
+            .into_iter()
+            .map(|term| {
+                let block = blocks.iter().find(|block| block.term == term);
+                let in_term = || {
+                    subjects
+                        .iter()
+                        .filter(move |subject| subject.term == Some(term))
+                };
+                let calculated_gpa = report
+                    .terms
+                    .iter()
+                    .find(|summary| summary.term == Some(term))
+                    .and_then(|summary| summary.gpa);
+                let credits = |subject: &Subject| subject.credit_count.unwrap_or(0);
+
+                let gpa = match block {
+                    Some(TermBlock { gpa: Some(gpa), .. }) => Some(*gpa),
+                    Some(_) => {
+                        gpa_fallback = true;
+                        calculated_gpa
+                    }
+                    None => calculated_gpa,
+                };
+                TermSummary {
+                    term,
+                    gpa,
+                    earned_credits: block.and_then(|block| block.earned_credits).unwrap_or_else(
+                        || {
+                            in_term()
+                                .filter(|subject| is_completed(subject))
+                                .map(credits)
+                                .sum()
+                        },
+                    ),
+                    registered_credits: block
+                        .and_then(|block| block.registered_credits)
+                        .unwrap_or_else(|| in_term().map(credits).sum()),
+                }
+            })
+            .collect();
 
-    /// 成績評価をGPAポイントに変換
-    fn grade_to_point(&self, grade_text: &str) -> f64 {
-        match grade_text {
-            "秀" => 4.0,
-            "優" => 3.0,
-            "良" => 2.0,
-            "可" => 1.0,
-            "不可" => 0.0,
-            "合格" => 3.0, // 合格は平均的な評価として扱う
-            _ => 0.0,
+        if gpa_fallback {
+            ctx.fallback_used("term_summaries.gpa", "calculated from subject grades")?;
         }
+        Ok(summaries)
     }
 
     /// 単位取得状況を解析する
-    fn parse_credit_summary(&self, document: &Html) -> Result<CreditSummary, ParseError> {
+    fn parse_credit_summary(
+        &self,
+        document: &Html,
+        subjects: &[Subject],
+        ctx: &mut ParseContext,
+    ) -> Result<CreditSummary, ParseError> {
+        let selectors = GradeInquirySelectors::get()?;
         let mut credit_summary = CreditSummary::default();
 
         // 単位状況テーブルを探す
-        let summary_table_selector =
-            Selector::parse("table.creditSummary, .creditTable, .unitTable").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "table.creditSummary, .creditTable, .unitTable",
-                    &e.to_string(),
-                )
-            })?;
-
-        if let Some(table) = document.select(&summary_table_selector).next() {
-            let row_selector = Selector::parse("tr")
-                .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-
-            let cell_selector = Selector::parse("td, th")
-                .map_err(|e| ParseError::selector_creation_failed("td, th", &e.to_string()))?;
-
-            for row in table.select(&row_selector) {
-                let cells: Vec<_> = row.select(&cell_selector).collect();
+        ctx.trace_selector(
+            "credit_summary",
+            &selectors.credit_summary_table,
+            document.root_element(),
+        );
+        if let Some(table) = document.select(&selectors.credit_summary_table).next() {
+            for (row_index, row) in table.select(&selectors.row).enumerate() {
+                let cells: Vec<_> = row.select(&selectors.header_or_cell).collect();
+                if row.select(&selectors.cell).next().is_none() {
+                    // 見出し行
+                    continue;
+                }
 
                 if cells.len() >= 4 {
                     let category_name = cells[0].inner_html().trim().to_string();
 
-                    // 数値セルをパース
-                    let required = cells[1].inner_html().trim().parse::<u32>().unwrap_or(0);
-                    let completed = cells[2].inner_html().trim().parse::<u32>().unwrap_or(0);
-                    let current = cells[3].inner_html().trim().parse::<u32>().unwrap_or(0);
+                    // 卒業要件単位数は空欄や解釈できない場合に値を補わない
+                    let required =
+                        ctx.parse_cell("credit_summary", row_index, 1, &cells[1].inner_html())?;
+
+                    // 数値セルをパース（解釈できない場合は0として警告を記録）
+                    let mut parse_credit = |column: usize| -> Result<u32, ParseError> {
+                        let html = cells[column].inner_html();
+                        match html.trim().parse::<u32>() {
+                            Ok(credits) => Ok(credits),
+                            Err(_) => {
+                                ctx.unparseable_cell(
+                                    "credit_summary",
+                                    row_index,
+                                    column,
+                                    html.trim(),
+                                )?;
+                                Ok(0)
+                            }
+                        }
+                    };
+                    let completed = parse_credit(2)?;
+                    let current = parse_credit(3)?;
 
                     let credit_details = CreditDetails {
                         required_for_graduation: required,
@@ -232,61 +472,428 @@ impl GradeInquiryParserImpl {
                             });
                         }
                     }
+                } else {
+                    ctx.skip_short_row("credit_summary", row_index, cells.len(), 4);
                 }
             }
         } else {
             // テーブルが見つからない場合は科目一覧から計算
-            self.calculate_credit_summary_from_subjects(document, &mut credit_summary)?;
+            ctx.missing_section("credit_summary", selectors.credit_summary_table.source())?;
+            ctx.fallback_used("credit_summary.overall", "calculated from subjects")?;
+            self.calculate_credit_summary_from_subjects(subjects, &mut credit_summary);
         }
 
         Ok(credit_summary)
     }
 
     /// 科目一覧から単位状況を計算する
+    ///
+    /// 履修中または評価前の科目は履修中単位、不可以外の評価の科目は修得済み単位として数えます。
+    /// 卒業要件単位数は科目一覧からは分からないため`None`とします。
     fn calculate_credit_summary_from_subjects(
         &self,
-        document: &Html,
+        subjects: &[Subject],
         credit_summary: &mut CreditSummary,
-    ) -> Result<(), ParseError> {
-        let table_selector = Selector::parse("table.listTable")
-            .map_err(|e| ParseError::selector_creation_failed("table.listTable", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tbody tr")
-            .map_err(|e| ParseError::selector_creation_failed("tbody tr", &e.to_string()))?;
-
-        let cell_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
+    ) {
         let mut completed_credits = 0;
         let mut current_credits = 0;
 
-        if let Some(table) = document.select(&table_selector).next() {
-            for row in table.select(&row_selector) {
-                let cells: Vec<_> = row.select(&cell_selector).collect();
-
-                if cells.len() >= 3 {
-                    if let Ok(credits) = cells[1].inner_html().trim().parse::<u32>() {
-                        let grade_html = cells[2].inner_html();
-                        let grade_text = grade_html.trim();
-
-                        if grade_text.is_empty() || grade_text == "-" {
-                            current_credits += credits;
-                        } else if !grade_text.contains("不可") {
-                            completed_credits += credits;
-                        }
-                    }
-                }
+        for subject in subjects {
+            let credits = subject.credit_count.unwrap_or(0);
+            match subject.grade {
+                _ if subject.is_currently_enrolled => current_credits += credits,
+                None | Some(Grade::NoEvaluation) => current_credits += credits,
+                Some(Grade::D) => {}
+                Some(_) => completed_credits += credits,
             }
         }
 
         // 全体の単位状況を設定
         credit_summary.overall = CreditDetails {
-            required_for_graduation: 124, // 一般的な卒業要件単位数
+            required_for_graduation: None,
             completed_credits,
             currently_enrolled_credits: current_credits,
             total_credits: completed_credits + current_credits,
         };
+    }
+}
 
-        Ok(())
+/// 科目一覧の解析結果
+#[derive(Default)]
+struct SubjectList {
+    subjects: Vec<Subject>,
+    /// 年度学期別表示の学期ごとのまとまり（通常表示では空）
+    blocks: Vec<TermBlock>,
+}
+
+impl SubjectList {
+    /// 学期のまとまりを始める（見出しに集計値が含まれていれば読み取る）
+    fn start_block(&mut self, term: AcademicTerm, heading: &str) {
+        let mut block = TermBlock {
+            term,
+            gpa: None,
+            earned_credits: None,
+            registered_credits: None,
+        };
+        block.read_summary(heading);
+        self.blocks.push(block);
+    }
+}
+
+/// 年度学期別表示の学期のまとまりと、画面に表示された集計値
+struct TermBlock {
+    term: AcademicTerm,
+    gpa: Option<f64>,
+    earned_credits: Option<u32>,
+    registered_credits: Option<u32>,
+}
+
+impl TermBlock {
+    /// 「学期GPA 3.20 修得単位数 18」のような表記から集計値を読み取る
+    fn read_summary(&mut self, text: &str) {
+        let [gpa, earned, registered] = summary_regexes();
+        let capture = |regex: &Regex| regex.captures(text).map(|captures| captures[1].to_string());
+        self.gpa = capture(gpa)
+            .and_then(|value| value.parse().ok())
+            .or(self.gpa);
+        self.earned_credits = capture(earned)
+            .and_then(|value| value.parse().ok())
+            .or(self.earned_credits);
+        self.registered_credits = capture(registered)
+            .and_then(|value| value.parse().ok())
+            .or(self.registered_credits);
+    }
+}
+
+/// 科目一覧の各列の位置
+struct SubjectColumns {
+    name: usize,
+    credits: usize,
+    grade: usize,
+    score: Option<usize>,
+    /// 「2024年度 前期」のように年度と学期をまとめた列
+    year_term: Option<usize>,
+    year: Option<usize>,
+    term: Option<usize>,
+    instructor: Option<usize>,
+    status: Option<usize>,
+    requirement: Option<usize>,
+    /// 科目の行に必要なセル数
+    len: usize,
+}
+
+impl Default for SubjectColumns {
+    /// 見出し行がない場合の並び（科目名・単位・評価・担当教員・備考）
+    fn default() -> Self {
+        Self {
+            name: 0,
+            credits: 1,
+            grade: 2,
+            score: None,
+            year_term: None,
+            year: None,
+            term: None,
+            instructor: Some(3),
+            status: Some(4),
+            requirement: None,
+            len: 5,
+        }
+    }
+}
+
+impl SubjectColumns {
+    /// 見出し行の名前から列の位置を判定する（判定できない必須列は既定の位置）
+    fn from_labels(labels: &[String]) -> Self {
+        let find = |matches: &dyn Fn(&str) -> bool| labels.iter().position(|l| matches(l));
+        let defaults = Self::default();
+        // 年度と学期をまとめた列がある場合は、それぞれの列を探さない
+        let year_term = find(&|l| l.contains("年度") && l.contains("期"));
+        let separate = |matches: &dyn Fn(&str) -> bool| match year_term {
+            Some(_) => None,
+            None => find(matches),
+        };
+        Self {
+            name: find(&|l| l.contains("科目名") || l == "科目").unwrap_or(defaults.name),
+            credits: find(&|l| l.contains("単位")).unwrap_or(defaults.credits),
+            grade: find(&|l| l.contains("評価") || l.contains("成績")).unwrap_or(defaults.grade),
+            score: find(&|l| l.contains("素点") || l.contains("点数") || l.contains("得点")),
+            year_term,
+            year: separate(&|l| l.contains("年度")),
+            term: separate(&|l| l.contains("学期") || l == "期"),
+            instructor: find(&|l| l.contains("教員")),
+            status: find(&|l| l.contains("備考") || l.contains("状態") || l.contains("状況")),
+            requirement: find(&|l| l.contains("必修") || l.contains("必選")),
+            len: labels.len().max(3),
+        }
+    }
+
+    /// 年度・学期の表記と、その列の位置（年度・学期の列がない場合は`None`）
+    fn term_text(&self, cells: &[ElementRef]) -> Option<(usize, String)> {
+        match (self.year_term, self.year, self.term) {
+            (Some(column), _, _) => Some((column, cell_text(cells[column]))),
+            (None, Some(year), Some(term)) => {
+                let year_text = cell_text(cells[year]);
+                let year_text = year_text.trim_end_matches("年度");
+                Some((term, format!("{year_text}年度 {}", cell_text(cells[term]))))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 見出し行でたどっている科目区分の階層
+#[derive(Default)]
+struct CategoryStack {
+    curriculum_name: String,
+    /// 階層レベルと区分名、区分名から読み取った必修・選択の区分
+    levels: Vec<(u32, String, Option<RequirementType>)>,
+}
+
+impl CategoryStack {
+    /// 見出し行の区分を追加する（同じか深いレベルの区分は閉じる）
+    fn push(&mut self, level: u32, text: &str) {
+        if text.contains("カリキュラム") {
+            self.curriculum_name = text.to_string();
+            self.levels.clear();
+            return;
+        }
+        self.levels.retain(|(open, _, _)| *open < level);
+        let (name, requirement) = split_requirement(text);
+        self.levels.push((level, name, requirement));
+    }
+
+    /// 開いている区分をすべて閉じる（カリキュラム名は残す）
+    fn close_levels(&mut self) {
+        self.levels.clear();
+    }
+
+    /// 現在の区分を科目カテゴリにする
+    ///
+    /// 1〜3番目の区分を大・中・小分類とし、それより深い区分は小分類とします。
+    /// 必修・選択の区分は科目の行の値、最も深い区分名の表記、選択の順で決めます。
+    fn category(&self, requirement_type: Option<RequirementType>) -> SubjectCategory {
+        let name = |index: usize| {
+            self.levels
+                .get(index)
+                .map(|(_, name, _)| name.clone())
+                .unwrap_or_default()
+        };
+        let sub_category_name = match self.levels.len() {
+            0..=2 => String::new(),
+            len => name(len - 1),
+        };
+        let requirement_type = requirement_type
+            .or_else(|| self.levels.iter().rev().find_map(|(_, _, r)| r.clone()))
+            .unwrap_or_default();
+
+        SubjectCategory {
+            curriculum_name: self.curriculum_name.clone(),
+            major_category_name: name(0),
+            middle_category_name: name(1),
+            sub_category_name,
+            requirement_type,
+            hierarchy_level: self.levels.len() as u32,
+        }
     }
 }
+
+/// 科目区分の見出し行であれば、階層レベルと区分名を返す
+///
+/// 空でないセルが1つだけの行を見出し行とします（合計・小計の行を除く）。
+/// 階層レベルは行またはセルのクラス名の`level2`などの番号、なければ区分名より前の空のセルと
+/// 区分名の先頭の全角空白の数から求めます。
+fn category_header(row: ElementRef, cells: &[ElementRef]) -> Option<(u32, String)> {
+    let mut filled = cells
+        .iter()
+        .enumerate()
+        .filter(|(_, cell)| !cell_text(**cell).is_empty());
+    let (index, cell) = filled.next()?;
+    if filled.next().is_some() {
+        return None;
+    }
+
+    let raw: String = cell.text().collect();
+    let text = raw.trim().to_string();
+    if ["合計", "小計", "総計"]
+        .iter()
+        .any(|total| text.starts_with(total))
+    {
+        return None;
+    }
+
+    let level = [row, *cell]
+        .iter()
+        .find_map(|element| {
+            let class = element.value().attr("class")?;
+            let captures = level_regex().captures(class)?;
+            captures[1].parse::<u32>().ok()
+        })
+        .unwrap_or_else(|| {
+            let indent = raw
+                .trim_start_matches(|c: char| c.is_ascii_whitespace())
+                .chars()
+                .take_while(|c| *c == '\u{3000}' || *c == '\u{a0}')
+                .count();
+            (index + indent) as u32 + 1
+        });
+
+    Some((level, text))
+}
+
+/// GPA表示エリアの数値部分を取り出す正規表現
+fn gpa_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"(\d+\.\d+)").expect("gpa pattern is valid"))
+}
+
+fn level_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"(?i)level-?(\d+)").expect("level pattern is valid"))
+}
+
+/// 学期GPA・修得単位数・履修単位数の値を取り出す正規表現
+fn summary_regexes() -> &'static [Regex; 3] {
+    static REGEXES: OnceLock<[Regex; 3]> = OnceLock::new();
+    REGEXES.get_or_init(|| {
+        [
+            r"GPA\D*?(\d+(?:\.\d+)?)",
+            r"修得単位数?\D*?(\d+)",
+            r"履修単位数?\D*?(\d+)",
+        ]
+        .map(|pattern| Regex::new(pattern).expect("summary pattern is valid"))
+    })
+}
+
+/// 学期GPAや単位数の集計行か
+fn is_term_summary(text: &str) -> bool {
+    text.contains("GPA") || text.contains("修得単位") || text.contains("履修単位")
+}
+
+/// 「2024年度 前期」のような学期の見出しを解釈する
+///
+/// 見出しに続く「GPA 3.20」などの集計値や括弧書きは無視します。
+fn heading_term(text: &str) -> Option<AcademicTerm> {
+    let heading = text.split(['(', '（']).next()?.trim();
+    AcademicTerm::parse(heading).ok().or_else(|| {
+        let mut words = heading.split_whitespace();
+        AcademicTerm::parse(&format!("{} {}", words.next()?, words.next()?)).ok()
+    })
+}
+
+/// 表の学期と見出しの文字列（表題、なければ直前の要素を見出しとする）
+fn table_term(
+    table: ElementRef,
+    selectors: &GradeInquirySelectors,
+) -> Option<(AcademicTerm, String)> {
+    let caption = table.select(&selectors.caption).next().map(cell_text);
+    let previous = || {
+        table
+            .prev_siblings()
+            .find_map(ElementRef::wrap)
+            .map(cell_text)
+    };
+    [caption, previous()]
+        .into_iter()
+        .flatten()
+        .find_map(|heading| Some((heading_term(&heading)?, heading)))
+}
+
+/// チェックボックス・ラジオボタンの名前
+///
+/// `label[for]`、囲んでいる`label`、直後の文字列の順に探します。
+fn control_label(document: &Html, input: ElementRef, selectors: &GradeInquirySelectors) -> String {
+    let for_label = input.value().id().and_then(|id| {
+        document
+            .select(&selectors.label)
+            .find(|label| label.value().attr("for") == Some(id))
+    });
+    let enclosing = || {
+        input
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|element| element.value().name() == "label")
+    };
+    if let Some(label) = for_label.or_else(enclosing) {
+        return cell_text(label);
+    }
+    input
+        .next_siblings()
+        .find_map(|node| {
+            let text = match ElementRef::wrap(node) {
+                Some(element) => cell_text(element),
+                None => node.value().as_text()?.trim().to_string(),
+            };
+            (!text.is_empty()).then_some(text)
+        })
+        .unwrap_or_default()
+}
+
+/// チェックボックスの名前に対応する表示設定の項目
+fn display_setting<'a>(settings: &'a mut DisplaySettings, label: &str) -> Option<&'a mut bool> {
+    let field = match label {
+        l if l.contains("不合格") => &mut settings.is_failed_subjects_visible,
+        l if l.contains(CURRENTLY_ENROLLED) => &mut settings.is_current_subjects_visible,
+        l if l.contains("評価") => &mut settings.is_grade_label_visible,
+        l if l.contains("素点") => &mut settings.is_numeric_score_visible,
+        l if l.contains("出席") => &mut settings.is_attendance_visible,
+        l if l.contains("GPA") => &mut settings.is_gpa_visible,
+        l if l.contains("単位") => &mut settings.is_credit_status_visible,
+        _ => return None,
+    };
+    Some(field)
+}
+
+/// 表示切り替えのラジオボタンの名前に対応する表示タイプ
+fn view_type(label: &str) -> Option<ViewType> {
+    if label.contains("学期別") {
+        Some(ViewType::BySemester)
+    } else if label.contains("通常") || label.contains("標準") {
+        Some(ViewType::Standard)
+    } else {
+        None
+    }
+}
+
+/// 修得済みの科目か（履修中・評価前・不可の科目を除く）
+fn is_completed(subject: &Subject) -> bool {
+    !subject.is_currently_enrolled
+        && !matches!(
+            subject.grade,
+            None | Some(Grade::NoEvaluation) | Some(Grade::D)
+        )
+}
+
+/// 区分名の末尾の「（必修）」などを取り除き、必修・選択の区分として返す
+fn split_requirement(text: &str) -> (String, Option<RequirementType>) {
+    let trimmed = text.trim_end_matches([')', '）']);
+    if let Some(open) = trimmed.rfind(['(', '（']) {
+        let requirement = requirement_type(&trimmed[open..]);
+        if requirement.is_some() {
+            return (trimmed[..open].trim_end().to_string(), requirement);
+        }
+    }
+    (text.to_string(), requirement_type(text))
+}
+
+/// 「必修」「選択」などの表記を必修・選択の区分にする
+///
+/// 選択必修（指定された科目群から選んで修得する）は選択として扱います。
+fn requirement_type(text: &str) -> Option<RequirementType> {
+    if text.contains("選択") || text == "選" {
+        Some(RequirementType::Elective)
+    } else if text.contains("必修") || text == "必" {
+        Some(RequirementType::Required)
+    } else {
+        None
+    }
+}
+
+/// セルの文字列（タグを除いて前後の空白を取り除いたもの）
+fn cell_text(cell: ElementRef) -> String {
+    cell.text().collect::<String>().trim().to_string()
+}
+
+/// 値がないことを表すセルか
+fn is_blank(text: &str) -> bool {
+    text.is_empty() || text == "-" || text == "－"
+}
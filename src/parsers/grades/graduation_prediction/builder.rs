@@ -1,5 +1,8 @@
 //! 卒業見込判定パーサービルダー
 
+use crate::common::context::ParseContext;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 use scraper::Html;
@@ -9,13 +12,28 @@ use super::parser::GraduationPredictionParserImpl;
 
 /// 卒業見込判定パーサービルダー
 pub struct GraduationPredictionParserBuilder {
-    // 現在は設定項目なし、将来的に拡張可能
+    /// 解析オプション
+    options: ParseOptions,
 }
 
 impl GraduationPredictionParserBuilder {
     /// 新しいパーサービルダーインスタンスを作成
     pub fn new() -> Self {
-        Self {}
+        Self {
+            options: ParseOptions::default(),
+        }
+    }
+
+    /// 解析オプションを設定
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 厳密なパースモードを設定
+    pub fn with_strict_mode(mut self, strict_mode: bool) -> Self {
+        self.options.strict_mode = strict_mode;
+        self
     }
 
     /// 卒業見込判定パーサーを構築
@@ -28,8 +46,26 @@ impl PageParser<SotsugyoMikonHanteiKekka> for GraduationPredictionParserBuilder
     const PAGE_TYPE: &'static str = "卒業見込判定";
 
     fn parse_document(&self, document: &Html) -> Result<SotsugyoMikonHanteiKekka, ParseError> {
-        let parser = self.build()?;
-        parser.parse_document(document)
+        let mut ctx = ParseContext::new().with_strict(self.options.strict_mode);
+        self.build()?.parse_with_context(document, &mut ctx)
+    }
+
+    fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<SotsugyoMikonHanteiKekka>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.build()?.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+
+    fn parse_with_diagnostics(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<SotsugyoMikonHanteiKekka>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options).with_diagnostics(true);
+        let data = self.build()?.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
     }
 }
 
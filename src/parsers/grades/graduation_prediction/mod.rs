@@ -8,5 +8,7 @@ pub mod model;
 pub mod parser;
 
 pub use builder::GraduationPredictionParserBuilder;
-pub use model::{SotsugyoMikonHanteiKekka, YokenFusokuItem};
+pub use model::{
+    FusokuRyo, FusokuUnit, HanteiStatus, SotsugyoMikonHanteiKekka, YokenFusokuItem, YokenNode,
+};
 pub use parser::GraduationPredictionParserImpl;
@@ -3,10 +3,13 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::datetime::normalize;
+
 /// 卒業見込判定における要件不足項目を表す構造体。
 ///
 /// 各フィールドは、要件の具体的な不足内容と、その不足量を明確に示します。
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct YokenFusokuItem {
     /// 要件のコード。
@@ -15,18 +18,136 @@ pub struct YokenFusokuItem {
     pub yoso_number: i32,
     /// 要件不足の具体的なメッセージ。
     pub fusoku_message: String,
-    /// 要件の不足量を示す文字列。
-    pub fusoku_ryo: String,
+    /// 要件の不足量。
+    /// 「4単位」「0.5単位」「2科目」のような表記を解釈できない場合は`None`になります。
+    pub fusoku_ryo: Option<FusokuRyo>,
+}
+
+/// 要件の不足量を表す構造体。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct FusokuRyo {
+    /// 不足している量（0.5単位のような端数を含む）。
+    pub amount: f64,
+    /// 量の単位。
+    pub unit: FusokuUnit,
+}
+
+/// 不足量の単位。
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum FusokuUnit {
+    /// 単位数（単位の表記がない数値もこちらとみなします）
+    Credits,
+    /// 科目数
+    Subjects,
+}
+
+impl FusokuRyo {
+    /// 「4単位」「1.5単位」「2科目」「4」のような不足量の表記を解釈する
+    ///
+    /// 全角の数字・小数点も受け付けます。
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = normalize(text);
+        let text = text.trim();
+        let digits = text
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(text.len());
+        let amount = parse_amount(&text[..digits])?;
+        let unit = match text[digits..].trim() {
+            "" | "単位" => FusokuUnit::Credits,
+            "科目" => FusokuUnit::Subjects,
+            _ => return None,
+        };
+        Some(Self { amount, unit })
+    }
+}
+
+/// 単位数・不足量の数値を解釈する（負の値や数値でない表記は`None`）
+pub(crate) fn parse_amount(text: &str) -> Option<f64> {
+    text.parse::<f64>()
+        .ok()
+        .filter(|amount| amount.is_finite() && *amount >= 0.0)
+}
+
+/// 卒業見込判定の判定結果。
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum HanteiStatus {
+    /// 卒業見込あり
+    MikomiAri,
+    /// 卒業見込なし
+    MikomiNashi,
+    /// 判定対象外
+    TaishoGai,
+}
+
+impl HanteiStatus {
+    /// 判定結果欄・判定メッセージの文言から判定結果を読み取る
+    ///
+    /// 「見込み」の送り仮名の有無は区別しません。判定結果を含まない文言の場合は`None`を返します。
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.replace("見込み", "見込");
+        if text.contains("対象外") {
+            Some(Self::TaishoGai)
+        } else if ["見込なし", "見込無", "見込がありません", "見込はありません"]
+            .iter()
+            .any(|pattern| text.contains(pattern))
+        {
+            Some(Self::MikomiNashi)
+        } else if ["見込あり", "見込有", "見込です"]
+            .iter()
+            .any(|pattern| text.contains(pattern))
+        {
+            Some(Self::MikomiAri)
+        } else {
+            None
+        }
+    }
+}
+
+/// 卒業要件の階層の1つの要件を表す構造体。
+///
+/// 要件一覧の字下げ（またはクラス名の`level2`などの番号）から親子関係を組み立てます。
+#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct YokenNode {
+    /// 要件名（科目区分名）。
+    pub yoken_name: String,
+    /// 必要単位数。
+    /// 単位数の定めがない要件（科目の指定のみなど）は`None`になります。
+    pub required_credits: Option<f64>,
+    /// 修得済み単位数。
+    /// 修得単位数の列がない場合や、値を解釈できない場合は`None`になります。
+    pub completed_credits: Option<f64>,
+    /// 履修中単位数。
+    /// 履修中単位数の列がない場合や、値を解釈できない場合は`None`になります。
+    pub currently_enrolled_credits: Option<f64>,
+    /// 要件を満たしているかどうか。
+    /// 判定欄の「○」「×」を優先し、判定欄がない場合は修得済み単位数と必要単位数から求めます。
+    pub is_satisfied: Option<bool>,
+    /// 下位の要件。
+    pub children: Vec<YokenNode>,
 }
 
 /// 卒業見込判定の結果全体を表す構造体。
 ///
-/// 判定メッセージと、複数の要件不足項目リストを含みます。
+/// 判定結果とメッセージ、卒業要件の階層、複数の要件不足項目リストを含みます。
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct SotsugyoMikonHanteiKekka {
+    /// 判定結果。
+    /// 判定結果欄・判定メッセージから読み取れない場合は`None`になります。
+    pub hantei_status: Option<HanteiStatus>,
     /// 卒業見込判定のメッセージ。
     pub hantei_message: String,
+    /// 卒業要件の階層（最上位の要件のリスト）。
+    pub yoken_tree: Vec<YokenNode>,
     /// 要件不足項目のリスト。
     /// 不足がない場合は空のベクターとなります。
     pub fusoku_items: Vec<YokenFusokuItem>,
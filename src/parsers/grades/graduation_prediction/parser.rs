@@ -1,10 +1,34 @@
 //! 卒業見込判定パーサー実装
+//!
+//! 卒業見込判定画面は、判定結果の表、卒業要件の一覧（要件名・必要単位数・修得単位数・
+//! 履修中単位数・判定）、要件不足項目の一覧（条件コード・要素番号・不足内容・不足量）で
+//! 構成されます。判定結果は「判定結果」の見出しに続くセルから読み取ります。
+//! 2つの一覧はどちらも`table.listTable`のため見出し行の名前から判別し、列の並びも見出しから判定します。
 
-use scraper::{Html, Selector};
+use std::sync::OnceLock;
 
+use regex_lite::Regex;
+use scraper::{ElementRef, Html};
+
+use crate::common::context::ParseContext;
+use crate::common::datetime::normalize;
+use crate::common::selectors::selector_registry;
 use crate::utils::error::ParseError;
 
-use super::model::{SotsugyoMikonHanteiKekka, YokenFusokuItem};
+use super::model::{
+    parse_amount, FusokuRyo, HanteiStatus, SotsugyoMikonHanteiKekka, YokenFusokuItem, YokenNode,
+};
+
+selector_registry! {
+    /// 卒業見込判定画面で使用するセレクター
+    struct GraduationPredictionSelectors {
+        list_table: "table.listTable",
+        body_row: "tbody tr",
+        row: "tr",
+        header: "th",
+        cell: "td",
+    }
+}
 
 /// 卒業見込判定パーサー実装
 pub struct GraduationPredictionParserImpl {}
@@ -17,72 +41,375 @@ impl GraduationPredictionParserImpl {
 
     /// HTMLドキュメントから卒業見込判定を解析する
     pub fn parse_document(&self, document: &Html) -> Result<SotsugyoMikonHanteiKekka, ParseError> {
-        let hantei_message = self.parse_judgement_message(document)?;
-        let fusoku_items = self.parse_fusoku_items(document)?;
+        self.parse_with_context(document, &mut ParseContext::new())
+    }
+
+    /// 警告をコンテキストに記録しながら卒業見込判定を解析する
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<SotsugyoMikonHanteiKekka, ParseError> {
+        let (hantei_status, hantei_message) = self.parse_judgement(document, ctx)?;
+        // 判定対象外の場合は要件の一覧が表示されない
+        let expects_tables = hantei_status != Some(HanteiStatus::TaishoGai);
+        let yoken_tree = self.parse_yoken_tree(document, expects_tables, ctx)?;
+        // 見込ありの場合は不足項目の一覧が表示されない
+        let expects_fusoku = expects_tables && hantei_status != Some(HanteiStatus::MikomiAri);
+        let fusoku_items = self.parse_fusoku_items(document, expects_fusoku, ctx)?;
 
         Ok(SotsugyoMikonHanteiKekka {
+            hantei_status,
             hantei_message,
+            yoken_tree,
             fusoku_items,
         })
     }
 
-    /// 判定メッセージを解析する
-    fn parse_judgement_message(&self, document: &Html) -> Result<String, ParseError> {
-        // 判定結果メッセージエリアを探す
-        let message_selector = Selector::parse(".result-message, .judgement-message, .message")
-            .map_err(|e| {
-                ParseError::selector_creation_failed(
-                    ".result-message, .judgement-message, .message",
-                    &e.to_string(),
-                )
-            })?;
-
-        if let Some(message_element) = document.select(&message_selector).next() {
-            return Ok(message_element.inner_html().trim().to_string());
+    /// 判定結果と判定メッセージを解析する
+    ///
+    /// 判定結果は「判定結果」の見出しに続くセルの文言から読み取ります。
+    fn parse_judgement(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<(Option<HanteiStatus>, String), ParseError> {
+        let selectors = GraduationPredictionSelectors::get()?;
+
+        // 「判定結果」の見出しのセルを探す
+        ctx.trace_selector("hantei_message", &selectors.header, document.root_element());
+        let result_cell = document
+            .select(&selectors.header)
+            .filter(|header| element_text(*header).contains("判定結果"))
+            .find_map(|header| header.next_siblings().find_map(ElementRef::wrap))
+            .map(element_text);
+
+        let status = result_cell.as_deref().and_then(HanteiStatus::parse);
+        let Some(hantei_message) = result_cell else {
+            // 見つからない場合はプレースホルダ
+            let placeholder = "判定結果を取得できませんでした。";
+            ctx.fallback_used("hantei_message", placeholder)?;
+            return Ok((None, placeholder.to_string()));
+        };
+        if status.is_none() {
+            ctx.unparseable_cell("hantei_status", 0, 0, &hantei_message)?;
         }
 
-        // 見つからない場合はプレースホルダ
-        Ok("判定結果を取得できませんでした。".to_string())
+        Ok((status, hantei_message))
+    }
+
+    /// 卒業要件の一覧を要件の階層として解析する
+    fn parse_yoken_tree(
+        &self,
+        document: &Html,
+        expected: bool,
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<YokenNode>, ParseError> {
+        let selectors = GraduationPredictionSelectors::get()?;
+
+        ctx.trace_selector("yoken_tree", &selectors.list_table, document.root_element());
+        let Some((table, labels)) = find_table(document, |labels| {
+            has_label(labels, "必要") && has_label(labels, "単位")
+        })?
+        else {
+            if expected {
+                ctx.missing_section("yoken_tree", selectors.list_table.source())?;
+            }
+            return Ok(Vec::new());
+        };
+
+        let columns = YokenColumns::from_labels(&labels);
+        if columns.completed.is_none() {
+            ctx.missing_section("yoken_tree.completed_credits", selectors.header.source())?;
+        }
+        let mut nodes = Vec::new();
+        for (row_index, row) in table.select(&selectors.body_row).enumerate() {
+            let cells: Vec<_> = row.select(&selectors.cell).collect();
+            if cells.is_empty() {
+                // 見出し行
+                continue;
+            }
+            if cells.len() < columns.len {
+                ctx.skip_short_row("yoken_tree", row_index, cells.len(), columns.len);
+                continue;
+            }
+
+            let mut credits = |column: Option<usize>| -> Result<Option<f64>, ParseError> {
+                let Some(column) = column else {
+                    return Ok(None);
+                };
+                let text = normalize(&element_text(cells[column]));
+                let text = text.trim_end_matches("単位").trim();
+                if text.is_empty() || text == "-" {
+                    return Ok(None);
+                }
+                let credits = parse_amount(text);
+                if credits.is_none() {
+                    ctx.unparseable_cell("yoken_tree", row_index, column, text)?;
+                }
+                Ok(credits)
+            };
+            let required_credits = credits(columns.required)?;
+            let completed_credits = credits(columns.completed)?;
+            let currently_enrolled_credits = credits(columns.enrolled)?;
+
+            let is_satisfied = columns
+                .judgement
+                .and_then(|column| satisfaction(&element_text(cells[column])))
+                .or(required_credits
+                    .zip(completed_credits)
+                    .map(|(required, completed)| completed >= required));
+
+            let name_cell = cells[columns.name];
+            nodes.push((
+                yoken_level(row, name_cell),
+                YokenNode {
+                    yoken_name: element_text(name_cell),
+                    required_credits,
+                    completed_credits,
+                    currently_enrolled_credits,
+                    is_satisfied,
+                    children: Vec::new(),
+                },
+            ));
+        }
+
+        Ok(build_tree(nodes))
     }
 
     /// 要件不足項目リストを解析する
-    fn parse_fusoku_items(&self, document: &Html) -> Result<Vec<YokenFusokuItem>, ParseError> {
+    fn parse_fusoku_items(
+        &self,
+        document: &Html,
+        expected: bool,
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<YokenFusokuItem>, ParseError> {
+        let selectors = GraduationPredictionSelectors::get()?;
         let mut fusoku_items = Vec::new();
 
-        // 不足項目テーブルを探す
-        let table_selector = Selector::parse("table.fusoku, .requirements-table").map_err(|e| {
-            ParseError::selector_creation_failed(
-                "table.fusoku, .requirements-table",
-                &e.to_string(),
-            )
-        })?;
-
-        if let Some(table) = document.select(&table_selector).next() {
-            let row_selector = Selector::parse("tbody tr")
-                .map_err(|e| ParseError::selector_creation_failed("tbody tr", &e.to_string()))?;
-
-            let cell_selector = Selector::parse("td")
-                .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
-            for row in table.select(&row_selector) {
-                let cells: Vec<_> = row.select(&cell_selector).collect();
-
-                if cells.len() >= 4 {
-                    let joken_code = cells[0].inner_html().trim().to_string();
-                    let yoso_number = cells[1].inner_html().trim().parse::<i32>().unwrap_or(0);
-                    let fusoku_message = cells[2].inner_html().trim().to_string();
-                    let fusoku_ryo = cells[3].inner_html().trim().to_string();
-
-                    fusoku_items.push(YokenFusokuItem {
-                        joken_code,
-                        yoso_number,
-                        fusoku_message,
-                        fusoku_ryo,
-                    });
+        // 「不足」の見出しを持つ一覧を探す
+        ctx.trace_selector(
+            "fusoku_items",
+            &selectors.list_table,
+            document.root_element(),
+        );
+        let Some((table, labels)) = find_table(document, |labels| {
+            has_label(labels, "不足") && !has_label(labels, "修得")
+        })?
+        else {
+            if expected {
+                ctx.missing_section("fusoku_items", selectors.list_table.source())?;
+            }
+            return Ok(fusoku_items);
+        };
+
+        let columns = FusokuColumns::from_labels(&labels);
+        for (row_index, row) in table.select(&selectors.body_row).enumerate() {
+            let cells: Vec<_> = row.select(&selectors.cell).collect();
+
+            if cells.is_empty() && row.select(&selectors.header).next().is_some() {
+                // 見出し行
+                continue;
+            }
+            if cells.len() >= columns.len {
+                let joken_code = element_text(cells[columns.code]);
+                let yoso_text = element_text(cells[columns.number]);
+                let yoso_number = match yoso_text.parse::<i32>() {
+                    Ok(number) => number,
+                    Err(_) => {
+                        ctx.unparseable_cell(
+                            "fusoku_items",
+                            row_index,
+                            columns.number,
+                            &yoso_text,
+                        )?;
+                        0
+                    }
+                };
+                let fusoku_message = element_text(cells[columns.message]);
+                let ryo_text = element_text(cells[columns.amount]);
+                let fusoku_ryo = FusokuRyo::parse(&ryo_text);
+                if fusoku_ryo.is_none() {
+                    ctx.unparseable_cell("fusoku_items", row_index, columns.amount, &ryo_text)?;
                 }
+
+                fusoku_items.push(YokenFusokuItem {
+                    joken_code,
+                    yoso_number,
+                    fusoku_message,
+                    fusoku_ryo,
+                });
+            } else {
+                ctx.skip_short_row("fusoku_items", row_index, cells.len(), columns.len);
             }
         }
 
         Ok(fusoku_items)
     }
 }
+
+impl Default for GraduationPredictionParserImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 卒業要件の一覧の各列の位置
+struct YokenColumns {
+    name: usize,
+    required: Option<usize>,
+    completed: Option<usize>,
+    enrolled: Option<usize>,
+    judgement: Option<usize>,
+    /// 要件の行に必要なセル数
+    len: usize,
+}
+
+impl YokenColumns {
+    /// 見出し行の名前から列の位置を判定する
+    fn from_labels(labels: &[String]) -> Self {
+        let find = |matches: &dyn Fn(&str) -> bool| labels.iter().position(|l| matches(l));
+        Self {
+            name: find(&|l| (l.contains("要件") && !l.contains("単位")) || l.contains("区分"))
+                .unwrap_or(0),
+            required: find(&|l| l.contains("必要")),
+            completed: find(&|l| l.contains("修得")),
+            enrolled: find(&|l| l.contains("履修中")),
+            judgement: find(&|l| l.contains("判定") || l.contains("充足")),
+            len: labels.len(),
+        }
+    }
+}
+
+/// 要件不足項目の一覧の各列の位置
+struct FusokuColumns {
+    code: usize,
+    number: usize,
+    message: usize,
+    amount: usize,
+    /// 不足項目の行に必要なセル数
+    len: usize,
+}
+
+impl FusokuColumns {
+    /// 見出し行の名前から列の位置を判定する
+    ///
+    /// 見出し行がない場合は条件コード・要素番号・不足内容・不足量の順とみなします。
+    fn from_labels(labels: &[String]) -> Self {
+        let find = |matches: &dyn Fn(&str) -> bool| labels.iter().position(|l| matches(l));
+        Self {
+            code: find(&|l| l.contains("コード") || l.contains("条件")).unwrap_or(0),
+            number: find(&|l| l.contains("要素") || l.contains("番号")).unwrap_or(1),
+            message: find(&|l| l.contains("内容") || l.contains("メッセージ")).unwrap_or(2),
+            amount: find(&|l| {
+                l.contains("不足量") || l.contains("不足数") || l.contains("不足単位")
+            })
+            .unwrap_or(3),
+            len: labels.len().max(4),
+        }
+    }
+}
+
+/// 見出し行が`matches`を満たす一覧を探す
+fn find_table<'a>(
+    document: &'a Html,
+    matches: impl Fn(&[String]) -> bool,
+) -> Result<Option<(ElementRef<'a>, Vec<String>)>, ParseError> {
+    let selectors = GraduationPredictionSelectors::get()?;
+    for table in document.select(&selectors.list_table) {
+        let labels = header_labels(table)?;
+        if matches(&labels) {
+            return Ok(Some((table, labels)));
+        }
+    }
+    Ok(None)
+}
+
+/// 表の最初の見出し行の名前（見出し行がない場合は空）
+fn header_labels(table: ElementRef) -> Result<Vec<String>, ParseError> {
+    let selectors = GraduationPredictionSelectors::get()?;
+    Ok(table
+        .select(&selectors.row)
+        .map(|row| {
+            row.select(&selectors.header)
+                .map(element_text)
+                .collect::<Vec<_>>()
+        })
+        .find(|labels| !labels.is_empty())
+        .unwrap_or_default())
+}
+
+fn has_label(labels: &[String], text: &str) -> bool {
+    labels.iter().any(|label| label.contains(text))
+}
+
+/// 判定欄の「○」「×」などを要件の充足状況にする
+fn satisfaction(text: &str) -> Option<bool> {
+    match text {
+        "○" | "◯" | "OK" | "充足" | "済" => Some(true),
+        "×" | "✕" | "NG" | "不足" | "未" => Some(false),
+        _ => None,
+    }
+}
+
+/// 要件の階層レベル
+///
+/// 行または要件名のセルのクラス名の`level2`などの番号、なければ要件名の先頭の全角空白の数から求めます。
+fn yoken_level(row: ElementRef, name_cell: ElementRef) -> u32 {
+    [row, name_cell]
+        .iter()
+        .find_map(|element| {
+            let class = element.value().attr("class")?;
+            let captures = level_regex().captures(class)?;
+            captures[1].parse::<u32>().ok()
+        })
+        .unwrap_or_else(|| {
+            let raw: String = name_cell.text().collect();
+            let indent = raw
+                .trim_start_matches(|c: char| c.is_ascii_whitespace())
+                .chars()
+                .take_while(|c| *c == '\u{3000}' || *c == '\u{a0}')
+                .count();
+            indent as u32 + 1
+        })
+}
+
+fn level_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"(?i)level-?(\d+)").expect("level pattern is valid"))
+}
+
+/// 階層レベル付きの要件を親子関係に組み立てる（自分より浅いレベルの直前の要件を親とする）
+fn build_tree(nodes: Vec<(u32, YokenNode)>) -> Vec<YokenNode> {
+    let mut roots = Vec::new();
+    let mut open: Vec<(u32, YokenNode)> = Vec::new();
+
+    let close = |open: &mut Vec<(u32, YokenNode)>, roots: &mut Vec<YokenNode>| {
+        if let Some((_, node)) = open.pop() {
+            match open.last_mut() {
+                Some((_, parent)) => parent.children.push(node),
+                None => roots.push(node),
+            }
+        }
+    };
+
+    for (level, node) in nodes {
+        while open
+            .last()
+            .is_some_and(|(open_level, _)| *open_level >= level)
+        {
+            close(&mut open, &mut roots);
+        }
+        open.push((level, node));
+    }
+    while !open.is_empty() {
+        close(&mut open, &mut roots);
+    }
+
+    roots
+}
+
+/// 要素の文字列（タグを除いて前後の空白を取り除いたもの）
+fn element_text(element: ElementRef) -> String {
+    element.text().collect::<String>().trim().to_string()
+}
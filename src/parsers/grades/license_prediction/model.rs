@@ -8,6 +8,7 @@ use tsify::Tsify;
 /// 注意: この構造体は現在プレースホルダ実装です。
 /// 実際の要件に応じて構造を変更する必要があります。
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct LicensePredictionData {
     /// 免許の種類
@@ -1,11 +1,24 @@
 //! 進級見込判定パーサー実装
 
-use scraper::{Html, Selector};
+use scraper::Html;
 
+use crate::common::context::ParseContext;
+use crate::common::selectors::{selector_registry, CompiledSelector};
+use crate::common::term::AcademicTerm;
 use crate::utils::error::ParseError;
 
 use super::model::PromotionPredictionData;
 
+selector_registry! {
+    /// 進級見込判定画面で使用するセレクター
+    struct PromotionPredictionSelectors {
+        judgement_message: ".result-message, .judgement-message, .message",
+        last_search_student_id: "input[name='lastSearchStudentId']",
+        academic_year: "input[name='academicYear']",
+        semester: "input[name='semester']",
+    }
+}
+
 /// 進級見込判定パーサー実装
 pub struct PromotionPredictionParserImpl {}
 
@@ -17,55 +30,104 @@ impl PromotionPredictionParserImpl {
 
     /// HTMLドキュメントから進級見込判定を解析する
     pub fn parse_document(&self, document: &Html) -> Result<PromotionPredictionData, ParseError> {
-        let judgement_message = self.parse_judgement_message(document)?;
-        let last_search_student_id = self.parse_hidden_field(document, "lastSearchStudentId")?;
-        let academic_year = self.parse_hidden_field(document, "academicYear")?;
-        let semester = self.parse_hidden_field(document, "semester")?;
+        self.parse_with_context(document, &mut ParseContext::new())
+    }
+
+    /// 警告をコンテキストに記録しながら進級見込判定を解析する
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<PromotionPredictionData, ParseError> {
+        let selectors = PromotionPredictionSelectors::get()?;
+        let judgement_message = self.parse_judgement_message(document, ctx)?;
+        let last_search_student_id = self.parse_hidden_field(
+            document,
+            "lastSearchStudentId",
+            &selectors.last_search_student_id,
+            ctx,
+        )?;
+        let academic_year =
+            self.parse_hidden_field(document, "academicYear", &selectors.academic_year, ctx)?;
+        let semester = self.parse_hidden_field(document, "semester", &selectors.semester, ctx)?;
+        let term = self.parse_term(&academic_year, &semester, ctx)?;
 
         Ok(PromotionPredictionData {
             judgement_message,
             last_search_student_id,
-            academic_year,
-            semester,
+            term,
         })
     }
 
+    /// 隠しフィールドの年度と学期から年度・学期を組み立てる
+    fn parse_term(
+        &self,
+        academic_year: &str,
+        semester: &str,
+        ctx: &mut ParseContext,
+    ) -> Result<Option<AcademicTerm>, ParseError> {
+        // 見つからなかったフィールドは既に記録済み
+        if academic_year.is_empty() || semester.is_empty() {
+            return Ok(None);
+        }
+        let term = academic_year
+            .trim()
+            .parse()
+            .ok()
+            .and_then(|year| AcademicTerm::parse_in_year(semester, year).ok());
+        if term.is_none() {
+            ctx.unparseable_cell("term", 0, 0, &format!("{academic_year} {semester}"))?;
+        }
+        Ok(term)
+    }
+
     /// 判定メッセージを解析する
-    fn parse_judgement_message(&self, document: &Html) -> Result<String, ParseError> {
+    fn parse_judgement_message(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<String, ParseError> {
+        let selectors = PromotionPredictionSelectors::get()?;
+
         // 判定結果メッセージエリアを探す
-        let message_selector = Selector::parse(".result-message, .judgement-message, .message")
-            .map_err(|e| {
-                ParseError::selector_creation_failed(
-                    ".result-message, .judgement-message, .message",
-                    &e.to_string(),
-                )
-            })?;
-
-        if let Some(message_element) = document.select(&message_selector).next() {
+        ctx.trace_selector(
+            "judgement_message",
+            &selectors.judgement_message,
+            document.root_element(),
+        );
+        if let Some(message_element) = document.select(&selectors.judgement_message).next() {
             return Ok(message_element.inner_html().trim().to_string());
         }
 
         // 見つからない場合はプレースホルダ
-        Ok("判定結果を取得できませんでした。".to_string())
+        let placeholder = "判定結果を取得できませんでした。";
+        ctx.fallback_used("judgement_message", placeholder)?;
+        Ok(placeholder.to_string())
     }
 
     /// 隠しフィールドの値を解析する
-    fn parse_hidden_field(&self, document: &Html, field_name: &str) -> Result<String, ParseError> {
-        let input_selector =
-            Selector::parse(&format!("input[name='{}']", field_name)).map_err(|e| {
-                ParseError::selector_creation_failed(
-                    &format!("input[name='{}']", field_name),
-                    &e.to_string(),
-                )
-            })?;
-
-        if let Some(input_element) = document.select(&input_selector).next() {
+    fn parse_hidden_field(
+        &self,
+        document: &Html,
+        field_name: &str,
+        input_selector: &CompiledSelector,
+        ctx: &mut ParseContext,
+    ) -> Result<String, ParseError> {
+        ctx.trace_selector(field_name, input_selector, document.root_element());
+        if let Some(input_element) = document.select(input_selector).next() {
             if let Some(value) = input_element.value().attr("value") {
                 return Ok(value.to_string());
             }
         }
 
         // 見つからない場合は空文字列
+        ctx.missing_section(field_name, input_selector.source())?;
         Ok(String::new())
     }
 }
+
+impl Default for PromotionPredictionParserImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
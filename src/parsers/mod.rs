@@ -5,36 +5,57 @@
 //! 対応しており、構造化されたデータとして情報を返します。
 
 /// 課題提出関連のページパーサー
+#[cfg(feature = "assignments")]
 pub mod assignment_submit;
 
 /// クラスプロファイル（授業詳細）関連のページパーサー
+#[cfg(feature = "class-profile")]
 pub mod class_profile;
 
 /// 教室予約状況関連のページパーサー
+#[cfg(feature = "classroom")]
 pub mod classroom_reservation_status;
 
 /// 授業評価関連のページパーサー
+#[cfg(feature = "course-evaluation")]
 pub mod course_evaluation;
 
+/// バージョン情報付きの解析結果と保存済みデータの移行
+pub mod envelope;
+
 /// 成績・単位関連のページパーサー
+#[cfg(feature = "grades")]
 pub mod grades;
 
+/// ページ種別の判定と各パーサーへの振り分け
+pub mod page;
+
 /// ポータル（メイン画面）関連のページパーサー
+#[cfg(feature = "portal")]
 pub mod portal;
 
 /// アンケート関連のページパーサー
+#[cfg(feature = "questionnaire")]
 pub mod questionnaire;
 
 /// 学籍情報照会関連のページパーサー
+#[cfg(feature = "student-info")]
 pub mod student_info_inquiry;
 
+/// 各ページ種別の解析結果のJSON Schema
+#[cfg(feature = "schema")]
+pub mod schema;
+
 /// シラバス関連のページパーサー
+#[cfg(feature = "syllabus")]
 pub mod syllabus;
 
 /// テスト解答状況関連のページパーサー
+#[cfg(feature = "tests")]
 pub mod test_answer_status;
 
 /// 時間割関連のページパーサー（学生・教員・学科開講）
+#[cfg(feature = "timetable")]
 pub mod timetable;
 
 #[cfg(target_arch = "wasm32")]
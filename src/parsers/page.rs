@@ -0,0 +1,535 @@
+//! ページ種別の判定とパーサーの振り分け
+//!
+//! 保存済みのHTMLがどの画面のものかを判定し、対応するパーサーで解析します。
+//! CLIやバッチ処理など、ページ種別を事前に知らない呼び出し元から利用します。
+
+use std::fmt;
+use std::str::FromStr;
+
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::selectors::selector_registry;
+use crate::common::traits::PageParser;
+use crate::utils::error::ParseError;
+
+#[cfg(feature = "assignments")]
+use super::assignment_submit::builder::AssignmentListParserBuilder;
+#[cfg(feature = "assignments")]
+use super::assignment_submit::model::AssignmentList;
+#[cfg(feature = "classroom")]
+use super::classroom_reservation_status::builder::ClassroomReservationStatusParserBuilder;
+#[cfg(feature = "classroom")]
+use super::classroom_reservation_status::model::ClassroomReservationStatus;
+#[cfg(feature = "grades")]
+use super::grades::grade_inquiry::{GradeInquiry, GradeInquiryParserBuilder};
+#[cfg(feature = "grades")]
+use super::grades::graduation_prediction::{
+    GraduationPredictionParserBuilder, SotsugyoMikonHanteiKekka,
+};
+#[cfg(feature = "grades")]
+use super::grades::license_prediction::{LicensePredictionData, LicensePredictionParserBuilder};
+#[cfg(feature = "grades")]
+use super::grades::promotion_prediction::{
+    PromotionPredictionData, PromotionPredictionParserBuilder,
+};
+#[cfg(feature = "portal")]
+use super::portal::builder::PortalParserBuilder;
+#[cfg(feature = "portal")]
+use super::portal::model::Portal;
+#[cfg(feature = "portal")]
+use super::portal::notification_detail::builder::NotificationDetailParserBuilder;
+#[cfg(feature = "portal")]
+use super::portal::notification_detail::model::NotificationDetail;
+#[cfg(feature = "questionnaire")]
+use super::questionnaire::questionnaire_list::{QuestionnaireList, QuestionnaireListParserBuilder};
+#[cfg(feature = "student-info")]
+use super::student_info_inquiry::builder::StudentInfoInquiryParserBuilder;
+#[cfg(feature = "student-info")]
+use super::student_info_inquiry::model::StudentInfo;
+#[cfg(feature = "syllabus")]
+use super::syllabus::search_result::{SyllabusSearchResultPage, SyllabusSearchResultParser};
+#[cfg(feature = "syllabus")]
+use super::syllabus::view::{LessonInfo, SyllabusViewParser};
+#[cfg(feature = "tests")]
+use super::test_answer_status::model::TestAnswerStatus;
+#[cfg(feature = "tests")]
+use super::test_answer_status::parser::TestAnswerStatusParserImpl;
+
+selector_registry! {
+    /// ページ種別の判定に使用するセレクター
+    struct PageDetectionSelectors {
+        portal: "#form1\\:Poa00201A\\:htmlParentTable, #form1\\:Poa00101A\\:htmlCalendarTable",
+        test_answer_status: "#form1\\:htmlNendo, [id^='form1:Stb00101']",
+        classroom_reservation_status:
+            "[id*='Ksc00101A'], form[action*='Ksc00101A'], table.reservationTable",
+        syllabus_search_result: "#form1\\:htmlKekkatable",
+        student_info: "#baseTable",
+        promotion_prediction: "input[name='lastSearchStudentId']",
+        grade_inquiry: ".gpaScore, .gpa, #gpaValue, table.creditSummary",
+        notification_detail: ".popup-title, .notification-title",
+        list_table: "table.listTable",
+        header: "th",
+    }
+}
+
+/// 解析対象のページ種別
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "kebab-case")]
+pub enum PageKind {
+    /// ポータル
+    Portal,
+    /// ポータル（お知らせ全表示）
+    PortalAllNotifications,
+    /// ポータル（授業連絡表示）
+    PortalClassContact,
+    /// ポータル（授業連絡全表示）
+    PortalAllClassContact,
+    /// お知らせ詳細
+    NotificationDetail,
+    /// 成績照会
+    GradeInquiry,
+    /// 卒業見込判定
+    GraduationPrediction,
+    /// 進級見込判定
+    PromotionPrediction,
+    /// 免許取得見込判定
+    LicensePrediction,
+    /// シラバス検索結果
+    SyllabusSearchResult,
+    /// シラバス詳細
+    SyllabusView,
+    /// 課題提出一覧
+    AssignmentList,
+    /// 教室予約状況
+    ClassroomReservationStatus,
+    /// アンケート一覧
+    QuestionnaireList,
+    /// 学籍情報照会
+    StudentInfo,
+    /// テスト解答状況
+    TestAnswerStatus,
+}
+
+impl PageKind {
+    /// すべてのページ種別
+    pub const ALL: [PageKind; 16] = [
+        PageKind::Portal,
+        PageKind::PortalAllNotifications,
+        PageKind::PortalClassContact,
+        PageKind::PortalAllClassContact,
+        PageKind::NotificationDetail,
+        PageKind::GradeInquiry,
+        PageKind::GraduationPrediction,
+        PageKind::PromotionPrediction,
+        PageKind::LicensePrediction,
+        PageKind::SyllabusSearchResult,
+        PageKind::SyllabusView,
+        PageKind::AssignmentList,
+        PageKind::ClassroomReservationStatus,
+        PageKind::QuestionnaireList,
+        PageKind::StudentInfo,
+        PageKind::TestAnswerStatus,
+    ];
+
+    /// CLIやシリアライズで使用する識別子（kebab-case）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PageKind::Portal => "portal",
+            PageKind::PortalAllNotifications => "portal-all-notifications",
+            PageKind::PortalClassContact => "portal-class-contact",
+            PageKind::PortalAllClassContact => "portal-all-class-contact",
+            PageKind::NotificationDetail => "notification-detail",
+            PageKind::GradeInquiry => "grade-inquiry",
+            PageKind::GraduationPrediction => "graduation-prediction",
+            PageKind::PromotionPrediction => "promotion-prediction",
+            PageKind::LicensePrediction => "license-prediction",
+            PageKind::SyllabusSearchResult => "syllabus-search-result",
+            PageKind::SyllabusView => "syllabus-view",
+            PageKind::AssignmentList => "assignment-list",
+            PageKind::ClassroomReservationStatus => "classroom-reservation-status",
+            PageKind::QuestionnaireList => "questionnaire-list",
+            PageKind::StudentInfo => "student-info",
+            PageKind::TestAnswerStatus => "test-answer-status",
+        }
+    }
+
+    /// このページ種別の解析に必要なcargoフィーチャー
+    pub fn feature(&self) -> &'static str {
+        match self {
+            PageKind::Portal
+            | PageKind::PortalAllNotifications
+            | PageKind::PortalClassContact
+            | PageKind::PortalAllClassContact
+            | PageKind::NotificationDetail => "portal",
+            PageKind::GradeInquiry
+            | PageKind::GraduationPrediction
+            | PageKind::PromotionPrediction
+            | PageKind::LicensePrediction => "grades",
+            PageKind::SyllabusSearchResult | PageKind::SyllabusView => "syllabus",
+            PageKind::AssignmentList => "assignments",
+            PageKind::ClassroomReservationStatus => "classroom",
+            PageKind::QuestionnaireList => "questionnaire",
+            PageKind::StudentInfo => "student-info",
+            PageKind::TestAnswerStatus => "tests",
+        }
+    }
+
+    /// 画面ID（例: `Stb00101A`）からページ種別を推定する
+    ///
+    /// ポータルの各ポートレット（`Poa00101A`〜`Poa00401A`）はポータル画面として扱います。
+    /// 対応するパーサーが存在しない画面IDの場合は`None`を返します。
+    pub fn from_screen_id(screen_id: &str) -> Option<PageKind> {
+        match screen_id {
+            "Poa00101A" | "Poa00201A" | "Poa00301A" | "Poa00401A" => Some(PageKind::Portal),
+            "Ksc00101A" => Some(PageKind::ClassroomReservationStatus),
+            id if id.starts_with("Stb00101") => Some(PageKind::TestAnswerStatus),
+            _ => None,
+        }
+    }
+
+    /// HTMLドキュメントの構造からページ種別を推定する
+    ///
+    /// 画面固有のIDを持つ要素を優先して判定し、汎用的な一覧テーブルは
+    /// ヘッダーの文言から判別します。判定できない場合は`None`を返します。
+    ///
+    /// ポータルの表示モード（全表示・授業連絡）はHTMLから区別できないため、
+    /// 常に[`PageKind::Portal`]として判定されます。
+    pub fn detect(document: &Html) -> Option<PageKind> {
+        let selectors = PageDetectionSelectors::get().ok()?;
+        let markers = [
+            (&selectors.portal, PageKind::Portal),
+            (&selectors.test_answer_status, PageKind::TestAnswerStatus),
+            (
+                &selectors.classroom_reservation_status,
+                PageKind::ClassroomReservationStatus,
+            ),
+            (
+                &selectors.syllabus_search_result,
+                PageKind::SyllabusSearchResult,
+            ),
+            (&selectors.student_info, PageKind::StudentInfo),
+            (
+                &selectors.promotion_prediction,
+                PageKind::PromotionPrediction,
+            ),
+            (&selectors.grade_inquiry, PageKind::GradeInquiry),
+        ];
+
+        for (selector, kind) in markers {
+            if document.select(selector).next().is_some() {
+                return Some(kind);
+            }
+        }
+
+        let headers: Vec<String> = document
+            .select(&selectors.header)
+            .map(|th| th.text().collect::<String>().trim().to_string())
+            .filter(|text| !text.is_empty())
+            .collect();
+        let has_header = |text: &str| headers.iter().any(|h| h.contains(text));
+
+        if has_header("授業コード") && has_header("年度学期") {
+            return Some(PageKind::SyllabusView);
+        }
+
+        // 卒業要件の一覧（必要単位数・修得単位数）または要件不足項目の一覧
+        if (has_header("必要") && has_header("修得")) || has_header("不足量") {
+            return Some(PageKind::GraduationPrediction);
+        }
+
+        if document.select(&selectors.list_table).next().is_some() {
+            if has_header("提出") {
+                return Some(PageKind::AssignmentList);
+            }
+            if has_header("アンケート") || has_header("回答") {
+                return Some(PageKind::QuestionnaireList);
+            }
+            if has_header("評価") || has_header("成績") || has_header("単位") {
+                return Some(PageKind::GradeInquiry);
+            }
+        }
+
+        if document
+            .select(&selectors.notification_detail)
+            .next()
+            .is_some()
+        {
+            return Some(PageKind::NotificationDetail);
+        }
+
+        None
+    }
+
+    /// 対応するパーサーでドキュメントを解析する
+    pub fn parse(&self, document: &Html) -> Result<PageData, ParseError> {
+        self.parse_with_warnings(document)
+            .map(|outcome| outcome.data)
+    }
+
+    /// 対応するパーサーでドキュメントを警告付きで解析する
+    pub fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<PageData>, ParseError> {
+        self.parse_with_options(document, ParseOptions::default())
+    }
+
+    /// 対応するパーサーでドキュメントを解析し、セレクターの試行記録を含めて返す
+    pub fn parse_with_diagnostics(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<PageData>, ParseError> {
+        self.parse_with_options(document, ParseOptions::new().with_debug_mode(true))
+    }
+
+    /// 解析オプションを指定して、対応するパーサーでドキュメントを解析する
+    ///
+    /// `debug_mode`が有効な場合はセレクターの試行記録を含め、`strict_mode`が有効な場合は
+    /// 既定値による補完や必要な要素の欠落をエラーとして返します。
+    ///
+    /// 対応するパーサーのフィーチャー（[`PageKind::feature`]）が無効な場合は
+    /// `NotImplemented`エラーを返します。
+    // ページ種別を持つパーサー群がすべて無効な場合、振り分け処理は使用されない
+    #[cfg_attr(
+        not(any(
+            feature = "portal",
+            feature = "grades",
+            feature = "syllabus",
+            feature = "assignments",
+            feature = "classroom",
+            feature = "questionnaire",
+            feature = "student-info",
+            feature = "tests"
+        )),
+        allow(unused_variables, unreachable_code, dead_code)
+    )]
+    pub fn parse_with_options(
+        &self,
+        document: &Html,
+        options: ParseOptions,
+    ) -> Result<ParseOutcome<PageData>, ParseError> {
+        fn run<T, P: PageParser<T>>(
+            parser: P,
+            (document, diagnostics): (&Html, bool),
+            wrap: impl FnOnce(T) -> PageData,
+        ) -> Result<ParseOutcome<PageData>, ParseError> {
+            let outcome = if diagnostics {
+                parser.parse_with_diagnostics(document)?
+            } else {
+                parser.parse_with_warnings(document)?
+            };
+            Ok(outcome.map(wrap))
+        }
+
+        let input = (document, options.debug_mode);
+        #[cfg(feature = "portal")]
+        let portal = || PortalParserBuilder::new().with_options(options.clone());
+
+        match self {
+            #[cfg(feature = "portal")]
+            PageKind::Portal => run(portal().build()?, input, PageData::Portal),
+            #[cfg(feature = "portal")]
+            PageKind::PortalAllNotifications => {
+                run(portal().build_all_notifications()?, input, PageData::Portal)
+            }
+            #[cfg(feature = "portal")]
+            PageKind::PortalClassContact => {
+                run(portal().build_class_contact()?, input, PageData::Portal)
+            }
+            #[cfg(feature = "portal")]
+            PageKind::PortalAllClassContact => {
+                run(portal().build_all_class_contact()?, input, PageData::Portal)
+            }
+            #[cfg(feature = "portal")]
+            PageKind::NotificationDetail => run(
+                NotificationDetailParserBuilder::new()
+                    .with_options(options)
+                    .build()?,
+                input,
+                PageData::NotificationDetail,
+            ),
+            #[cfg(feature = "grades")]
+            PageKind::GradeInquiry => run(
+                GradeInquiryParserBuilder::new().with_options(options),
+                input,
+                PageData::GradeInquiry,
+            ),
+            #[cfg(feature = "grades")]
+            PageKind::GraduationPrediction => run(
+                GraduationPredictionParserBuilder::new().with_options(options),
+                input,
+                PageData::GraduationPrediction,
+            ),
+            #[cfg(feature = "grades")]
+            PageKind::PromotionPrediction => run(
+                PromotionPredictionParserBuilder::new().with_options(options),
+                input,
+                PageData::PromotionPrediction,
+            ),
+            #[cfg(feature = "grades")]
+            PageKind::LicensePrediction => run(
+                LicensePredictionParserBuilder::new(),
+                input,
+                PageData::LicensePrediction,
+            ),
+            #[cfg(feature = "syllabus")]
+            PageKind::SyllabusSearchResult => run(
+                SyllabusSearchResultParser::new()?.with_options(options),
+                input,
+                PageData::SyllabusSearchResult,
+            ),
+            #[cfg(feature = "syllabus")]
+            PageKind::SyllabusView => run(
+                SyllabusViewParser::new()?.with_options(options),
+                input,
+                |data| PageData::SyllabusView(Box::new(data)),
+            ),
+            #[cfg(feature = "assignments")]
+            PageKind::AssignmentList => run(
+                AssignmentListParserBuilder::new().with_options(options),
+                input,
+                PageData::AssignmentList,
+            ),
+            #[cfg(feature = "classroom")]
+            PageKind::ClassroomReservationStatus => run(
+                ClassroomReservationStatusParserBuilder::new().with_options(options),
+                input,
+                |data| PageData::ClassroomReservationStatus(Box::new(data)),
+            ),
+            #[cfg(feature = "questionnaire")]
+            PageKind::QuestionnaireList => run(
+                QuestionnaireListParserBuilder::new().with_options(options),
+                input,
+                PageData::QuestionnaireList,
+            ),
+            #[cfg(feature = "student-info")]
+            PageKind::StudentInfo => run(
+                StudentInfoInquiryParserBuilder::new().with_options(options),
+                input,
+                PageData::StudentInfo,
+            ),
+            #[cfg(feature = "tests")]
+            PageKind::TestAnswerStatus => run(
+                TestAnswerStatusParserImpl::new_with_options(options)?,
+                input,
+                PageData::TestAnswerStatus,
+            ),
+            #[allow(unreachable_patterns)]
+            _ => Err(ParseError::NotImplemented {
+                operation: format!("{self} parser (enable the \"{}\" feature)", self.feature()),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for PageKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PageKind {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PageKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| ParseError::InvalidConfiguration {
+                config_name: "page kind".to_string(),
+                value: s.to_string(),
+            })
+    }
+}
+
+/// ページ種別ごとの解析結果
+///
+/// シリアライズ時はページ種別のタグを含めず、各モデルをそのまま出力します。
+/// サイズの大きいモデルは列挙型全体の肥大化を避けるためボックス化しています。
+#[derive(Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum PageData {
+    /// ポータル（全表示モードを含む）
+    #[cfg(feature = "portal")]
+    Portal(Portal),
+    /// お知らせ詳細
+    #[cfg(feature = "portal")]
+    NotificationDetail(NotificationDetail),
+    /// 成績照会
+    #[cfg(feature = "grades")]
+    GradeInquiry(GradeInquiry),
+    /// 卒業見込判定
+    #[cfg(feature = "grades")]
+    GraduationPrediction(SotsugyoMikonHanteiKekka),
+    /// 進級見込判定
+    #[cfg(feature = "grades")]
+    PromotionPrediction(PromotionPredictionData),
+    /// 免許取得見込判定
+    #[cfg(feature = "grades")]
+    LicensePrediction(LicensePredictionData),
+    /// シラバス検索結果
+    #[cfg(feature = "syllabus")]
+    SyllabusSearchResult(SyllabusSearchResultPage),
+    /// シラバス詳細
+    #[cfg(feature = "syllabus")]
+    SyllabusView(Box<LessonInfo>),
+    /// 課題提出一覧
+    #[cfg(feature = "assignments")]
+    AssignmentList(AssignmentList),
+    /// 教室予約状況
+    #[cfg(feature = "classroom")]
+    ClassroomReservationStatus(Box<ClassroomReservationStatus>),
+    /// アンケート一覧
+    #[cfg(feature = "questionnaire")]
+    QuestionnaireList(QuestionnaireList),
+    /// 学籍情報照会
+    #[cfg(feature = "student-info")]
+    StudentInfo(StudentInfo),
+    /// テスト解答状況
+    #[cfg(feature = "tests")]
+    TestAnswerStatus(TestAnswerStatus),
+}
+
+/// ページ種別を自動判定して解析する
+///
+/// 判定できない場合は`InvalidHtmlStructure`エラーを返します。
+pub fn parse_page(document: &Html) -> Result<(PageKind, PageData), ParseError> {
+    parse_page_with_warnings(document).map(|(kind, outcome)| (kind, outcome.data))
+}
+
+/// ページ種別を自動判定し、警告付きで解析する
+pub fn parse_page_with_warnings(
+    document: &Html,
+) -> Result<(PageKind, ParseOutcome<PageData>), ParseError> {
+    let kind = detect_page_kind(document)?;
+    Ok((kind, kind.parse_with_warnings(document)?))
+}
+
+/// ページ種別を自動判定し、セレクターの試行記録を含めて解析する
+pub fn parse_page_with_diagnostics(
+    document: &Html,
+) -> Result<(PageKind, ParseOutcome<PageData>), ParseError> {
+    let kind = detect_page_kind(document)?;
+    Ok((kind, kind.parse_with_diagnostics(document)?))
+}
+
+/// ページ種別を自動判定し、解析オプションを指定して解析する
+pub fn parse_page_with_options(
+    document: &Html,
+    options: ParseOptions,
+) -> Result<(PageKind, ParseOutcome<PageData>), ParseError> {
+    let kind = detect_page_kind(document)?;
+    Ok((kind, kind.parse_with_options(document, options)?))
+}
+
+fn detect_page_kind(document: &Html) -> Result<PageKind, ParseError> {
+    PageKind::detect(document).ok_or_else(|| ParseError::InvalidHtmlStructure {
+        expected: "a known UNIVERSAL PASSPORT EX page".to_string(),
+    })
+}
@@ -4,17 +4,16 @@
 //! 将来的にパーサーの挙動をカスタマイズするための設定値を
 //! 保持できるように設計されています。
 
+use crate::common::options::ParseOptions;
 use crate::utils::error::ParseError;
 use super::parser::{PortalParser, PortalAllNotificationsParser, PortalClassContactParser, PortalAllClassContactParser};
 
 /// ポータルパーサービルダー
 ///
 /// PortalParserのインスタンスを生成するためのビルダーパターンを提供します。
-/// 現在は最小限の実装ですが、将来的に設定オプションを追加できるように設計されています。
+/// 構築した各パーサーには`with_options`で設定した解析オプションが引き継がれます。
 pub struct PortalParserBuilder {
-    // 将来的な設定オプション用のフィールドを予約
-    // debug_mode: bool,
-    // strict_mode: bool,
+    options: ParseOptions,
 }
 
 impl PortalParserBuilder {
@@ -25,12 +24,19 @@ impl PortalParserBuilder {
     /// デフォルト設定で初期化されたPortalParserBuilderインスタンス
     pub fn new() -> Self {
         Self {
-            // 将来的な設定オプション用のデフォルト値
-            // debug_mode: false,
-            // strict_mode: false,
+            options: ParseOptions::default(),
         }
     }
 
+    /// 解析オプションを設定
+    ///
+    /// 厳密モードでは、カレンダー・時限割・お気に入りリンク・お知らせの
+    /// いずれかのテーブルが見つからない場合にエラーになります。
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// 設定されたオプションでPortalParserインスタンスを構築
     ///
     /// # Returns
@@ -41,7 +47,7 @@ impl PortalParserBuilder {
     /// 
     /// パーサーの初期化に失敗した場合にParseErrorを返します
     pub fn build(self) -> Result<PortalParser, ParseError> {
-        PortalParser::new()
+        Ok(PortalParser::new()?.with_options(self.options))
     }
 
     /// お知らせ全表示パーサーを構築
@@ -54,7 +60,7 @@ impl PortalParserBuilder {
     /// 
     /// パーサーの初期化に失敗した場合にParseErrorを返します
     pub fn build_all_notifications(self) -> Result<PortalAllNotificationsParser, ParseError> {
-        PortalAllNotificationsParser::new()
+        Ok(PortalAllNotificationsParser::new()?.with_options(self.options))
     }
 
     /// 授業連絡表示パーサーを構築
@@ -67,7 +73,7 @@ impl PortalParserBuilder {
     /// 
     /// パーサーの初期化に失敗した場合にParseErrorを返します
     pub fn build_class_contact(self) -> Result<PortalClassContactParser, ParseError> {
-        PortalClassContactParser::new()
+        Ok(PortalClassContactParser::new()?.with_options(self.options))
     }
 
     /// 授業連絡全表示パーサーを構築
@@ -80,7 +86,7 @@ impl PortalParserBuilder {
     /// 
     /// パーサーの初期化に失敗した場合にParseErrorを返します
     pub fn build_all_class_contact(self) -> Result<PortalAllClassContactParser, ParseError> {
-        PortalAllClassContactParser::new()
+        Ok(PortalAllClassContactParser::new()?.with_options(self.options))
     }
 }
 
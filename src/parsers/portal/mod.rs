@@ -5,104 +5,120 @@
 //! 主要コンポーネントを含みます。
 
 pub mod builder;
-pub mod parser;
 pub mod model;
 pub mod notification_detail;
+pub mod parser;
+pub mod sections;
 
-use crate::common::traits::PageParser;
+#[cfg(target_arch = "wasm32")]
+use crate::common::options::ParseOptions;
+#[cfg(target_arch = "wasm32")]
+use crate::common::traits::{PageParser, SectionParser};
+#[cfg(target_arch = "wasm32")]
+use crate::utils::encoding::decode_html;
+#[cfg(target_arch = "wasm32")]
+use crate::utils::error::ParseError;
+#[cfg(target_arch = "wasm32")]
 use scraper::Html;
-
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
+/// 一度だけ解析したポータル画面から、必要なセクションを繰り返し取り出すためのハンドル
+///
+/// `PortalParser`の`parse`などは呼び出しごとにHTMLを解析し直しますが、
+/// `ParsedPage`は構築時に解析したドキュメントを保持するため、
+/// お知らせだけを定期的に読み直すといった用途でも解析は一度で済みます。
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
-pub struct PortalParser {
-    parser: parser::PortalParser,
+pub struct ParsedPage {
+    document: Html,
+    options: ParseOptions,
 }
 
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
-impl PortalParser {
+impl ParsedPage {
+    /// HTML文字列を解析してハンドルを作成
     #[wasm_bindgen(constructor)]
-    pub fn new() -> Result<Self, JsError> {
-        Ok(Self {
-            parser: builder::PortalParserBuilder::new().build()?,
-        })
+    pub fn new(html_content: &str) -> Self {
+        Self {
+            document: Html::parse_document(html_content),
+            options: ParseOptions::default(),
+        }
     }
 
-    pub fn portal(&self, html_content: &str) -> Result<model::Portal, JsError> {
-        Ok(self
-            .parser
-            .parse_document(&Html::parse_document(html_content))?)
+    /// 解析オプションを指定してハンドルを作成
+    #[wasm_bindgen]
+    pub fn with_options(html_content: &str, options: ParseOptions) -> Self {
+        Self {
+            document: Html::parse_document(html_content),
+            options,
+        }
     }
-}
-
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-pub struct PortalAllNotificationsParser {
-    parser: parser::PortalAllNotificationsParser,
-}
 
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-impl PortalAllNotificationsParser {
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Result<Self, JsError> {
-        Ok(Self {
-            parser: builder::PortalParserBuilder::new().build_all_notifications()?,
-        })
+    /// バイト列からハンドルを作成する（文字コードは自動判定）
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &[u8], content_type: Option<String>) -> Result<ParsedPage, ParseError> {
+        let html = decode_html(bytes, content_type.as_deref(), "ポータル")?;
+        Ok(Self::new(&html))
     }
 
-    pub fn portal_all_notifications(&self, html_content: &str) -> Result<model::Portal, JsError> {
-        Ok(self
-            .parser
-            .parse_document(&Html::parse_document(html_content))?)
+    /// ドキュメント内に存在するセクション名の一覧
+    ///
+    /// `calendar`、`schedule`、`favorite_links`、`notifications`のうち、
+    /// 対応する要素が見つかったものを返します。
+    pub fn available_sections(&self) -> Vec<String> {
+        [
+            ("calendar", sections::CalendarSectionParser::new().section_exists(&self.document)),
+            ("schedule", sections::ScheduleSectionParser::new().section_exists(&self.document)),
+            (
+                "favorite_links",
+                sections::FavoriteLinksSectionParser::new().section_exists(&self.document),
+            ),
+            (
+                "notifications",
+                sections::NotificationsSectionParser::new().section_exists(&self.document),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, exists)| *exists)
+        .map(|(name, _)| name.to_string())
+        .collect()
     }
-}
-
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-pub struct PortalClassContactParser {
-    parser: parser::PortalClassContactParser,
-}
 
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-impl PortalClassContactParser {
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Result<Self, JsError> {
-        Ok(Self {
-            parser: builder::PortalParserBuilder::new().build_class_contact()?,
-        })
+    /// カレンダーを取得する
+    pub fn calendar(&self) -> Result<model::Calendar, ParseError> {
+        sections::CalendarSectionParser::new()
+            .with_options(self.options.clone())
+            .parse_section(&self.document)
     }
 
-    pub fn portal_class_contact(&self, html_content: &str) -> Result<model::Portal, JsError> {
-        Ok(self
-            .parser
-            .parse_document(&Html::parse_document(html_content))?)
+    /// 今日の時限割を取得する
+    pub fn schedule(&self) -> Result<model::Schedule, ParseError> {
+        sections::ScheduleSectionParser::new()
+            .with_options(self.options.clone())
+            .parse_section(&self.document)
     }
-}
 
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-pub struct PortalAllClassContactParser {
-    parser: parser::PortalAllClassContactParser,
-}
+    /// お気に入りリンクを取得する
+    pub fn favorite_links(&self) -> Result<model::FavoriteLinks, ParseError> {
+        sections::FavoriteLinksSectionParser::new()
+            .with_options(self.options.clone())
+            .parse_section(&self.document)
+    }
 
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-impl PortalAllClassContactParser {
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Result<Self, JsError> {
-        Ok(Self {
-            parser: builder::PortalParserBuilder::new().build_all_class_contact()?,
-        })
+    /// お知らせを取得する
+    pub fn notifications(&self) -> Result<model::Notifications, ParseError> {
+        sections::NotificationsSectionParser::new()
+            .with_options(self.options.clone())
+            .parse_section(&self.document)
     }
 
-    pub fn portal_all_class_contact(&self, html_content: &str) -> Result<model::Portal, JsError> {
-        Ok(self
-            .parser
-            .parse_document(&Html::parse_document(html_content))?)
+    /// すべてのセクションをまとめてポータルとして取得する
+    pub fn portal(&self) -> Result<model::Portal, ParseError> {
+        builder::PortalParserBuilder::new()
+            .with_options(self.options.clone())
+            .build()?
+            .parse_document(&self.document)
     }
 }
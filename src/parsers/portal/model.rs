@@ -7,8 +7,13 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::datetime::JstDate;
+use crate::common::period::ClassTime;
+use crate::common::term::AcademicTerm;
+
 /// ポータル画面全体の情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Portal {
     /// カレンダーコンポーネント
@@ -23,6 +28,7 @@ pub struct Portal {
 
 /// カレンダーコンポーネント
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Calendar {
     /// 前月ボタン
@@ -33,12 +39,10 @@ pub struct Calendar {
     pub current_day_button: String,
     /// 月間スケジュールボタン
     pub month_schedule_button: String,
-    /// 年表示
-    pub year: String,
-    /// 月表示
-    pub month: String,
+    /// 年度・学期表示（例: "2025春学期"）
+    pub term: Option<AcademicTerm>,
     /// 現在日付（hidden）
-    pub current_date: String,
+    pub current_date: Option<JstDate>,
     /// 選択日付（hidden）
     pub selected_day: String,
     /// カレンダーの日付グリッド（週のリストで、各週が日のリストを含む）
@@ -47,6 +51,7 @@ pub struct Calendar {
 
 /// カレンダーの1日分の情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct CalendarDay {
     /// 日付の数値（`&nbsp;` の場合はNone）
@@ -63,6 +68,7 @@ pub struct CalendarDay {
 
 /// スケジュールコンポーネント
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Schedule {
     /// タイトル
@@ -75,10 +81,13 @@ pub struct Schedule {
 
 /// スケジュールの1エントリ
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ScheduleEntry {
     /// 日付
     pub date: String,
+    /// 日付・時限の表記から求めた曜日・時限と開始・終了時刻（時限の表記がない場合はNone）
+    pub class_time: Option<ClassTime>,
     /// 授業内容
     pub class_content: String,
     /// 画像パス（区切り線など、存在しない場合はNone）
@@ -87,6 +96,7 @@ pub struct ScheduleEntry {
 
 /// お気に入りリンクコンポーネント
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct FavoriteLinks {
     /// タイトル
@@ -99,6 +109,7 @@ pub struct FavoriteLinks {
 
 /// お気に入りリンクの1項目
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct FavoriteLink {
     /// リンク名
@@ -113,6 +124,7 @@ pub struct FavoriteLink {
 
 /// お知らせコンポーネント
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct Notifications {
     /// お知らせセクションのリスト
@@ -123,6 +135,7 @@ pub struct Notifications {
 
 /// お知らせの1セクション
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct NotificationSection {
     /// ヘッダータイトル
@@ -143,6 +156,7 @@ pub struct NotificationSection {
 
 /// お知らせの1エントリ
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct NotificationEntry {
     /// 既読/未読アイコンの画像パス（存在しない場合はNone）
@@ -153,6 +167,6 @@ pub struct NotificationEntry {
     pub title: Option<String>,
     /// 情報源（存在しない場合はNone）
     pub source: Option<String>,
-    /// 掲載日
-    pub insert_date: String,
+    /// 掲載日（表記を解釈できない場合はNone）
+    pub insert_date: Option<JstDate>,
 } 
\ No newline at end of file
@@ -4,17 +4,15 @@
 //! 将来的にパーサーの挙動をカスタマイズするための設定値を
 //! 保持できるように設計されています。
 
+use crate::common::options::ParseOptions;
 use crate::utils::error::ParseError;
 use super::parser::NotificationDetailParser;
 
 /// お知らせ詳細パーサービルダー
 ///
 /// NotificationDetailParserのインスタンスを生成するためのビルダーパターンを提供します。
-/// 現在は最小限の実装ですが、将来的に設定オプションを追加できるように設計されています。
 pub struct NotificationDetailParserBuilder {
-    // 将来的な設定オプション用のフィールドを予約
-    // debug_mode: bool,
-    // strict_mode: bool,
+    options: ParseOptions,
 }
 
 impl NotificationDetailParserBuilder {
@@ -25,12 +23,19 @@ impl NotificationDetailParserBuilder {
     /// デフォルト設定で初期化されたNotificationDetailParserBuilderインスタンス
     pub fn new() -> Self {
         Self {
-            // 将来的な設定オプション用のデフォルト値
-            // debug_mode: false,
-            // strict_mode: false,
+            options: ParseOptions::default(),
         }
     }
 
+    /// 解析オプションを設定
+    ///
+    /// 厳密モードでは、タイトル・送信者・本文が見つからない場合や、
+    /// 閉じるボタンの表記を既定値で補う場合にエラーになります。
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// 設定されたオプションでNotificationDetailParserインスタンスを構築
     ///
     /// # Returns
@@ -41,7 +46,7 @@ impl NotificationDetailParserBuilder {
     /// 
     /// パーサーの初期化に失敗した場合にParseErrorを返します
     pub fn build(self) -> Result<NotificationDetailParser, ParseError> {
-        NotificationDetailParser::new()
+        Ok(NotificationDetailParser::new()?.with_options(self.options))
     }
 }
 
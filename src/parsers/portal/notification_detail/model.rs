@@ -8,6 +8,7 @@ use tsify::Tsify;
 
 /// お知らせ詳細の情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct NotificationDetail {
     /// タイトル
@@ -24,6 +25,7 @@ pub struct NotificationDetail {
 
 /// 添付ファイル情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct AttachmentFile {
     /// ファイル名
@@ -3,38 +3,74 @@
 //! UNIVERSAL PASSPORT EXのお知らせ詳細ポップアップ画面のHTML解析ロジックを提供します。
 //! タイトル、送信者、本文、添付ファイルなどの詳細情報を解析します。
 
-use scraper::{Html, Selector};
+use scraper::Html;
 
+use crate::common::context::ParseContext;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::selectors::selector_registry;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 use super::model::{NotificationDetail, AttachmentFile};
 
+selector_registry! {
+    /// お知らせ詳細画面で使用するセレクター
+    struct NotificationDetailSelectors {
+        title: [".popup-title", ".notification-title", "h1", "h2", ".title", "#title"],
+        from: [".sender", ".from", ".author"],
+        main_text: [".main-content", ".content", ".message", ".body", ".text-content", "p"],
+        attachment_table: [".attachment-table", ".file-table"],
+        close_button: ["input[value*='閉じる']", "input[value*='Close']", ".close-button"],
+        table: "table",
+        row: "tr",
+        cell: "td",
+        body: "body",
+        button: "input[type='button'], button",
+        link: "a",
+    }
+}
+
 /// お知らせ詳細パーサー
-pub struct NotificationDetailParser;
+pub struct NotificationDetailParser {
+    options: ParseOptions,
+}
 
 impl NotificationDetailParser {
     /// 新しいパーサーインスタンスを作成
     pub fn new() -> Result<Self, ParseError> {
-        Ok(Self)
+        Ok(Self { options: ParseOptions::default() })
+    }
+
+    /// 解析オプションを設定する
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 警告をコンテキストに記録しながらお知らせ詳細を解析する
+    pub fn parse_with_context(&self, document: &Html, ctx: &mut ParseContext) -> Result<NotificationDetail, ParseError> {
+        let title = self.parse_title(document, ctx)?;
+        let from = self.parse_from(document, ctx)?;
+        let main_text = self.parse_main_text(document, ctx)?;
+        let attachments = self.parse_attachments(document)?;
+        let close_button = self.parse_close_button(document, ctx)?;
+
+        Ok(NotificationDetail {
+            title,
+            from,
+            main_text,
+            attachments,
+            close_button,
+        })
     }
 
     /// タイトル情報を解析する
-    fn parse_title(&self, document: &Html) -> Result<String, ParseError> {
+    fn parse_title(&self, document: &Html, ctx: &mut ParseContext) -> Result<String, ParseError> {
+        let selectors = NotificationDetailSelectors::get()?;
+
         // タイトル要素を探す（複数のセレクターを試行）
-        let title_selectors = vec![
-            ".popup-title",
-            ".notification-title", 
-            "h1",
-            "h2",
-            ".title",
-            "#title"
-        ];
-
-        for selector_str in title_selectors {
-            let selector = Selector::parse(selector_str)
-                .map_err(|e| ParseError::selector_creation_failed(selector_str, &e.to_string()))?;
-
-            if let Some(title_elem) = document.select(&selector).next() {
+        for selector in &selectors.title {
+            if let Some(title_elem) = document.select(selector).next() {
                 let title_text = title_elem.inner_html().trim().to_string();
                 if !title_text.is_empty() && title_text != "&nbsp;" {
                     return Ok(title_text);
@@ -42,45 +78,29 @@ impl NotificationDetailParser {
             }
         }
 
+        ctx.missing_section("title", ".popup-title, .notification-title, h1, h2, .title, #title")?;
         Ok("".to_string())
     }
 
     /// 送信者情報を解析する
-    fn parse_from(&self, document: &Html) -> Result<String, ParseError> {
+    fn parse_from(&self, document: &Html, ctx: &mut ParseContext) -> Result<String, ParseError> {
+        let selectors = NotificationDetailSelectors::get()?;
+
         // 送信者要素を探す（複数のセレクターを試行）
-        let from_selectors = vec![
-            ".sender",
-            ".from",
-            ".author",
-            "span:contains('送信者')",
-            "td:contains('送信者')"
-        ];
-
-        for selector_str in from_selectors {
-            if let Ok(selector) = Selector::parse(selector_str) {
-                if let Some(from_elem) = document.select(&selector).next() {
-                    let from_text = from_elem.inner_html().trim().to_string();
-                    if !from_text.is_empty() && from_text != "&nbsp;" {
-                        // "送信者:" プレフィックスがある場合は除去
-                        return Ok(from_text.replace("送信者:", "").replace("送信者：", "").trim().to_string());
-                    }
+        for selector in &selectors.from {
+            if let Some(from_elem) = document.select(selector).next() {
+                let from_text = from_elem.inner_html().trim().to_string();
+                if !from_text.is_empty() && from_text != "&nbsp;" {
+                    // "送信者:" プレフィックスがある場合は除去
+                    return Ok(from_text.replace("送信者:", "").replace("送信者：", "").trim().to_string());
                 }
             }
         }
 
         // テーブル構造からの抽出を試行
-        let table_selector = Selector::parse("table")
-            .map_err(|e| ParseError::selector_creation_failed("table", &e.to_string()))?;
-
-        for table in document.select(&table_selector) {
-            let row_selector = Selector::parse("tr")
-                .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-
-            for row in table.select(&row_selector) {
-                let cell_selector = Selector::parse("td")
-                    .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
-                let cells: Vec<_> = row.select(&cell_selector).collect();
+        for table in document.select(&selectors.table) {
+            for row in table.select(&selectors.row) {
+                let cells: Vec<_> = row.select(&selectors.cell).collect();
                 if cells.len() >= 2 {
                     let header_text = cells[0].inner_html();
                     let header = header_text.trim();
@@ -91,27 +111,18 @@ impl NotificationDetailParser {
             }
         }
 
+        ctx.missing_section("from", ".sender, .from, .author")?;
         Ok("".to_string())
     }
 
     /// メイン本文を解析する
-    fn parse_main_text(&self, document: &Html) -> Result<String, ParseError> {
-        // 本文要素を探す（複数のセレクターを試行）
-        let text_selectors = vec![
-            ".main-content",
-            ".content",
-            ".message",
-            ".body",
-            ".text-content",
-            "p"
-        ];
-
-        for selector_str in text_selectors {
-            let selector = Selector::parse(selector_str)
-                .map_err(|e| ParseError::selector_creation_failed(selector_str, &e.to_string()))?;
+    fn parse_main_text(&self, document: &Html, ctx: &mut ParseContext) -> Result<String, ParseError> {
+        let selectors = NotificationDetailSelectors::get()?;
 
+        // 本文要素を探す（複数のセレクターを試行）
+        for selector in &selectors.main_text {
             let mut content_parts = Vec::new();
-            for text_elem in document.select(&selector) {
+            for text_elem in document.select(selector) {
                 let text_content = text_elem.inner_html().trim().to_string();
                 if !text_content.is_empty() && text_content != "&nbsp;" {
                     content_parts.push(text_content);
@@ -124,10 +135,8 @@ impl NotificationDetailParser {
         }
 
         // 全体のテキストコンテンツから抽出（最後の手段）
-        let body_selector = Selector::parse("body")
-            .map_err(|e| ParseError::selector_creation_failed("body", &e.to_string()))?;
-
-        if let Some(body) = document.select(&body_selector).next() {
+        if let Some(body) = document.select(&selectors.body).next() {
+            ctx.fallback_used("main_text", "body text")?;
             let body_text = body.inner_html();
             // HTMLタグを除去して純粋なテキストを抽出
             let text_only = body_text
@@ -138,60 +147,41 @@ impl NotificationDetailParser {
             return Ok(text_only);
         }
 
+        ctx.missing_section("main_text", "body")?;
         Ok("".to_string())
     }
 
     /// 添付ファイル情報を解析する
     fn parse_attachments(&self, document: &Html) -> Result<Vec<AttachmentFile>, ParseError> {
+        let selectors = NotificationDetailSelectors::get()?;
         let mut attachments = Vec::new();
 
         // 添付ファイルテーブルを探す
-        let attachment_selectors = vec![
-            ".attachment-table",
-            "table:contains('添付')",
-            ".file-table"
-        ];
-
-        for selector_str in attachment_selectors {
-            if let Ok(selector) = Selector::parse(selector_str) {
-                for table in document.select(&selector) {
-                    let row_selector = Selector::parse("tr")
-                        .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-
-                    for row in table.select(&row_selector) {
-                        let cell_selector = Selector::parse("td")
-                            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
-                        let cells: Vec<_> = row.select(&cell_selector).collect();
-                        
-                        if cells.len() >= 2 {
-                            let mut attachment = AttachmentFile::default();
-
+        for selector in &selectors.attachment_table {
+            for table in document.select(selector) {
+                for row in table.select(&selectors.row) {
+                    let cells: Vec<_> = row.select(&selectors.cell).collect();
+
+                    if cells.len() >= 2 {
+                        // ダウンロードボタンIDを取得（idがなければname）
+                        let download_button_id = row
+                            .select(&selectors.button)
+                            .find_map(|button| {
+                                button.value().attr("id").or(button.value().attr("name"))
+                            })
+                            .unwrap_or_default()
+                            .to_string();
+
+                        let attachment = AttachmentFile {
                             // ファイル名を取得
-                            attachment.file_name = cells[0].inner_html().trim().to_string();
-
+                            file_name: cells[0].inner_html().trim().to_string(),
                             // ファイルサイズを取得
-                            if cells.len() > 1 {
-                                attachment.file_size = cells[1].inner_html().trim().to_string();
-                            }
-
-                            // ダウンロードボタンIDを取得
-                            let button_selector = Selector::parse("input[type='button'], button")
-                                .map_err(|e| ParseError::selector_creation_failed("input[type='button'], button", &e.to_string()))?;
-
-                            for button in row.select(&button_selector) {
-                                if let Some(id) = button.value().attr("id") {
-                                    attachment.download_button_id = id.to_string();
-                                    break;
-                                } else if let Some(name) = button.value().attr("name") {
-                                    attachment.download_button_id = name.to_string();
-                                    break;
-                                }
-                            }
-
-                            if !attachment.file_name.is_empty() {
-                                attachments.push(attachment);
-                            }
+                            file_size: cells[1].inner_html().trim().to_string(),
+                            download_button_id,
+                        };
+
+                        if !attachment.file_name.is_empty() {
+                            attachments.push(attachment);
                         }
                     }
                 }
@@ -199,16 +189,18 @@ impl NotificationDetailParser {
         }
 
         // ダウンロードリンクからの抽出（フォールバック）
-        let download_link_selector = Selector::parse("a[href*='download'], a:contains('ダウンロード')")
-            .map_err(|e| ParseError::selector_creation_failed("a[href*='download'], a:contains('ダウンロード')", &e.to_string()))?;
-
-        for link in document.select(&download_link_selector) {
-            let mut attachment = AttachmentFile::default();
-            attachment.file_name = link.inner_html().trim().to_string();
-
-            if let Some(href) = link.value().attr("href") {
-                attachment.download_button_id = href.to_string();
-            }
+        // hrefに"download"を含むか、リンク文字列に「ダウンロード」を含むリンクを対象とする
+        let download_links = document.select(&selectors.link).filter(|link| {
+            link.value().attr("href").is_some_and(|href| href.contains("download"))
+                || link.text().any(|text| text.contains("ダウンロード"))
+        });
+
+        for link in download_links {
+            let attachment = AttachmentFile {
+                file_name: link.inner_html().trim().to_string(),
+                download_button_id: link.value().attr("href").unwrap_or_default().to_string(),
+                ..Default::default()
+            };
 
             if !attachment.file_name.is_empty() {
                 attachments.push(attachment);
@@ -219,28 +211,22 @@ impl NotificationDetailParser {
     }
 
     /// 閉じるボタン情報を解析する
-    fn parse_close_button(&self, document: &Html) -> Result<String, ParseError> {
+    fn parse_close_button(&self, document: &Html, ctx: &mut ParseContext) -> Result<String, ParseError> {
+        let selectors = NotificationDetailSelectors::get()?;
+
         // 閉じるボタンを探す
-        let close_selectors = vec![
-            "input[value*='閉じる']",
-            "button:contains('閉じる')",
-            "input[value*='Close']",
-            "button:contains('Close')",
-            ".close-button"
-        ];
-
-        for selector_str in close_selectors {
-            if let Ok(selector) = Selector::parse(selector_str) {
-                if let Some(close_elem) = document.select(&selector).next() {
-                    if let Some(value) = close_elem.value().attr("value") {
-                        return Ok(value.to_string());
-                    } else {
-                        return Ok(close_elem.inner_html().trim().to_string());
-                    }
+        for selector in &selectors.close_button {
+            if let Some(close_elem) = document.select(selector).next() {
+                if let Some(value) = close_elem.value().attr("value") {
+                    return Ok(value.to_string());
+                } else {
+                    return Ok(close_elem.inner_html().trim().to_string());
                 }
             }
         }
 
+
+        ctx.fallback_used("close_button", "閉じる")?;
         Ok("閉じる".to_string()) // デフォルト値
     }
 }
@@ -249,18 +235,19 @@ impl PageParser<NotificationDetail> for NotificationDetailParser {
     const PAGE_TYPE: &'static str = "お知らせ詳細";
 
     fn parse_document(&self, document: &Html) -> Result<NotificationDetail, ParseError> {
-        let title = self.parse_title(document)?;
-        let from = self.parse_from(document)?;
-        let main_text = self.parse_main_text(document)?;
-        let attachments = self.parse_attachments(document)?;
-        let close_button = self.parse_close_button(document)?;
+        let mut ctx = ParseContext::new().with_strict(self.options.strict_mode);
+        self.parse_with_context(document, &mut ctx)
+    }
 
-        Ok(NotificationDetail {
-            title,
-            from,
-            main_text,
-            attachments,
-            close_button,
-        })
+    fn parse_with_warnings(&self, document: &Html) -> Result<ParseOutcome<NotificationDetail>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+
+    fn parse_with_diagnostics(&self, document: &Html) -> Result<ParseOutcome<NotificationDetail>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options).with_diagnostics(true);
+        let data = self.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
     }
 }
\ No newline at end of file
@@ -0,0 +1,479 @@
+//! ポータル画面のセクション別パーサー
+//!
+//! ポータル画面を構成するカレンダー、今日の時限割、お気に入りリンク、お知らせを
+//! それぞれ独立した[`SectionParser`]として提供します。
+//! 一度解析した`Html`に対して必要なセクションだけを繰り返し抽出できます。
+
+use scraper::Html;
+
+use super::model::{
+    Calendar, CalendarDay, FavoriteLink, FavoriteLinks, NotificationEntry, NotificationSection,
+    Notifications, Schedule, ScheduleEntry,
+};
+use super::parser::PortalSelectors;
+use crate::common::context::ParseContext;
+use crate::common::dom::DomElement;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::selectors::CompiledSelector;
+use crate::common::traits::SectionParser;
+use crate::utils::error::ParseError;
+
+/// 行番号を名前に含む隠しフィールドの値を取得する
+fn row_hidden_value<E: DomElement>(
+    root: &E,
+    selector: &CompiledSelector,
+    row_index: usize,
+) -> Option<String> {
+    let row_key = row_index.to_string();
+    root.select_all(selector)
+        .into_iter()
+        .find(|input| {
+            input
+                .attr("name")
+                .is_some_and(|name| name.contains(&row_key))
+        })
+        .and_then(|input| input.attr("value"))
+}
+
+/// セクション別パーサーの共通実装を生成する
+macro_rules! section_parser {
+    ($(#[$meta:meta])* $name:ident => $model:ty, $exists:ident) => {
+        $(#[$meta])*
+        #[derive(Default)]
+        pub struct $name {
+            options: ParseOptions,
+        }
+
+        impl $name {
+            /// 新しいパーサーインスタンスを作成
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// 解析オプションを設定する
+            pub fn with_options(mut self, options: ParseOptions) -> Self {
+                self.options = options;
+                self
+            }
+
+            /// 警告をコンテキストに記録しながら解析する
+            pub fn parse_with_context(
+                &self,
+                document: &Html,
+                ctx: &mut ParseContext,
+            ) -> Result<$model, ParseError> {
+                self.parse_element_with_context(&document.root_element(), ctx)
+            }
+
+            /// `root`配下の要素から解析する
+            ///
+            /// ブラウザのDOM要素を渡した場合も、HTML文字列から解析した場合と同じ結果になります。
+            pub fn parse_element<E: DomElement>(&self, root: &E) -> Result<$model, ParseError> {
+                self.parse_element_with_warnings(root).map(|outcome| outcome.data)
+            }
+
+            /// `root`配下の要素から解析し、警告とあわせて返す
+            ///
+            /// `debug_mode`が有効な場合は[`Self::parse_element_with_diagnostics`]と同じく`diagnostics`も含めます。
+            pub fn parse_element_with_warnings<E: DomElement>(
+                &self,
+                root: &E,
+            ) -> Result<ParseOutcome<$model>, ParseError> {
+                if self.options.debug_mode {
+                    return self.parse_element_with_diagnostics(root);
+                }
+                let mut ctx = ParseContext::from_options(&self.options);
+                let data = self.parse_element_with_context(root, &mut ctx)?;
+                Ok(ctx.finish(data))
+            }
+
+            /// `root`配下の要素から解析し、セレクターの試行記録を`diagnostics`に含めて返す
+            pub fn parse_element_with_diagnostics<E: DomElement>(
+                &self,
+                root: &E,
+            ) -> Result<ParseOutcome<$model>, ParseError> {
+                let mut ctx = ParseContext::from_options(&self.options).with_diagnostics(true);
+                let data = self.parse_element_with_context(root, &mut ctx)?;
+                Ok(ctx.finish(data))
+            }
+
+            /// 対象セクションが`root`配下に存在するかを確認する
+            pub fn element_exists<E: DomElement>(&self, root: &E) -> bool {
+                PortalSelectors::get()
+                    .is_ok_and(|selectors| root.select_first(&selectors.$exists).is_some())
+            }
+        }
+
+        impl SectionParser<$model> for $name {
+            fn parse_section(&self, document: &Html) -> Result<$model, ParseError> {
+                self.parse_element(&document.root_element())
+            }
+
+            fn section_exists(&self, document: &Html) -> bool {
+                self.element_exists(&document.root_element())
+            }
+        }
+    };
+}
+
+section_parser! {
+    /// カレンダー（月間カレンダーと年度・学期）のセクションパーサー
+    CalendarSectionParser => Calendar, calendar_table
+}
+
+impl CalendarSectionParser {
+    /// 警告をコンテキストに記録しながらカレンダーを解析する
+    pub fn parse_element_with_context<E: DomElement>(
+        &self,
+        root: &E,
+        ctx: &mut ParseContext,
+    ) -> Result<Calendar, ParseError> {
+        let selectors = PortalSelectors::get()?;
+        let mut calendar = Calendar::default();
+
+        // カレンダーボタンを解析
+        for button in root.select_all(&selectors.image_button) {
+            if let Some(alt) = button.attr("alt") {
+                match alt.as_str() {
+                    s if s.contains("前月") => {
+                        calendar.last_month_button = alt.to_string();
+                    }
+                    s if s.contains("次月") => {
+                        calendar.next_month_button = alt.to_string();
+                    }
+                    s if s.contains("今日") || s.contains("本日") => {
+                        calendar.current_day_button = alt.to_string();
+                    }
+                    s if s.contains("月間") => {
+                        calendar.month_schedule_button = alt.to_string();
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // 年度・学期情報を解析
+        if let Some(year_semester) = root.select_first(&selectors.year_semester) {
+            let text = year_semester.inner_html();
+            calendar.term = ctx.parse_cell("year_semester", 0, 0, &text)?;
+        }
+
+        // 隠しフィールドから現在日付と選択日付を取得
+        if let Some(current_date_input) = root.select_first(&selectors.current_date) {
+            let value = current_date_input.attr("value").unwrap_or_default();
+            calendar.current_date = ctx.parse_cell("calendar", 0, 0, &value)?;
+        }
+
+        if let Some(selected_day_input) = root.select_first(&selectors.selected_day) {
+            calendar.selected_day = selected_day_input.attr("value").unwrap_or_default();
+        }
+
+        // カレンダーテーブルから日付グリッドを解析
+        ctx.trace_selector("calendar", &selectors.calendar_table, root.clone());
+        if let Some(calendar_table) = root.select_first(&selectors.calendar_table) {
+            for row in calendar_table.select_all(&selectors.body_row) {
+                let mut week_days = Vec::new();
+
+                for cell in row.select_all(&selectors.cell) {
+                    let mut day = CalendarDay::default();
+
+                    // セルIDを取得
+                    if let Some(id) = cell.attr("id") {
+                        day.cell_id = Some(id.to_string());
+                    }
+
+                    // CSSクラスを取得
+                    if let Some(class) = cell.attr("class") {
+                        day.css_classes = class.split_whitespace().map(|s| s.to_string()).collect();
+                        day.is_today = day.css_classes.contains(&"todayColor".to_string());
+                    }
+
+                    // 日付数値を解析
+                    let cell_text = cell.inner_html().trim().to_string();
+                    if !cell_text.is_empty() && cell_text != "&nbsp;" {
+                        if let Ok(day_num) = cell_text.parse::<u32>() {
+                            day.day_number = Some(day_num);
+                        }
+                    }
+
+                    // リンク先を取得（onclick属性から）
+                    if let Some(onclick) = cell.attr("onclick") {
+                        day.link = Some(onclick.to_string());
+                    }
+
+                    week_days.push(day);
+                }
+
+                if !week_days.is_empty() {
+                    calendar.days.push(week_days);
+                }
+            }
+        } else {
+            ctx.missing_section("calendar", "#form1:Poa00101A:htmlCalendarTable")?;
+        }
+
+        Ok(calendar)
+    }
+}
+
+section_parser! {
+    /// 今日の時限割のセクションパーサー
+    ScheduleSectionParser => Schedule, schedule_table
+}
+
+impl ScheduleSectionParser {
+    /// 警告をコンテキストに記録しながら今日の時限割を解析する
+    pub fn parse_element_with_context<E: DomElement>(
+        &self,
+        root: &E,
+        ctx: &mut ParseContext,
+    ) -> Result<Schedule, ParseError> {
+        let selectors = PortalSelectors::get()?;
+        let mut schedule = Schedule {
+            title: "今日の時限割".to_string(),
+            ..Default::default()
+        };
+
+        // スケジュールテーブルを解析
+        ctx.trace_selector("schedule", &selectors.schedule_table, root.clone());
+        if let Some(schedule_table) = root.select_first(&selectors.schedule_table) {
+            for (row_index, row) in schedule_table
+                .select_all(&selectors.row)
+                .into_iter()
+                .enumerate()
+            {
+                let _cells: Vec<_> = row.select_all(&selectors.cell);
+
+                let mut entry = ScheduleEntry::default();
+                let mut has_content = false;
+
+                // 日付・時限情報を取得
+                for date_elem in row.select_all(&selectors.date_jigen) {
+                    let text = date_elem.inner_html().trim().to_string();
+                    if !text.is_empty() {
+                        if entry.date.is_empty() {
+                            entry.date = text;
+                        } else {
+                            entry.date = format!("{} {}", entry.date, text);
+                        }
+                        has_content = true;
+                    }
+                }
+                if !entry.date.is_empty() {
+                    entry.class_time =
+                        ctx.parse_class_time("schedule", row_index, 0, &entry.date)?;
+                }
+
+                // 授業情報を取得
+                let mut class_parts = Vec::new();
+                for class_elem in row.select_all(&selectors.class_info) {
+                    let text = class_elem.inner_html().trim().to_string();
+                    if !text.is_empty() {
+                        class_parts.push(text);
+                        has_content = true;
+                    }
+                }
+
+                if !class_parts.is_empty() {
+                    entry.class_content = class_parts.join(" ");
+                }
+
+                // 画像パス（区切り線など）を取得
+                for img in row.select_all(&selectors.image) {
+                    if let Some(src) = img.attr("src") {
+                        entry.image_path = Some(src.to_string());
+                        break;
+                    }
+                }
+
+                if has_content {
+                    schedule.entries.push(entry);
+                }
+            }
+        } else {
+            ctx.missing_section("schedule", "#form1:Poa00401A:htmlTodayJikanTable")?;
+        }
+
+        // 隠しフィールドから選択授業コードを取得
+        if let Some(hidden) = root.select_first(&selectors.jugyo_list_state) {
+            schedule.selected_class_code = hidden.attr("value").unwrap_or_default();
+        }
+
+        Ok(schedule)
+    }
+}
+
+section_parser! {
+    /// お気に入りリンクのセクションパーサー
+    FavoriteLinksSectionParser => FavoriteLinks, favorite_table
+}
+
+impl FavoriteLinksSectionParser {
+    /// 警告をコンテキストに記録しながらお気に入りリンクを解析する
+    pub fn parse_element_with_context<E: DomElement>(
+        &self,
+        root: &E,
+        ctx: &mut ParseContext,
+    ) -> Result<FavoriteLinks, ParseError> {
+        let selectors = PortalSelectors::get()?;
+        let mut favorite_links = FavoriteLinks {
+            title: "お気に入りリンク".to_string(),
+            edit_button: "編集".to_string(),
+            ..Default::default()
+        };
+
+        // お気に入りリンクテーブルを解析
+        ctx.trace_selector("favorite_links", &selectors.favorite_table, root.clone());
+        if let Some(link_table) = root.select_first(&selectors.favorite_table) {
+            for (row_index, row) in link_table
+                .select_all(&selectors.row)
+                .into_iter()
+                .enumerate()
+            {
+                for link in row.select_all(&selectors.link) {
+                    // リンク名を取得
+                    let mut link_item = FavoriteLink {
+                        name: link.inner_html().replace("&nbsp;", " ").trim().to_string(),
+                        ..Default::default()
+                    };
+
+                    if !link_item.name.is_empty() {
+                        // 隠しフィールドからURL、パラメータ、メソッドを取得
+                        link_item.url = row_hidden_value(root, &selectors.link_url, row_index)
+                            .unwrap_or_default();
+                        link_item.params =
+                            row_hidden_value(root, &selectors.link_params, row_index)
+                                .unwrap_or_default();
+                        link_item.method =
+                            row_hidden_value(root, &selectors.link_method, row_index)
+                                .unwrap_or_else(|| "POST".to_string());
+
+                        // onclickからJavaScript呼び出しを取得（フォールバック）
+                        if link_item.url.is_empty() {
+                            if let Some(onclick) = link.attr("onclick") {
+                                link_item.url = onclick.to_string();
+                                link_item.method = "POST".to_string();
+                            }
+                        }
+
+                        favorite_links.links.push(link_item);
+                    }
+                }
+            }
+        } else {
+            ctx.missing_section("favorite_links", "#form1:Poa00301A:htmlPrjTable")?;
+        }
+
+        Ok(favorite_links)
+    }
+}
+
+section_parser! {
+    /// お知らせ（お知らせ・遠隔授業・授業連絡・就職支援課）のセクションパーサー
+    NotificationsSectionParser => Notifications, notification_parent
+}
+
+impl NotificationsSectionParser {
+    /// 警告をコンテキストに記録しながらお知らせを解析する
+    pub fn parse_element_with_context<E: DomElement>(
+        &self,
+        root: &E,
+        ctx: &mut ParseContext,
+    ) -> Result<Notifications, ParseError> {
+        let selectors = PortalSelectors::get()?;
+        let mut notifications = Notifications {
+            all_info_button: "全て表示".to_string(),
+            ..Default::default()
+        };
+
+        // お知らせ親テーブルを解析
+        ctx.trace_selector(
+            "notifications",
+            &selectors.notification_parent,
+            root.clone(),
+        );
+        if let Some(_parent_table) = root.select_first(&selectors.notification_parent) {
+            // セクション別テーブルを解析（お知らせ、遠隔授業、授業連絡、就職支援課）
+            for (section_index, section_title, section_table_selector) in
+                selectors.notification_sections()
+            {
+                if let Some(section_table) = root.select_first(section_table_selector) {
+                    let mut section = NotificationSection {
+                        header_title: section_title.to_string(),
+                        section_id: section_index.to_string(),
+                        display_mode: "summary".to_string(),
+                        has_all_button: true,
+                        ..Default::default()
+                    };
+
+                    // テーブル行を解析
+                    for (row_index, row) in section_table
+                        .select_all(&selectors.row)
+                        .into_iter()
+                        .enumerate()
+                    {
+                        let _cells: Vec<_> = row.select_all(&selectors.cell);
+
+                        if _cells.len() >= 3 {
+                            let mut entry = NotificationEntry::default();
+
+                            // 既読/未読アイコン
+                            if let Some(img) = _cells[0].select_first(&selectors.image) {
+                                if let Some(src) = img.attr("src") {
+                                    if src.contains("read") || src.contains("未読") {
+                                        entry.read_status_image = Some(src.to_string());
+                                    } else if src.contains("important") || src.contains("重要") {
+                                        entry.important_status_image = Some(src.to_string());
+                                    }
+                                }
+                            }
+
+                            // タイトル
+                            let title_text = _cells[1].inner_html().trim().to_string();
+                            if !title_text.is_empty() && title_text != "&nbsp;" {
+                                entry.title = Some(title_text);
+                            }
+
+                            // 情報源
+                            if _cells.len() > 2 {
+                                let source_text = _cells[2].inner_html().trim().to_string();
+                                if !source_text.is_empty() && source_text != "&nbsp;" {
+                                    entry.source = Some(source_text);
+                                }
+                            }
+
+                            // 掲載日
+                            if _cells.len() > 3 {
+                                entry.insert_date = ctx.parse_cell(
+                                    "notifications",
+                                    row_index,
+                                    3,
+                                    &_cells[3].inner_html(),
+                                )?;
+                            }
+
+                            section.entries.push(entry);
+                        }
+                    }
+
+                    section.total_count = section.entries.len().to_string();
+
+                    // コメント情報を取得（セクションヘッダーから）
+                    if let Some(comment_elem) = section_table.select_first(&selectors.comment) {
+                        let comment_text = comment_elem.inner_html().trim().to_string();
+                        if !comment_text.is_empty() {
+                            section.comment = Some(comment_text);
+                        }
+                    }
+
+                    notifications.sections.push(section);
+                }
+            }
+        } else {
+            ctx.missing_section("notifications", "#form1:Poa00201A:htmlParentTable")?;
+        }
+
+        Ok(notifications)
+    }
+}
@@ -4,6 +4,9 @@
 
 use scraper::Html;
 
+use crate::common::context::ParseContext;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 
@@ -13,7 +16,10 @@ use super::parser::QuestionnaireListParserImpl;
 /// アンケート一覧パーサービルダー
 ///
 /// パーサーの設定値を管理し、適切に設定されたパーサーインスタンスを作成します。
-pub struct QuestionnaireListParserBuilder;
+pub struct QuestionnaireListParserBuilder {
+    /// 解析オプション
+    options: ParseOptions,
+}
 
 impl QuestionnaireListParserBuilder {
     /// 新しいパーサービルダーインスタンスを作成
@@ -22,7 +28,23 @@ impl QuestionnaireListParserBuilder {
     ///
     /// 新しいパーサービルダーインスタンス
     pub fn new() -> Self {
-        Self
+        Self {
+            options: ParseOptions::default(),
+        }
+    }
+
+    /// 解析オプションを設定
+    ///
+    /// # 引数
+    ///
+    /// * `options` - 厳密モードやデバッグモードの設定
+    ///
+    /// # 戻り値
+    ///
+    /// 設定が反映されたビルダー
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
     }
 
     /// アンケート一覧パーサーを構築
@@ -43,7 +65,31 @@ impl PageParser<QuestionnaireList> for QuestionnaireListParserBuilder {
     const PAGE_TYPE: &'static str = "アンケート一覧";
 
     fn parse_document(&self, document: &Html) -> Result<QuestionnaireList, ParseError> {
-        let parser = self.build()?;
-        parser.parse_document(document)
+        let mut ctx = ParseContext::new().with_strict(self.options.strict_mode);
+        self.build()?.parse_with_context(document, &mut ctx)
+    }
+
+    fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<QuestionnaireList>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.build()?.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+
+    fn parse_with_diagnostics(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<QuestionnaireList>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options).with_diagnostics(true);
+        let data = self.build()?.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+}
+
+impl Default for QuestionnaireListParserBuilder {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -6,8 +6,11 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::datetime::JstDate;
+
 /// アンケート一覧の全体情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct QuestionnaireList {
     /// アンケート項目のリスト
@@ -18,6 +21,7 @@ pub struct QuestionnaireList {
 
 /// 個別のアンケート項目
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct QuestionnaireItem {
     /// アンケートタイトル
@@ -26,8 +30,8 @@ pub struct QuestionnaireItem {
     pub subject_name: Option<String>,
     /// 担当教員名（ある場合）
     pub instructor_name: Option<String>,
-    /// 回答期限
-    pub deadline: String,
+    /// 回答期限（表記を解釈できない場合はNone）
+    pub deadline: Option<JstDate>,
     /// 回答状況
     pub response_status: ResponseStatus,
     /// アンケート詳細へのリンク情報
@@ -36,6 +40,7 @@ pub struct QuestionnaireItem {
 
 /// 回答状況の種類
 #[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum ResponseStatus {
     /// 未回答
@@ -54,6 +59,7 @@ impl Default for ResponseStatus {
 
 /// アンケート詳細へのリンク情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct QuestionnaireLink {
     /// リンクのURL
@@ -66,6 +72,7 @@ pub struct QuestionnaireLink {
 
 /// リンクパラメータ
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct LinkParameter {
     /// パラメータ名
@@ -76,6 +83,7 @@ pub struct LinkParameter {
 
 /// ページネーション情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct PaginationInfo {
     /// 現在のページ番号
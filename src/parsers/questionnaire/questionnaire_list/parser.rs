@@ -2,14 +2,28 @@
 //!
 //! HTMLドキュメントからアンケート一覧情報を解析するロジックを担当します。
 
-use scraper::{Html, Selector};
+use scraper::Html;
 
+use crate::common::context::ParseContext;
+use crate::common::selectors::selector_registry;
 use crate::utils::error::ParseError;
 
 use super::model::{
     PaginationInfo, QuestionnaireItem, QuestionnaireLink, QuestionnaireList, ResponseStatus,
 };
 
+selector_registry! {
+    /// アンケート一覧画面で使用するセレクター
+    struct QuestionnaireListSelectors {
+        questionnaire_table: "table.listTable",
+        pagination: ".pagination, .pager, .page-nav, .page-list",
+        page_item: "a, span",
+        hidden_input: "input[type='hidden']",
+        body_row: "tbody tr",
+        cell: "td",
+    }
+}
+
 /// アンケート一覧パーサー実装
 ///
 /// HTMLドキュメントからアンケート一覧の情報を抽出するパースロジックを提供します。
@@ -39,8 +53,31 @@ impl QuestionnaireListParserImpl {
     ///
     /// HTMLの解析に失敗した場合は `ParseError` を返します。
     pub fn parse_document(&self, document: &Html) -> Result<QuestionnaireList, ParseError> {
-        let questionnaires = self.parse_questionnaires(document)?;
-        let pagination = self.parse_pagination(document)?;
+        self.parse_with_context(document, &mut ParseContext::new())
+    }
+
+    /// 警告や診断情報をコンテキストに記録しながらアンケート一覧を解析する
+    ///
+    /// # 引数
+    ///
+    /// * `document` - 解析対象のHTMLドキュメント
+    /// * `ctx` - 警告の記録先（厳密モードでは警告の代わりにエラーを返します）
+    ///
+    /// # 戻り値
+    ///
+    /// 解析されたアンケート一覧情報
+    ///
+    /// # エラー
+    ///
+    /// HTMLの解析に失敗した場合、または厳密モードで一覧テーブルが見つからない・
+    /// 回答状況を解釈できない場合は `ParseError` を返します。
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<QuestionnaireList, ParseError> {
+        let questionnaires = self.parse_questionnaires(document, ctx)?;
+        let pagination = self.parse_pagination(document, ctx)?;
 
         Ok(QuestionnaireList {
             questionnaires,
@@ -53,6 +90,7 @@ impl QuestionnaireListParserImpl {
     /// # 引数
     ///
     /// * `document` - 解析対象のHTMLドキュメント
+    /// * `ctx` - 警告の記録先
     ///
     /// # 戻り値
     ///
@@ -61,21 +99,22 @@ impl QuestionnaireListParserImpl {
     /// # エラー
     ///
     /// テーブル構造の解析に失敗した場合は `ParseError` を返します。
-    fn parse_questionnaires(&self, document: &Html) -> Result<Vec<QuestionnaireItem>, ParseError> {
-        let table_selector = Selector::parse("table.listTable")
-            .map_err(|e| ParseError::selector_creation_failed("table.listTable", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tbody tr")
-            .map_err(|e| ParseError::selector_creation_failed("tbody tr", &e.to_string()))?;
-
-        let cell_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
+    fn parse_questionnaires(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<QuestionnaireItem>, ParseError> {
+        let selectors = QuestionnaireListSelectors::get()?;
         let mut questionnaires = Vec::new();
 
-        if let Some(table) = document.select(&table_selector).next() {
-            for row in table.select(&row_selector) {
-                let cells: Vec<_> = row.select(&cell_selector).collect();
+        ctx.trace_selector(
+            "questionnaires",
+            &selectors.questionnaire_table,
+            document.root_element(),
+        );
+        if let Some(table) = document.select(&selectors.questionnaire_table).next() {
+            for (row_index, row) in table.select(&selectors.body_row).enumerate() {
+                let cells: Vec<_> = row.select(&selectors.cell).collect();
 
                 if cells.len() >= 4 {
                     let title = cells[0].inner_html().trim().to_string();
@@ -88,10 +127,17 @@ impl QuestionnaireListParserImpl {
                             Some(subject_text.to_string())
                         }
                     };
-                    let deadline = cells[2].inner_html().trim().to_string();
+                    let deadline =
+                        ctx.parse_cell("questionnaires", row_index, 2, &cells[2].inner_html())?;
                     let status_html = cells[3].inner_html();
                     let status_text = status_html.trim();
-                    let response_status = self.parse_response_status(status_text)?;
+                    let response_status = match self.parse_response_status(status_text)? {
+                        Some(status) => status,
+                        None => {
+                            ctx.unparseable_cell("questionnaires", row_index, 3, status_text)?;
+                            ResponseStatus::NotAnswered
+                        }
+                    };
 
                     questionnaires.push(QuestionnaireItem {
                         title,
@@ -101,8 +147,12 @@ impl QuestionnaireListParserImpl {
                         response_status,
                         questionnaire_link: QuestionnaireLink::default(),
                     });
+                } else {
+                    ctx.skip_short_row("questionnaires", row_index, cells.len(), 4);
                 }
             }
+        } else {
+            ctx.missing_section("questionnaires", selectors.questionnaire_table.source())?;
         }
 
         Ok(questionnaires)
@@ -116,17 +166,20 @@ impl QuestionnaireListParserImpl {
     ///
     /// # 戻り値
     ///
-    /// 解析された回答状況
+    /// 解析された回答状況（既知の表記でない場合は`None`）
     ///
     /// # エラー
     ///
     /// 回答状況の解析に失敗した場合は `ParseError` を返します。
-    fn parse_response_status(&self, status_text: &str) -> Result<ResponseStatus, ParseError> {
+    fn parse_response_status(
+        &self,
+        status_text: &str,
+    ) -> Result<Option<ResponseStatus>, ParseError> {
         match status_text {
-            s if s.contains("未回答") => Ok(ResponseStatus::NotAnswered),
-            s if s.contains("回答済") => Ok(ResponseStatus::Answered),
-            s if s.contains("期限切れ") => Ok(ResponseStatus::Expired),
-            _ => Ok(ResponseStatus::NotAnswered), // デフォルトは未回答
+            s if s.contains("未回答") => Ok(Some(ResponseStatus::NotAnswered)),
+            s if s.contains("回答済") => Ok(Some(ResponseStatus::Answered)),
+            s if s.contains("期限切れ") => Ok(Some(ResponseStatus::Expired)),
+            _ => Ok(None),
         }
     }
 
@@ -135,6 +188,7 @@ impl QuestionnaireListParserImpl {
     /// # 引数
     ///
     /// * `document` - 解析対象のHTMLドキュメント
+    /// * `ctx` - 警告の記録先
     ///
     /// # 戻り値
     ///
@@ -143,26 +197,21 @@ impl QuestionnaireListParserImpl {
     /// # エラー
     ///
     /// ページネーション構造の解析に失敗した場合は `ParseError` を返します。
-    fn parse_pagination(&self, document: &Html) -> Result<Option<PaginationInfo>, ParseError> {
-        // ページネーション要素を探す
-        let pagination_selector = Selector::parse(".pagination, .pager, .page-nav, .page-list")
-            .map_err(|e| {
-                ParseError::selector_creation_failed(
-                    ".pagination, .pager, .page-nav, .page-list",
-                    &e.to_string(),
-                )
-            })?;
+    fn parse_pagination(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<Option<PaginationInfo>, ParseError> {
+        let selectors = QuestionnaireListSelectors::get()?;
 
-        if let Some(pagination_element) = document.select(&pagination_selector).next() {
+        // ページネーション要素を探す
+        if let Some(pagination_element) = document.select(&selectors.pagination).next() {
             let mut current_page = 1;
 
             // ページ番号リンクを探す
-            let link_selector = Selector::parse("a, span")
-                .map_err(|e| ParseError::selector_creation_failed("a, span", &e.to_string()))?;
-
             let mut page_numbers = Vec::new();
 
-            for element in pagination_element.select(&link_selector) {
+            for element in pagination_element.select(&selectors.page_item) {
                 let element_html = element.inner_html();
                 let text = element_html.trim();
 
@@ -212,7 +261,7 @@ impl QuestionnaireListParserImpl {
         }
 
         // フォーム内の隠しフィールドからページネーション情報を探す
-        self.parse_pagination_from_form(document)
+        self.parse_pagination_from_form(document, ctx)
     }
 
     /// フォームの隠しフィールドからページネーション情報を取得する
@@ -220,6 +269,7 @@ impl QuestionnaireListParserImpl {
     /// # 引数
     ///
     /// * `document` - 解析対象のHTMLドキュメント
+    /// * `ctx` - 警告の記録先
     ///
     /// # 戻り値
     ///
@@ -231,23 +281,33 @@ impl QuestionnaireListParserImpl {
     fn parse_pagination_from_form(
         &self,
         document: &Html,
+        ctx: &mut ParseContext,
     ) -> Result<Option<PaginationInfo>, ParseError> {
-        let hidden_selector = Selector::parse("input[type='hidden']").map_err(|e| {
-            ParseError::selector_creation_failed("input[type='hidden']", &e.to_string())
-        })?;
-
+        let selectors = QuestionnaireListSelectors::get()?;
         let mut current_page = 1;
         let mut total_pages = 1;
 
-        for hidden in document.select(&hidden_selector) {
+        for hidden in document.select(&selectors.hidden_input) {
             if let Some(name) = hidden.value().attr("name") {
                 if let Some(value) = hidden.value().attr("value") {
                     match name {
                         n if n.contains("currentPage") || n.contains("pageNo") => {
-                            current_page = value.parse::<u32>().unwrap_or(1);
+                            current_page = match value.parse::<u32>() {
+                                Ok(page) => page,
+                                Err(_) => {
+                                    ctx.fallback_used("pagination.current_page", "1")?;
+                                    1
+                                }
+                            };
                         }
                         n if n.contains("totalPages") || n.contains("maxPage") => {
-                            total_pages = value.parse::<u32>().unwrap_or(1);
+                            total_pages = match value.parse::<u32>() {
+                                Ok(page) => page,
+                                Err(_) => {
+                                    ctx.fallback_used("pagination.total_pages", "1")?;
+                                    1
+                                }
+                            };
                         }
                         _ => {}
                     }
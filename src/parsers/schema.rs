@@ -0,0 +1,87 @@
+//! 解析結果のJSON Schema
+//!
+//! 各ページ種別のパーサーが返すモデルのJSON Schema（Draft 2020-12）を生成します。
+//! Rust以外のサービスで解析結果を検証する用途を想定しており、モデルのドキュメントコメントは
+//! 各フィールドの`description`として出力されます。
+//!
+//! スキーマはserdeでシリアライズした結果（CLIの`parse`の出力や、`PageData`をJSONにしたもの）に対応します。
+
+use schemars::Schema;
+
+use crate::utils::error::ParseError;
+
+use super::page::PageKind;
+
+/// ページ種別に対応するモデルのJSON Schemaを生成する
+///
+/// ポータルの各表示モードは同じ`Portal`モデルのスキーマを返します。
+/// 対応するパーサーのフィーチャーが無効な場合は`NotImplemented`エラーを返します。
+pub fn json_schema(kind: PageKind) -> Result<Schema, ParseError> {
+    #[allow(unreachable_patterns)]
+    match kind {
+        #[cfg(feature = "portal")]
+        PageKind::Portal
+        | PageKind::PortalAllNotifications
+        | PageKind::PortalClassContact
+        | PageKind::PortalAllClassContact => {
+            Ok(schemars::schema_for!(super::portal::model::Portal))
+        }
+        #[cfg(feature = "portal")]
+        PageKind::NotificationDetail => Ok(schemars::schema_for!(
+            super::portal::notification_detail::model::NotificationDetail
+        )),
+        #[cfg(feature = "grades")]
+        PageKind::GradeInquiry => Ok(schemars::schema_for!(
+            super::grades::grade_inquiry::GradeInquiry
+        )),
+        #[cfg(feature = "grades")]
+        PageKind::GraduationPrediction => Ok(schemars::schema_for!(
+            super::grades::graduation_prediction::SotsugyoMikonHanteiKekka
+        )),
+        #[cfg(feature = "grades")]
+        PageKind::PromotionPrediction => Ok(schemars::schema_for!(
+            super::grades::promotion_prediction::PromotionPredictionData
+        )),
+        #[cfg(feature = "grades")]
+        PageKind::LicensePrediction => Ok(schemars::schema_for!(
+            super::grades::license_prediction::LicensePredictionData
+        )),
+        #[cfg(feature = "syllabus")]
+        PageKind::SyllabusSearchResult => Ok(schemars::schema_for!(
+            super::syllabus::search_result::SyllabusSearchResultPage
+        )),
+        #[cfg(feature = "syllabus")]
+        PageKind::SyllabusView => Ok(schemars::schema_for!(super::syllabus::view::LessonInfo)),
+        #[cfg(feature = "assignments")]
+        PageKind::AssignmentList => Ok(schemars::schema_for!(
+            super::assignment_submit::model::AssignmentList
+        )),
+        #[cfg(feature = "classroom")]
+        PageKind::ClassroomReservationStatus => Ok(schemars::schema_for!(
+            super::classroom_reservation_status::model::ClassroomReservationStatus
+        )),
+        #[cfg(feature = "questionnaire")]
+        PageKind::QuestionnaireList => Ok(schemars::schema_for!(
+            super::questionnaire::questionnaire_list::QuestionnaireList
+        )),
+        #[cfg(feature = "student-info")]
+        PageKind::StudentInfo => Ok(schemars::schema_for!(
+            super::student_info_inquiry::model::StudentInfo
+        )),
+        #[cfg(feature = "tests")]
+        PageKind::TestAnswerStatus => Ok(schemars::schema_for!(
+            super::test_answer_status::model::TestAnswerStatus
+        )),
+        _ => Err(ParseError::NotImplemented {
+            operation: format!("{kind} schema (enable the \"{}\" feature)", kind.feature()),
+        }),
+    }
+}
+
+/// 有効なフィーチャーで解析できるすべてのページ種別のJSON Schemaを生成する
+pub fn json_schemas() -> Vec<(PageKind, Schema)> {
+    PageKind::ALL
+        .into_iter()
+        .filter_map(|kind| json_schema(kind).ok().map(|schema| (kind, schema)))
+        .collect()
+}
@@ -6,16 +6,36 @@ use scraper::Html;
 
 use super::model::StudentInfo;
 use super::parser::StudentInfoInquiryParserImpl;
+use crate::common::context::ParseContext;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 
 /// 学籍情報照会パーサービルダー
-pub struct StudentInfoInquiryParserBuilder;
+pub struct StudentInfoInquiryParserBuilder {
+    /// 解析オプション
+    options: ParseOptions,
+}
 
 impl StudentInfoInquiryParserBuilder {
     /// 新しいパーサービルダーインスタンスを作成
     pub fn new() -> Self {
-        Self
+        Self {
+            options: ParseOptions::default(),
+        }
+    }
+
+    /// 解析オプションを設定
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 厳密なパースモードを設定
+    pub fn with_strict_mode(mut self, strict_mode: bool) -> Self {
+        self.options.strict_mode = strict_mode;
+        self
     }
 
     /// 学籍情報照会パーサーを構築
@@ -24,11 +44,35 @@ impl StudentInfoInquiryParserBuilder {
     }
 }
 
+impl Default for StudentInfoInquiryParserBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PageParser<StudentInfo> for StudentInfoInquiryParserBuilder {
     const PAGE_TYPE: &'static str = "学籍情報照会";
 
     fn parse_document(&self, document: &Html) -> Result<StudentInfo, ParseError> {
-        let parser = self.build()?;
-        parser.parse_document(document)
+        let mut ctx = ParseContext::new().with_strict(self.options.strict_mode);
+        self.build()?.parse_with_context(document, &mut ctx)
+    }
+
+    fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<StudentInfo>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.build()?.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+
+    fn parse_with_diagnostics(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<StudentInfo>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options).with_diagnostics(true);
+        let data = self.build()?.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
     }
 }
@@ -17,32 +17,3 @@ pub mod student_info_inquiry {
     pub use super::builder::StudentInfoInquiryParserBuilder as StudentInfoInquiryParser;
     pub use super::model::*;
 }
-
-use crate::common::traits::PageParser;
-use scraper::Html;
-
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen::prelude::*;
-
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-pub struct StudentInfoInquiryParser {
-    parser: builder::StudentInfoInquiryParserBuilder,
-}
-
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-impl StudentInfoInquiryParser {
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Self {
-        Self {
-            parser: builder::StudentInfoInquiryParserBuilder::new(),
-        }
-    }
-
-    pub fn parse(&self, html_content: &str) -> Result<model::StudentInfo, JsError> {
-        Ok(self
-            .parser
-            .parse_document(&Html::parse_document(html_content))?)
-    }
-}
@@ -6,8 +6,11 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::datetime::JstDate;
+
 /// 学生情報全体を表現する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct StudentInfo {
     /// 基本情報セクション
@@ -22,6 +25,7 @@ pub struct StudentInfo {
 
 /// 学生の基本情報を表現する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct BasicInfo {
     /// 学籍番号
@@ -33,7 +37,7 @@ pub struct BasicInfo {
     /// 性別
     pub gender: String,
     /// 生年月日（例：「2003年01月10日」→「2003-01-10」）
-    pub date_of_birth: String,
+    pub date_of_birth: Option<JstDate>,
     /// 国籍（記載がない場合はNone）
     pub nationality: Option<String>,
     /// PCメールアドレス（記載がない場合はNone）
@@ -51,17 +55,18 @@ pub struct BasicInfo {
     /// カリキュラム対象期
     pub curriculum_target_term: u32,
     /// 入学年月日（例：「2021年04月01日」→「2021-04-01」）
-    pub enrollment_date: String,
+    pub enrollment_date: Option<JstDate>,
     /// 退学年月日（記載がない場合はNone）
-    pub withdrawal_date: Option<String>,
+    pub withdrawal_date: Option<JstDate>,
     /// 卒業予定年月（例：「2026年03月」→「2026-03」）
     pub expected_graduation_month_year: String,
     /// 修了年月日（記載がない場合はNone）
-    pub completion_date: Option<String>,
+    pub completion_date: Option<JstDate>,
 }
 
 /// 学生の所属情報を表現する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct AffiliationInfo {
     /// 所属学部・組織
@@ -80,18 +85,20 @@ pub struct AffiliationInfo {
 
 /// 学生の指導教員情報を表現する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct AdvisorInfo {
     /// 指導教員氏名
     pub advisor_name: String,
     /// 指導開始日（例：「2025年04月01日」→「2025-04-01」）
-    pub advisor_start_date: String,
+    pub advisor_start_date: Option<JstDate>,
     /// 指導終了日（例：「2026年03月31日」→「2026-03-31」）
-    pub advisor_end_date: String,
+    pub advisor_end_date: Option<JstDate>,
 }
 
 /// 学生の学籍変更情報を表現する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct StatusChangeInfo {
     /// 学籍変更履歴のリスト（例：「セメスター進行（2025年04月01日）」→「Semester progression (2025-04-01)」）
@@ -2,11 +2,27 @@
 //!
 //! HTMLドキュメントから学生の学籍情報を抽出するパースロジックを提供します。
 
-use scraper::{Html, Selector};
+use std::str::FromStr;
+
+use scraper::Html;
 
 use super::model::{AdvisorInfo, AffiliationInfo, BasicInfo, StatusChangeInfo, StudentInfo};
+use crate::common::context::ParseContext;
+use crate::common::datetime::JstDate;
+use crate::common::selectors::selector_registry;
 use crate::utils::error::ParseError;
 
+selector_registry! {
+    /// 学籍情報照会画面で使用するセレクター
+    struct StudentInfoSelectors {
+        base_table: "#baseTable",
+        sub_title_area: ".subTitleArea",
+        row: "tr",
+        header: "th",
+        cell: "td",
+    }
+}
+
 /// 学籍情報照会パーサー実装
 pub struct StudentInfoInquiryParserImpl;
 
@@ -18,9 +34,18 @@ impl StudentInfoInquiryParserImpl {
 
     /// HTMLドキュメントから学生情報を解析する
     pub fn parse_document(&self, document: &Html) -> Result<StudentInfo, ParseError> {
-        let basic_info = self.parse_basic_info(document)?;
-        let affiliation_info = self.parse_affiliation_info(document)?;
-        let advisor_info = self.parse_advisor_info(document)?;
+        self.parse_with_context(document, &mut ParseContext::new())
+    }
+
+    /// 警告をコンテキストに記録しながら学生情報を解析する
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<StudentInfo, ParseError> {
+        let basic_info = self.parse_basic_info(document, ctx)?;
+        let affiliation_info = self.parse_affiliation_info(document, ctx)?;
+        let advisor_info = self.parse_advisor_info(document, ctx)?;
         let status_change_info = self.parse_status_change_info(document)?;
 
         Ok(StudentInfo {
@@ -32,29 +57,24 @@ impl StudentInfoInquiryParserImpl {
     }
 
     /// 基本情報セクションをパースする
-    fn parse_basic_info(&self, document: &Html) -> Result<BasicInfo, ParseError> {
-        let base_table_selector = Selector::parse("#baseTable")
-            .map_err(|e| ParseError::selector_creation_failed("#baseTable", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tr")
-            .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-
-        let th_selector = Selector::parse("th")
-            .map_err(|e| ParseError::selector_creation_failed("th", &e.to_string()))?;
-
-        let td_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
+    fn parse_basic_info(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<BasicInfo, ParseError> {
+        let selectors = StudentInfoSelectors::get()?;
+
+        ctx.trace_selector("basic_info", &selectors.base_table, document.root_element());
         let base_table = document
-            .select(&base_table_selector)
+            .select(&selectors.base_table)
             .next()
             .ok_or_else(|| ParseError::element_not_found("#baseTable", "基本情報テーブル"))?;
 
         let mut basic_info = BasicInfo::default();
 
-        for row in base_table.select(&row_selector) {
-            let th = row.select(&th_selector).next();
-            let td = row.select(&td_selector).next();
+        for (row_index, row) in base_table.select(&selectors.row).enumerate() {
+            let th = row.select(&selectors.header).next();
+            let td = row.select(&selectors.cell).next();
 
             if let (Some(th), Some(td)) = (th, td) {
                 let label = th.inner_html().trim().to_string();
@@ -65,7 +85,10 @@ impl StudentInfoInquiryParserImpl {
                     "学生氏名" => basic_info.student_name = value,
                     "カナ氏名" => basic_info.kana_name = value,
                     "性別" => basic_info.gender = value,
-                    "生年月日" => basic_info.date_of_birth = self.format_date(&value)?,
+                    "生年月日" => {
+                        basic_info.date_of_birth =
+                            self.parse_date(&value, "basic_info", row_index, ctx)?
+                    }
                     "国籍" => {
                         basic_info.nationality = if value.is_empty() { None } else { Some(value) }
                     }
@@ -75,32 +98,37 @@ impl StudentInfoInquiryParserImpl {
                     }
                     "入学種別" => basic_info.enrollment_type = value,
                     "就学種別" => basic_info.student_status_type = value,
-                    "入学年度" => basic_info.enrollment_year = value.parse().unwrap_or(0),
-                    "入学期NO" => basic_info.enrollment_term_no = value.parse().unwrap_or(0),
+                    "入学年度" => {
+                        basic_info.enrollment_year =
+                            self.parse_number(&value, "basic_info", row_index, ctx)?
+                    }
+                    "入学期NO" => {
+                        basic_info.enrollment_term_no =
+                            self.parse_number(&value, "basic_info", row_index, ctx)?
+                    }
                     "カリキュラム対象年度" => {
-                        basic_info.curriculum_target_year = value.parse().unwrap_or(0)
+                        basic_info.curriculum_target_year =
+                            self.parse_number(&value, "basic_info", row_index, ctx)?
                     }
                     "カリキュラム対象学期" => {
-                        basic_info.curriculum_target_term = value.parse().unwrap_or(0)
+                        basic_info.curriculum_target_term =
+                            self.parse_number(&value, "basic_info", row_index, ctx)?
+                    }
+                    "入学日付" => {
+                        basic_info.enrollment_date =
+                            self.parse_date(&value, "basic_info", row_index, ctx)?
                     }
-                    "入学日付" => basic_info.enrollment_date = self.format_date(&value)?,
                     "出学日付" => {
-                        basic_info.withdrawal_date = if value.is_empty() {
-                            None
-                        } else {
-                            Some(self.format_date(&value)?)
-                        }
+                        basic_info.withdrawal_date =
+                            self.parse_date(&value, "basic_info", row_index, ctx)?
                     }
                     "卒業予定年月" => {
                         basic_info.expected_graduation_month_year =
                             self.format_year_month(&value)?
                     }
                     "修了予定日" => {
-                        basic_info.completion_date = if value.is_empty() {
-                            None
-                        } else {
-                            Some(self.format_date(&value)?)
-                        }
+                        basic_info.completion_date =
+                            self.parse_date(&value, "basic_info", row_index, ctx)?
                     }
                     _ => {} // Unknown field, ignore
                 }
@@ -111,35 +139,37 @@ impl StudentInfoInquiryParserImpl {
     }
 
     /// 所属情報セクションをパースする
-    fn parse_affiliation_info(&self, document: &Html) -> Result<AffiliationInfo, ParseError> {
-        let selector = Selector::parse(".subTitleArea")
-            .map_err(|e| ParseError::selector_creation_failed(".subTitleArea", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tr")
-            .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-
-        let th_selector = Selector::parse("th")
-            .map_err(|e| ParseError::selector_creation_failed("th", &e.to_string()))?;
-
-        let td_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
+    fn parse_affiliation_info(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<AffiliationInfo, ParseError> {
+        let selectors = StudentInfoSelectors::get()?;
 
         // Find the 所属情報 section - look for tables after the subtitle
         let mut affiliation_info = AffiliationInfo::default();
-
-        for element in document.select(&selector) {
+        let mut section_found = false;
+
+        ctx.trace_selector(
+            "affiliation_info",
+            &selectors.sub_title_area,
+            document.root_element(),
+        );
+        for element in document.select(&selectors.sub_title_area) {
             if element.inner_html().trim() == "所属情報" {
+                section_found = true;
                 // Find the next table after this subtitle
                 let mut current = element.next_sibling();
                 while let Some(node) = current {
                     if let Some(table_element) = scraper::ElementRef::wrap(node) {
                         if table_element.value().name() == "table"
-                            || table_element.select(&row_selector).next().is_some()
+                            || table_element.select(&selectors.row).next().is_some()
                         {
                             // Found a table, parse its rows
-                            for row in table_element.select(&row_selector) {
-                                let th = row.select(&th_selector).next();
-                                let td = row.select(&td_selector).next();
+                            for (row_index, row) in table_element.select(&selectors.row).enumerate()
+                            {
+                                let th = row.select(&selectors.header).next();
+                                let td = row.select(&selectors.cell).next();
 
                                 if let (Some(th), Some(td)) = (th, td) {
                                     let label = th.inner_html().trim().to_string();
@@ -155,11 +185,20 @@ impl StudentInfoInquiryParserImpl {
                                                 value
                                         }
                                         "学年" => {
-                                            affiliation_info.grade_level =
-                                                value.parse().unwrap_or(0)
+                                            affiliation_info.grade_level = self.parse_number(
+                                                &value,
+                                                "affiliation_info",
+                                                row_index,
+                                                ctx,
+                                            )?
                                         }
                                         "セメスタ" => {
-                                            affiliation_info.semester = value.parse().unwrap_or(0)
+                                            affiliation_info.semester = self.parse_number(
+                                                &value,
+                                                "affiliation_info",
+                                                row_index,
+                                                ctx,
+                                            )?
                                         }
                                         "専攻コース" => {
                                             affiliation_info.major_course =
@@ -181,39 +220,55 @@ impl StudentInfoInquiryParserImpl {
             }
         }
 
+        if !section_found {
+            ctx.missing_section("affiliation_info", selectors.sub_title_area.source())?;
+        }
+
         Ok(affiliation_info)
     }
 
-    /// 指導教員情報セクションをパースする
-    fn parse_advisor_info(&self, document: &Html) -> Result<AdvisorInfo, ParseError> {
-        let selector = Selector::parse(".subTitleArea")
-            .map_err(|e| ParseError::selector_creation_failed(".subTitleArea", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tr")
-            .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-
-        let th_selector = Selector::parse("th")
-            .map_err(|e| ParseError::selector_creation_failed("th", &e.to_string()))?;
+    /// 数値セルをパースする（解釈できない場合は0として警告を記録）
+    fn parse_number<N: FromStr + Default>(
+        &self,
+        value: &str,
+        section: &str,
+        row: usize,
+        ctx: &mut ParseContext,
+    ) -> Result<N, ParseError> {
+        match value.parse() {
+            Ok(number) => Ok(number),
+            Err(_) => {
+                ctx.unparseable_cell(section, row, 1, value)?;
+                Ok(N::default())
+            }
+        }
+    }
 
-        let td_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
+    /// 指導教員情報セクションをパースする
+    fn parse_advisor_info(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<AdvisorInfo, ParseError> {
+        let selectors = StudentInfoSelectors::get()?;
 
         // Find the 担当教員 section - look for tables after the subtitle
         let mut advisor_info = AdvisorInfo::default();
 
-        for element in document.select(&selector) {
+        for element in document.select(&selectors.sub_title_area) {
             if element.inner_html().trim() == "担当教員" {
                 // Find the next table after this subtitle
                 let mut current = element.next_sibling();
                 while let Some(node) = current {
                     if let Some(table_element) = scraper::ElementRef::wrap(node) {
                         if table_element.value().name() == "table"
-                            || table_element.select(&row_selector).next().is_some()
+                            || table_element.select(&selectors.row).next().is_some()
                         {
                             // Found a table, parse its rows
-                            for row in table_element.select(&row_selector) {
-                                let th = row.select(&th_selector).next();
-                                let td = row.select(&td_selector).next();
+                            for (row_index, row) in table_element.select(&selectors.row).enumerate()
+                            {
+                                let th = row.select(&selectors.header).next();
+                                let td = row.select(&selectors.cell).next();
 
                                 if let (Some(th), Some(td)) = (th, td) {
                                     let label = th.inner_html().trim().to_string();
@@ -223,12 +278,20 @@ impl StudentInfoInquiryParserImpl {
                                     match label.as_str() {
                                         "担当教員名" => advisor_info.advisor_name = value,
                                         "担当開始日" => {
-                                            advisor_info.advisor_start_date =
-                                                self.format_date(&value)?
+                                            advisor_info.advisor_start_date = self.parse_date(
+                                                &value,
+                                                "advisor_info",
+                                                row_index,
+                                                ctx,
+                                            )?
                                         }
                                         "担当終了日" => {
-                                            advisor_info.advisor_end_date =
-                                                self.format_date(&value)?
+                                            advisor_info.advisor_end_date = self.parse_date(
+                                                &value,
+                                                "advisor_info",
+                                                row_index,
+                                                ctx,
+                                            )?
                                         }
                                         _ => {} // Unknown field, ignore
                                     }
@@ -248,34 +311,24 @@ impl StudentInfoInquiryParserImpl {
 
     /// 学籍変更情報セクションをパースする
     fn parse_status_change_info(&self, document: &Html) -> Result<StatusChangeInfo, ParseError> {
-        let selector = Selector::parse(".subTitleArea")
-            .map_err(|e| ParseError::selector_creation_failed(".subTitleArea", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tr")
-            .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-
-        let th_selector = Selector::parse("th")
-            .map_err(|e| ParseError::selector_creation_failed("th", &e.to_string()))?;
-
-        let td_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
+        let selectors = StudentInfoSelectors::get()?;
 
         // Find the 異動情報 section - look for tables after the subtitle
         let mut status_change_info = StatusChangeInfo::default();
 
-        for element in document.select(&selector) {
+        for element in document.select(&selectors.sub_title_area) {
             if element.inner_html().trim() == "異動情報" {
                 // Find the next table after this subtitle
                 let mut current = element.next_sibling();
                 while let Some(node) = current {
                     if let Some(table_element) = scraper::ElementRef::wrap(node) {
                         if table_element.value().name() == "table"
-                            || table_element.select(&row_selector).next().is_some()
+                            || table_element.select(&selectors.row).next().is_some()
                         {
                             // Found a table, parse its rows
-                            for row in table_element.select(&row_selector) {
-                                let th = row.select(&th_selector).next();
-                                let td = row.select(&td_selector).next();
+                            for row in table_element.select(&selectors.row) {
+                                let th = row.select(&selectors.header).next();
+                                let td = row.select(&selectors.cell).next();
 
                                 if let (Some(th), Some(td)) = (th, td) {
                                     let label = th.inner_html().trim().to_string();
@@ -311,27 +364,15 @@ impl StudentInfoInquiryParserImpl {
         Ok(status_change_info)
     }
 
-    /// 日付形式を変換する（例：「2003年01月10日」→「2003-01-10」）
-    fn format_date(&self, date_str: &str) -> Result<String, ParseError> {
-        if date_str.is_empty() {
-            return Ok(String::new());
-        }
-
-        // Remove any HTML tags and trim
-        let clean_date = date_str.replace("<BR>", "").trim().to_string();
-
-        // Convert Japanese date format to ISO format
-        if let Some(captures) = regex_lite::Regex::new(r"(\d{4})年(\d{1,2})月(\d{1,2})日")
-            .map_err(|e| ParseError::data_parsing_failed("date regex", &e.to_string()))?
-            .captures(&clean_date)
-        {
-            let year = &captures[1];
-            let month = format!("{:02}", captures[2].parse::<u32>().unwrap_or(1));
-            let day = format!("{:02}", captures[3].parse::<u32>().unwrap_or(1));
-            Ok(format!("{}-{}-{}", year, month, day))
-        } else {
-            Ok(clean_date)
-        }
+    /// 日付セルをパースする（空欄は`None`、解釈できない場合は警告を記録して`None`）
+    fn parse_date(
+        &self,
+        value: &str,
+        section: &str,
+        row: usize,
+        ctx: &mut ParseContext,
+    ) -> Result<Option<JstDate>, ParseError> {
+        ctx.parse_cell(section, row, 1, &value.replace("<BR>", ""))
     }
 
     /// 年月形式を変換する（例：「2026年03月」→「2026-03」）
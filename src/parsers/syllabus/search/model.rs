@@ -10,6 +10,7 @@ use tsify::Tsify;
 /// UNIVERSAL PASSPORT EXのシラバス検索で使用可能な全ての検索条件を含みます。
 /// すべてのフィールドはオプショナルで、指定されない場合は検索条件から除外されます。
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct SyllabusSearchForm {
     /// 管理部署名
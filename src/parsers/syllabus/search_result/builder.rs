@@ -3,6 +3,7 @@
 //! ビルダーパターンを使用してシラバス検索結果パーサーを構築します。
 
 use super::parser::SyllabusSearchResultParser;
+use crate::common::options::ParseOptions;
 use crate::utils::error::ParseError;
 
 /// シラバス検索結果パーサービルダー
@@ -11,8 +12,7 @@ use crate::utils::error::ParseError;
 /// 現在は設定オプションは少ないですが、将来の拡張を想定した構造になっています。
 #[derive(Default, Debug)]
 pub struct SyllabusSearchResultParserBuilder {
-    debug_mode: bool,
-    strict_mode: bool,
+    options: ParseOptions,
 }
 
 impl SyllabusSearchResultParserBuilder {
@@ -21,28 +21,30 @@ impl SyllabusSearchResultParserBuilder {
         Self::default()
     }
 
+    /// 解析オプションを設定する
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// デバッグモードを有効にする
     ///
-    /// デバッグモードでは、パース処理の詳細ログが出力されます。
-    /// 現在は実装されていませんが、将来のデバッグ機能拡張用です。
+    /// デバッグモードでは、警告付きの解析結果に診断情報を含めます。
     pub fn debug_mode(mut self, enabled: bool) -> Self {
-        self.debug_mode = enabled;
+        self.options.debug_mode = enabled;
         self
     }
 
     /// 厳密モードを有効にする
     ///
-    /// 厳密モードでは、HTMLの構造に厳格な検証を行います。
-    /// 現在は実装されていませんが、将来の検証機能拡張用です。
+    /// 厳密モードでは、ページ番号を読み取れずに1で補う場合もエラーになります。
     pub fn strict_mode(mut self, enabled: bool) -> Self {
-        self.strict_mode = enabled;
+        self.options.strict_mode = enabled;
         self
     }
 
     /// ビルドしてSyllabusSearchResultParserを作成
     pub fn build(self) -> Result<SyllabusSearchResultParser, ParseError> {
-        // 現在はビルダーの設定値は使用していませんが、将来の拡張用に保持
-        // 将来的にはdebug_modeやstrict_modeをパーサーに渡すことが可能です
-        SyllabusSearchResultParser::new()
+        Ok(SyllabusSearchResultParser::new()?.with_options(self.options))
     }
 }
@@ -6,8 +6,12 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::period::ClassTime;
+use crate::common::term::AcademicTerm;
+
 /// シラバス検索結果のページ全体を表現する構造体
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct SyllabusSearchResultPage {
     /// 検索条件の表示情報
@@ -24,10 +28,11 @@ pub struct SyllabusSearchResultPage {
 
 /// 検索条件の表示情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct SearchConditions {
-    /// 開講年度／学期
-    pub academic_year_semester: String,
+    /// 開講年度／学期（解釈できない場合は`None`）
+    pub term: Option<AcademicTerm>,
     /// 科目名
     pub subject_name: Option<String>,
     /// 学科・コース／専攻
@@ -36,6 +41,7 @@ pub struct SearchConditions {
 
 /// 検索結果のメタ情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ResultMetadata {
     /// 総件数
@@ -48,10 +54,13 @@ pub struct ResultMetadata {
 
 /// 個別の科目エントリ
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct CourseEntry {
     /// 開講曜日
     pub schedule_day_period: String,
+    /// 開講曜日から求めた曜日・時限と開始・終了時刻（空欄または解釈できない場合はNone）
+    pub class_time: Option<ClassTime>,
     /// 科目コードと科目名
     pub course_code_and_name: String,
     /// 教員氏名
@@ -60,8 +69,8 @@ pub struct CourseEntry {
     pub course_type: String,
     /// 学年
     pub target_grade: Option<String>,
-    /// 開講学期
-    pub semester: String,
+    /// 開講年度・学期（年度は検索条件の開講年度）
+    pub term: Option<AcademicTerm>,
     /// 単位数
     pub credits: String,
     /// シラバス詳細へのリンク情報
@@ -70,6 +79,7 @@ pub struct CourseEntry {
 
 /// シラバス詳細へのリンク情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct SyllabusLinkInfo {
     /// リンクのID（例：「form1:htmlKekkatable:0:edit」）
@@ -82,6 +92,7 @@ pub struct SyllabusLinkInfo {
 
 /// ページネーション情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct PaginationInfo {
     /// 先頭ページへのボタン状態
@@ -100,6 +111,7 @@ pub struct PaginationInfo {
 
 /// フォーム情報（戻るボタンや隠しフィールド用）
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct FormInfo {
     /// フォームのaction属性値
@@ -114,6 +126,7 @@ pub struct FormInfo {
 
 /// 隠しフィールド情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct HiddenField {
     /// フィールド名
@@ -3,37 +3,103 @@
 //! UNIVERSAL PASSPORT EXのシラバス検索結果ページから各種情報を抽出するパーサーロジックを提供します。
 
 use super::model::*;
+use crate::common::context::ParseContext;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::selectors::selector_registry;
+use crate::common::term::AcademicTerm;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
-use scraper::{Html, Selector};
+use scraper::Html;
+
+selector_registry! {
+    /// シラバス検索結果画面で使用するセレクター
+    struct SyllabusSearchResultSelectors {
+        search_conditions: "#form1\\:htmlKensakuJyoken",
+        total_count: "#form1\\:htmlKekkatable\\:htmlGokeiKensu",
+        pager_text: "#form1\\:htmlKekkatable\\:deluxe1__pagerText",
+        course_row: "#form1\\:htmlKekkatable tbody tr.rowClass1",
+        schedule: "td.yobi span",
+        course_link: "td.kamokuName a",
+        instructor: "td.kyoin span",
+        course_type: "td.kubun span",
+        grade: "td.gakunen span",
+        semester: "td.gakki span",
+        credits: "td.tani span",
+        span: "span",
+        current_page: "#form1\\:htmlKekkatable\\:web1 strong",
+        first_button: "#form1\\:htmlKekkatable\\:deluxe1__pagerFirst",
+        previous_button: "#form1\\:htmlKekkatable\\:deluxe1__pagerPrevious",
+        next_button: "#form1\\:htmlKekkatable\\:deluxe1__pagerNext",
+        last_button: "#form1\\:htmlKekkatable\\:deluxe1__pagerLast",
+        form: "#form1",
+        hidden_input: "input[type=\"hidden\"]",
+    }
+}
 
 /// シラバス検索結果パーサー
-pub struct SyllabusSearchResultParser;
+pub struct SyllabusSearchResultParser {
+    options: ParseOptions,
+}
 
 impl SyllabusSearchResultParser {
     /// 新しいパーサーインスタンスを作成
     pub fn new() -> Result<Self, ParseError> {
-        Ok(Self)
+        Ok(Self {
+            options: ParseOptions::default(),
+        })
     }
 
-    /// 検索条件を解析
-    fn parse_search_conditions(&self, document: &Html) -> Result<SearchConditions, ParseError> {
-        let selector = Selector::parse("#form1\\:htmlKensakuJyoken").map_err(|e| {
-            ParseError::selector_creation_failed("#form1:htmlKensakuJyoken", &e.to_string())
-        })?;
+    /// 解析オプションを設定する
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
 
-        let element = document.select(&selector).next().ok_or_else(|| {
-            ParseError::element_not_found(
-                "#form1:htmlKensakuJyoken",
-                "検索条件要素が見つかりません",
-            )
-        })?;
+    /// 警告をコンテキストに記録しながら検索結果を解析する
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<SyllabusSearchResultPage, ParseError> {
+        let search_conditions = self.parse_search_conditions(document, ctx)?;
+        let result_metadata = self.parse_result_metadata(document, ctx)?;
+        let year = search_conditions.term.map(|term| term.year);
+        let course_entries = self.parse_course_entries(document, year, ctx)?;
+        let pagination = self.parse_pagination(document, ctx)?;
+        let form_info = self.parse_form_info(document)?;
+
+        Ok(SyllabusSearchResultPage {
+            search_conditions,
+            result_metadata,
+            course_entries,
+            pagination,
+            form_info,
+        })
+    }
+
+    /// 検索条件を解析
+    fn parse_search_conditions(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<SearchConditions, ParseError> {
+        let selectors = SyllabusSearchResultSelectors::get()?;
+        let element = document
+            .select(&selectors.search_conditions)
+            .next()
+            .ok_or_else(|| {
+                ParseError::element_not_found(
+                    "#form1:htmlKensakuJyoken",
+                    "検索条件要素が見つかりません",
+                )
+            })?;
 
         let text = element.inner_html();
         // <BR>と<br>の両方に対応
         let lines: Vec<&str> = text.split("<BR>").flat_map(|s| s.split("<br>")).collect();
 
-        let mut academic_year_semester = String::new();
+        let mut term = None;
         let mut subject_name = None;
         let mut department_course = None;
 
@@ -48,10 +114,8 @@ impl SyllabusSearchResultParser {
                 .join(" ");
 
             if clean_line.starts_with("開講年度／学期：") {
-                academic_year_semester = clean_line
-                    .replace("開講年度／学期：", "")
-                    .trim()
-                    .to_string();
+                let value = clean_line.replace("開講年度／学期：", "");
+                term = ctx.parse_cell("search_conditions", 0, 0, &value)?;
             } else if clean_line.starts_with("科目名：") {
                 let name = clean_line.replace("科目名：", "").trim().to_string();
                 if !name.is_empty() {
@@ -69,30 +133,31 @@ impl SyllabusSearchResultParser {
         }
 
         Ok(SearchConditions {
-            academic_year_semester,
+            term,
             subject_name,
             department_course,
         })
     }
 
     /// 検索結果のメタ情報を解析
-    fn parse_result_metadata(&self, document: &Html) -> Result<ResultMetadata, ParseError> {
+    fn parse_result_metadata(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<ResultMetadata, ParseError> {
+        let selectors = SyllabusSearchResultSelectors::get()?;
+
         // 総件数を取得
-        let count_selector =
-            Selector::parse("#form1\\:htmlKekkatable\\:htmlGokeiKensu").map_err(|e| {
-                ParseError::selector_creation_failed(
+        let count_element = document
+            .select(&selectors.total_count)
+            .next()
+            .ok_or_else(|| {
+                ParseError::element_not_found(
                     "#form1:htmlKekkatable:htmlGokeiKensu",
-                    &e.to_string(),
+                    "総件数要素が見つかりません",
                 )
             })?;
 
-        let count_element = document.select(&count_selector).next().ok_or_else(|| {
-            ParseError::element_not_found(
-                "#form1:htmlKekkatable:htmlGokeiKensu",
-                "総件数要素が見つかりません",
-            )
-        })?;
-
         let count_text = count_element.inner_html();
         let total_count = count_text
             .replace("件", "")
@@ -101,39 +166,35 @@ impl SyllabusSearchResultParser {
             .map_err(|_| ParseError::data_parsing_failed("総件数", &count_text))?;
 
         // ページ情報を取得
-        let page_selector = Selector::parse("#form1\\:htmlKekkatable\\:deluxe1__pagerText")
-            .map_err(|e| {
-                ParseError::selector_creation_failed(
+        let page_element = document
+            .select(&selectors.pager_text)
+            .next()
+            .ok_or_else(|| {
+                ParseError::element_not_found(
                     "#form1:htmlKekkatable:deluxe1__pagerText",
-                    &e.to_string(),
+                    "ページ情報要素が見つかりません",
                 )
             })?;
 
-        let page_element = document.select(&page_selector).next().ok_or_else(|| {
-            ParseError::element_not_found(
-                "#form1:htmlKekkatable:deluxe1__pagerText",
-                "ページ情報要素が見つかりません",
-            )
-        })?;
-
         let page_text = page_element.inner_html();
         let parts: Vec<&str> = page_text.split('/').collect();
 
-        let current_page = if parts.len() >= 2 {
-            parts[0].trim().parse::<u32>().unwrap_or(1)
-        } else {
-            1
-        };
+        let current_page = parts
+            .first()
+            .filter(|_| parts.len() >= 2)
+            .and_then(|part| part.trim().parse::<u32>().ok());
+        let total_pages = parts
+            .get(1)
+            .and_then(|part| part.replace(" ページ", "").trim().parse::<u32>().ok());
 
-        let total_pages = if parts.len() >= 2 {
-            parts[1]
-                .replace(" ページ", "")
-                .trim()
-                .parse::<u32>()
-                .unwrap_or(1)
-        } else {
-            1
-        };
+        if current_page.is_none() {
+            ctx.fallback_used("result_metadata.current_page", "1")?;
+        }
+        if total_pages.is_none() {
+            ctx.fallback_used("result_metadata.total_pages", "1")?;
+        }
+        let current_page = current_page.unwrap_or(1);
+        let total_pages = total_pages.unwrap_or(1);
 
         Ok(ResultMetadata {
             total_count,
@@ -143,19 +204,19 @@ impl SyllabusSearchResultParser {
     }
 
     /// 科目エントリ一覧を解析
-    fn parse_course_entries(&self, document: &Html) -> Result<Vec<CourseEntry>, ParseError> {
-        let row_selector =
-            Selector::parse("#form1\\:htmlKekkatable tbody tr.rowClass1").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:htmlKekkatable tbody tr.rowClass1",
-                    &e.to_string(),
-                )
-            })?;
-
+    ///
+    /// 各行の開講学期は`year`（検索条件の開講年度）の学期として解釈します。
+    fn parse_course_entries(
+        &self,
+        document: &Html,
+        year: Option<u32>,
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<CourseEntry>, ParseError> {
+        let selectors = SyllabusSearchResultSelectors::get()?;
         let mut entries = Vec::new();
 
-        for row in document.select(&row_selector) {
-            let entry = self.parse_course_entry(&row)?;
+        for (row_index, row) in document.select(&selectors.course_row).enumerate() {
+            let entry = self.parse_course_entry(&row, row_index, year, ctx)?;
             // 空のエントリをフィルタリング（科目名が空の場合はスキップ）
             if !entry.course_code_and_name.trim().is_empty() {
                 entries.push(entry);
@@ -166,12 +227,18 @@ impl SyllabusSearchResultParser {
     }
 
     /// 個別の科目エントリを解析
-    fn parse_course_entry(&self, row: &scraper::ElementRef) -> Result<CourseEntry, ParseError> {
+    fn parse_course_entry(
+        &self,
+        row: &scraper::ElementRef,
+        row_index: usize,
+        year: Option<u32>,
+        ctx: &mut ParseContext,
+    ) -> Result<CourseEntry, ParseError> {
+        let selectors = SyllabusSearchResultSelectors::get()?;
+
         // 開講曜日
-        let schedule_selector = Selector::parse("td.yobi span")
-            .map_err(|e| ParseError::selector_creation_failed("td.yobi span", &e.to_string()))?;
         let schedule_day_period = row
-            .select(&schedule_selector)
+            .select(&selectors.schedule)
             .next()
             .map(|e| {
                 e.inner_html()
@@ -181,15 +248,15 @@ impl SyllabusSearchResultParser {
                     .to_string()
             })
             .unwrap_or_default();
+        let class_time =
+            ctx.parse_class_time("course_entries", row_index, 0, &schedule_day_period)?;
 
         // 科目名とリンク情報
-        let link_selector = Selector::parse("td.kamokuName a")
-            .map_err(|e| ParseError::selector_creation_failed("td.kamokuName a", &e.to_string()))?;
-        let link_element = row.select(&link_selector).next();
+        let link_element = row.select(&selectors.course_link).next();
 
         let (course_code_and_name, syllabus_link) = if let Some(link) = link_element {
             let course_name = link
-                .select(&Selector::parse("span").unwrap())
+                .select(&selectors.span)
                 .next()
                 .map(|e| e.inner_html())
                 .unwrap_or_default();
@@ -210,19 +277,15 @@ impl SyllabusSearchResultParser {
         };
 
         // 教員氏名
-        let instructor_selector = Selector::parse("td.kyoin span")
-            .map_err(|e| ParseError::selector_creation_failed("td.kyoin span", &e.to_string()))?;
         let instructor_names = row
-            .select(&instructor_selector)
+            .select(&selectors.instructor)
             .next()
             .map(|e| e.inner_html())
             .unwrap_or_default();
 
         // 開講区分
-        let course_type_selector = Selector::parse("td.kubun span")
-            .map_err(|e| ParseError::selector_creation_failed("td.kubun span", &e.to_string()))?;
         let course_type = row
-            .select(&course_type_selector)
+            .select(&selectors.course_type)
             .next()
             .map(|e| {
                 e.inner_html()
@@ -234,19 +297,15 @@ impl SyllabusSearchResultParser {
             .unwrap_or_default();
 
         // 学年
-        let grade_selector = Selector::parse("td.gakunen span")
-            .map_err(|e| ParseError::selector_creation_failed("td.gakunen span", &e.to_string()))?;
         let target_grade = row
-            .select(&grade_selector)
+            .select(&selectors.grade)
             .next()
             .map(|e| e.inner_html().trim().to_string())
             .filter(|s| !s.is_empty() && s != "　");
 
         // 開講学期
-        let semester_selector = Selector::parse("td.gakki span")
-            .map_err(|e| ParseError::selector_creation_failed("td.gakki span", &e.to_string()))?;
         let semester = row
-            .select(&semester_selector)
+            .select(&selectors.semester)
             .next()
             .map(|e| {
                 e.inner_html()
@@ -256,107 +315,88 @@ impl SyllabusSearchResultParser {
                     .to_string()
             })
             .unwrap_or_default();
+        let term = if semester.is_empty() || semester == "&nbsp;" {
+            None
+        } else {
+            let term = match year {
+                Some(year) => AcademicTerm::parse_in_year(&semester, year).ok(),
+                None => AcademicTerm::parse(&semester).ok(),
+            };
+            if term.is_none() {
+                ctx.unparseable_cell("course_entries", row_index, 5, &semester)?;
+            }
+            term
+        };
 
         // 単位数
-        let credits_selector = Selector::parse("td.tani span")
-            .map_err(|e| ParseError::selector_creation_failed("td.tani span", &e.to_string()))?;
         let credits = row
-            .select(&credits_selector)
+            .select(&selectors.credits)
             .next()
             .map(|e| e.inner_html())
             .unwrap_or_default();
 
         Ok(CourseEntry {
             schedule_day_period,
+            class_time,
             course_code_and_name,
             instructor_names,
             course_type,
             target_grade,
-            semester,
+            term,
             credits,
             syllabus_link,
         })
     }
 
     /// ページネーション情報を解析
-    fn parse_pagination(&self, document: &Html) -> Result<PaginationInfo, ParseError> {
+    fn parse_pagination(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<PaginationInfo, ParseError> {
+        let selectors = SyllabusSearchResultSelectors::get()?;
+
         // ページ表示テキスト
-        let page_text_selector = Selector::parse("#form1\\:htmlKekkatable\\:deluxe1__pagerText")
-            .map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:htmlKekkatable:deluxe1__pagerText",
-                    &e.to_string(),
-                )
-            })?;
         let page_display_text = document
-            .select(&page_text_selector)
+            .select(&selectors.pager_text)
             .next()
             .map(|e| e.inner_html())
             .unwrap_or_default();
 
         // 現在のページ番号
-        let current_page_selector = Selector::parse("#form1\\:htmlKekkatable\\:web1 strong")
-            .map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:htmlKekkatable:web1 strong",
-                    &e.to_string(),
-                )
-            })?;
-        let current_page_number = document
-            .select(&current_page_selector)
+        let current_page_number = match document
+            .select(&selectors.current_page)
             .next()
-            .map(|e| e.inner_html().parse::<u32>().unwrap_or(1))
-            .unwrap_or(1);
+            .and_then(|e| e.inner_html().parse::<u32>().ok())
+        {
+            Some(number) => number,
+            None => {
+                ctx.fallback_used("pagination.current_page_number", "1")?;
+                1
+            }
+        };
 
         // ボタンの状態を確認
-        let first_button_selector =
-            Selector::parse("#form1\\:htmlKekkatable\\:deluxe1__pagerFirst").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:htmlKekkatable:deluxe1__pagerFirst",
-                    &e.to_string(),
-                )
-            })?;
         let first_button_enabled = document
-            .select(&first_button_selector)
+            .select(&selectors.first_button)
             .next()
             .map(|e| !e.value().attr("disabled").is_some())
             .unwrap_or(false);
 
-        let previous_button_selector =
-            Selector::parse("#form1\\:htmlKekkatable\\:deluxe1__pagerPrevious").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:htmlKekkatable:deluxe1__pagerPrevious",
-                    &e.to_string(),
-                )
-            })?;
         let previous_button_enabled = document
-            .select(&previous_button_selector)
+            .select(&selectors.previous_button)
             .next()
             .map(|e| !e.value().attr("disabled").is_some())
             .unwrap_or(false);
 
-        let next_button_selector = Selector::parse("#form1\\:htmlKekkatable\\:deluxe1__pagerNext")
-            .map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:htmlKekkatable:deluxe1__pagerNext",
-                    &e.to_string(),
-                )
-            })?;
         let next_button_enabled = document
-            .select(&next_button_selector)
+            .select(&selectors.next_button)
             .next()
             .map(|e| !e.value().attr("disabled").is_some())
             .unwrap_or(false);
 
-        let last_button_selector = Selector::parse("#form1\\:htmlKekkatable\\:deluxe1__pagerLast")
-            .map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:htmlKekkatable:deluxe1__pagerLast",
-                    &e.to_string(),
-                )
-            })?;
         let last_button_enabled = document
-            .select(&last_button_selector)
+            .select(&selectors.last_button)
             .next()
             .map(|e| !e.value().attr("disabled").is_some())
             .unwrap_or(false);
@@ -373,10 +413,8 @@ impl SyllabusSearchResultParser {
 
     /// フォーム情報を解析
     fn parse_form_info(&self, document: &Html) -> Result<FormInfo, ParseError> {
-        let form_selector = Selector::parse("#form1")
-            .map_err(|e| ParseError::selector_creation_failed("#form1", &e.to_string()))?;
-
-        let form_element = document.select(&form_selector).next().ok_or_else(|| {
+        let selectors = SyllabusSearchResultSelectors::get()?;
+        let form_element = document.select(&selectors.form).next().ok_or_else(|| {
             ParseError::element_not_found("#form1", "フォーム要素が見つかりません")
         })?;
 
@@ -399,12 +437,8 @@ impl SyllabusSearchResultParser {
             .to_string();
 
         // 隠しフィールドを取得
-        let hidden_selector = Selector::parse("input[type=\"hidden\"]").map_err(|e| {
-            ParseError::selector_creation_failed("input[type=\"hidden\"]", &e.to_string())
-        })?;
-
         let mut hidden_fields = Vec::new();
-        for input in form_element.select(&hidden_selector) {
+        for input in form_element.select(&selectors.hidden_input) {
             let field_name = input.value().attr("name").unwrap_or_default().to_string();
             let field_value = input.value().attr("value").unwrap_or_default().to_string();
 
@@ -429,18 +463,18 @@ impl PageParser<SyllabusSearchResultPage> for SyllabusSearchResultParser {
     const PAGE_TYPE: &'static str = "シラバス検索結果";
 
     fn parse_document(&self, document: &Html) -> Result<SyllabusSearchResultPage, ParseError> {
-        let search_conditions = self.parse_search_conditions(document)?;
-        let result_metadata = self.parse_result_metadata(document)?;
-        let course_entries = self.parse_course_entries(document)?;
-        let pagination = self.parse_pagination(document)?;
-        let form_info = self.parse_form_info(document)?;
+        let mut ctx = ParseContext::new()
+            .with_strict(self.options.strict_mode)
+            .with_period_table(self.options.period_table.clone());
+        self.parse_with_context(document, &mut ctx)
+    }
 
-        Ok(SyllabusSearchResultPage {
-            search_conditions,
-            result_metadata,
-            course_entries,
-            pagination,
-            form_info,
-        })
+    fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<SyllabusSearchResultPage>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
     }
 }
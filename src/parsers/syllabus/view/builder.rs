@@ -3,6 +3,7 @@
 //! ビルダーパターンを使用してシラバス詳細表示パーサーを構築します。
 
 use super::parser::SyllabusViewParser;
+use crate::common::options::ParseOptions;
 use crate::utils::error::ParseError;
 
 /// シラバス詳細表示パーサービルダー
@@ -11,8 +12,7 @@ use crate::utils::error::ParseError;
 /// 現在は設定オプションは少ないですが、将来の拡張を想定した構造になっています。
 #[derive(Default, Debug)]
 pub struct SyllabusViewParserBuilder {
-    debug_mode: bool,
-    strict_mode: bool,
+    options: ParseOptions,
     enable_lesson_plan_parsing: bool,
     enable_active_learning_parsing: bool,
 }
@@ -21,28 +21,32 @@ impl SyllabusViewParserBuilder {
     /// 新しいビルダーインスタンスを作成
     pub fn new() -> Self {
         Self {
-            debug_mode: false,
-            strict_mode: false,
+            options: ParseOptions::default(),
             enable_lesson_plan_parsing: true,
             enable_active_learning_parsing: true,
         }
     }
 
+    /// 解析オプションを設定する
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// デバッグモードを有効にする
     ///
     /// デバッグモードでは、パース処理の詳細ログが出力されます。
     /// 現在は実装されていませんが、将来のデバッグ機能拡張用です。
     pub fn debug_mode(mut self, enabled: bool) -> Self {
-        self.debug_mode = enabled;
+        self.options.debug_mode = enabled;
         self
     }
 
     /// 厳密モードを有効にする
     ///
-    /// 厳密モードでは、HTMLの構造に厳格な検証を行います。
-    /// 現在は実装されていませんが、将来の検証機能拡張用です。
+    /// シラバス詳細の必須項目は常に検証されるため、現在は解析結果に影響しません。
     pub fn strict_mode(mut self, enabled: bool) -> Self {
-        self.strict_mode = enabled;
+        self.options.strict_mode = enabled;
         self
     }
 
@@ -66,8 +70,7 @@ impl SyllabusViewParserBuilder {
 
     /// ビルドしてSyllabusViewParserを作成
     pub fn build(self) -> Result<SyllabusViewParser, ParseError> {
-        // 現在はビルダーの設定値は使用していませんが、将来の拡張用に保持
-        // 将来的には各種設定をパーサーに渡すことが可能です
-        SyllabusViewParser::new()
+        // 授業計画・アクティブラーニングの選択は将来の拡張用に保持
+        Ok(SyllabusViewParser::new()?.with_options(self.options))
     }
 }
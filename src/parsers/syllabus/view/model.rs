@@ -6,8 +6,12 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::period::ClassTime;
+use crate::common::term::AcademicTerm;
+
 /// アクティブラーニング情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ActiveLearningInfo {
     /// ディスカッション
@@ -28,6 +32,7 @@ pub struct ActiveLearningInfo {
 
 /// 授業計画項目
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct LessonPlanItem {
     /// 第X回 (例: 第１回)
@@ -39,7 +44,8 @@ pub struct LessonPlanItem {
 }
 
 /// シラバス詳細情報
-#[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct LessonInfo {
     /// 授業コード
@@ -52,10 +58,12 @@ pub struct LessonInfo {
     pub assigned_grade: u32,
     /// 単位数
     pub credits: u32,
-    /// 年度学期
-    pub academic_year_semester: String,
+    /// 年度学期（「2025年度 夏期」のように解釈できない場合はNone）
+    pub term: Option<AcademicTerm>,
     /// 曜日時限
     pub day_period: String,
+    /// 曜日時限から求めた曜日・時限と開始・終了時刻（解釈できない場合はNone）
+    pub class_time: Option<ClassTime>,
     /// 対象学科
     pub target_department: String,
     /// コース
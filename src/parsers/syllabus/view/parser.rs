@@ -3,17 +3,157 @@
 //! UNIVERSAL PASSPORT EXのシラバス詳細表示ページから授業情報を抽出するパーサーロジックを提供します。
 
 use super::model::*;
+use crate::common::context::ParseContext;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::period::ClassTime;
+use crate::common::selectors::selector_registry;
+use crate::common::term::AcademicTerm;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
-use scraper::{Html, Selector};
+use scraper::Html;
+
+selector_registry! {
+    /// シラバス詳細表示画面で使用するセレクター
+    struct SyllabusViewSelectors {
+        row: "tr",
+        header: "th",
+        cell: "td",
+    }
+}
 
 /// シラバス詳細表示パーサー
-pub struct SyllabusViewParser;
+pub struct SyllabusViewParser {
+    options: ParseOptions,
+}
 
 impl SyllabusViewParser {
     /// 新しいパーサーインスタンスを作成
     pub fn new() -> Result<Self, ParseError> {
-        Ok(Self)
+        Ok(Self {
+            options: ParseOptions::default(),
+        })
+    }
+
+    /// 解析オプションを設定する
+    ///
+    /// 曜日時限の開始・終了時刻は`period_table`から求めます。
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 警告をコンテキストに記録しながら授業情報を解析する
+    ///
+    /// 年度学期を解釈できない場合は`term`を`None`とし、警告を記録します。
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<LessonInfo, ParseError> {
+        // 基本情報の抽出
+        let lesson_code_str = self.extract_table_value(document, "授業コード")?;
+        let lesson_code = lesson_code_str
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| ParseError::data_parsing_failed("lesson_code", &lesson_code_str))?;
+
+        let assigned_grade_str = self.extract_table_value(document, "配当学年")?;
+        let assigned_grade = assigned_grade_str
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| ParseError::data_parsing_failed("assigned_grade", &assigned_grade_str))?;
+
+        let credits_str = self.extract_table_value(document, "単位数")?;
+        let credits = credits_str
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| ParseError::data_parsing_failed("credits", &credits_str))?;
+
+        let subject_name = self.extract_table_value(document, "科目名")?;
+        let term_text = self
+            .extract_optional_value(document, "年度学期")
+            .unwrap_or_default();
+        let term = AcademicTerm::parse(&term_text).ok();
+        if term.is_none() {
+            ctx.unparseable_cell("term", 0, 0, &term_text)?;
+        }
+        let day_period = self.extract_table_value(document, "曜日時限")?;
+        let class_time = ClassTime::parse(&day_period, ctx.period_table()).ok();
+        let target_department = self.extract_table_value(document, "対象学科")?;
+        let subject_category = self.extract_table_value(document, "科目区分")?;
+        let required_elective_distinction = self.extract_table_value(document, "必選の別")?;
+        let instructor = self.extract_table_value(document, "担当者")?;
+
+        // オプション項目の抽出
+        let omnibus = self.extract_optional_value(document, "オムニバス");
+        let course = self.extract_optional_value(document, "コース");
+        let classroom = self.extract_optional_value(document, "教室");
+        let industry_professional_led_class =
+            self.extract_optional_value(document, "実務家教員担当授業");
+        let class_objectives_and_approach =
+            self.extract_optional_value(document, "授業の目的と進め方");
+
+        // 達成目標の抽出
+        let achievement_goal_1 = self.extract_optional_value(document, "達成目標１");
+        let achievement_goal_2 = self.extract_optional_value(document, "達成目標２");
+        let achievement_goal_3 = self.extract_optional_value(document, "達成目標３");
+        let achievement_goal_4 = self.extract_optional_value(document, "達成目標４");
+        let achievement_goal_5 = self.extract_optional_value(document, "達成目標５");
+        let achievement_goal_6 = self.extract_optional_value(document, "達成目標６");
+        let achievement_goal_7 = self.extract_optional_value(document, "達成目標７");
+
+        // その他の情報
+        let feedback_on_assignments =
+            self.extract_optional_value(document, "課題等に対するフィードバック");
+        let evaluation_methods_and_criteria =
+            self.extract_optional_value(document, "評価方法と基準");
+        let textbook = self.extract_optional_value(document, "テキスト");
+        let reference_books = self.extract_optional_value(document, "参考図書");
+        let subject_positioning =
+            self.extract_optional_value(document, "科目の位置づけ（学習・教育目標との対応）");
+        let preparation_before_registration =
+            self.extract_optional_value(document, "履修登録前の準備");
+
+        // アクティブラーニング情報の抽出
+        let active_learning = self.extract_active_learning(document)?;
+
+        // 授業計画詳細の抽出
+        let lesson_plan_details = self.extract_lesson_plan_details(document)?;
+
+        Ok(LessonInfo {
+            lesson_code,
+            omnibus,
+            subject_name,
+            assigned_grade,
+            credits,
+            term,
+            day_period,
+            class_time,
+            target_department,
+            course,
+            subject_category,
+            required_elective_distinction,
+            instructor,
+            classroom,
+            industry_professional_led_class,
+            class_objectives_and_approach,
+            achievement_goal_1,
+            achievement_goal_2,
+            achievement_goal_3,
+            achievement_goal_4,
+            achievement_goal_5,
+            achievement_goal_6,
+            achievement_goal_7,
+            active_learning,
+            lesson_plan_details,
+            feedback_on_assignments,
+            evaluation_methods_and_criteria,
+            textbook,
+            reference_books,
+            subject_positioning,
+            preparation_before_registration,
+        })
     }
 
     /// テーブル行から値を抽出するヘルパー関数
@@ -22,17 +162,12 @@ impl SyllabusViewParser {
         document: &Html,
         header_text: &str,
     ) -> Result<String, ParseError> {
-        let tr_selector = Selector::parse("tr")
-            .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
-        let th_selector = Selector::parse("th")
-            .map_err(|e| ParseError::selector_creation_failed("th", &e.to_string()))?;
-        let td_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
+        let selectors = SyllabusViewSelectors::get()?;
 
         // 各行を調べて、指定されたヘッダーテキストを含むTHがある行を探す
-        for tr_element in document.select(&tr_selector) {
-            let th_elements: Vec<_> = tr_element.select(&th_selector).collect();
-            let td_elements: Vec<_> = tr_element.select(&td_selector).collect();
+        for tr_element in document.select(&selectors.row) {
+            let th_elements: Vec<_> = tr_element.select(&selectors.header).collect();
+            let td_elements: Vec<_> = tr_element.select(&selectors.cell).collect();
 
             // 小さなテーブル行のみを対象とする（大きな行は無視）
             if th_elements.len() > 10 || td_elements.len() > 10 {
@@ -143,13 +278,13 @@ impl SyllabusViewParser {
 
     /// 特定のアクティブラーニング項目が有効かどうかをチェック
     fn check_active_learning_item(&self, document: &Html, item_name: &str) -> bool {
-        let tr_selector = Selector::parse("tr").unwrap();
-        let th_selector = Selector::parse("th").unwrap();
-        let td_selector = Selector::parse("td").unwrap();
+        let Ok(selectors) = SyllabusViewSelectors::get() else {
+            return false;
+        };
 
-        for tr_element in document.select(&tr_selector) {
-            let th_elements: Vec<_> = tr_element.select(&th_selector).collect();
-            let td_elements: Vec<_> = tr_element.select(&td_selector).collect();
+        for tr_element in document.select(&selectors.row) {
+            let th_elements: Vec<_> = tr_element.select(&selectors.header).collect();
+            let td_elements: Vec<_> = tr_element.select(&selectors.cell).collect();
 
             // 大きなテーブル行は無視（すべてのデータが含まれている行を避けるため）
             if th_elements.len() > 10 || td_elements.len() > 10 {
@@ -179,27 +314,21 @@ impl SyllabusViewParser {
         &self,
         document: &Html,
     ) -> Result<Vec<LessonPlanItem>, ParseError> {
+        let selectors = SyllabusViewSelectors::get()?;
         let mut lesson_plans = Vec::new();
-        let tr_selector = Selector::parse("tr")
-            .map_err(|e| ParseError::selector_creation_failed("tr", &e.to_string()))?;
 
-        for tr_element in document.select(&tr_selector) {
+        for tr_element in document.select(&selectors.row) {
             let tr_html = tr_element.html();
 
             // 「第X回」を含む行を探す
             if tr_html.contains("第") && tr_html.contains("回") {
-                let th_selector = Selector::parse("th")
-                    .map_err(|e| ParseError::selector_creation_failed("th", &e.to_string()))?;
-                let td_selector = Selector::parse("td")
-                    .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
                 let tr_doc = Html::parse_fragment(&tr_html);
                 let mut session_number = String::new();
                 let mut lesson_plan = String::new();
                 let mut outside_class_tasks = String::new();
 
                 // セッション番号を抽出
-                for th_element in tr_doc.select(&th_selector) {
+                for th_element in tr_doc.select(&selectors.header) {
                     let th_text = th_element.text().collect::<String>().trim().to_string();
                     if th_text.contains("第") && th_text.contains("回") {
                         session_number = th_text;
@@ -208,7 +337,7 @@ impl SyllabusViewParser {
                 }
 
                 // TDから授業計画と課題を抽出
-                let td_elements: Vec<_> = tr_doc.select(&td_selector).collect();
+                let td_elements: Vec<_> = tr_doc.select(&selectors.cell).collect();
                 if td_elements.len() >= 2 {
                     lesson_plan = td_elements[0].text().collect::<String>().trim().to_string();
                     outside_class_tasks =
@@ -233,100 +362,15 @@ impl PageParser<LessonInfo> for SyllabusViewParser {
     const PAGE_TYPE: &'static str = "syllabus_view";
 
     fn parse_document(&self, document: &Html) -> Result<LessonInfo, ParseError> {
-        // 基本情報の抽出
-        let lesson_code_str = self.extract_table_value(document, "授業コード")?;
-        let lesson_code = lesson_code_str
-            .trim()
-            .parse::<u32>()
-            .map_err(|_| ParseError::data_parsing_failed("lesson_code", &lesson_code_str))?;
-
-        let assigned_grade_str = self.extract_table_value(document, "配当学年")?;
-        let assigned_grade = assigned_grade_str
-            .trim()
-            .parse::<u32>()
-            .map_err(|_| ParseError::data_parsing_failed("assigned_grade", &assigned_grade_str))?;
-
-        let credits_str = self.extract_table_value(document, "単位数")?;
-        let credits = credits_str
-            .trim()
-            .parse::<u32>()
-            .map_err(|_| ParseError::data_parsing_failed("credits", &credits_str))?;
-
-        let subject_name = self.extract_table_value(document, "科目名")?;
-        let academic_year_semester = self.extract_table_value(document, "年度学期")?;
-        let day_period = self.extract_table_value(document, "曜日時限")?;
-        let target_department = self.extract_table_value(document, "対象学科")?;
-        let subject_category = self.extract_table_value(document, "科目区分")?;
-        let required_elective_distinction = self.extract_table_value(document, "必選の別")?;
-        let instructor = self.extract_table_value(document, "担当者")?;
-
-        // オプション項目の抽出
-        let omnibus = self.extract_optional_value(document, "オムニバス");
-        let course = self.extract_optional_value(document, "コース");
-        let classroom = self.extract_optional_value(document, "教室");
-        let industry_professional_led_class =
-            self.extract_optional_value(document, "実務家教員担当授業");
-        let class_objectives_and_approach =
-            self.extract_optional_value(document, "授業の目的と進め方");
-
-        // 達成目標の抽出
-        let achievement_goal_1 = self.extract_optional_value(document, "達成目標１");
-        let achievement_goal_2 = self.extract_optional_value(document, "達成目標２");
-        let achievement_goal_3 = self.extract_optional_value(document, "達成目標３");
-        let achievement_goal_4 = self.extract_optional_value(document, "達成目標４");
-        let achievement_goal_5 = self.extract_optional_value(document, "達成目標５");
-        let achievement_goal_6 = self.extract_optional_value(document, "達成目標６");
-        let achievement_goal_7 = self.extract_optional_value(document, "達成目標７");
-
-        // その他の情報
-        let feedback_on_assignments =
-            self.extract_optional_value(document, "課題等に対するフィードバック");
-        let evaluation_methods_and_criteria =
-            self.extract_optional_value(document, "評価方法と基準");
-        let textbook = self.extract_optional_value(document, "テキスト");
-        let reference_books = self.extract_optional_value(document, "参考図書");
-        let subject_positioning =
-            self.extract_optional_value(document, "科目の位置づけ（学習・教育目標との対応）");
-        let preparation_before_registration =
-            self.extract_optional_value(document, "履修登録前の準備");
-
-        // アクティブラーニング情報の抽出
-        let active_learning = self.extract_active_learning(document)?;
-
-        // 授業計画詳細の抽出
-        let lesson_plan_details = self.extract_lesson_plan_details(document)?;
+        let mut ctx = ParseContext::new()
+            .with_strict(self.options.strict_mode)
+            .with_period_table(self.options.period_table.clone());
+        self.parse_with_context(document, &mut ctx)
+    }
 
-        Ok(LessonInfo {
-            lesson_code,
-            omnibus,
-            subject_name,
-            assigned_grade,
-            credits,
-            academic_year_semester,
-            day_period,
-            target_department,
-            course,
-            subject_category,
-            required_elective_distinction,
-            instructor,
-            classroom,
-            industry_professional_led_class,
-            class_objectives_and_approach,
-            achievement_goal_1,
-            achievement_goal_2,
-            achievement_goal_3,
-            achievement_goal_4,
-            achievement_goal_5,
-            achievement_goal_6,
-            achievement_goal_7,
-            active_learning,
-            lesson_plan_details,
-            feedback_on_assignments,
-            evaluation_methods_and_criteria,
-            textbook,
-            reference_books,
-            subject_positioning,
-            preparation_before_registration,
-        })
+    fn parse_with_warnings(&self, document: &Html) -> Result<ParseOutcome<LessonInfo>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
     }
 }
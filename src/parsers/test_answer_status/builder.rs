@@ -4,16 +4,15 @@
 //! ビルダーパターンの実装を提供します。
 
 use super::parser::TestAnswerStatusParserImpl;
+use crate::common::options::ParseOptions;
 use crate::common::traits::DataBuilder;
 use crate::utils::error::ParseError;
 
 /// テスト解答状況パーサーのビルダー
 #[derive(Debug, Default)]
 pub struct TestAnswerStatusParserBuilder {
-    /// デバッグモードの有効/無効
-    debug_mode: bool,
-    /// 厳密なパースモード（エラー時に停止するかどうか）
-    strict_mode: bool,
+    /// 解析オプション（厳密モードではエラー時に停止する）
+    options: ParseOptions,
 }
 
 impl TestAnswerStatusParserBuilder {
@@ -22,21 +21,27 @@ impl TestAnswerStatusParserBuilder {
         Self::default()
     }
 
+    /// 解析オプションを設定する
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// デバッグモードを有効にする
     pub fn with_debug_mode(mut self, enabled: bool) -> Self {
-        self.debug_mode = enabled;
+        self.options.debug_mode = enabled;
         self
     }
 
     /// 厳密なパースモードを設定する
     pub fn with_strict_mode(mut self, enabled: bool) -> Self {
-        self.strict_mode = enabled;
+        self.options.strict_mode = enabled;
         self
     }
 
     /// パーサーインスタンスを構築する
     pub fn build(self) -> Result<TestAnswerStatusParserImpl, ParseError> {
-        TestAnswerStatusParserImpl::new_with_config(self.debug_mode, self.strict_mode)
+        TestAnswerStatusParserImpl::new_with_options(self.options)
     }
 }
 
@@ -11,33 +11,3 @@ pub mod model;
 
 /// テスト解答状況パーサーのビルダー
 pub mod builder;
-
-use crate::common::traits::PageParser;
-use scraper::Html;
-
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen::prelude::*;
-
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-pub struct TestAnswerStatusParser {
-    parser: parser::TestAnswerStatusParserImpl,
-}
-
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-impl TestAnswerStatusParser {
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Result<Self, JsError> {
-        Ok(Self {
-            parser: parser::TestAnswerStatusParserImpl::new()?,
-        })
-    }
-
-    /// テスト解答状況をパースする
-    pub fn parse(&self, html_content: &str) -> Result<model::TestAnswerStatus, JsError> {
-        Ok(self
-            .parser
-            .parse_document(&Html::parse_document(html_content))?)
-    }
-}
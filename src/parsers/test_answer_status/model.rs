@@ -6,8 +6,11 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
+use crate::common::datetime::JstDateTime;
+
 /// テスト解答状況の全体情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct TestAnswerStatus {
     /// 年度
@@ -22,6 +25,7 @@ pub struct TestAnswerStatus {
 
 /// タブ情報（各状態の件数）
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct TabInfo {
     /// 未実施のテスト件数
@@ -34,6 +38,7 @@ pub struct TabInfo {
 
 /// テストの実施状況
 #[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum TestStatus {
     /// 未実施
@@ -52,6 +57,7 @@ impl Default for TestStatus {
 
 /// テスト項目の情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct TestItem {
     /// テストタイトル
@@ -64,6 +70,7 @@ pub struct TestItem {
 
 /// テストの詳細情報（状態によって異なる）
 #[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum TestDetails {
     /// 未実施テストの詳細
@@ -82,12 +89,13 @@ impl Default for TestDetails {
 
 /// 未実施テストの詳細情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct NotImplementedDetails {
-    /// 開始日
-    pub start_date: Option<String>,
-    /// 終了日
-    pub end_date: Option<String>,
+    /// 開始日時
+    pub start_date: Option<JstDateTime>,
+    /// 終了日時
+    pub end_date: Option<JstDateTime>,
     /// 制限時間（分）
     pub time_limit_minutes: Option<u32>,
     /// 再解答可能かどうか
@@ -96,12 +104,13 @@ pub struct NotImplementedDetails {
 
 /// 実施中テストの詳細情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct InProgressDetails {
     /// 実施回数
     pub attempt_count: u32,
-    /// 最新実施日
-    pub latest_attempt_date: Option<String>,
+    /// 最新実施日時
+    pub latest_attempt_date: Option<JstDateTime>,
     /// 実施時間（分）
     pub duration_minutes: Option<u32>,
     /// 点数
@@ -110,12 +119,13 @@ pub struct InProgressDetails {
 
 /// 実施済テストの詳細情報
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct CompletedDetails {
     /// 実施回数
     pub attempt_count: u32,
-    /// 最新実施日
-    pub latest_attempt_date: Option<String>,
+    /// 最新実施日時
+    pub latest_attempt_date: Option<JstDateTime>,
     /// 実施時間（分）
     pub duration_minutes: Option<u32>,
     /// 点数
@@ -7,81 +7,163 @@ use regex_lite::Regex;
 use scraper::{Html, Selector};
 
 use super::model::*;
+use crate::common::context::ParseContext;
+use crate::common::datetime::JstDateTime;
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::selectors::{selector_registry, CompiledSelector};
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 
+selector_registry! {
+    /// テスト解答一覧画面で使用するセレクター
+    struct TestAnswerStatusSelectors {
+        academic_year: "#form1\\:htmlNendo",
+        not_implemented_tab_count: ["#form1\\:htmlTab1aCount", "#form1\\:htmlTab1bCount"],
+        in_progress_tab_count: ["#form1\\:htmlTab2aCount", "#form1\\:htmlTab2bCount"],
+        completed_tab_count: ["#form1\\:htmlTab3aCount", "#form1\\:htmlTab3bCount"],
+        active_tab: ["#form1\\:htmlTab1a", "#form1\\:htmlTab2a", "#form1\\:htmlTab3a"],
+        not_implemented_rows: "#form1\\:Stb00101B\\:table1 tbody tr",
+        in_progress_rows: "#form1\\:Stb00101C\\:table1 tbody tr",
+        completed_rows: "#form1\\:Stb00101D\\:table1 tbody tr",
+        title: ".title",
+        jugyo: ".jugyo",
+        start_date: ".startDate",
+        end_date: ".endDate",
+        saikaito: ".saikaito",
+        latest_attempt_date: ".SaishinJissiDate",
+        score: ".Score",
+        limit_time: ".limitTime",
+        jissi_time: ".JissiTime",
+        jissi_kaisu: ".jissiKaisu",
+        output_text: ".outputText",
+    }
+}
+
+impl TestAnswerStatusSelectors {
+    /// 行内のセルを示すクラス名に対応するセレクターを返す
+    fn cell_class(&self, class_name: &str) -> Result<&Selector, ParseError> {
+        match class_name {
+            "title" => Ok(&self.title),
+            "jugyo" => Ok(&self.jugyo),
+            "startDate" => Ok(&self.start_date),
+            "endDate" => Ok(&self.end_date),
+            "saikaito" => Ok(&self.saikaito),
+            "SaishinJissiDate" => Ok(&self.latest_attempt_date),
+            "Score" => Ok(&self.score),
+            "limitTime" => Ok(&self.limit_time),
+            "JissiTime" => Ok(&self.jissi_time),
+            "jissiKaisu" => Ok(&self.jissi_kaisu),
+            _ => Err(ParseError::selector_creation_failed(
+                &format!(".{}", class_name),
+                "Unregistered cell class",
+            )),
+        }
+    }
+}
+
 /// テスト解答状況パーサーの実装
 #[derive(Debug)]
 pub struct TestAnswerStatusParserImpl {
-    /// デバッグモード
-    debug_mode: bool,
-    /// 厳密なパースモード
-    strict_mode: bool,
+    /// 解析オプション
+    options: ParseOptions,
 }
 
 impl TestAnswerStatusParserImpl {
     /// デフォルト設定で新しいパーサーインスタンスを作成
     pub fn new() -> Result<Self, ParseError> {
-        Ok(Self {
-            debug_mode: false,
-            strict_mode: false,
-        })
+        Self::new_with_options(ParseOptions::default())
     }
 
     /// 設定を指定して新しいパーサーインスタンスを作成
     pub fn new_with_config(debug_mode: bool, strict_mode: bool) -> Result<Self, ParseError> {
-        Ok(Self {
-            debug_mode,
-            strict_mode,
+        Self::new_with_options(
+            ParseOptions::new()
+                .with_debug_mode(debug_mode)
+                .with_strict_mode(strict_mode),
+        )
+    }
+
+    /// 解析オプションを指定して新しいパーサーインスタンスを作成
+    pub fn new_with_options(options: ParseOptions) -> Result<Self, ParseError> {
+        Ok(Self { options })
+    }
+
+    /// 警告や診断情報をコンテキストに記録しながらテスト解答状況を解析する
+    pub fn parse_with_context(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<TestAnswerStatus, ParseError> {
+        let academic_year = self.parse_academic_year(document, ctx)?;
+        let tab_info = self.parse_tab_info(document, ctx)?;
+        let current_tab = self.parse_current_tab(document, ctx)?;
+        let tests = self.parse_tests(document, &current_tab, ctx)?;
+
+        Ok(TestAnswerStatus {
+            academic_year,
+            tab_info,
+            current_tab,
+            tests,
         })
     }
 
     /// 年度情報を抽出
-    fn parse_academic_year(&self, document: &Html) -> Result<String, ParseError> {
-        let selector = Selector::parse("#form1\\:htmlNendo").map_err(|e| {
-            ParseError::selector_creation_failed(
-                "#form1:htmlNendo",
-                &format!("CSS selector parsing failed: {}", e),
-            )
-        })?;
-
-        if let Some(element) = document.select(&selector).next() {
+    fn parse_academic_year(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<String, ParseError> {
+        let selectors = TestAnswerStatusSelectors::get()?;
+
+        ctx.trace_selector(
+            "academic_year",
+            &selectors.academic_year,
+            document.root_element(),
+        );
+        if let Some(element) = document.select(&selectors.academic_year).next() {
             let text = element.text().collect::<String>();
             // HTMLエンティティをデコード（例: &#24180;&#24230; -> 年度）
             let decoded = self.decode_html_entities(&text);
             Ok(decoded)
         } else {
-            if self.strict_mode {
+            if self.options.strict_mode {
                 return Err(ParseError::element_not_found(
                     "#form1:htmlNendo",
                     "Academic year element not found",
                 ));
             }
+            ctx.fallback_used("academic_year", "不明")?;
             Ok("不明".to_string())
         }
     }
 
     /// タブ情報（各状態の件数）を抽出
-    fn parse_tab_info(&self, document: &Html) -> Result<TabInfo, ParseError> {
+    fn parse_tab_info(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<TabInfo, ParseError> {
+        let selectors = TestAnswerStatusSelectors::get()?;
         let mut tab_info = TabInfo::default();
 
         // 未実施タブの件数
         if let Some(count) =
-            self.extract_tab_count(document, "form1:htmlTab1aCount", "form1:htmlTab1bCount")?
+            self.extract_tab_count(document, &selectors.not_implemented_tab_count, ctx)?
         {
             tab_info.not_implemented_count = count;
         }
 
         // 実施中タブの件数
         if let Some(count) =
-            self.extract_tab_count(document, "form1:htmlTab2aCount", "form1:htmlTab2bCount")?
+            self.extract_tab_count(document, &selectors.in_progress_tab_count, ctx)?
         {
             tab_info.in_progress_count = count;
         }
 
         // 実施済タブの件数
         if let Some(count) =
-            self.extract_tab_count(document, "form1:htmlTab3aCount", "form1:htmlTab3bCount")?
+            self.extract_tab_count(document, &selectors.completed_tab_count, ctx)?
         {
             tab_info.completed_count = count;
         }
@@ -93,19 +175,13 @@ impl TestAnswerStatusParserImpl {
     fn extract_tab_count(
         &self,
         document: &Html,
-        id_a: &str,
-        id_b: &str,
+        candidates: &[CompiledSelector],
+        ctx: &mut ParseContext,
     ) -> Result<Option<u32>, ParseError> {
         // アクティブタブ（_a）とリンクタブ（_b）の両方を試す
-        let ids = vec![id_a.to_string(), id_b.to_string()];
-        for id in &ids {
-            let selector_str = format!("#{}", id.replace(":", "\\:"));
-            let selector = match Selector::parse(&selector_str) {
-                Ok(s) => s,
-                Err(_) => continue,
-            };
-
-            if let Some(element) = document.select(&selector).next() {
+        for selector in candidates {
+            ctx.trace_selector("tab_info", selector, document.root_element());
+            if let Some(element) = document.select(selector).next() {
                 let text = element.text().collect::<String>();
                 let decoded = self.decode_html_entities(&text);
                 // (数字) の形式から数字を抽出
@@ -122,23 +198,29 @@ impl TestAnswerStatusParserImpl {
     }
 
     /// 現在のタブ状態を判定
-    fn parse_current_tab(&self, document: &Html) -> Result<TestStatus, ParseError> {
+    fn parse_current_tab(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<TestStatus, ParseError> {
+        let selectors = TestAnswerStatusSelectors::get()?;
+
         // アクティブなタブを探す（tab01_on.gif の背景画像を持つタブ）
-        let selectors = [
-            ("#form1\\:htmlTab1a", TestStatus::NotImplemented),
-            ("#form1\\:htmlTab2a", TestStatus::InProgress),
-            ("#form1\\:htmlTab3a", TestStatus::Completed),
+        let tabs = [
+            TestStatus::NotImplemented,
+            TestStatus::InProgress,
+            TestStatus::Completed,
         ];
 
-        for (selector_str, status) in &selectors {
-            if let Ok(selector) = Selector::parse(selector_str) {
-                if document.select(&selector).next().is_some() {
-                    return Ok(status.clone());
-                }
+        for (status, selector) in tabs.iter().zip(&selectors.active_tab) {
+            ctx.trace_selector("current_tab", selector, document.root_element());
+            if document.select(selector).next().is_some() {
+                return Ok(status.clone());
             }
         }
 
         // デフォルトは未実施
+        ctx.fallback_used("current_tab", "NotImplemented")?;
         Ok(TestStatus::NotImplemented)
     }
 
@@ -147,35 +229,46 @@ impl TestAnswerStatusParserImpl {
         &self,
         document: &Html,
         current_tab: &TestStatus,
+        ctx: &mut ParseContext,
     ) -> Result<Vec<TestItem>, ParseError> {
         match current_tab {
-            TestStatus::NotImplemented => self.parse_not_implemented_tests(document),
-            TestStatus::InProgress => self.parse_in_progress_tests(document),
-            TestStatus::Completed => self.parse_completed_tests(document),
+            TestStatus::NotImplemented => self.parse_not_implemented_tests(document, ctx),
+            TestStatus::InProgress => self.parse_in_progress_tests(document, ctx),
+            TestStatus::Completed => self.parse_completed_tests(document, ctx),
         }
     }
 
     /// 未実施テストの一覧を抽出
-    fn parse_not_implemented_tests(&self, document: &Html) -> Result<Vec<TestItem>, ParseError> {
+    fn parse_not_implemented_tests(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<TestItem>, ParseError> {
+        let selectors = TestAnswerStatusSelectors::get()?;
         let mut tests = Vec::new();
 
         // 未実施テストのテーブル（Stb00101B）を探す
-        let table_selector =
-            Selector::parse("#form1\\:Stb00101B\\:table1 tbody tr").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:Stb00101B:table1 tbody tr",
-                    &format!("CSS selector parsing failed: {}", e),
-                )
-            })?;
 
-        for row in document.select(&table_selector) {
-            if let Ok(test) = self.parse_not_implemented_test_row(&row) {
-                tests.push(test);
-            } else if self.strict_mode {
-                return Err(ParseError::data_extraction_failed(
-                    "not implemented test row",
-                    "Failed to parse test row data",
-                ));
+        ctx.trace_selector(
+            "tests",
+            &selectors.not_implemented_rows,
+            document.root_element(),
+        );
+        for (row_index, row) in document.select(&selectors.not_implemented_rows).enumerate() {
+            match self.parse_not_implemented_test_row(&row, row_index, ctx) {
+                Ok(test) => tests.push(test),
+                Err(_) if self.options.strict_mode => {
+                    return Err(ParseError::data_extraction_failed(
+                        "not implemented test row",
+                        "Failed to parse test row data",
+                    ));
+                }
+                Err(e) => ctx.skip_row(
+                    "tests",
+                    row_index,
+                    row.child_elements().count(),
+                    &e.to_string(),
+                ),
             }
         }
 
@@ -186,11 +279,13 @@ impl TestAnswerStatusParserImpl {
     fn parse_not_implemented_test_row(
         &self,
         row: &scraper::ElementRef,
+        row_index: usize,
+        ctx: &mut ParseContext,
     ) -> Result<TestItem, ParseError> {
         let title = self.extract_text_by_class(row, "title")?;
         let course_name = self.extract_text_by_class(row, "jugyo")?;
-        let start_date = self.extract_optional_text_by_class(row, "startDate");
-        let end_date = self.extract_optional_text_by_class(row, "endDate");
+        let start_date = self.extract_datetime_by_class(row, "startDate", row_index, ctx)?;
+        let end_date = self.extract_datetime_by_class(row, "endDate", row_index, ctx)?;
         let time_limit = self.extract_time_limit(row)?;
         let can_reanswer = self.extract_reanswer_flag(row)?;
 
@@ -209,26 +304,36 @@ impl TestAnswerStatusParserImpl {
     }
 
     /// 実施中テストの一覧を抽出
-    fn parse_in_progress_tests(&self, document: &Html) -> Result<Vec<TestItem>, ParseError> {
+    fn parse_in_progress_tests(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<TestItem>, ParseError> {
+        let selectors = TestAnswerStatusSelectors::get()?;
         let mut tests = Vec::new();
 
         // 実施中テストのテーブル（Stb00101C）を探す
-        let table_selector =
-            Selector::parse("#form1\\:Stb00101C\\:table1 tbody tr").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:Stb00101C:table1 tbody tr",
-                    &format!("CSS selector parsing failed: {}", e),
-                )
-            })?;
 
-        for row in document.select(&table_selector) {
-            if let Ok(test) = self.parse_in_progress_test_row(&row) {
-                tests.push(test);
-            } else if self.strict_mode {
-                return Err(ParseError::data_extraction_failed(
-                    "in progress test row",
-                    "Failed to parse test row data",
-                ));
+        ctx.trace_selector(
+            "tests",
+            &selectors.in_progress_rows,
+            document.root_element(),
+        );
+        for (row_index, row) in document.select(&selectors.in_progress_rows).enumerate() {
+            match self.parse_in_progress_test_row(&row, row_index, ctx) {
+                Ok(test) => tests.push(test),
+                Err(_) if self.options.strict_mode => {
+                    return Err(ParseError::data_extraction_failed(
+                        "in progress test row",
+                        "Failed to parse test row data",
+                    ));
+                }
+                Err(e) => ctx.skip_row(
+                    "tests",
+                    row_index,
+                    row.child_elements().count(),
+                    &e.to_string(),
+                ),
             }
         }
 
@@ -239,17 +344,20 @@ impl TestAnswerStatusParserImpl {
     fn parse_in_progress_test_row(
         &self,
         row: &scraper::ElementRef,
+        row_index: usize,
+        ctx: &mut ParseContext,
     ) -> Result<TestItem, ParseError> {
         let title = self.extract_text_by_class(row, "title")?;
         let course_name = self.extract_text_by_class(row, "jugyo")?;
         let attempt_count = self.extract_attempt_count(row)?;
-        let latest_attempt_date = self.extract_optional_text_by_class(row, "SaishinJissiDate");
+        let latest_attempt_date =
+            self.extract_datetime_by_class(row, "SaishinJissiDate", row_index, ctx)?;
         let duration_minutes = self.extract_duration_minutes(row)?;
         let score = self.extract_optional_text_by_class(row, "Score");
 
         let details = TestDetails::InProgress(InProgressDetails {
             attempt_count,
-            latest_attempt_date: latest_attempt_date.map(|s| self.decode_html_entities(&s)),
+            latest_attempt_date,
             duration_minutes,
             score: score.map(|s| self.decode_html_entities(&s)),
         });
@@ -262,26 +370,32 @@ impl TestAnswerStatusParserImpl {
     }
 
     /// 実施済テストの一覧を抽出
-    fn parse_completed_tests(&self, document: &Html) -> Result<Vec<TestItem>, ParseError> {
+    fn parse_completed_tests(
+        &self,
+        document: &Html,
+        ctx: &mut ParseContext,
+    ) -> Result<Vec<TestItem>, ParseError> {
+        let selectors = TestAnswerStatusSelectors::get()?;
         let mut tests = Vec::new();
 
         // 実施済テストのテーブル（Stb00101D）を探す
-        let table_selector =
-            Selector::parse("#form1\\:Stb00101D\\:table1 tbody tr").map_err(|e| {
-                ParseError::selector_creation_failed(
-                    "#form1:Stb00101D:table1 tbody tr",
-                    &format!("CSS selector parsing failed: {}", e),
-                )
-            })?;
 
-        for row in document.select(&table_selector) {
-            if let Ok(test) = self.parse_completed_test_row(&row) {
-                tests.push(test);
-            } else if self.strict_mode {
-                return Err(ParseError::data_extraction_failed(
-                    "completed test row",
-                    "Failed to parse test row data",
-                ));
+        ctx.trace_selector("tests", &selectors.completed_rows, document.root_element());
+        for (row_index, row) in document.select(&selectors.completed_rows).enumerate() {
+            match self.parse_completed_test_row(&row, row_index, ctx) {
+                Ok(test) => tests.push(test),
+                Err(_) if self.options.strict_mode => {
+                    return Err(ParseError::data_extraction_failed(
+                        "completed test row",
+                        "Failed to parse test row data",
+                    ));
+                }
+                Err(e) => ctx.skip_row(
+                    "tests",
+                    row_index,
+                    row.child_elements().count(),
+                    &e.to_string(),
+                ),
             }
         }
 
@@ -289,17 +403,23 @@ impl TestAnswerStatusParserImpl {
     }
 
     /// 実施済テストの行をパース
-    fn parse_completed_test_row(&self, row: &scraper::ElementRef) -> Result<TestItem, ParseError> {
+    fn parse_completed_test_row(
+        &self,
+        row: &scraper::ElementRef,
+        row_index: usize,
+        ctx: &mut ParseContext,
+    ) -> Result<TestItem, ParseError> {
         let title = self.extract_text_by_class(row, "title")?;
         let course_name = self.extract_text_by_class(row, "jugyo")?;
         let attempt_count = self.extract_attempt_count(row)?;
-        let latest_attempt_date = self.extract_optional_text_by_class(row, "SaishinJissiDate");
+        let latest_attempt_date =
+            self.extract_datetime_by_class(row, "SaishinJissiDate", row_index, ctx)?;
         let duration_minutes = self.extract_duration_minutes(row)?;
         let score = self.extract_optional_text_by_class(row, "Score");
 
         let details = TestDetails::Completed(CompletedDetails {
             attempt_count,
-            latest_attempt_date: latest_attempt_date.map(|s| self.decode_html_entities(&s)),
+            latest_attempt_date,
             duration_minutes,
             score: score.map(|s| self.decode_html_entities(&s)),
         });
@@ -317,14 +437,9 @@ impl TestAnswerStatusParserImpl {
         element: &scraper::ElementRef,
         class_name: &str,
     ) -> Result<String, ParseError> {
-        let selector = Selector::parse(&format!(".{}", class_name)).map_err(|e| {
-            ParseError::selector_creation_failed(
-                &format!(".{}", class_name),
-                &format!("CSS selector parsing failed: {}", e),
-            )
-        })?;
+        let selector = TestAnswerStatusSelectors::get()?.cell_class(class_name)?;
 
-        if let Some(cell) = element.select(&selector).next() {
+        if let Some(cell) = element.select(selector).next() {
             Ok(cell.text().collect::<String>().trim().to_string())
         } else {
             Err(ParseError::element_not_found(
@@ -351,6 +466,31 @@ impl TestAnswerStatusParserImpl {
         }
     }
 
+    /// クラス名で日時を抽出（解釈できない値は警告として記録）
+    fn extract_datetime_by_class(
+        &self,
+        row: &scraper::ElementRef,
+        class_name: &str,
+        row_index: usize,
+        ctx: &mut ParseContext,
+    ) -> Result<Option<JstDateTime>, ParseError> {
+        let Some(text) = self.extract_optional_text_by_class(row, class_name) else {
+            return Ok(None);
+        };
+        let selector = TestAnswerStatusSelectors::get()?.cell_class(class_name)?;
+        let column = row
+            .child_elements()
+            .position(|cell| selector.matches(&cell) || cell.select(selector).next().is_some())
+            .unwrap_or_default();
+
+        ctx.parse_cell(
+            "tests",
+            row_index,
+            column,
+            &self.decode_html_entities(&text),
+        )
+    }
+
     /// 制限時間を抽出
     fn extract_time_limit(&self, row: &scraper::ElementRef) -> Result<Option<u32>, ParseError> {
         if let Some(text) = self.extract_text_from_class_and_nested_output_span(row, "limitTime") {
@@ -454,20 +594,11 @@ impl TestAnswerStatusParserImpl {
         element: &scraper::ElementRef,
         parent_class_name: &str,
     ) -> Option<String> {
-        let parent_selector_str = format!(".{}", parent_class_name);
-        let parent_selector = match Selector::parse(&parent_selector_str) {
-            Ok(s) => s,
-            Err(_) => return None,
-        };
-
-        if let Some(parent_element) = element.select(&parent_selector).next() {
-            let nested_span_selector_str = ".outputText";
-            let nested_span_selector = match Selector::parse(nested_span_selector_str) {
-                Ok(s) => s,
-                Err(_) => return None,
-            };
+        let selectors = TestAnswerStatusSelectors::get().ok()?;
+        let parent_selector = selectors.cell_class(parent_class_name).ok()?;
 
-            if let Some(span_element) = parent_element.select(&nested_span_selector).next() {
+        if let Some(parent_element) = element.select(parent_selector).next() {
+            if let Some(span_element) = parent_element.select(&selectors.output_text).next() {
                 let text = span_element.text().collect::<String>().trim().to_string();
                 if text.is_empty() {
                     None
@@ -487,16 +618,25 @@ impl PageParser<TestAnswerStatus> for TestAnswerStatusParserImpl {
     const PAGE_TYPE: &'static str = "Stb00101A";
 
     fn parse_document(&self, document: &Html) -> Result<TestAnswerStatus, ParseError> {
-        let academic_year = self.parse_academic_year(document)?;
-        let tab_info = self.parse_tab_info(document)?;
-        let current_tab = self.parse_current_tab(document)?;
-        let tests = self.parse_tests(document, &current_tab)?;
+        let mut ctx = ParseContext::new().with_strict(self.options.strict_mode);
+        self.parse_with_context(document, &mut ctx)
+    }
 
-        Ok(TestAnswerStatus {
-            academic_year,
-            tab_info,
-            current_tab,
-            tests,
-        })
+    fn parse_with_warnings(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<TestAnswerStatus>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options);
+        let data = self.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
+    }
+
+    fn parse_with_diagnostics(
+        &self,
+        document: &Html,
+    ) -> Result<ParseOutcome<TestAnswerStatus>, ParseError> {
+        let mut ctx = ParseContext::from_options(&self.options.clone().with_debug_mode(true));
+        let data = self.parse_with_context(document, &mut ctx)?;
+        Ok(ctx.finish(data))
     }
 }
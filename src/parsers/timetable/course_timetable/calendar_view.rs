@@ -6,6 +6,7 @@
 use scraper::Html;
 
 use super::super::{BaseClassInfo, DisplayFormat, ScheduleData};
+use crate::common::term::AcademicTerm;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 
@@ -29,10 +30,8 @@ pub struct CourseEntry {
 pub struct CourseTimetableCalendar {
     /// 学生情報ラベル（大学・学部など判別用）
     pub student_info_label: String,
-    /// 開講年度
-    pub opening_year: u32,
-    /// 学期
-    pub semester: String,
+    /// 開講年度・学期
+    pub term: AcademicTerm,
     /// 表示形式（常にCalendar）
     pub display_format: DisplayFormat,
     /// カレンダー形式のスケジュールデータ
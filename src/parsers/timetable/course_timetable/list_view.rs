@@ -3,12 +3,25 @@
 //! UNIVERSAL PASSPORT EXの学科開講一覧ページの一覧表示形式を解析します。
 //! テーブル形式で表示される授業情報を構造化して抽出します。
 
-use scraper::{Html, Selector};
+use scraper::Html;
 
 use super::super::{BaseClassInfo, DisplayFormat, ScheduleData};
+use crate::common::period::{ClassTime, PeriodTable};
+use crate::common::selectors::selector_registry;
+use crate::common::term::AcademicTerm;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 
+selector_registry! {
+    /// 学科開講一覧（一覧表示）で使用するセレクター
+    struct ListViewSelectors {
+        class_table: "#form1\\:table2",
+        body_row: "tbody tr",
+        cell: "td",
+        link: "a",
+    }
+}
+
 /// 学科開講一覧の一覧表示パーサー
 pub struct ListViewParser;
 
@@ -20,24 +33,17 @@ impl ListViewParser {
 
     /// コース情報を解析する
     fn parse_course_entries(&self, document: &Html) -> Result<Vec<CourseEntry>, ParseError> {
-        let table_selector = Selector::parse("#form1\\:table2")
-            .map_err(|e| ParseError::selector_creation_failed("#form1:table2", &e.to_string()))?;
-
-        let row_selector = Selector::parse("tbody tr")
-            .map_err(|e| ParseError::selector_creation_failed("tbody tr", &e.to_string()))?;
-
-        let cell_selector = Selector::parse("td")
-            .map_err(|e| ParseError::selector_creation_failed("td", &e.to_string()))?;
-
+        let selectors = ListViewSelectors::get()?;
         let table = document
-            .select(&table_selector)
+            .select(&selectors.class_table)
             .next()
             .ok_or_else(|| ParseError::element_not_found("#form1:table2", "メインテーブル"))?;
 
         let mut entries = Vec::new();
+        let period_table = PeriodTable::default();
 
-        for row in table.select(&row_selector) {
-            let cells: Vec<_> = row.select(&cell_selector).collect();
+        for row in table.select(&selectors.body_row) {
+            let cells: Vec<_> = row.select(&selectors.cell).collect();
 
             if cells.len() >= 5 {
                 let opening_type = cells[0].inner_html().trim().to_string();
@@ -54,9 +60,12 @@ impl ListViewParser {
                     }
                 };
 
+                let class_time = ClassTime::parse(&opening_type, &period_table).ok();
+
                 entries.push(CourseEntry {
                     base: BaseClassInfo {
                         day_and_period: opening_type,
+                        class_time,
                         class_code,
                         subject_name,
                         teacher_name,
@@ -73,10 +82,9 @@ impl ListViewParser {
 
     /// 科目名を抽出する（リンクが含まれる場合も考慮）
     fn extract_subject_name(&self, cell: &scraper::ElementRef) -> Result<String, ParseError> {
-        let link_selector = Selector::parse("a")
-            .map_err(|e| ParseError::selector_creation_failed("a", &e.to_string()))?;
+        let selectors = ListViewSelectors::get()?;
 
-        if let Some(link) = cell.select(&link_selector).next() {
+        if let Some(link) = cell.select(&selectors.link).next() {
             Ok(link.inner_html().trim().to_string())
         } else {
             Ok(cell.inner_html().trim().to_string())
@@ -84,7 +92,7 @@ impl ListViewParser {
     }
 
     /// 学期情報を抽出する
-    fn parse_semester_info(&self, document: &Html) -> Result<(u32, String), ParseError> {
+    fn parse_semester_info(&self, document: &Html) -> Result<AcademicTerm, ParseError> {
         let _ = document; // suppress unused variable warnings
         Err(ParseError::NotImplemented {
             operation: "Course timetable semester info parsing".to_string(),
@@ -114,10 +122,8 @@ pub struct CourseEntry {
 pub struct CourseTimetableList {
     /// 学生情報ラベル（大学・学部など判別用）
     pub student_info_label: String,
-    /// 開講年度
-    pub opening_year: u32,
-    /// 学期
-    pub semester: String,
+    /// 開講年度・学期
+    pub term: AcademicTerm,
     /// 表示形式（常にList）
     pub display_format: DisplayFormat,
     /// 一覧形式のスケジュールデータ
@@ -130,7 +136,7 @@ impl PageParser<CourseTimetableList> for ListViewParser {
     const PAGE_TYPE: &'static str = "学科開講一覧 表示形式：一覧";
 
     fn parse_document(&self, document: &Html) -> Result<CourseTimetableList, ParseError> {
-        let (opening_year, semester) = self.parse_semester_info(document)?;
+        let term = self.parse_semester_info(document)?;
         let student_info_label = self.parse_student_info_label(document)?;
         let course_entries = self.parse_course_entries(document)?;
 
@@ -141,8 +147,7 @@ impl PageParser<CourseTimetableList> for ListViewParser {
 
         Ok(CourseTimetableList {
             student_info_label,
-            opening_year,
-            semester,
+            term,
             display_format: DisplayFormat::List,
             schedule: ScheduleData::List(regular_classes),
             irregular_classes,
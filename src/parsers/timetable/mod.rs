@@ -13,10 +13,15 @@ pub mod student_timetable;
 /// 教員時間割（担当者側の視点）
 pub mod teacher_timetable;
 
+use crate::common::period::ClassTime;
+use crate::common::term::AcademicTerm;
+
 /// 一覧表示 1 行ぶんの必須情報
 pub struct BaseClassInfo {
     /// 曜日と時限（例: "月 3"）
     pub day_and_period: String,
+    /// 曜日と時限から求めた開始・終了時刻（解釈できない場合はNone）
+    pub class_time: Option<ClassTime>,
     /// 授業コード
     pub class_code: String,
     /// 科目名
@@ -188,10 +193,8 @@ pub struct SpecializedSubjectCreditDetails {
 
 /// 学生時間割（Kma00401A）全体
 pub struct StudentTimetable {
-    /// 開講年度
-    pub opening_year: u32,
-    /// 学期
-    pub semester: String,
+    /// 開講年度・学期
+    pub term: AcademicTerm,
     /// 表示形式
     pub display_format: DisplayFormat,
     /// スケジュールデータ
@@ -240,10 +243,8 @@ pub type OfferingIrregularEntry = OfferingClassEntry;
 pub struct DepartmentTimetable {
     /// 学生情報ラベル（大学・学部など判別用）
     pub student_info_label: String,
-    /// 開講年度
-    pub opening_year: u32,
-    /// 学期
-    pub semester: String,
+    /// 開講年度・学期
+    pub term: AcademicTerm,
     /// 表示形式
     pub display_format: DisplayFormat,
     /// スケジュールデータ
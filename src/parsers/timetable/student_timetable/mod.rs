@@ -1,16 +1,20 @@
 pub mod calendar_view;
 pub mod list_view;
 
-use crate::common::traits::PageParser;
-use scraper::Html;
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
-
+#[cfg(target_arch = "wasm32")]
+use crate::common::traits::PageParser;
+#[cfg(target_arch = "wasm32")]
+use crate::utils::error::ParseError;
+#[cfg(target_arch = "wasm32")]
+use scraper::Html;
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
 #[derive(Tsify, Serialize, Deserialize, Clone, Default, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct StudentTimetable {}
 
@@ -24,20 +28,24 @@ pub struct StudentTimetableParser {
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 impl StudentTimetableParser {
-    pub fn new() -> Result<Self, JsError> {
+    /// パーサーインスタンスを作成する
+    ///
+    /// 学生時間割は`PageKind`に含まれないため、ページ種別ごとのクラスとは別に提供しています。
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<Self, ParseError> {
         Ok(Self {
             calendar_view: calendar_view::CalendarViewParser::new()?,
             list_view: list_view::ListViewParser::new()?,
         })
     }
 
-    pub fn calendar_view(&self, html_content: &str) -> Result<StudentTimetable, JsError> {
+    pub fn calendar_view(&self, html_content: &str) -> Result<StudentTimetable, ParseError> {
         Ok(self
             .calendar_view
             .parse_document(&Html::parse_document(html_content))?)
     }
 
-    pub fn list_view(&self, html_content: &str) -> Result<StudentTimetable, JsError> {
+    pub fn list_view(&self, html_content: &str) -> Result<StudentTimetable, ParseError> {
         Ok(self
             .list_view
             .parse_document(&Html::parse_document(html_content))?)
@@ -6,6 +6,7 @@
 use scraper::Html;
 
 use super::super::super::{BaseClassInfo, DisplayFormat, ScheduleData};
+use crate::common::term::AcademicTerm;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 
@@ -31,10 +32,8 @@ pub struct TeacherClassEntry {
 pub struct TeacherTimetableCalendar {
     /// 教員名
     pub teacher_name: String,
-    /// 開講年度
-    pub opening_year: u32,
-    /// 学期
-    pub semester: String,
+    /// 開講年度・学期
+    pub term: AcademicTerm,
     /// 表示形式（常にCalendar）
     pub display_format: DisplayFormat,
     /// カレンダー形式のスケジュールデータ
@@ -3,6 +3,7 @@
 //! UNIVERSAL PASSPORT EXの教員時間割検索ページを解析します。
 //! 教員検索フォーム、検索結果一覧、検索条件の設定機能を提供します。
 
+use crate::common::term::AcademicTerm;
 use crate::common::traits::PageParser;
 use crate::utils::error::ParseError;
 use scraper::Html;
@@ -13,10 +14,8 @@ pub struct TeacherSearchCriteria {
     pub teacher_name: Option<String>,
     /// 所属学部・学科
     pub department: Option<String>,
-    /// 開講年度
-    pub academic_year: u32,
-    /// 学期
-    pub semester: String,
+    /// 開講年度・学期
+    pub term: AcademicTerm,
 }
 
 /// 教員検索結果の1項目
@@ -1,10 +1,326 @@
-// Export all parsers for use in lib.rs
-pub use crate::parsers::assignment_submit::AssignmentSubmitParser;
-pub use crate::parsers::classroom_reservation_status::ClassroomReservationStatusParser;
-pub use crate::parsers::grades::GradesParser;
-pub use crate::parsers::portal::{PortalParser, PortalAllNotificationsParser, PortalClassContactParser, PortalAllClassContactParser};
-pub use crate::parsers::portal::notification_detail::NotificationDetailParser;
-pub use crate::parsers::questionnaire::QuestionnaireParser;
-pub use crate::parsers::student_info_inquiry::StudentInfoInquiryParser;
-pub use crate::parsers::syllabus::SyllabusParser;
-pub use crate::parsers::test_answer_status::TestAnswerStatusParser;
+//! WebAssembly向けのパーサー公開層
+//!
+//! ページ種別（[`PageKind`]）ごとに1つのクラスを[`page_parsers!`]で生成します。
+//! すべてのクラスは同じ形をしており、JavaScriptからは次のように使用できます。
+//!
+//! ```text
+//! const parser = new GradeInquiryParser({ strict_mode: true }); // オプションは省略可能
+//! parser.parse(html);                         // 構築時のオプションで解析
+//! parser.parse(html, { debug_mode: true });   // この呼び出しだけオプションを上書き
+//! parser.parse_bytes(bytes, contentType);
+//! parser.parse_with_warnings(html);
+//! parser.parse_with_diagnostics(html);
+//! parser.parse_envelope(html);                // { kind, schema_version, parser_version, parsed_at, data }
+//! parser.page_kind; // "grade-inquiry"
+//! ```
+//!
+//! 各クラスは対応するパーサー群のフィーチャーが有効な場合のみ生成されます。
+//!
+//! 学生時間割の`StudentTimetableParser`はこの形に含めていません。学生時間割の解析結果は
+//! まだ項目を持たないため[`PageKind`]にも含めておらず、解析結果が定まった時点で
+//! ページ種別とあわせて追加します。
+//!
+//! 置き換え前のページ群ごとのクラス（`GradesParser`など）は、1リリースの間だけ
+//! [`legacy`]モジュールで互換クラスとして提供します。
+
+// ページ種別を持つパーサー群がすべて無効な場合、生成されるクラスはない
+#![cfg_attr(
+    not(any(
+        feature = "portal",
+        feature = "grades",
+        feature = "syllabus",
+        feature = "assignments",
+        feature = "classroom",
+        feature = "questionnaire",
+        feature = "student-info",
+        feature = "tests"
+    )),
+    allow(unused_imports)
+)]
+
+use wasm_bindgen::prelude::*;
+
+use crate::common::options::ParseOptions;
+use crate::common::outcome::ParseOutcome;
+use crate::common::traits::PageParser;
+use crate::parsers::envelope::Envelope;
+use crate::parsers::page::PageKind;
+use crate::utils::error::ParseError;
+use scraper::Html;
+
+#[cfg(feature = "portal")]
+use crate::common::dom::document_root;
+
+#[cfg(feature = "csv")]
+pub use crate::export::csv::GradeInquiryCsvExporter;
+#[cfg(feature = "ical")]
+pub use crate::export::ical::IcalExporter;
+#[cfg(feature = "grades")]
+pub use crate::parsers::grades::gpa::GpaCalculator;
+#[cfg(feature = "portal")]
+pub use crate::parsers::portal::ParsedPage;
+#[cfg(feature = "timetable")]
+pub use crate::parsers::timetable::student_timetable::StudentTimetableParser;
+
+// 互換クラスのあるページ群のフィーチャーがどれも無効なビルドでは含めない
+#[cfg(any(
+    feature = "grades",
+    feature = "syllabus",
+    feature = "assignments",
+    feature = "questionnaire",
+    feature = "student-info"
+))]
+pub mod legacy;
+#[cfg(any(
+    feature = "grades",
+    feature = "syllabus",
+    feature = "assignments",
+    feature = "questionnaire",
+    feature = "student-info"
+))]
+pub use legacy::*;
+
+/// ページ種別ごとのWASMクラスを生成する
+///
+/// `クラス名 => ページ種別 (フィーチャー): モデル = |options| パーサーの構築式`の形で列挙します。
+/// 構築式は解析のたびに評価され、`PageParser<モデル>`を実装する値を返す必要があります。
+/// 構築時にも一度評価し、セレクターの構築エラーなどをコンストラクターのエラーとして返します。
+macro_rules! page_parsers {
+    (
+        $(
+            $(#[$meta:meta])*
+            $name:ident => $kind:ident ($feature:literal): $model:ty = |$options:ident| $build:expr;
+        )*
+    ) => {
+        $(
+            $(#[$meta])*
+            #[cfg(feature = $feature)]
+            #[wasm_bindgen]
+            pub struct $name {
+                options: ParseOptions,
+            }
+
+            #[cfg(feature = $feature)]
+            #[wasm_bindgen]
+            impl $name {
+                /// パーサーインスタンスを作成する
+                ///
+                /// `options`を省略した場合は既定のオプションを使用します。
+                #[wasm_bindgen(constructor)]
+                pub fn new(options: Option<ParseOptions>) -> Result<$name, ParseError> {
+                    let parser = Self {
+                        options: options.unwrap_or_default(),
+                    };
+                    parser.parser(None)?;
+                    Ok(parser)
+                }
+
+                /// このクラスが解析するページ種別
+                #[wasm_bindgen(getter)]
+                pub fn page_kind(&self) -> PageKind {
+                    PageKind::$kind
+                }
+
+                /// 構築時に指定した解析オプション
+                #[wasm_bindgen(getter)]
+                pub fn options(&self) -> ParseOptions {
+                    self.options.clone()
+                }
+
+                /// HTML文字列を解析する
+                ///
+                /// `options`を指定した場合は、この呼び出しに限り構築時のオプションの代わりに使用します。
+                pub fn parse(
+                    &self,
+                    html_content: &str,
+                    options: Option<ParseOptions>,
+                ) -> Result<$model, ParseError> {
+                    self.parser(options)?
+                        .parse_document(&Html::parse_document(html_content))
+                }
+
+                /// バイト列を解析する（文字コードは`content_type`の`charset`またはHTML内の宣言から判定）
+                pub fn parse_bytes(
+                    &self,
+                    bytes: &[u8],
+                    content_type: Option<String>,
+                    options: Option<ParseOptions>,
+                ) -> Result<$model, ParseError> {
+                    self.parser(options)?
+                        .parse_bytes(bytes, content_type.as_deref())
+                }
+
+                /// HTML文字列を解析し、既定値で補った項目などの警告とあわせて返す
+                ///
+                /// `debug_mode`が有効な場合は`diagnostics`も含めます。
+                pub fn parse_with_warnings(
+                    &self,
+                    html_content: &str,
+                    options: Option<ParseOptions>,
+                ) -> Result<ParseOutcome<$model>, ParseError> {
+                    let document = Html::parse_document(html_content);
+                    let options = options.unwrap_or_else(|| self.options.clone());
+                    let debug_mode = options.debug_mode;
+                    let parser = self.parser(Some(options))?;
+                    if debug_mode {
+                        parser.parse_with_diagnostics(&document)
+                    } else {
+                        parser.parse_with_warnings(&document)
+                    }
+                }
+
+                /// HTML文字列を解析し、ページ種別・スキーマバージョン・解析日時などを付けたエンベロープで返す
+                pub fn parse_envelope(
+                    &self,
+                    html_content: &str,
+                    options: Option<ParseOptions>,
+                ) -> Result<Envelope<$model>, ParseError> {
+                    self.parse(html_content, options)
+                        .map(|data| Envelope::new(PageKind::$kind, data))
+                }
+
+                /// HTML文字列を解析し、セレクターの試行記録を`diagnostics`に含めて返す
+                pub fn parse_with_diagnostics(
+                    &self,
+                    html_content: &str,
+                    options: Option<ParseOptions>,
+                ) -> Result<ParseOutcome<$model>, ParseError> {
+                    self.parser(options)?
+                        .parse_with_diagnostics(&Html::parse_document(html_content))
+                }
+            }
+
+            #[cfg(feature = $feature)]
+            impl $name {
+                fn parser(
+                    &self,
+                    options: Option<ParseOptions>,
+                ) -> Result<impl PageParser<$model>, ParseError> {
+                    #[allow(unused_variables)]
+                    let $options = options.unwrap_or_else(|| self.options.clone());
+                    Ok($build)
+                }
+            }
+        )*
+    };
+}
+
+page_parsers! {
+    /// ポータル
+    PortalParser => Portal ("portal"): crate::parsers::portal::model::Portal =
+        |options| crate::parsers::portal::builder::PortalParserBuilder::new()
+            .with_options(options)
+            .build()?;
+    /// ポータル（お知らせ全表示）
+    PortalAllNotificationsParser => PortalAllNotifications ("portal"): crate::parsers::portal::model::Portal =
+        |options| crate::parsers::portal::builder::PortalParserBuilder::new()
+            .with_options(options)
+            .build_all_notifications()?;
+    /// ポータル（授業連絡表示）
+    PortalClassContactParser => PortalClassContact ("portal"): crate::parsers::portal::model::Portal =
+        |options| crate::parsers::portal::builder::PortalParserBuilder::new()
+            .with_options(options)
+            .build_class_contact()?;
+    /// ポータル（授業連絡全表示）
+    PortalAllClassContactParser => PortalAllClassContact ("portal"): crate::parsers::portal::model::Portal =
+        |options| crate::parsers::portal::builder::PortalParserBuilder::new()
+            .with_options(options)
+            .build_all_class_contact()?;
+    /// お知らせ詳細
+    NotificationDetailParser => NotificationDetail ("portal"):
+        crate::parsers::portal::notification_detail::model::NotificationDetail =
+        |options| crate::parsers::portal::notification_detail::builder::NotificationDetailParserBuilder::new()
+            .with_options(options)
+            .build()?;
+    /// 成績照会
+    GradeInquiryParser => GradeInquiry ("grades"): crate::parsers::grades::grade_inquiry::GradeInquiry =
+        |options| crate::parsers::grades::grade_inquiry::GradeInquiryParserBuilder::new()
+            .with_options(options);
+    /// 卒業見込判定
+    GraduationPredictionParser => GraduationPrediction ("grades"):
+        crate::parsers::grades::graduation_prediction::SotsugyoMikonHanteiKekka =
+        |options| crate::parsers::grades::graduation_prediction::GraduationPredictionParserBuilder::new()
+            .with_options(options);
+    /// 進級見込判定
+    PromotionPredictionParser => PromotionPrediction ("grades"):
+        crate::parsers::grades::promotion_prediction::PromotionPredictionData =
+        |options| crate::parsers::grades::promotion_prediction::PromotionPredictionParserBuilder::new()
+            .with_options(options);
+    /// 免許取得見込判定（解析オプションの影響を受けません）
+    LicensePredictionParser => LicensePrediction ("grades"):
+        crate::parsers::grades::license_prediction::LicensePredictionData =
+        |options| crate::parsers::grades::license_prediction::LicensePredictionParserBuilder::new();
+    /// シラバス検索結果
+    SyllabusSearchResultParser => SyllabusSearchResult ("syllabus"):
+        crate::parsers::syllabus::search_result::SyllabusSearchResultPage =
+        |options| crate::parsers::syllabus::search_result::SyllabusSearchResultParser::new()?
+            .with_options(options);
+    /// シラバス詳細
+    SyllabusViewParser => SyllabusView ("syllabus"): crate::parsers::syllabus::view::LessonInfo =
+        |options| crate::parsers::syllabus::view::SyllabusViewParser::new()?
+            .with_options(options);
+    /// 課題提出一覧
+    AssignmentListParser => AssignmentList ("assignments"):
+        crate::parsers::assignment_submit::model::AssignmentList =
+        |options| crate::parsers::assignment_submit::builder::AssignmentListParserBuilder::new()
+            .with_options(options);
+    /// 教室予約状況
+    ClassroomReservationStatusParser => ClassroomReservationStatus ("classroom"):
+        crate::parsers::classroom_reservation_status::model::ClassroomReservationStatus =
+        |options| crate::parsers::classroom_reservation_status::builder::ClassroomReservationStatusParserBuilder::new()
+            .with_options(options);
+    /// アンケート一覧
+    QuestionnaireListParser => QuestionnaireList ("questionnaire"):
+        crate::parsers::questionnaire::questionnaire_list::QuestionnaireList =
+        |options| crate::parsers::questionnaire::questionnaire_list::QuestionnaireListParserBuilder::new()
+            .with_options(options);
+    /// 学籍情報照会
+    StudentInfoParser => StudentInfo ("student-info"): crate::parsers::student_info_inquiry::model::StudentInfo =
+        |options| crate::parsers::student_info_inquiry::builder::StudentInfoInquiryParserBuilder::new()
+            .with_options(options);
+    /// テスト解答状況
+    TestAnswerStatusParser => TestAnswerStatus ("tests"): crate::parsers::test_answer_status::model::TestAnswerStatus =
+        |options| crate::parsers::test_answer_status::parser::TestAnswerStatusParserImpl::new_with_options(options)?;
+}
+
+/// 読み込み済みのブラウザのDOMからポータルを解析するメソッドを追加する
+///
+/// `outerHTML`を文字列化して[`PortalParser::parse`]などに渡した場合と同じ結果を返します。
+#[cfg(feature = "portal")]
+macro_rules! portal_dom_methods {
+    ($($name:ident => $build:ident;)*) => {
+        $(
+            #[wasm_bindgen]
+            impl $name {
+                /// ブラウザのDOM要素を起点に解析する
+                pub fn parse_element(
+                    &self,
+                    root: &web_sys::Element,
+                    options: Option<ParseOptions>,
+                ) -> Result<crate::parsers::portal::model::Portal, ParseError> {
+                    crate::parsers::portal::builder::PortalParserBuilder::new()
+                        .with_options(options.unwrap_or_else(|| self.options.clone()))
+                        .$build()?
+                        .parse_element(root)
+                }
+
+                /// ブラウザのドキュメント全体を解析する
+                pub fn parse_dom_document(
+                    &self,
+                    document: &web_sys::Document,
+                    options: Option<ParseOptions>,
+                ) -> Result<crate::parsers::portal::model::Portal, ParseError> {
+                    self.parse_element(&document_root(document)?, options)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "portal")]
+portal_dom_methods! {
+    PortalParser => build;
+    PortalAllNotificationsParser => build_all_notifications;
+    PortalClassContactParser => build_class_contact;
+    PortalAllClassContactParser => build_all_class_contact;
+}
@@ -0,0 +1,323 @@
+//! 旧WASMクラスの互換レイヤー
+//!
+//! ページ群ごとのクラス（`GradesParser`・`SyllabusParser`など）は、ページ種別ごとのクラスに
+//! 置き換えられました。移行期間として1リリースの間だけ、同じ名前とメソッドで
+//! 新しいクラスに処理を委譲するクラスを残します。次のリリースで削除します。
+
+use wasm_bindgen::prelude::*;
+
+use super::*;
+
+/// 成績関連ページのパーサー
+///
+/// @deprecated `GradeInquiryParser`・`GraduationPredictionParser`を使用してください。次のリリースで削除します。
+#[cfg(feature = "grades")]
+#[wasm_bindgen]
+pub struct GradesParser {
+    grade_inquiry: GradeInquiryParser,
+    graduation_prediction: GraduationPredictionParser,
+}
+
+#[cfg(feature = "grades")]
+#[wasm_bindgen]
+impl GradesParser {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::with_options(ParseOptions::default())
+    }
+
+    /// 解析オプションを指定してパーサーインスタンスを作成
+    #[wasm_bindgen]
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self {
+            grade_inquiry: GradeInquiryParser {
+                options: options.clone(),
+            },
+            graduation_prediction: GraduationPredictionParser { options },
+        }
+    }
+
+    pub fn grade_inquiry(
+        &self,
+        html_content: &str,
+    ) -> Result<crate::parsers::grades::grade_inquiry::GradeInquiry, ParseError> {
+        self.grade_inquiry.parse(html_content, None)
+    }
+
+    /// バイト列から成績照会を解析する（文字コードは自動判定）
+    pub fn grade_inquiry_bytes(
+        &self,
+        bytes: &[u8],
+        content_type: Option<String>,
+    ) -> Result<crate::parsers::grades::grade_inquiry::GradeInquiry, ParseError> {
+        self.grade_inquiry.parse_bytes(bytes, content_type, None)
+    }
+
+    /// 成績照会を解析し、既定値で補った項目などの警告とあわせて返す
+    pub fn grade_inquiry_with_warnings(
+        &self,
+        html_content: &str,
+    ) -> Result<ParseOutcome<crate::parsers::grades::grade_inquiry::GradeInquiry>, ParseError> {
+        self.grade_inquiry.parse_with_warnings(html_content, None)
+    }
+
+    /// 成績照会を解析し、GPAや単位表の探索に使ったセレクターの記録を`diagnostics`に含めて返す
+    pub fn grade_inquiry_with_diagnostics(
+        &self,
+        html_content: &str,
+    ) -> Result<ParseOutcome<crate::parsers::grades::grade_inquiry::GradeInquiry>, ParseError> {
+        self.grade_inquiry
+            .parse_with_diagnostics(html_content, None)
+    }
+
+    /// HTMLコンテンツから卒業見込判定を解析
+    pub fn graduation_prediction(
+        &self,
+        html_content: &str,
+    ) -> Result<crate::parsers::grades::graduation_prediction::SotsugyoMikonHanteiKekka, ParseError>
+    {
+        self.graduation_prediction.parse(html_content, None)
+    }
+
+    /// バイト列から卒業見込判定を解析する（文字コードは自動判定）
+    pub fn graduation_prediction_bytes(
+        &self,
+        bytes: &[u8],
+        content_type: Option<String>,
+    ) -> Result<crate::parsers::grades::graduation_prediction::SotsugyoMikonHanteiKekka, ParseError>
+    {
+        self.graduation_prediction
+            .parse_bytes(bytes, content_type, None)
+    }
+
+    /// 卒業見込判定を解析し、見つからなかった一覧や解釈できなかった単位数などの警告とあわせて返す
+    pub fn graduation_prediction_with_warnings(
+        &self,
+        html_content: &str,
+    ) -> Result<
+        ParseOutcome<crate::parsers::grades::graduation_prediction::SotsugyoMikonHanteiKekka>,
+        ParseError,
+    > {
+        self.graduation_prediction
+            .parse_with_warnings(html_content, None)
+    }
+}
+
+/// WASM用のシラバスパーサー
+///
+/// @deprecated `SyllabusSearchResultParser`・`SyllabusViewParser`を使用してください。次のリリースで削除します。
+#[cfg(feature = "syllabus")]
+#[wasm_bindgen]
+pub struct SyllabusParser {
+    search_result: SyllabusSearchResultParser,
+    view: SyllabusViewParser,
+}
+
+#[cfg(feature = "syllabus")]
+#[wasm_bindgen]
+impl SyllabusParser {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<SyllabusParser, ParseError> {
+        Self::with_options(ParseOptions::default())
+    }
+
+    /// 解析オプションを指定してパーサーインスタンスを作成
+    #[wasm_bindgen]
+    pub fn with_options(options: ParseOptions) -> Result<Self, ParseError> {
+        Ok(Self {
+            search_result: SyllabusSearchResultParser::new(Some(options.clone()))?,
+            view: SyllabusViewParser::new(Some(options))?,
+        })
+    }
+
+    /// HTMLコンテンツからシラバス検索結果を解析
+    pub fn parse_search_result(
+        &self,
+        html_content: &str,
+    ) -> Result<crate::parsers::syllabus::search_result::SyllabusSearchResultPage, ParseError> {
+        self.search_result.parse(html_content, None)
+    }
+
+    /// バイト列からシラバス検索結果を解析する（文字コードは自動判定）
+    pub fn parse_search_result_bytes(
+        &self,
+        bytes: &[u8],
+        content_type: Option<String>,
+    ) -> Result<crate::parsers::syllabus::search_result::SyllabusSearchResultPage, ParseError> {
+        self.search_result.parse_bytes(bytes, content_type, None)
+    }
+
+    /// HTMLコンテンツからシラバス詳細情報を解析
+    pub fn parse_view(
+        &self,
+        html_content: &str,
+    ) -> Result<crate::parsers::syllabus::view::LessonInfo, ParseError> {
+        self.view.parse(html_content, None)
+    }
+
+    /// バイト列からシラバス詳細情報を解析する（文字コードは自動判定）
+    pub fn parse_view_bytes(
+        &self,
+        bytes: &[u8],
+        content_type: Option<String>,
+    ) -> Result<crate::parsers::syllabus::view::LessonInfo, ParseError> {
+        self.view.parse_bytes(bytes, content_type, None)
+    }
+}
+
+/// 課題提出関連ページのパーサー
+///
+/// @deprecated `AssignmentListParser`を使用してください。次のリリースで削除します。
+#[cfg(feature = "assignments")]
+#[wasm_bindgen]
+pub struct AssignmentSubmitParser {
+    assignment_list: AssignmentListParser,
+}
+
+#[cfg(feature = "assignments")]
+#[wasm_bindgen]
+impl AssignmentSubmitParser {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::with_options(ParseOptions::default())
+    }
+
+    /// 解析オプションを指定してパーサーインスタンスを作成
+    #[wasm_bindgen]
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self {
+            assignment_list: AssignmentListParser { options },
+        }
+    }
+
+    pub fn assignment_list(
+        &self,
+        html_content: &str,
+    ) -> Result<crate::parsers::assignment_submit::model::AssignmentList, ParseError> {
+        self.assignment_list.parse(html_content, None)
+    }
+
+    /// バイト列から課題一覧を解析する（文字コードは自動判定）
+    pub fn assignment_list_bytes(
+        &self,
+        bytes: &[u8],
+        content_type: Option<String>,
+    ) -> Result<crate::parsers::assignment_submit::model::AssignmentList, ParseError> {
+        self.assignment_list.parse_bytes(bytes, content_type, None)
+    }
+
+    /// 課題一覧を解析し、一覧表とページネーションの探索結果を`diagnostics`に含めて返す
+    pub fn assignment_list_with_diagnostics(
+        &self,
+        html_content: &str,
+    ) -> Result<ParseOutcome<crate::parsers::assignment_submit::model::AssignmentList>, ParseError>
+    {
+        self.assignment_list
+            .parse_with_diagnostics(html_content, None)
+    }
+}
+
+/// アンケート関連ページのパーサー
+///
+/// @deprecated `QuestionnaireListParser`を使用してください。次のリリースで削除します。
+#[cfg(feature = "questionnaire")]
+#[wasm_bindgen]
+pub struct QuestionnaireParser {
+    questionnaire_list: QuestionnaireListParser,
+}
+
+#[cfg(feature = "questionnaire")]
+#[wasm_bindgen]
+impl QuestionnaireParser {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::with_options(ParseOptions::default())
+    }
+
+    /// 解析オプションを指定してパーサーインスタンスを作成
+    #[wasm_bindgen]
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self {
+            questionnaire_list: QuestionnaireListParser { options },
+        }
+    }
+
+    pub fn questionnaire_list(
+        &self,
+        html_content: &str,
+    ) -> Result<crate::parsers::questionnaire::questionnaire_list::QuestionnaireList, ParseError>
+    {
+        self.questionnaire_list.parse(html_content, None)
+    }
+
+    /// バイト列からアンケート一覧を解析する（文字コードは自動判定）
+    pub fn questionnaire_list_bytes(
+        &self,
+        bytes: &[u8],
+        content_type: Option<String>,
+    ) -> Result<crate::parsers::questionnaire::questionnaire_list::QuestionnaireList, ParseError>
+    {
+        self.questionnaire_list
+            .parse_bytes(bytes, content_type, None)
+    }
+}
+
+/// 学籍情報照会のパーサー
+///
+/// @deprecated `StudentInfoParser`を使用してください。次のリリースで削除します。
+#[cfg(feature = "student-info")]
+#[wasm_bindgen]
+pub struct StudentInfoInquiryParser {
+    parser: StudentInfoParser,
+}
+
+#[cfg(feature = "student-info")]
+#[wasm_bindgen]
+impl StudentInfoInquiryParser {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::with_options(ParseOptions::default())
+    }
+
+    /// 解析オプションを指定してパーサーインスタンスを作成
+    #[wasm_bindgen]
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self {
+            parser: StudentInfoParser { options },
+        }
+    }
+
+    pub fn parse(
+        &self,
+        html_content: &str,
+    ) -> Result<crate::parsers::student_info_inquiry::model::StudentInfo, ParseError> {
+        self.parser.parse(html_content, None)
+    }
+
+    /// バイト列から学籍情報を解析する（文字コードは自動判定）
+    pub fn parse_bytes(
+        &self,
+        bytes: &[u8],
+        content_type: Option<String>,
+    ) -> Result<crate::parsers::student_info_inquiry::model::StudentInfo, ParseError> {
+        self.parser.parse_bytes(bytes, content_type, None)
+    }
+
+    /// 学籍情報を解析し、欠落した項目などの警告とあわせて返す
+    pub fn parse_with_warnings(
+        &self,
+        html_content: &str,
+    ) -> Result<ParseOutcome<crate::parsers::student_info_inquiry::model::StudentInfo>, ParseError>
+    {
+        self.parser.parse_with_warnings(html_content, None)
+    }
+
+    /// 学籍情報を解析し、試行したセレクターの記録もあわせて返す
+    pub fn parse_with_diagnostics(
+        &self,
+        html_content: &str,
+    ) -> Result<ParseOutcome<crate::parsers::student_info_inquiry::model::StudentInfo>, ParseError>
+    {
+        self.parser.parse_with_diagnostics(html_content, None)
+    }
+}
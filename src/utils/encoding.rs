@@ -0,0 +1,151 @@
+//! # Character Encoding Helpers
+//!
+//! UNIVERSAL PASSPORT EX installations frequently serve Shift_JIS or EUC-JP
+//! pages. These helpers detect the charset of raw response bytes and turn
+//! them into UTF-8 strings that the parsers can consume.
+
+use encoding_rs::{Encoding, EUC_JP, SHIFT_JIS, UTF_8};
+
+use crate::utils::error::ParseError;
+
+/// Number of leading bytes scanned for a `<meta>` charset declaration
+///
+/// Matches the prescan window used by browsers.
+const META_PRESCAN_LENGTH: usize = 1024;
+
+/// Extract the `charset` parameter from a Content-Type value
+///
+/// Returns `None` when the value carries no charset parameter.
+pub fn charset_from_content_type(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches(|c| c == '"' || c == '\''))
+            .filter(|value| !value.is_empty())
+    })
+}
+
+/// Extract the charset declared by a `<meta>` tag near the start of the document
+///
+/// Both `<meta charset="...">` and the `http-equiv="Content-Type"` form are
+/// recognized.
+pub fn charset_from_meta(bytes: &[u8]) -> Option<&str> {
+    let head = &bytes[..bytes.len().min(META_PRESCAN_LENGTH)];
+    let mut rest = head;
+
+    while let Some(start) = find_ignore_ascii_case(rest, b"<meta") {
+        let tag = &rest[start..];
+        let tag = &tag[..tag.iter().position(|&b| b == b'>').unwrap_or(tag.len())];
+
+        if let Some(position) = find_ignore_ascii_case(tag, b"charset") {
+            let value = skip_while(&tag[position + b"charset".len()..], |b| {
+                b.is_ascii_whitespace()
+            });
+            if let Some(value) = value.strip_prefix(b"=") {
+                let value = skip_while(value, |b| {
+                    b.is_ascii_whitespace() || b == b'"' || b == b'\''
+                });
+                let length = value
+                    .iter()
+                    .position(|b| {
+                        !(b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'))
+                    })
+                    .unwrap_or(value.len());
+                if length > 0 {
+                    return std::str::from_utf8(&value[..length]).ok();
+                }
+            }
+        }
+
+        rest = &rest[start + b"<meta".len()..];
+    }
+
+    None
+}
+
+/// Detect the encoding of an HTML document
+///
+/// Sources are consulted in the same order as browsers: byte order mark,
+/// the transport Content-Type, the `<meta>` declaration, and finally a
+/// heuristic that chooses between UTF-8, Shift_JIS and EUC-JP.
+pub fn detect_encoding(bytes: &[u8], content_type: Option<&str>) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+
+    content_type
+        .and_then(charset_from_content_type)
+        .or_else(|| charset_from_meta(bytes))
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .map(Encoding::output_encoding)
+        .unwrap_or_else(|| guess_japanese_encoding(bytes))
+}
+
+/// Guess between UTF-8, Shift_JIS and EUC-JP from the byte content alone
+///
+/// Pure ASCII and valid UTF-8 are treated as UTF-8. Otherwise the candidate
+/// that decodes without errors wins; when both do, bytes in the range
+/// `0x81..=0x9F` (lead bytes that only Shift_JIS uses, apart from the EUC-JP
+/// single shifts `0x8E`/`0x8F`) tip the balance.
+pub fn guess_japanese_encoding(bytes: &[u8]) -> &'static Encoding {
+    if std::str::from_utf8(bytes).is_ok() {
+        return UTF_8;
+    }
+
+    let decodes = |encoding: &'static Encoding| {
+        encoding
+            .decode_without_bom_handling_and_without_replacement(bytes)
+            .is_some()
+    };
+
+    match (decodes(SHIFT_JIS), decodes(EUC_JP)) {
+        (true, false) => SHIFT_JIS,
+        (false, true) => EUC_JP,
+        _ if bytes.iter().any(|b| matches!(b, 0x81..=0x8D | 0x90..=0x9F)) => SHIFT_JIS,
+        _ => EUC_JP,
+    }
+}
+
+/// Decode an HTML document from raw bytes
+///
+/// The encoding is chosen by [`detect_encoding`]. Malformed byte sequences
+/// are rejected rather than replaced, producing
+/// [`ParseError::InvalidFileEncoding`]. `source` is only used to describe the
+/// input in the returned error.
+pub fn decode_html(
+    bytes: &[u8],
+    content_type: Option<&str>,
+    source: &str,
+) -> Result<String, ParseError> {
+    decode_with_encoding(bytes, detect_encoding(bytes, content_type), source)
+}
+
+fn decode_with_encoding(
+    bytes: &[u8],
+    encoding: &'static Encoding,
+    source: &str,
+) -> Result<String, ParseError> {
+    let (encoding, bom_length) = Encoding::for_bom(bytes).unwrap_or((encoding, 0));
+
+    encoding
+        .decode_without_bom_handling_and_without_replacement(&bytes[bom_length..])
+        .map(|text| text.into_owned())
+        .ok_or_else(|| ParseError::InvalidFileEncoding {
+            path: format!("{source} (not valid {})", encoding.name()),
+        })
+}
+
+fn find_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+}
+
+fn skip_while(bytes: &[u8], predicate: impl Fn(u8) -> bool) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|&b| !predicate(b))
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
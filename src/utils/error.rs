@@ -3,13 +3,32 @@
 //! This module defines all error types used throughout the library, following
 //! the new architecture error message formatting and structure.
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
 
 /// Primary error type for parsing operations
 ///
 /// All error messages follow the format: "Failed to <action>: <reason>"
 /// or "Invalid <target>: <details>" for validation errors.
-#[derive(Error, Debug)]
+///
+/// Serializes as an object tagged by `code` (see [`ParseError::code`]) with the
+/// variant's fields and the display `message` alongside it. The WASM layer
+/// throws a JavaScript `Error` carrying the same properties, and the TypeScript
+/// type is a discriminated union on `code` that always has `message`.
+// `remote = "Self"` keeps the derived impls as inherent functions so that the
+// `Serialize`/`Deserialize` impls below can add the `message` field.
+#[derive(Error, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(extend(
+        "properties" = { "message": { "type": "string", "description": "Display message of the error" } },
+        "required" = ["message"]
+    ))
+)]
+#[serde(remote = "Self", tag = "code", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ParseError {
     // CSS Selector related errors
     #[error("Failed to create CSS selector '{selector}': {context}")]
@@ -85,8 +104,13 @@ pub enum ParseError {
     #[error("Failed to complete operation: {operation} was cancelled")]
     OperationCancelled { operation: String },
 
+    // Serialized as `reason` so it does not collide with the display `message`
     #[error("Failed to process {context}: {message}")]
-    UnexpectedError { context: String, message: String },
+    UnexpectedError {
+        context: String,
+        #[serde(rename = "reason")]
+        message: String,
+    },
 
     // Data validation errors
     #[error("Failed to process empty {data_type} entry")]
@@ -117,7 +141,143 @@ pub enum ParseError {
     PaginationProcessingFailed { reason: String },
 }
 
+/// TypeScript declaration of the serialized shape
+///
+/// Written by hand because the derived declaration only covers the variants'
+/// fields; keep the union in sync with the variants (checked in `tests/error.rs`).
+const TS_DECL: &str = r#"/**
+ * Primary error type for parsing operations
+ *
+ * Serialized as an object tagged by `code` with the variant's fields and the
+ * display `message`.
+ */
+export type ParseError = { message: string } & (
+    { code: "SELECTOR_CREATION_FAILED"; selector: string; context: string } |
+    { code: "ELEMENT_NOT_FOUND"; selector: string; context: string } |
+    { code: "ATTRIBUTE_EXTRACTION_FAILED"; attribute: string; context: string } |
+    { code: "DATA_EXTRACTION_FAILED"; data_type: string; reason: string } |
+    { code: "DATA_PARSING_FAILED"; data_type: string; value: string } |
+    { code: "INVALID_DATA_FORMAT"; data_type: string; details: string } |
+    { code: "HTML_PARSING_FAILED"; reason: string } |
+    { code: "INVALID_HTML_STRUCTURE"; expected: string } |
+    { code: "EMPTY_HTML_CONTENT" } |
+    { code: "FILE_NOT_FOUND"; path: string } |
+    { code: "FILE_PERMISSION_DENIED"; path: string } |
+    { code: "INVALID_FILE_ENCODING"; path: string } |
+    { code: "INVALID_SESSION_ID"; session_id: string } |
+    { code: "INVALID_URL"; url: string } |
+    { code: "REQUEST_TIMEOUT" } |
+    { code: "SERVER_ERROR"; status: string } |
+    { code: "MISSING_CONFIGURATION"; config_name: string } |
+    { code: "INVALID_CONFIGURATION"; config_name: string; value: string } |
+    { code: "VALUE_OUT_OF_RANGE"; field: string; value: string; range: string } |
+    { code: "NOT_IMPLEMENTED"; operation: string } |
+    { code: "OPERATION_CANCELLED"; operation: string } |
+    { code: "UNEXPECTED_ERROR"; context: string; reason: string } |
+    { code: "EMPTY_DATA_ENTRY"; data_type: string } |
+    { code: "MISSING_REQUIRED_FIELD"; field_name: string } |
+    { code: "FORM_DATA_EXTRACTION_FAILED"; reason: string } |
+    { code: "FORM_ELEMENT_NOT_FOUND"; form_selector: string } |
+    { code: "TABLE_DATA_EXTRACTION_FAILED"; reason: string } |
+    { code: "TABLE_ROW_PROCESSING_FAILED"; row_context: string } |
+    { code: "NAVIGATION_EXTRACTION_FAILED"; reason: string } |
+    { code: "PAGINATION_PROCESSING_FAILED"; reason: string }
+);"#;
+
+const _: () = {
+    #[wasm_bindgen(typescript_custom_section)]
+    const TS_APPEND_CONTENT: &'static str = TS_DECL;
+};
+
+impl Tsify for ParseError {
+    type JsType = JsValue;
+    const DECL: &'static str = TS_DECL;
+}
+
+impl Serialize for ParseError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct WithMessage<'a> {
+            #[serde(flatten, serialize_with = "tagged")]
+            error: &'a ParseError,
+            message: String,
+        }
+
+        fn tagged<S: Serializer>(error: &&ParseError, serializer: S) -> Result<S::Ok, S::Error> {
+            ParseError::serialize(error, serializer)
+        }
+
+        WithMessage {
+            error: self,
+            message: self.to_string(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ParseError {
+    /// The `message` field is ignored; it is rebuilt from the variant's fields.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ParseError::deserialize(deserializer)
+    }
+}
+
+/// Thrown to JavaScript as an `Error` whose `message` is the display message,
+/// with `code` and the variant's fields set as properties
+#[cfg(target_arch = "wasm32")]
+impl From<ParseError> for wasm_bindgen::JsValue {
+    fn from(error: ParseError) -> Self {
+        use wasm_bindgen::JsCast;
+
+        let object = js_sys::Error::new(&error.to_string());
+        object.set_name("ParseError");
+        if let Ok(fields) = error.into_js() {
+            js_sys::Object::assign(&object, fields.unchecked_ref());
+        }
+        object.into()
+    }
+}
+
 impl ParseError {
+    /// Stable machine-readable code identifying the error kind
+    ///
+    /// Matches the `code` tag of the serialized form, so JavaScript callers
+    /// can branch on it without parsing the display message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::SelectorCreationFailed { .. } => "SELECTOR_CREATION_FAILED",
+            Self::ElementNotFound { .. } => "ELEMENT_NOT_FOUND",
+            Self::AttributeExtractionFailed { .. } => "ATTRIBUTE_EXTRACTION_FAILED",
+            Self::DataExtractionFailed { .. } => "DATA_EXTRACTION_FAILED",
+            Self::DataParsingFailed { .. } => "DATA_PARSING_FAILED",
+            Self::InvalidDataFormat { .. } => "INVALID_DATA_FORMAT",
+            Self::HtmlParsingFailed { .. } => "HTML_PARSING_FAILED",
+            Self::InvalidHtmlStructure { .. } => "INVALID_HTML_STRUCTURE",
+            Self::EmptyHtmlContent => "EMPTY_HTML_CONTENT",
+            Self::FileNotFound { .. } => "FILE_NOT_FOUND",
+            Self::FilePermissionDenied { .. } => "FILE_PERMISSION_DENIED",
+            Self::InvalidFileEncoding { .. } => "INVALID_FILE_ENCODING",
+            Self::InvalidSessionId { .. } => "INVALID_SESSION_ID",
+            Self::InvalidUrl { .. } => "INVALID_URL",
+            Self::RequestTimeout => "REQUEST_TIMEOUT",
+            Self::ServerError { .. } => "SERVER_ERROR",
+            Self::MissingConfiguration { .. } => "MISSING_CONFIGURATION",
+            Self::InvalidConfiguration { .. } => "INVALID_CONFIGURATION",
+            Self::ValueOutOfRange { .. } => "VALUE_OUT_OF_RANGE",
+            Self::NotImplemented { .. } => "NOT_IMPLEMENTED",
+            Self::OperationCancelled { .. } => "OPERATION_CANCELLED",
+            Self::UnexpectedError { .. } => "UNEXPECTED_ERROR",
+            Self::EmptyDataEntry { .. } => "EMPTY_DATA_ENTRY",
+            Self::MissingRequiredField { .. } => "MISSING_REQUIRED_FIELD",
+            Self::FormDataExtractionFailed { .. } => "FORM_DATA_EXTRACTION_FAILED",
+            Self::FormElementNotFound { .. } => "FORM_ELEMENT_NOT_FOUND",
+            Self::TableDataExtractionFailed { .. } => "TABLE_DATA_EXTRACTION_FAILED",
+            Self::TableRowProcessingFailed { .. } => "TABLE_ROW_PROCESSING_FAILED",
+            Self::NavigationExtractionFailed { .. } => "NAVIGATION_EXTRACTION_FAILED",
+            Self::PaginationProcessingFailed { .. } => "PAGINATION_PROCESSING_FAILED",
+        }
+    }
+
     // Convenience constructors following the naming guidelines
 
     /// Create attribute extraction failure error
@@ -2,4 +2,6 @@
 //!
 //! This module contains utility functions and helpers used throughout the library.
 
+pub mod encoding;
 pub mod error;
+pub mod time;
@@ -15,11 +15,5 @@ pub fn initialize() {
     console_error_panic_hook::set_once();
 }
 
-// Export all parsers for WebAssembly usage
-pub use crate::parsers::wasm::AssignmentSubmitParser;
-pub use crate::parsers::wasm::GradesParser;
-pub use crate::parsers::wasm::PortalParser;
-pub use crate::parsers::wasm::QuestionnaireParser;
-pub use crate::parsers::wasm::StudentInfoInquiryParser;
-pub use crate::parsers::wasm::SyllabusParser;
-pub use crate::parsers::wasm::TestAnswerStatusParser;
+// Export one parser class per page kind (plus the portal handle and student timetable)
+pub use crate::parsers::wasm::*;
@@ -0,0 +1,167 @@
+use scraper::Html;
+use unipa_ex::common::term::{AcademicTerm, Term};
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::export::csv::{CreditColumn, CsvFormat, CsvOptions, GradeInquiryCsv, SubjectColumn};
+use unipa_ex::parsers::grades::grade_inquiry::{
+    CategoryCredits, CreditDetails, CreditSummary, Grade, GradeInquiry, GradeInquiryParserBuilder,
+    RequirementType, Subject, SubjectCategory,
+};
+use unipa_ex::utils::error::ParseError;
+
+fn grades() -> GradeInquiry {
+    GradeInquiry {
+        subjects: vec![
+            Subject {
+                name: "線形代数".to_string(),
+                credit_count: Some(2),
+                grade: Some(Grade::AA),
+                numeric_score: Some(95),
+                term: Some(AcademicTerm::new(2024, Term::FirstHalf)),
+                instructor_name: "山田 太郎".to_string(),
+                is_currently_enrolled: false,
+                category: SubjectCategory {
+                    curriculum_name: "2024年度カリキュラム".to_string(),
+                    major_category_name: "専門教育科目".to_string(),
+                    middle_category_name: "基礎科目".to_string(),
+                    sub_category_name: "数学".to_string(),
+                    requirement_type: RequirementType::Required,
+                    hierarchy_level: 3,
+                },
+            },
+            Subject {
+                name: "英語, 上級\"A\"".to_string(),
+                credit_count: None,
+                grade: None,
+                numeric_score: None,
+                term: Some(AcademicTerm::new(2025, Term::SecondHalf)),
+                instructor_name: "Smith\tJohn".to_string(),
+                is_currently_enrolled: true,
+                category: SubjectCategory::default(),
+            },
+        ],
+        credit_summary: CreditSummary {
+            category_breakdown: vec![CategoryCredits {
+                category_name: "専門教育科目".to_string(),
+                credit_details: CreditDetails {
+                    required_for_graduation: Some(84),
+                    completed_credits: 40,
+                    currently_enrolled_credits: 12,
+                    total_credits: 52,
+                },
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn subjects_sheet_has_one_row_per_subject_with_category_columns() {
+    let csv = GradeInquiryCsv::new().subjects(&grades());
+    let csv = csv.strip_prefix('\u{feff}').expect("BOM for Excel");
+    let rows: Vec<&str> = csv.split_terminator("\r\n").collect();
+
+    assert_eq!(
+        rows[0],
+        "年度,学期,カリキュラム,大分類,中分類,小分類,必修・選択,科目名,単位数,評価,素点,担当教員,履修中"
+    );
+    assert_eq!(
+        rows[1],
+        "2024,前期,2024年度カリキュラム,専門教育科目,基礎科目,数学,必修,線形代数,2,秀,95,山田 太郎,"
+    );
+    // 区切り文字と引用符を含む値は引用符で囲む
+    assert_eq!(
+        rows[2],
+        "2025,後期,,,,,選択,\"英語, 上級\"\"A\"\"\",,,,Smith\tJohn,○"
+    );
+    assert_eq!(rows.len(), 3);
+}
+
+#[test]
+fn columns_and_format_are_configurable() {
+    let tsv = GradeInquiryCsv::new()
+        .with_format(CsvFormat::Tsv)
+        .with_byte_order_mark(false)
+        .with_subject_columns(vec![SubjectColumn::Name, SubjectColumn::InstructorName])
+        .subjects(&grades());
+    assert_eq!(
+        tsv,
+        "科目名\t担当教員\r\n線形代数\t山田 太郎\r\n英語, 上級\"A\"\tSmith John\r\n"
+    );
+
+    let options: CsvOptions = serde_json::from_value(serde_json::json!({
+        "format": "tsv",
+        "credit_columns": ["category_name", "completed_credits"],
+    }))
+    .unwrap();
+    assert!(options.byte_order_mark);
+    assert_eq!(options.subject_columns, SubjectColumn::ALL.to_vec());
+
+    let credits = GradeInquiryCsv::new()
+        .with_options(options)
+        .credit_summary(&grades());
+    assert_eq!(
+        credits,
+        "\u{feff}カテゴリ\t修得済み単位数\r\n専門教育科目\t40\r\n"
+    );
+}
+
+#[test]
+fn credit_summary_sheet_lists_category_breakdown() {
+    let csv = GradeInquiryCsv::new()
+        .with_byte_order_mark(false)
+        .credit_summary(&grades());
+    assert_eq!(
+        csv,
+        "カテゴリ,卒業要件単位数,修得済み単位数,履修中単位数,合計単位数\r\n専門教育科目,84,40,12,52\r\n"
+    );
+}
+
+#[test]
+fn credit_summary_sheet_includes_education_and_total_rows() {
+    let html = r#"
+    <table class="listTable">
+      <tbody><tr><td>線形代数</td><td>2</td><td>秀</td><td>山田 太郎</td><td>-</td></tr></tbody>
+    </table>
+    <table class="creditSummary">
+      <tr><th>区分</th><th>要件</th><th>修得</th><th>履修中</th></tr>
+      <tr><td>共通教育</td><td>40</td><td>38</td><td>2</td></tr>
+      <tr><td>専門教育</td><td>84</td><td>70</td><td>10</td></tr>
+      <tr><td>自由選択</td><td></td><td>4</td><td>0</td></tr>
+      <tr><td>合計</td><td>124</td><td>112</td><td>12</td></tr>
+    </table>
+    "#;
+    let grades = GradeInquiryParserBuilder::new()
+        .parse_document(&Html::parse_document(html))
+        .unwrap();
+
+    let csv = GradeInquiryCsv::new()
+        .with_byte_order_mark(false)
+        .credit_summary(&grades);
+    assert_eq!(
+        csv,
+        "カテゴリ,卒業要件単位数,修得済み単位数,履修中単位数,合計単位数\r\n\
+         共通教育,40,38,2,40\r\n\
+         専門教育,84,70,10,80\r\n\
+         自由選択,,4,0,4\r\n\
+         全体,124,112,12,124\r\n"
+    );
+}
+
+#[test]
+fn column_names_round_trip() {
+    for column in SubjectColumn::ALL {
+        assert_eq!(column.as_str().parse::<SubjectColumn>().unwrap(), column);
+        assert_eq!(
+            serde_json::to_value(column).unwrap(),
+            serde_json::json!(column.as_str())
+        );
+    }
+    for column in CreditColumn::ALL {
+        assert_eq!(column.as_str().parse::<CreditColumn>().unwrap(), column);
+    }
+    assert!(matches!(
+        "grade_point".parse::<SubjectColumn>(),
+        Err(ParseError::InvalidConfiguration { .. })
+    ));
+}
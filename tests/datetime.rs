@@ -0,0 +1,160 @@
+use scraper::Html;
+use unipa_ex::common::datetime::{parse, JstDate, JstDateTime, JstRange, JstValue};
+use unipa_ex::common::outcome::ParseWarning;
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::assignment_submit::builder::AssignmentListParserBuilder;
+use unipa_ex::utils::error::ParseError;
+
+fn date(text: &str) -> String {
+    JstDate::parse(text).unwrap().to_string()
+}
+
+fn datetime(text: &str) -> String {
+    JstDateTime::parse(text).unwrap().to_string()
+}
+
+#[test]
+fn dates_in_every_notation_normalize_to_iso() {
+    for text in [
+        "2025年06月15日",
+        "2025年6月15日(日)",
+        "2025/06/15",
+        "2025-06-15",
+        "2025.6.15",
+        "２０２５／０６／１５",
+        "令和7年6月15日",
+        "R7.6.15",
+    ] {
+        assert_eq!(date(text), "2025-06-15", "{text}");
+    }
+
+    assert_eq!(date("平成元年1月8日"), "1989-01-08");
+    assert_eq!(date("昭和64年1月7日"), "1989-01-07");
+    assert_eq!(date("2024/02/29"), "2024-02-29");
+}
+
+#[test]
+fn datetimes_are_jst_rfc3339() {
+    for text in [
+        "2025/06/15 23:59",
+        "2025年06月15日 23:59",
+        "2025年6月15日 23時59分",
+        "2025-06-15T23:59:00+09:00",
+        "2025-06-15T14:59:00Z",
+        "２０２５／０６／１５　２３：５９",
+    ] {
+        assert_eq!(datetime(text), "2025-06-15T23:59:00+09:00", "{text}");
+    }
+
+    let datetime = JstDateTime::parse("2025/04/01 09:00").unwrap();
+    assert_eq!(datetime.unix_seconds(), 1_743_465_600);
+    assert_eq!(JstDateTime::from_unix_seconds(1_743_465_600), datetime);
+}
+
+#[test]
+fn ranges_accept_time_only_ends() {
+    let range = JstRange::<JstDateTime>::parse("2025/06/15 10:00～12:00").unwrap();
+    assert_eq!(range.start.to_string(), "2025-06-15T10:00:00+09:00");
+    assert_eq!(range.end.to_string(), "2025-06-15T12:00:00+09:00");
+
+    // 開始より前の時刻は翌日とみなす
+    let overnight = JstRange::<JstDateTime>::parse("2025/06/15 22:00〜1:00").unwrap();
+    assert_eq!(overnight.end.to_string(), "2025-06-16T01:00:00+09:00");
+
+    let dates = JstRange::<JstDate>::parse("2025年6月1日 ~ 2025年6月15日").unwrap();
+    assert!(dates.contains(&JstDate::new(2025, 6, 10).unwrap()));
+    assert!(!dates.contains(&JstDate::new(2025, 6, 16).unwrap()));
+}
+
+#[test]
+fn one_parser_reports_which_shape_it_found() {
+    assert!(matches!(parse("2025/06/15"), Ok(JstValue::Date(_))));
+    assert!(matches!(
+        parse("2025/06/15 9:00"),
+        Ok(JstValue::DateTime(_))
+    ));
+    assert!(matches!(
+        parse("2025/06/01～2025/06/15"),
+        Ok(JstValue::DateRange(_))
+    ));
+    assert!(matches!(
+        parse("2025/06/15 9:00～10:30"),
+        Ok(JstValue::DateTimeRange(_))
+    ));
+}
+
+#[test]
+fn invalid_dates_are_rejected() {
+    assert!(matches!(
+        JstDate::parse("2025/02/29"),
+        Err(ParseError::ValueOutOfRange { .. })
+    ));
+    assert!(matches!(
+        JstDateTime::parse("2025/06/15 24:00"),
+        Err(ParseError::ValueOutOfRange { .. })
+    ));
+    assert!(matches!(
+        JstRange::<JstDate>::parse("2025/06/15～2025/06/01"),
+        Err(ParseError::ValueOutOfRange { .. })
+    ));
+    assert!(matches!(
+        JstDate::parse("未定"),
+        Err(ParseError::InvalidDataFormat { .. })
+    ));
+    assert!(matches!(
+        JstDate::parse("2025/06/15 23:59"),
+        Err(ParseError::InvalidDataFormat { .. })
+    ));
+    assert!(matches!(
+        JstDateTime::parse("2025/06/15"),
+        Err(ParseError::InvalidDataFormat { .. })
+    ));
+}
+
+#[test]
+fn serialized_as_strings_and_read_back_from_any_notation() {
+    let date = JstDate::new(2025, 6, 15).unwrap();
+    assert_eq!(serde_json::to_string(&date).unwrap(), r#""2025-06-15""#);
+    assert_eq!(
+        serde_json::from_str::<JstDate>(r#""2025年06月15日""#).unwrap(),
+        date
+    );
+
+    let range = JstRange::new(date.at(9, 0, 0).unwrap(), date.at(10, 30, 0).unwrap()).unwrap();
+    assert_eq!(
+        serde_json::to_value(range).unwrap(),
+        serde_json::json!({
+            "start": "2025-06-15T09:00:00+09:00",
+            "end": "2025-06-15T10:30:00+09:00",
+        })
+    );
+}
+
+#[test]
+fn models_carry_typed_dates_and_warn_on_unparseable_cells() {
+    let html = r#"
+<table class="listTable"><tbody>
+  <tr><td>授業 A</td><td>レポート1</td><td>2025/07/01 23:59</td><td>未提出</td></tr>
+  <tr><td>授業 B</td><td>レポート2</td><td>未定</td><td>未提出</td></tr>
+</tbody></table>
+"#;
+    let outcome = AssignmentListParserBuilder::new()
+        .parse_with_warnings(&Html::parse_document(html))
+        .unwrap();
+    let assignments = &outcome.data.assignments;
+
+    assert_eq!(
+        assignments[0]
+            .due_date
+            .map(|due| due.to_string())
+            .as_deref(),
+        Some("2025-07-01T23:59:00+09:00")
+    );
+    assert_eq!(assignments[1].due_date, None);
+    assert!(outcome.warnings.contains(&ParseWarning::UnparseableCell {
+        section: "assignments".to_string(),
+        row: 1,
+        column: 2,
+        value: "未定".to_string(),
+    }));
+}
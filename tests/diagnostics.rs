@@ -0,0 +1,80 @@
+use scraper::Html;
+use unipa_ex::common::diagnostics::ParseDiagnostics;
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::assignment_submit::builder::AssignmentListParserBuilder;
+use unipa_ex::parsers::grades::grade_inquiry::GradeInquiryParserBuilder;
+
+const GRADE_HTML: &str = r#"
+<div class="gpa">GPA: 3.10</div>
+<table class="listTable">
+  <tbody>
+    <tr><td>Programming I</td><td>2</td><td>秀</td><td>Prof. A</td><td>-</td></tr>
+    <tr><td colspan="5">合計</td></tr>
+  </tbody>
+</table>
+"#;
+
+#[test]
+fn trace_records_winning_alternative_and_skipped_rows() {
+    let document = Html::parse_document(GRADE_HTML);
+    let outcome = GradeInquiryParserBuilder::new()
+        .parse_with_diagnostics(&document)
+        .unwrap();
+    let diagnostics = outcome.diagnostics.expect("diagnostics should be recorded");
+
+    let gpa = diagnostics
+        .selectors
+        .iter()
+        .find(|trace| trace.section == "gpa_score")
+        .unwrap();
+    assert_eq!(gpa.match_count, 1);
+    assert_eq!(gpa.matched_alternatives, vec![".gpa"]);
+
+    let unmatched: Vec<_> = diagnostics
+        .unmatched_selectors()
+        .map(|trace| trace.section.as_str())
+        .collect();
+    assert_eq!(unmatched, vec!["credit_summary"]);
+
+    assert_eq!(diagnostics.skipped_rows.len(), 1);
+    assert_eq!(diagnostics.skipped_rows[0].section, "subjects");
+    assert_eq!(diagnostics.skipped_rows[0].row, 1);
+    assert_eq!(diagnostics.skipped_rows[0].cell_count, 1);
+}
+
+#[test]
+fn trace_is_only_collected_when_requested() {
+    let document = Html::parse_document(GRADE_HTML);
+    let builder = AssignmentListParserBuilder::new();
+
+    assert!(builder
+        .parse_with_warnings(&document)
+        .unwrap()
+        .diagnostics
+        .is_none());
+
+    let outcome = AssignmentListParserBuilder::new()
+        .with_debug_mode(true)
+        .parse_with_warnings(&document)
+        .unwrap();
+    let diagnostics = outcome.diagnostics.unwrap();
+    assert_eq!(diagnostics.selectors[0].section, "assignments");
+    assert_eq!(diagnostics.skipped_rows.len(), 1);
+}
+
+#[test]
+fn alternatives_inside_functional_pseudo_classes_are_not_split() {
+    let document = Html::parse_document(r#"<p class="b" title="x,y">text</p>"#);
+    let mut diagnostics = ParseDiagnostics::default();
+
+    diagnostics.record_selector(
+        "test",
+        ".missing, :is(.a, .b), [title='x,y']",
+        document.root_element(),
+    );
+
+    assert_eq!(
+        diagnostics.selectors[0].matched_alternatives,
+        vec![":is(.a, .b)", "[title='x,y']"]
+    );
+}
@@ -0,0 +1,72 @@
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::grades::grade_inquiry::GradeInquiryParserBuilder;
+use unipa_ex::utils::encoding::{charset_from_meta, decode_html, detect_encoding};
+use unipa_ex::utils::error::ParseError;
+
+const GRADE_HTML: &str = r#"<html><head>
+<meta http-equiv="Content-Type" content="text/html; charset=Shift_JIS">
+</head><body>
+<table class="listTable">
+  <tbody>
+    <tr><td>日本語表現法</td><td>2</td><td>優</td><td>佐藤 花子</td><td>-</td></tr>
+  </tbody>
+</table>
+</body></html>"#;
+
+#[test]
+fn parse_bytes_uses_meta_charset() {
+    let (bytes, _, _) = encoding_rs::SHIFT_JIS.encode(GRADE_HTML);
+
+    let result = GradeInquiryParserBuilder::new()
+        .parse_bytes(&bytes, None)
+        .unwrap();
+
+    assert_eq!(result.subjects[0].name, "日本語表現法");
+    assert_eq!(result.subjects[0].instructor_name, "佐藤 花子");
+}
+
+#[test]
+fn content_type_takes_precedence_over_meta() {
+    let html = GRADE_HTML.replace("Shift_JIS", "UTF-8");
+    let (bytes, _, _) = encoding_rs::EUC_JP.encode(&html);
+
+    assert_eq!(charset_from_meta(&bytes), Some("UTF-8"));
+    assert_eq!(
+        detect_encoding(&bytes, Some("text/html; charset=EUC-JP")),
+        encoding_rs::EUC_JP
+    );
+    assert!(
+        decode_html(&bytes, Some("text/html; charset=EUC-JP"), "test")
+            .unwrap()
+            .contains("日本語表現法")
+    );
+}
+
+#[test]
+fn heuristic_distinguishes_japanese_encodings() {
+    let text = "<p>成績照会 ひらがな カタカナ</p>";
+
+    for encoding in [
+        encoding_rs::UTF_8,
+        encoding_rs::SHIFT_JIS,
+        encoding_rs::EUC_JP,
+    ] {
+        let (bytes, _, _) = encoding.encode(text);
+        assert_eq!(detect_encoding(&bytes, None), encoding);
+        assert_eq!(decode_html(&bytes, None, "test").unwrap(), text);
+    }
+}
+
+#[test]
+fn undecodable_bytes_are_rejected() {
+    let result = decode_html(
+        b"<p>\xff\xfe\xfd</p>",
+        Some("text/html; charset=UTF-8"),
+        "test",
+    );
+
+    assert!(matches!(
+        result,
+        Err(ParseError::InvalidFileEncoding { .. })
+    ));
+}
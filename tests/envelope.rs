@@ -0,0 +1,282 @@
+use scraper::Html;
+use serde_json::{json, Value};
+use unipa_ex::parsers::envelope::{migrate, schema_version, Envelope, PARSER_VERSION};
+use unipa_ex::parsers::grades::grade_inquiry::GradeInquiry;
+use unipa_ex::parsers::grades::graduation_prediction::{
+    FusokuRyo, HanteiStatus, SotsugyoMikonHanteiKekka,
+};
+use unipa_ex::parsers::page::PageKind;
+use unipa_ex::utils::error::ParseError;
+use unipa_ex::utils::time::format_rfc3339;
+
+const GRADE_HTML: &str = r#"
+<div class="gpaScore">GPA: 3.50</div>
+<table class="listTable">
+  <tbody>
+    <tr><td>Programming I</td><td>2</td><td>秀</td><td>Prof. A</td><td>-</td></tr>
+  </tbody>
+</table>
+"#;
+
+#[test]
+fn envelope_records_versions_and_parse_time() {
+    let envelope = PageKind::GradeInquiry
+        .parse_envelope(&Html::parse_document(GRADE_HTML))
+        .unwrap();
+
+    assert_eq!(envelope.kind, PageKind::GradeInquiry);
+    assert_eq!(
+        envelope.schema_version,
+        schema_version(PageKind::GradeInquiry)
+    );
+    assert_eq!(envelope.parser_version.as_deref(), Some(PARSER_VERSION));
+    let parsed_at = envelope.parsed_at.as_deref().unwrap();
+    assert!(
+        parsed_at.ends_with('Z') && parsed_at.len() == 20,
+        "{parsed_at}"
+    );
+    assert!(envelope.is_current());
+
+    let value = serde_json::to_value(&envelope).unwrap();
+    assert_eq!(value["kind"], "grade-inquiry");
+    assert_eq!(value["data"]["subjects"][0]["name"], "Programming I");
+}
+
+#[test]
+fn legacy_model_json_is_wrapped_with_the_given_kind() {
+    let data = PageKind::GradeInquiry
+        .parse(&Html::parse_document(GRADE_HTML))
+        .unwrap();
+    let stored = serde_json::to_value(&data).unwrap();
+
+    let err = migrate(stored.clone(), None).unwrap_err();
+    assert!(matches!(err, ParseError::MissingRequiredField { .. }));
+
+    let envelope = migrate(stored, Some(PageKind::GradeInquiry)).unwrap();
+    assert!(envelope.is_current());
+    assert_eq!(envelope.parser_version, None);
+    assert_eq!(envelope.parsed_at, None);
+
+    let typed: Envelope<GradeInquiry> = envelope.into_typed().unwrap();
+    assert_eq!(typed.data.subjects.len(), 1);
+}
+
+#[test]
+fn legacy_cli_records_keep_their_kind() {
+    let record = json!({
+        "source": "grades.html",
+        "kind": "grade-inquiry",
+        "data": { "subjects": [] },
+        "warnings": [],
+    });
+
+    let envelope = migrate(record, None).unwrap();
+    assert_eq!(envelope.kind, PageKind::GradeInquiry);
+    assert_eq!(
+        envelope.data,
+        json!({ "subjects": [], "term_summaries": [] })
+    );
+}
+
+#[test]
+fn current_envelopes_pass_through_unchanged() {
+    let envelope = Envelope::new(PageKind::StudentInfo, json!({ "basic_info": {} }))
+        .with_parsed_at("2025-04-01T00:00:00Z");
+    let stored = serde_json::to_value(&envelope).unwrap();
+
+    assert_eq!(migrate(stored, None).unwrap(), envelope);
+}
+
+#[test]
+fn newer_schema_versions_are_rejected() {
+    let stored = json!({
+        "kind": "portal",
+        "schema_version": schema_version(PageKind::Portal) + 1,
+        "parser_version": "99.0.0",
+        "parsed_at": null,
+        "data": {},
+    });
+
+    let err = migrate(stored, None).unwrap_err();
+    assert!(matches!(err, ParseError::ValueOutOfRange { .. }));
+}
+
+#[test]
+fn timestamps_are_formatted_as_rfc3339_utc() {
+    assert_eq!(format_rfc3339(0), "1970-01-01T00:00:00Z");
+    assert_eq!(format_rfc3339(1_743_465_600), "2025-04-01T00:00:00Z");
+    assert_eq!(format_rfc3339(951_827_696), "2000-02-29T12:34:56Z");
+    assert_eq!(format_rfc3339(-1), "1969-12-31T23:59:59Z");
+}
+
+#[test]
+fn version_1_dates_are_migrated_to_typed_notation() {
+    let stored = json!({
+        "kind": "student-info",
+        "schema_version": 1,
+        "parser_version": "0.1.0",
+        "parsed_at": "2025-04-01T00:00:00Z",
+        "data": {
+            "basic_info": {
+                "date_of_birth": "2003-01-10",
+                "enrollment_date": "2021年04月01日",
+                "withdrawal_date": null,
+                "completion_date": "",
+            },
+            "advisor_info": { "advisor_start_date": "", "advisor_end_date": "未定" },
+        },
+    });
+
+    let envelope = migrate(stored, None).unwrap();
+    assert_eq!(envelope.schema_version, 2);
+    assert_eq!(envelope.data["basic_info"]["date_of_birth"], "2003-01-10");
+    assert_eq!(envelope.data["basic_info"]["enrollment_date"], "2021-04-01");
+    assert_eq!(envelope.data["basic_info"]["withdrawal_date"], json!(null));
+    assert_eq!(envelope.data["basic_info"]["completion_date"], json!(null));
+    assert_eq!(
+        envelope.data["advisor_info"]["advisor_end_date"],
+        json!(null)
+    );
+
+    let assignments = json!({
+        "assignments": [{ "due_date": "2025-06-15 23:59", "submission_date": null }],
+    });
+    let envelope = migrate(assignments, Some(PageKind::AssignmentList)).unwrap();
+    assert_eq!(
+        envelope.data["assignments"][0]["due_date"],
+        "2025-06-15T23:59:00+09:00"
+    );
+}
+
+#[test]
+fn separate_year_and_semester_fields_are_migrated_to_terms() {
+    // 旧形式の成績照会は全科目に固定の2025年度春学期を入れていたため、学期は不明とする
+    let grades = json!({
+        "subjects": [{ "name": "Programming I", "academic_year": 2025, "semester": "Spring" }],
+    });
+    let envelope = migrate(grades, Some(PageKind::GradeInquiry)).unwrap();
+    assert_eq!(
+        envelope.data["subjects"][0],
+        json!({ "name": "Programming I", "term": null })
+    );
+
+    let portal = json!({
+        "kind": "portal",
+        "schema_version": 2,
+        "parser_version": "0.1.0",
+        "parsed_at": null,
+        "data": { "calendar": { "year": "2025", "month": "春学期" } },
+    });
+    let envelope = migrate(portal, None).unwrap();
+    assert_eq!(envelope.schema_version, schema_version(PageKind::Portal));
+    assert_eq!(
+        envelope.data["calendar"],
+        json!({ "term": { "year": 2025, "term": "Spring" } })
+    );
+
+    let syllabus = json!({
+        "search_conditions": { "academic_year_semester": "2025年度 前期" },
+        "course_entries": [{ "semester": "後期" }, { "semester": "" }],
+    });
+    let envelope = migrate(syllabus, Some(PageKind::SyllabusSearchResult)).unwrap();
+    assert_eq!(
+        envelope.data["course_entries"],
+        json!([
+            { "term": { "year": 2025, "term": "SecondHalf" }, "class_time": null },
+            { "term": null, "class_time": null },
+        ])
+    );
+
+    let view = json!({ "academic_year_semester": "未定" });
+    let envelope = migrate(view, Some(PageKind::SyllabusView)).unwrap();
+    assert_eq!(envelope.data["term"], Value::Null);
+}
+
+#[test]
+fn weekday_and_period_text_is_migrated_to_class_times() {
+    let syllabus = json!({
+        "kind": "syllabus-search-result",
+        "schema_version": 2,
+        "parser_version": "0.1.0",
+        "parsed_at": null,
+        "data": {
+            "course_entries": [
+                { "schedule_day_period": "月3-4" },
+                { "schedule_day_period": "集中" },
+                { "schedule_day_period": "" },
+            ],
+        },
+    });
+    let envelope = migrate(syllabus, None).unwrap();
+    assert_eq!(envelope.schema_version, 3);
+    assert_eq!(
+        envelope.data["course_entries"][0]["class_time"],
+        json!({
+            "is_intensive": false,
+            "slots": [
+                { "weekday": "Monday", "period": 3, "start": "13:00", "end": "14:30" },
+                { "weekday": "Monday", "period": 4, "start": "14:40", "end": "16:10" },
+            ],
+        })
+    );
+    assert_eq!(
+        envelope.data["course_entries"][1]["class_time"],
+        json!({ "is_intensive": true, "slots": [] })
+    );
+    assert_eq!(
+        envelope.data["course_entries"][2]["class_time"],
+        json!(null)
+    );
+
+    let portal = json!({
+        "kind": "portal",
+        "schema_version": 3,
+        "parser_version": "0.1.0",
+        "parsed_at": null,
+        "data": { "schedule": { "entries": [{ "date": "1限" }] } },
+    });
+    let envelope = migrate(portal, None).unwrap();
+    assert_eq!(
+        envelope.data["schedule"]["entries"][0]["class_time"]["slots"][0],
+        json!({ "weekday": null, "period": 1, "start": "09:00", "end": "10:30" })
+    );
+}
+
+#[test]
+fn graduation_prediction_shortfall_amounts_are_typed() {
+    let stored = json!({
+        "kind": "graduation-prediction",
+        "schema_version": 1,
+        "parser_version": "0.1.0",
+        "parsed_at": null,
+        "data": {
+            "hantei_message": "卒業見込なし",
+            "fusoku_items": [
+                { "joken_code": "S01", "yoso_number": 1, "fusoku_message": "", "fusoku_ryo": "4単位" },
+                { "joken_code": "S02", "yoso_number": 2, "fusoku_message": "", "fusoku_ryo": "不明" },
+                { "joken_code": "S03", "yoso_number": 3, "fusoku_message": "", "fusoku_ryo": "１．５科目" },
+            ],
+        },
+    });
+    let envelope = migrate(stored, None).unwrap();
+    assert!(envelope.is_current());
+    assert_eq!(envelope.data["hantei_status"], "MikomiNashi");
+    assert_eq!(envelope.data["yoken_tree"], json!([]));
+    assert_eq!(
+        envelope.data["fusoku_items"][0]["fusoku_ryo"],
+        json!({ "amount": 4.0, "unit": "Credits" })
+    );
+    assert_eq!(envelope.data["fusoku_items"][1]["fusoku_ryo"], json!(null));
+    assert_eq!(
+        envelope.data["fusoku_items"][2]["fusoku_ryo"],
+        json!({ "amount": 1.5, "unit": "Subjects" })
+    );
+
+    // 移行後のデータは現在のモデルとして読み込める
+    let prediction: SotsugyoMikonHanteiKekka = serde_json::from_value(envelope.data).unwrap();
+    assert_eq!(prediction.hantei_status, Some(HanteiStatus::MikomiNashi));
+    assert_eq!(
+        prediction.fusoku_items[0].fusoku_ryo,
+        FusokuRyo::parse("4単位")
+    );
+}
@@ -0,0 +1,154 @@
+use tsify::Tsify;
+use unipa_ex::utils::error::ParseError;
+
+#[test]
+fn serialized_error_carries_code_and_fields() {
+    let error = ParseError::element_not_found("#baseTable", "student info");
+    let value = serde_json::to_value(&error).unwrap();
+
+    assert_eq!(value["code"], "ELEMENT_NOT_FOUND");
+    assert_eq!(value["code"], error.code());
+    assert_eq!(value["selector"], "#baseTable");
+    assert_eq!(value["context"], "student info");
+}
+
+#[test]
+fn unit_variants_serialize_with_code_and_message_only() {
+    let value = serde_json::to_value(ParseError::empty_html_content()).unwrap();
+
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "code": "EMPTY_HTML_CONTENT",
+            "message": "Failed to process empty HTML content",
+        })
+    );
+}
+
+#[test]
+fn error_round_trips_through_json() {
+    let error = ParseError::missing_required_field("student_id");
+    let json = serde_json::to_string(&error).unwrap();
+
+    assert_eq!(serde_json::from_str::<ParseError>(&json).unwrap(), error);
+}
+
+/// One value of every variant (keep in sync with `ParseError`)
+fn every_variant() -> Vec<ParseError> {
+    let text = || "x".to_string();
+    vec![
+        ParseError::SelectorCreationFailed {
+            selector: text(),
+            context: text(),
+        },
+        ParseError::ElementNotFound {
+            selector: text(),
+            context: text(),
+        },
+        ParseError::AttributeExtractionFailed {
+            attribute: text(),
+            context: text(),
+        },
+        ParseError::DataExtractionFailed {
+            data_type: text(),
+            reason: text(),
+        },
+        ParseError::DataParsingFailed {
+            data_type: text(),
+            value: text(),
+        },
+        ParseError::InvalidDataFormat {
+            data_type: text(),
+            details: text(),
+        },
+        ParseError::HtmlParsingFailed { reason: text() },
+        ParseError::InvalidHtmlStructure { expected: text() },
+        ParseError::EmptyHtmlContent,
+        ParseError::FileNotFound { path: text() },
+        ParseError::FilePermissionDenied { path: text() },
+        ParseError::InvalidFileEncoding { path: text() },
+        ParseError::InvalidSessionId { session_id: text() },
+        ParseError::InvalidUrl { url: text() },
+        ParseError::RequestTimeout,
+        ParseError::ServerError { status: text() },
+        ParseError::MissingConfiguration {
+            config_name: text(),
+        },
+        ParseError::InvalidConfiguration {
+            config_name: text(),
+            value: text(),
+        },
+        ParseError::ValueOutOfRange {
+            field: text(),
+            value: text(),
+            range: text(),
+        },
+        ParseError::NotImplemented { operation: text() },
+        ParseError::OperationCancelled { operation: text() },
+        ParseError::UnexpectedError {
+            context: text(),
+            message: text(),
+        },
+        ParseError::EmptyDataEntry { data_type: text() },
+        ParseError::MissingRequiredField { field_name: text() },
+        ParseError::FormDataExtractionFailed { reason: text() },
+        ParseError::FormElementNotFound {
+            form_selector: text(),
+        },
+        ParseError::TableDataExtractionFailed { reason: text() },
+        ParseError::TableRowProcessingFailed {
+            row_context: text(),
+        },
+        ParseError::NavigationExtractionFailed { reason: text() },
+        ParseError::PaginationProcessingFailed { reason: text() },
+    ]
+}
+
+#[test]
+fn every_code_matches_its_serialized_tag_and_message() {
+    for error in every_variant() {
+        let value = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(value["code"], error.code(), "{error:?}");
+        assert_eq!(value["message"], error.to_string(), "{error:?}");
+        assert_eq!(serde_json::from_value::<ParseError>(value).unwrap(), error);
+    }
+}
+
+#[test]
+fn unexpected_error_detail_does_not_shadow_the_message() {
+    let error = ParseError::unexpected_error("GPA", "no subjects");
+    let value = serde_json::to_value(&error).unwrap();
+
+    assert_eq!(value["message"], "Failed to process GPA: no subjects");
+    assert_eq!(value["reason"], "no subjects");
+}
+
+#[test]
+fn typescript_declaration_lists_every_variant_with_the_message() {
+    let decl = <ParseError as Tsify>::DECL;
+    assert!(decl.contains("export type ParseError = { message: string } & ("));
+
+    let variants = every_variant();
+    assert_eq!(decl.matches("{ code: ").count(), variants.len());
+    for error in variants {
+        let tag = format!("{{ code: \"{}\"", error.code());
+        let line = decl
+            .lines()
+            .find(|line| line.trim_start().starts_with(&tag))
+            .unwrap_or_else(|| panic!("{tag}"));
+
+        // `code`と`message`以外のフィールドがすべて文字列として宣言されている
+        let value = serde_json::to_value(&error).unwrap();
+        let fields: Vec<_> = value
+            .as_object()
+            .unwrap()
+            .keys()
+            .filter(|key| !matches!(key.as_str(), "code" | "message"))
+            .collect();
+        assert_eq!(line.matches(": string").count(), fields.len(), "{line}");
+        for field in fields {
+            assert!(line.contains(&format!("; {field}: string")), "{line}");
+        }
+    }
+}
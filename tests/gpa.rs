@@ -0,0 +1,197 @@
+use scraper::Html;
+use unipa_ex::common::term::{AcademicTerm, Term};
+use unipa_ex::parsers::grades::gpa::{
+    GpaExclusion, GpaMethod, GpaRules, GradePoint, GradeScale, NumericScale,
+};
+use unipa_ex::parsers::grades::grade_inquiry::{
+    Grade, GradeInquiryParserBuilder, Subject, SubjectCategory,
+};
+use unipa_ex::utils::error::ParseError;
+
+fn subject(name: &str, credits: u32, grade: Grade, term: AcademicTerm) -> Subject {
+    Subject {
+        name: name.to_string(),
+        credit_count: Some(credits),
+        grade: Some(grade),
+        numeric_score: None,
+        term: Some(term),
+        instructor_name: String::new(),
+        is_currently_enrolled: false,
+        category: SubjectCategory::default(),
+    }
+}
+
+fn first_half() -> AcademicTerm {
+    AcademicTerm::new(2024, Term::FirstHalf)
+}
+
+fn second_half() -> AcademicTerm {
+    AcademicTerm::new(2024, Term::SecondHalf)
+}
+
+fn close(actual: Option<f64>, expected: f64) -> bool {
+    actual.is_some_and(|actual| (actual - expected).abs() < 1e-9)
+}
+
+#[test]
+fn pass_recognized_and_current_subjects_do_not_count() {
+    let mut current = subject("卒業研究", 4, Grade::NoEvaluation, second_half());
+    current.is_currently_enrolled = true;
+    let subjects = vec![
+        subject("線形代数", 2, Grade::AA, first_half()),
+        subject("微分積分", 2, Grade::D, first_half()),
+        subject("インターンシップ", 2, Grade::Pass, first_half()),
+        subject("他大学単位", 2, Grade::Recognized, second_half()),
+        subject("英語", 1, Grade::B, second_half()),
+        current,
+    ];
+
+    let report = GpaRules::default().calculate_subjects(&subjects).unwrap();
+
+    // (4×2 + 0×2 + 2×1) / 5
+    assert!(close(report.cumulative.gpa, 2.0));
+    assert_eq!(report.cumulative.gpa_credits, 5);
+
+    let excluded: Vec<_> = report.subjects.iter().map(|s| s.excluded).collect();
+    assert_eq!(
+        excluded,
+        vec![
+            None,
+            None,
+            Some(GpaExclusion::Pass),
+            Some(GpaExclusion::Recognized),
+            None,
+            Some(GpaExclusion::CurrentlyEnrolled),
+        ]
+    );
+    assert!(close(Some(report.subjects[0].quality_points), 8.0));
+    assert_eq!(report.subjects[0].basis, Some(GpaMethod::Grade));
+
+    assert_eq!(report.terms.len(), 2);
+    assert_eq!(report.terms[0].term, Some(first_half()));
+    assert!(close(report.terms[0].gpa, 2.0));
+    assert_eq!(report.terms[1].term, Some(second_half()));
+    assert!(close(report.terms[1].gpa, 2.0));
+    assert_eq!(report.terms[1].gpa_credits, 1);
+}
+
+#[test]
+fn rules_and_scales_are_configurable() {
+    let subjects = vec![
+        subject("線形代数", 2, Grade::AA, first_half()),
+        subject("微分積分", 2, Grade::D, first_half()),
+        subject("インターンシップ", 2, Grade::Pass, first_half()),
+    ];
+
+    let rules = GpaRules {
+        exclude_pass: false,
+        exclude_failed: true,
+        ..Default::default()
+    }
+    .with_grade_scale(GradeScale::new(vec![
+        GradePoint {
+            grade: Grade::AA,
+            point: 4.3,
+        },
+        GradePoint {
+            grade: Grade::Pass,
+            point: 3.0,
+        },
+    ]));
+    let report = rules.calculate_subjects(&subjects).unwrap();
+    assert!(close(report.cumulative.gpa, 3.65));
+    assert_eq!(report.subjects[1].excluded, Some(GpaExclusion::Failed));
+
+    // 対応表にない評価は対象外
+    let report = GpaRules::default()
+        .with_grade_scale(GradeScale::new(Vec::new()))
+        .calculate_subjects(&subjects)
+        .unwrap();
+    assert_eq!(report.cumulative.gpa, None);
+    assert_eq!(
+        report.subjects[0].excluded,
+        Some(GpaExclusion::NoGradePoint)
+    );
+
+    let rules: GpaRules = serde_json::from_value(serde_json::json!({
+        "method": "NumericScore",
+        "exclude_currently_enrolled": false,
+    }))
+    .unwrap();
+    assert!(rules.exclude_pass);
+    assert_eq!(rules.grade_scale, GradeScale::default());
+}
+
+#[test]
+fn functional_gpa_uses_numeric_scores() {
+    let mut scored = subject("線形代数", 2, Grade::AA, first_half());
+    scored.numeric_score = Some(92);
+    let mut failed = subject("微分積分", 2, Grade::D, first_half());
+    failed.numeric_score = Some(45);
+    let unscored = subject("英語", 1, Grade::B, first_half());
+
+    let report = GpaRules::new()
+        .with_method(GpaMethod::NumericScore)
+        .calculate_subjects(&[scored, failed, unscored])
+        .unwrap();
+
+    assert!(close(report.subjects[0].grade_point, 3.7));
+    assert_eq!(report.subjects[0].basis, Some(GpaMethod::NumericScore));
+    assert!(close(report.subjects[1].grade_point, 0.0));
+    // 素点のない科目は評価から求める
+    assert!(close(report.subjects[2].grade_point, 2.0));
+    assert_eq!(report.subjects[2].basis, Some(GpaMethod::Grade));
+    assert!(close(report.cumulative.gpa, (3.7 * 2.0 + 2.0) / 5.0));
+
+    let capped = NumericScale {
+        max_point: Some(4.0),
+        ..Default::default()
+    };
+    assert!((capped.point(100) - 4.0).abs() < 1e-9);
+    assert!((NumericScale::default().point(100) - 4.5).abs() < 1e-9);
+    assert!((NumericScale::default().point(59)).abs() < 1e-9);
+}
+
+#[test]
+fn numeric_scales_without_a_positive_step_are_rejected() {
+    assert!(GpaRules::default().validate().is_ok());
+
+    for step in [0.0, -10.0, f64::NAN] {
+        let rules = GpaRules::new().with_numeric_scale(NumericScale {
+            step,
+            ..Default::default()
+        });
+        let err = rules.validate().unwrap_err();
+        assert!(
+            matches!(err, ParseError::InvalidDataFormat { ref data_type, .. } if data_type == "numeric scale"),
+            "{err:?}"
+        );
+        assert_eq!(rules.calculate_subjects(&[]).unwrap_err(), err);
+    }
+
+    let unbounded = NumericScale {
+        max_point: Some(f64::INFINITY),
+        ..Default::default()
+    };
+    assert!(unbounded.validate().is_err());
+}
+
+#[test]
+fn calculated_gpa_excludes_pass_subjects_and_counts_failures() {
+    let html = r#"
+    <table class="listTable"><tbody>
+      <tr><td>線形代数</td><td>2</td><td>秀</td><td>山田</td><td>-</td></tr>
+      <tr><td>微分積分</td><td>2</td><td>不可</td><td>山田</td><td>-</td></tr>
+      <tr><td>インターンシップ</td><td>2</td><td>合格</td><td>鈴木</td><td>-</td></tr>
+      <tr><td>他大学単位</td><td>2</td><td>認定</td><td>-</td><td>-</td></tr>
+    </tbody></table>
+    "#;
+    let grades = GradeInquiryParserBuilder::new()
+        .build()
+        .unwrap()
+        .parse_document(&Html::parse_document(html))
+        .unwrap();
+
+    assert_eq!(grades.subjects[3].grade, Some(Grade::Recognized));
+    assert!((grades.gpa_score.unwrap() - 2.0).abs() < 1e-9);
+}
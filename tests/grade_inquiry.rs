@@ -1,5 +1,10 @@
-use unipa_ex::parsers::grades::grade_inquiry::{GradeInquiryParserBuilder, Grade};
 use scraper::Html;
+use unipa_ex::common::outcome::ParseWarning;
+use unipa_ex::common::term::{AcademicTerm, Term};
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::grades::grade_inquiry::{
+    DisplaySettings, Grade, GradeInquiryParserBuilder, RequirementType, ViewType,
+};
 
 fn sample_html_with_gpa() -> &'static str {
     r#"
@@ -46,7 +51,7 @@ fn parse_basic_grade_inquiry() {
     assert_eq!(result.subjects.len(), 1);
     assert_eq!(result.subjects[0].name, "Programming I");
     assert!(matches!(result.subjects[0].grade, Some(Grade::AA)));
-    assert!((result.gpa_score - 3.5).abs() < f64::EPSILON);
+    assert_eq!(result.gpa_score, Some(3.5));
     assert_eq!(result.credit_summary.overall.total_credits, 2);
 }
 
@@ -56,6 +61,279 @@ fn parse_without_gpa_calculates_from_grades() {
     let parser = GradeInquiryParserBuilder::new().build().unwrap();
     let result = parser.parse_document(&html).unwrap();
 
-    assert_eq!(result.gpa_score, 3.0);
+    assert_eq!(result.gpa_score, Some(3.0));
     assert_eq!(result.credit_summary.overall.total_credits, 2);
+
+    // GPA対象の科目がなければ0ではなく不明とする
+    let html = Html::parse_document(&sample_html_without_gpa().replace("優", "合格"));
+    let result = parser.parse_document(&html).unwrap();
+    assert_eq!(result.gpa_score, None);
+}
+
+#[test]
+fn parse_category_hierarchy_terms_and_scores() {
+    let html = Html::parse_document(
+        r#"
+    <table class="listTable">
+      <thead>
+        <tr><th>科目名</th><th>単位</th><th>評価</th><th>素点</th><th>修得年度学期</th><th>担当教員</th><th>備考</th></tr>
+      </thead>
+      <tbody>
+        <tr><td colspan="7">2022年度入学者カリキュラム</td></tr>
+        <tr class="kamokuLevel1"><td colspan="7">教養教育科目</td></tr>
+        <tr class="kamokuLevel2"><td colspan="7">外国語科目（必修）</td></tr>
+        <tr><td>英語Ⅰ</td><td>1</td><td>優</td><td>85</td><td>2022年度 前期</td><td>Smith</td><td></td></tr>
+        <tr class="kamokuLevel1"><td colspan="7">専門教育科目</td></tr>
+        <tr><td></td><td colspan="6">専門基礎科目</td></tr>
+        <tr><td></td><td></td><td colspan="5">選択科目</td></tr>
+        <tr><td><span>データ構造</span></td><td>2</td><td>履修中</td><td>-</td><td>2025年度 後期</td><td>山田</td><td>履修中</td></tr>
+        <tr><td colspan="7">合計</td></tr>
+      </tbody>
+    </table>
+    "#,
+    );
+    let outcome = GradeInquiryParserBuilder::new()
+        .parse_with_warnings(&html)
+        .unwrap();
+    let subjects = &outcome.data.subjects;
+
+    assert_eq!(subjects.len(), 2);
+    assert_eq!(subjects[0].name, "英語Ⅰ");
+    assert_eq!(subjects[0].numeric_score, Some(85));
+    assert_eq!(
+        subjects[0].term,
+        Some(AcademicTerm::new(2022, Term::FirstHalf))
+    );
+    assert!(!subjects[0].is_currently_enrolled);
+    assert_eq!(
+        subjects[0].category.curriculum_name,
+        "2022年度入学者カリキュラム"
+    );
+    assert_eq!(subjects[0].category.major_category_name, "教養教育科目");
+    assert_eq!(subjects[0].category.middle_category_name, "外国語科目");
+    assert_eq!(subjects[0].category.sub_category_name, "");
+    assert_eq!(subjects[0].category.hierarchy_level, 2);
+    assert!(matches!(
+        subjects[0].category.requirement_type,
+        RequirementType::Required
+    ));
+
+    assert_eq!(subjects[1].name, "データ構造");
+    assert_eq!(subjects[1].numeric_score, None);
+    assert_eq!(
+        subjects[1].term,
+        Some(AcademicTerm::new(2025, Term::SecondHalf))
+    );
+    assert!(subjects[1].is_currently_enrolled);
+    assert_eq!(subjects[1].category.major_category_name, "専門教育科目");
+    assert_eq!(subjects[1].category.middle_category_name, "専門基礎科目");
+    assert_eq!(subjects[1].category.sub_category_name, "選択科目");
+    assert_eq!(subjects[1].category.hierarchy_level, 3);
+    assert!(matches!(
+        subjects[1].category.requirement_type,
+        RequirementType::Elective
+    ));
+
+    assert!(!outcome
+        .warnings
+        .iter()
+        .any(|w| matches!(w, ParseWarning::UnparseableCell { .. })));
+}
+
+#[test]
+fn parse_separate_year_term_and_requirement_columns() {
+    let html = Html::parse_document(
+        r#"
+    <table class="listTable">
+      <tr><th>年度</th><th>学期</th><th>必修選択</th><th>科目名</th><th>単位</th><th>評価</th><th>点数</th><th>担当教員</th></tr>
+      <tr><td>2024</td><td>後期</td><td>必</td><td>線形代数</td><td>2</td><td>秀</td><td>93</td><td>山田</td></tr>
+      <tr><td>2024</td><td>??</td><td>選</td><td>英語</td><td>1</td><td>可</td><td>A</td><td>Smith</td></tr>
+    </table>
+    "#,
+    );
+    let outcome = GradeInquiryParserBuilder::new()
+        .parse_with_warnings(&html)
+        .unwrap();
+    let subjects = &outcome.data.subjects;
+
+    assert_eq!(subjects.len(), 2);
+    assert_eq!(subjects[0].name, "線形代数");
+    assert_eq!(
+        subjects[0].term,
+        Some(AcademicTerm::new(2024, Term::SecondHalf))
+    );
+    assert_eq!(subjects[0].numeric_score, Some(93));
+    assert!(matches!(
+        subjects[0].category.requirement_type,
+        RequirementType::Required
+    ));
+    assert_eq!(subjects[0].category.hierarchy_level, 0);
+    assert!(matches!(
+        subjects[1].category.requirement_type,
+        RequirementType::Elective
+    ));
+
+    // 見出し行もtbodyの行として数える
+    assert!(outcome.warnings.contains(&ParseWarning::UnparseableCell {
+        section: "subjects".to_string(),
+        row: 2,
+        column: 6,
+        value: "A".to_string(),
+    }));
+    // 読み取れない年度・学期は補わず不明とする
+    assert_eq!(subjects[1].term, None);
+    assert!(outcome.warnings.contains(&ParseWarning::UnparseableCell {
+        section: "subjects".to_string(),
+        row: 2,
+        column: 1,
+        value: "2024年度 ??".to_string(),
+    }));
+}
+
+#[test]
+fn parse_by_semester_tables_and_display_checkboxes() {
+    let html = Html::parse_document(
+        r#"
+    <form>
+      <input type="radio" name="hyoji" id="r1"><label for="r1">通常表示</label>
+      <input type="radio" name="hyoji" id="r2" checked><label for="r2">年度学期別表示</label>
+      <label><input type="checkbox" checked> 評価名称</label>
+      <input type="checkbox"> 素点
+      <input type="checkbox" id="c3" checked><label for="c3">GPA</label>
+      <input type="checkbox" id="c4"><label for="c4">不合格科目</label>
+    </form>
+    <div class="gpaScore">GPA: 2.80</div>
+    <h3>2024年度 前期</h3>
+    <table class="listTable">
+      <tr><th>科目名</th><th>単位</th><th>評価</th><th>担当教員</th></tr>
+      <tr><td>線形代数</td><td>2</td><td>秀</td><td>山田</td></tr>
+      <tr><td>微分積分</td><td>2</td><td>不可</td><td>山田</td></tr>
+      <tfoot><tr><td colspan="4">学期GPA：2.50　修得単位数：2　履修単位数：4</td></tr></tfoot>
+    </table>
+    <h3>2024年度 後期</h3>
+    <table class="listTable">
+      <tr><td>英語</td><td>1</td><td>良</td><td>Smith</td></tr>
+      <tr><td>卒業研究</td><td>4</td><td>履修中</td><td>鈴木</td></tr>
+    </table>
+    "#,
+    );
+    let settings = DisplaySettings {
+        is_numeric_score_visible: true,
+        is_attendance_visible: true,
+        ..Default::default()
+    };
+    let outcome = GradeInquiryParserBuilder::new()
+        .with_display_settings(settings)
+        .parse_with_warnings(&html)
+        .unwrap();
+    let grades = &outcome.data;
+
+    assert!(matches!(
+        grades.display_pattern.view_type,
+        ViewType::BySemester
+    ));
+    assert!(grades.display_settings.is_grade_label_visible);
+    assert!(!grades.display_settings.is_numeric_score_visible);
+    assert!(grades.display_settings.is_gpa_visible);
+    assert!(!grades.display_settings.is_failed_subjects_visible);
+    // チェックボックスのない項目は指定した値のまま
+    assert!(grades.display_settings.is_attendance_visible);
+
+    // 2つ目の表は前の表の列の並びと後期の見出しを使う
+    assert_eq!(grades.subjects.len(), 4);
+    assert_eq!(
+        grades.subjects[0].term,
+        Some(AcademicTerm::new(2024, Term::FirstHalf))
+    );
+    assert_eq!(grades.subjects[2].name, "英語");
+    assert_eq!(grades.subjects[2].instructor_name, "Smith");
+    assert_eq!(
+        grades.subjects[3].term,
+        Some(AcademicTerm::new(2024, Term::SecondHalf))
+    );
+    assert!(grades.subjects[3].is_currently_enrolled);
+    assert!((grades.gpa_score.unwrap() - 2.8).abs() < 1e-9);
+
+    let summaries = &grades.term_summaries;
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(summaries[0].term, AcademicTerm::new(2024, Term::FirstHalf));
+    assert_eq!(summaries[0].gpa, Some(2.5));
+    assert_eq!(summaries[0].earned_credits, 2);
+    assert_eq!(summaries[0].registered_credits, 4);
+    // 表示のない後期は科目の成績から計算する
+    assert_eq!(summaries[1].gpa, Some(2.0));
+    assert_eq!(summaries[1].earned_credits, 1);
+    assert_eq!(summaries[1].registered_credits, 5);
+    assert!(outcome.warnings.iter().any(|w| matches!(
+        w,
+        ParseWarning::FallbackUsed { field, .. } if field == "term_summaries.gpa"
+    )));
+}
+
+#[test]
+fn parse_term_heading_rows_into_the_same_subjects_as_standard_view() {
+    let by_semester = Html::parse_document(
+        r#"
+    <table class="listTable">
+      <tr><th>科目名</th><th>単位</th><th>評価</th><th>担当教員</th></tr>
+      <tr><td colspan="4">2024年度 前期（GPA 4.00 修得単位数 2）</td></tr>
+      <tr><td colspan="4">専門教育科目</td></tr>
+      <tr><td>線形代数</td><td>2</td><td>秀</td><td>山田</td></tr>
+      <tr><td colspan="4">2024年度 後期</td></tr>
+      <tr><td>英語</td><td>1</td><td>良</td><td>Smith</td></tr>
+      <tr><td colspan="4">GPA 2.00　修得単位数 1　履修単位数 1</td></tr>
+    </table>
+    "#,
+    );
+    let standard = Html::parse_document(
+        r#"
+    <table class="listTable">
+      <tr><th>年度学期</th><th>科目名</th><th>単位</th><th>評価</th><th>担当教員</th></tr>
+      <tr><td>2024年度 前期</td><td>線形代数</td><td>2</td><td>秀</td><td>山田</td></tr>
+      <tr><td>2024年度 後期</td><td>英語</td><td>1</td><td>良</td><td>Smith</td></tr>
+    </table>
+    "#,
+    );
+    let parser = GradeInquiryParserBuilder::new();
+    let by_semester = parser.parse_with_warnings(&by_semester).unwrap();
+    let standard = parser.parse_with_warnings(&standard).unwrap();
+
+    assert!(matches!(
+        by_semester.data.display_pattern.view_type,
+        ViewType::BySemester
+    ));
+    assert!(matches!(
+        standard.data.display_pattern.view_type,
+        ViewType::Standard
+    ));
+    // 学期の見出しは科目区分として扱わない
+    assert_eq!(
+        by_semester.data.subjects[0].category.major_category_name,
+        "専門教育科目"
+    );
+    assert_eq!(
+        by_semester.data.subjects[1].category.major_category_name,
+        ""
+    );
+
+    for (a, b) in by_semester
+        .data
+        .subjects
+        .iter()
+        .zip(&standard.data.subjects)
+    {
+        assert_eq!(a.name, b.name);
+        assert_eq!(a.term, b.term);
+        assert_eq!(a.credit_count, b.credit_count);
+        assert_eq!(a.grade, b.grade);
+    }
+    assert_eq!(
+        by_semester.data.term_summaries,
+        standard.data.term_summaries
+    );
+    assert!(!by_semester.warnings.iter().any(|w| matches!(
+        w,
+        ParseWarning::FallbackUsed { field, .. } if field.contains("term")
+    )));
+    assert_eq!(standard.data.term_summaries[1].registered_credits, 1);
 }
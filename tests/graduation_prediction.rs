@@ -0,0 +1,242 @@
+use scraper::Html;
+use unipa_ex::common::outcome::ParseWarning;
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::grades::graduation_prediction::{
+    FusokuRyo, FusokuUnit, GraduationPredictionParserBuilder, HanteiStatus,
+};
+use unipa_ex::parsers::page::PageKind;
+
+#[path = "../benches/support/fixtures.rs"]
+#[allow(dead_code)]
+mod fixtures;
+
+const PREDICTION_HTML: &str = r#"
+<form id="form1">
+<table class="outputTable">
+  <tr><th>学籍番号</th><td>21X0001</td></tr>
+  <tr><th>判定結果</th><td>卒業見込なし</td></tr>
+</table>
+<table class="listTable">
+  <thead>
+    <tr><th>要件名</th><th>必要単位数</th><th>修得単位数</th><th>履修中単位数</th><th>判定</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>卒業要件</td><td>１２４</td><td>９９.５</td><td>16</td><td>×</td></tr>
+    <tr><td>　共通教育科目</td><td>30</td><td>31.5</td><td>0</td><td>○</td></tr>
+    <tr><td>　　外国語</td><td>8</td><td>8</td><td>0</td><td>○</td></tr>
+    <tr><td>　専門教育科目</td><td>94</td><td>68</td><td>16</td><td>×</td></tr>
+    <tr><td>　　卒業研究</td><td>-</td><td>0</td><td>4</td><td></td></tr>
+  </tbody>
+</table>
+<table class="listTable">
+  <thead>
+    <tr><th>条件コード</th><th>要素番号</th><th>不足内容</th><th>不足量</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>S01</td><td>1</td><td>専門教育科目の単位が不足しています</td><td>２６単位</td></tr>
+    <tr><td>S02</td><td>3</td><td>必修科目が未修得です</td><td>1科目</td></tr>
+    <tr><td>S03</td><td>4</td><td>体育実技の単位が不足しています</td><td>0.5単位</td></tr>
+  </tbody>
+</table>
+</form>
+"#;
+
+#[test]
+fn requirement_tree_and_shortfalls_are_parsed() {
+    let document = Html::parse_document(PREDICTION_HTML);
+    assert_eq!(
+        PageKind::detect(&document),
+        Some(PageKind::GraduationPrediction)
+    );
+
+    let outcome = GraduationPredictionParserBuilder::new()
+        .parse_with_warnings(&document)
+        .unwrap();
+    assert!(outcome.is_complete(), "{:?}", outcome.warnings);
+    let result = outcome.data;
+
+    assert_eq!(result.hantei_status, Some(HanteiStatus::MikomiNashi));
+    assert_eq!(result.hantei_message, "卒業見込なし");
+
+    assert_eq!(result.yoken_tree.len(), 1);
+    let root = &result.yoken_tree[0];
+    assert_eq!(root.yoken_name, "卒業要件");
+    assert_eq!(root.required_credits, Some(124.0));
+    assert_eq!(root.completed_credits, Some(99.5));
+    assert_eq!(root.currently_enrolled_credits, Some(16.0));
+    assert_eq!(root.is_satisfied, Some(false));
+
+    let names: Vec<_> = root
+        .children
+        .iter()
+        .map(|c| c.yoken_name.as_str())
+        .collect();
+    assert_eq!(names, ["共通教育科目", "専門教育科目"]);
+    assert_eq!(root.children[0].children[0].yoken_name, "外国語");
+    assert_eq!(root.children[0].completed_credits, Some(31.5));
+    assert_eq!(root.children[0].is_satisfied, Some(true));
+
+    // 必要単位数のない要件は判定欄が空なら充足状況も不明
+    let thesis = &root.children[1].children[0];
+    assert_eq!(thesis.yoken_name, "卒業研究");
+    assert_eq!(thesis.required_credits, None);
+    assert_eq!(thesis.currently_enrolled_credits, Some(4.0));
+    assert_eq!(thesis.is_satisfied, None);
+
+    assert_eq!(result.fusoku_items.len(), 3);
+    assert_eq!(result.fusoku_items[0].joken_code, "S01");
+    assert_eq!(
+        result.fusoku_items[0].fusoku_ryo,
+        Some(FusokuRyo {
+            amount: 26.0,
+            unit: FusokuUnit::Credits,
+        })
+    );
+    assert_eq!(result.fusoku_items[1].yoso_number, 3);
+    assert_eq!(
+        result.fusoku_items[1].fusoku_ryo,
+        Some(FusokuRyo {
+            amount: 1.0,
+            unit: FusokuUnit::Subjects,
+        })
+    );
+    assert_eq!(
+        result.fusoku_items[2].fusoku_ryo,
+        Some(FusokuRyo {
+            amount: 0.5,
+            unit: FusokuUnit::Credits,
+        })
+    );
+}
+
+#[test]
+fn generated_fixture_pages_are_parsed_completely() {
+    let document = Html::parse_document(&fixtures::graduation_prediction(4));
+    assert_eq!(
+        PageKind::detect(&document),
+        Some(PageKind::GraduationPrediction)
+    );
+
+    let outcome = GraduationPredictionParserBuilder::new()
+        .parse_with_warnings(&document)
+        .unwrap();
+    assert!(outcome.is_complete(), "{:?}", outcome.warnings);
+    let result = outcome.data;
+
+    assert_eq!(result.hantei_status, Some(HanteiStatus::MikomiNashi));
+    let root = &result.yoken_tree[0];
+    assert_eq!(root.completed_credits, Some(37.0));
+    assert_eq!(root.children.len(), 4);
+    assert_eq!(root.children[1].completed_credits, Some(8.5));
+    assert_eq!(root.children[1].children[0].yoken_name, "区分1必修");
+    assert_eq!(result.fusoku_items.len(), 2);
+    assert_eq!(
+        result.fusoku_items[0].fusoku_ryo,
+        Some(FusokuRyo {
+            amount: 1.5,
+            unit: FusokuUnit::Credits,
+        })
+    );
+}
+
+#[test]
+fn levels_from_class_names_and_judgement_messages() {
+    let html = r#"
+    <table class="outputTable">
+      <tr><th>判定結果</th><td>あなたは卒業見込みありと判定されました。</td></tr>
+    </table>
+    <table class="listTable">
+      <tr><th>区分</th><th>必要単位</th><th>修得単位</th></tr>
+      <tr class="level1"><td>卒業要件</td><td>124</td><td>124</td></tr>
+      <tr class="level2"><td>共通教育科目</td><td>30</td><td>30</td></tr>
+      <tr class="level2"><td>専門教育科目</td><td>94</td><td>94</td></tr>
+    </table>
+    "#;
+    let outcome = GraduationPredictionParserBuilder::new()
+        .parse_with_warnings(&Html::parse_document(html))
+        .unwrap();
+    let result = outcome.data;
+
+    assert_eq!(result.hantei_status, Some(HanteiStatus::MikomiAri));
+    assert_eq!(result.yoken_tree[0].children.len(), 2);
+    // 判定欄がない場合は修得単位数と必要単位数から求める
+    assert_eq!(result.yoken_tree[0].children[1].is_satisfied, Some(true));
+    // 見込ありの場合は不足項目の一覧がなくてもよい
+    assert!(result.fusoku_items.is_empty());
+    assert!(outcome.warnings.is_empty(), "{:?}", outcome.warnings);
+}
+
+#[test]
+fn out_of_scope_students_and_unknown_amounts() {
+    let html = r#"
+    <table class="outputTable">
+      <tr><th>判定結果</th><td>卒業見込判定の対象外です。</td></tr>
+    </table>
+    <table class="listTable">
+      <tr><th>条件コード</th><th>要素番号</th><th>不足内容</th><th>不足量</th></tr>
+      <tr><td>S09</td><td>1</td><td>在籍期間が不足しています</td><td>半期</td></tr>
+    </table>
+    "#;
+    let outcome = GraduationPredictionParserBuilder::new()
+        .parse_with_warnings(&Html::parse_document(html))
+        .unwrap();
+
+    assert_eq!(outcome.data.hantei_status, Some(HanteiStatus::TaishoGai));
+    assert!(outcome.data.yoken_tree.is_empty());
+    assert_eq!(outcome.data.fusoku_items[0].fusoku_ryo, None);
+    assert_eq!(
+        outcome.warnings,
+        vec![ParseWarning::UnparseableCell {
+            section: "fusoku_items".to_string(),
+            row: 1,
+            column: 3,
+            value: "半期".to_string(),
+        }]
+    );
+
+    assert_eq!(
+        FusokuRyo::parse("4"),
+        Some(FusokuRyo {
+            amount: 4.0,
+            unit: FusokuUnit::Credits
+        })
+    );
+    assert_eq!(
+        FusokuRyo::parse("１．５単位"),
+        Some(FusokuRyo {
+            amount: 1.5,
+            unit: FusokuUnit::Credits
+        })
+    );
+    assert_eq!(FusokuRyo::parse("-2単位"), None);
+    assert_eq!(HanteiStatus::parse("判定中"), None);
+}
+
+#[test]
+fn missing_completed_credits_column_is_reported() {
+    let html = r#"
+    <table class="outputTable">
+      <tr><th>判定結果</th><td>卒業見込あり</td></tr>
+    </table>
+    <table class="listTable">
+      <tr><th>要件名</th><th>必要単位数</th><th>判定</th></tr>
+      <tr><td>卒業要件</td><td>124</td><td>○</td></tr>
+    </table>
+    "#;
+    let outcome = GraduationPredictionParserBuilder::new()
+        .parse_with_warnings(&Html::parse_document(html))
+        .unwrap();
+
+    let root = &outcome.data.yoken_tree[0];
+    assert_eq!(root.required_credits, Some(124.0));
+    assert_eq!(root.completed_credits, None);
+    assert_eq!(root.currently_enrolled_credits, None);
+    assert_eq!(root.is_satisfied, Some(true));
+    assert_eq!(
+        outcome.warnings,
+        vec![ParseWarning::MissingSection {
+            section: "yoken_tree.completed_credits".to_string(),
+            selector: "th".to_string(),
+        }]
+    );
+}
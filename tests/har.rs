@@ -0,0 +1,93 @@
+use base64::Engine;
+use unipa_ex::har::{Classification, HarEntryOutcome, HarParser};
+use unipa_ex::parsers::page::{PageData, PageKind};
+
+const GRADE_HTML: &str = r#"
+<html><head><meta charset="Shift_JIS"></head><body>
+<div class="gpaScore">GPA: 3.50</div>
+<table class="listTable">
+  <tbody>
+    <tr><td>情報処理演習</td><td>2</td><td>秀</td><td>山田 太郎</td><td>-</td></tr>
+  </tbody>
+</table>
+</body></html>
+"#;
+
+fn entry(url: &str, mime_type: &str, text: &str, base64: bool) -> String {
+    format!(
+        r#"{{
+            "startedDateTime": "2025-04-01T09:00:00.000+09:00",
+            "request": {{ "method": "GET", "url": "{url}" }},
+            "response": {{
+                "status": 200,
+                "headers": [{{ "name": "Content-Type", "value": "{mime_type}" }}],
+                "content": {{ "mimeType": "{mime_type}", "text": {text}{encoding} }}
+            }}
+        }}"#,
+        text = serde_json::to_string(text).unwrap(),
+        encoding = if base64 {
+            r#", "encoding": "base64""#
+        } else {
+            ""
+        },
+    )
+}
+
+#[test]
+fn parse_har_with_shift_jis_body() {
+    let (bytes, _, _) = encoding_rs::SHIFT_JIS.encode(GRADE_HTML);
+    let body = base64::engine::general_purpose::STANDARD.encode(&bytes);
+
+    let har = format!(
+        r#"{{ "log": {{ "entries": [{}, {}, {}] }} }}"#,
+        entry(
+            "https://unipa.example.ac.jp/up/faces/up/km/Kmg00101A.jsp",
+            "text/html; charset=Shift_JIS",
+            &body,
+            true,
+        ),
+        entry(
+            "https://unipa.example.ac.jp/up/css/style.css",
+            "text/css",
+            "body {}",
+            false,
+        ),
+        entry(
+            "https://unipa.example.ac.jp/up/faces/login.jsp",
+            "text/html",
+            "<html><body><p>ログイン</p></body></html>",
+            false,
+        ),
+    );
+
+    let report = HarParser::new().parse_str(&har).unwrap();
+
+    assert_eq!(report.skipped_entries, 1);
+    assert_eq!(report.entries.len(), 2);
+    assert_eq!(report.parsed_count(), 1);
+    assert_eq!(report.failed_count(), 0);
+
+    let grades = &report.entries[0];
+    assert_eq!(grades.screen_id.as_deref(), Some("Kmg00101A"));
+    assert_eq!(grades.classified_by, Some(Classification::Detection));
+    match &grades.outcome {
+        HarEntryOutcome::Parsed {
+            kind: PageKind::GradeInquiry,
+            data,
+        } => match data.as_ref() {
+            PageData::GradeInquiry(data) => assert_eq!(data.subjects[0].name, "情報処理演習"),
+            other => panic!("unexpected data: {other:?}"),
+        },
+        other => panic!("unexpected outcome: {other:?}"),
+    }
+
+    assert!(matches!(
+        report.entries[1].outcome,
+        HarEntryOutcome::Unclassified
+    ));
+}
+
+#[test]
+fn invalid_har_is_rejected() {
+    assert!(HarParser::new().parse_str("not a har").is_err());
+}
@@ -0,0 +1,220 @@
+use unipa_ex::common::datetime::{JstDate, JstDateTime, JstRange};
+use unipa_ex::common::period::{ClassTime, PeriodTable};
+use unipa_ex::export::ical::{IcalBuilder, IcalOptions, TimetableClass};
+use unipa_ex::parsers::assignment_submit::model::{Assignment, AssignmentList, SubmissionStatus};
+use unipa_ex::parsers::page::PageData;
+use unipa_ex::parsers::test_answer_status::model::{
+    NotImplementedDetails, TestAnswerStatus, TestDetails, TestItem,
+};
+use unipa_ex::utils::error::ParseError;
+
+/// 2025-04-01（火）は日本時間で0時
+const TIMESTAMP: i64 = 1_743_433_200;
+
+fn class(code: &str, name: &str, time: &str) -> TimetableClass {
+    TimetableClass {
+        class_code: code.to_string(),
+        subject_name: name.to_string(),
+        teacher_name: Some("山田 太郎".to_string()),
+        classroom: Some("A101".to_string()),
+        class_time: ClassTime::parse(time, &PeriodTable::default()).unwrap(),
+    }
+}
+
+fn term() -> JstRange<JstDate> {
+    JstRange::new(
+        JstDate::parse("2025-04-07").unwrap(),
+        JstDate::parse("2025-07-25").unwrap(),
+    )
+    .unwrap()
+}
+
+fn datetime(text: &str) -> JstDateTime {
+    JstDateTime::parse(text).unwrap()
+}
+
+/// 折り返しを戻した論理行
+fn lines(ics: &str) -> Vec<String> {
+    ics.replace("\r\n ", "")
+        .split("\r\n")
+        .map(str::to_string)
+        .collect()
+}
+
+fn events(ics: &str) -> Vec<Vec<String>> {
+    let mut events = Vec::new();
+    let mut current = None;
+    for line in lines(ics) {
+        match line.as_str() {
+            "BEGIN:VEVENT" => current = Some(Vec::new()),
+            "END:VEVENT" => events.extend(current.take()),
+            _ => {
+                if let Some(event) = current.as_mut() {
+                    event.push(line);
+                }
+            }
+        }
+    }
+    events
+}
+
+#[test]
+fn weekly_classes_repeat_until_the_end_of_the_term() {
+    let ics = IcalBuilder::new()
+        .with_term_dates(term())
+        .with_calendar_name("2025年度 前期")
+        .with_timestamp(TIMESTAMP)
+        .add_class(class("K00001", "情報科学特論", "水3-4"))
+        .add_class(class("K00002", "線形代数", "月1, 金2"))
+        .build()
+        .unwrap();
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert!(lines(&ics).contains(&"X-WR-CALNAME:2025年度 前期".to_string()));
+    assert!(lines(&ics).contains(&"TZID:Asia/Tokyo".to_string()));
+
+    let events = events(&ics);
+    assert_eq!(events.len(), 3);
+
+    // 連続する時限は1つの予定にまとめ、授業期間開始後の最初の水曜日から始める
+    let wednesday = &events[0];
+    for expected in [
+        "UID:class-K00001-we3@unipa-ex",
+        "DTSTAMP:20250331T150000Z",
+        "DTSTART;TZID=Asia/Tokyo:20250409T130000",
+        "DTEND;TZID=Asia/Tokyo:20250409T161000",
+        "RRULE:FREQ=WEEKLY;UNTIL=20250725T145959Z;BYDAY=WE",
+        "SUMMARY:情報科学特論",
+        "LOCATION:A101",
+        "CATEGORIES:授業",
+    ] {
+        assert!(wednesday.contains(&expected.to_string()), "{expected}");
+    }
+
+    assert!(events[1].contains(&"UID:class-K00002-mo1@unipa-ex".to_string()));
+    assert!(events[1].contains(&"DTSTART;TZID=Asia/Tokyo:20250407T090000".to_string()));
+    assert!(events[2].contains(&"UID:class-K00002-fr2@unipa-ex".to_string()));
+    assert!(events[2].contains(&"DTSTART;TZID=Asia/Tokyo:20250411T104000".to_string()));
+}
+
+#[test]
+fn uids_are_stable_across_exports() {
+    let build = || {
+        IcalBuilder::new()
+            .with_options(IcalOptions {
+                term_dates: Some(term()),
+                calendar_name: None,
+            })
+            .with_timestamp(TIMESTAMP)
+            .add_class(class("K00001", "情報科学特論", "水3"))
+            .build()
+            .unwrap()
+    };
+    assert_eq!(build(), build());
+}
+
+#[test]
+fn classes_without_times_are_skipped_and_require_term_dates() {
+    let ics = IcalBuilder::new()
+        .with_term_dates(term())
+        .with_timestamp(TIMESTAMP)
+        .add_class(class("K00003", "卒業研究", "集中講義"))
+        .add_class(class("K00004", "特別講義", "土8"))
+        .build()
+        .unwrap();
+    assert!(events(&ics).is_empty());
+
+    let missing = IcalBuilder::new()
+        .add_class(class("K00001", "情報科学特論", "水3"))
+        .build();
+    assert!(matches!(
+        missing,
+        Err(ParseError::MissingRequiredField { field_name }) if field_name == "term_dates"
+    ));
+}
+
+#[test]
+fn tests_and_assignment_deadlines_become_events() {
+    let tests = TestAnswerStatus {
+        tests: vec![
+            TestItem {
+                title: "第1回小テスト".to_string(),
+                course_name: "線形代数".to_string(),
+                details: TestDetails::NotImplemented(NotImplementedDetails {
+                    start_date: Some(datetime("2025/05/01 09:00")),
+                    end_date: Some(datetime("2025/05/08 23:59")),
+                    time_limit_minutes: Some(30),
+                    can_reanswer: false,
+                }),
+            },
+            TestItem {
+                title: "日程未定".to_string(),
+                course_name: "線形代数".to_string(),
+                details: TestDetails::NotImplemented(NotImplementedDetails::default()),
+            },
+        ],
+        ..Default::default()
+    };
+    let assignments = AssignmentList {
+        assignments: vec![
+            Assignment {
+                subject_name: "情報科学特論".to_string(),
+                assignment_title: "レポート1".to_string(),
+                due_date: Some(datetime("2025/05/10 17:00")),
+                submission_status: SubmissionStatus::NotSubmitted,
+                ..Default::default()
+            },
+            Assignment {
+                subject_name: "情報科学特論".to_string(),
+                assignment_title: "レポート0".to_string(),
+                due_date: Some(datetime("2025/04/20 17:00")),
+                submission_status: SubmissionStatus::Submitted,
+                ..Default::default()
+            },
+        ],
+        pagination: None,
+    };
+
+    let ics = IcalBuilder::new()
+        .with_timestamp(TIMESTAMP)
+        .add_page(&PageData::TestAnswerStatus(tests))
+        .unwrap()
+        .add_assignments(&assignments)
+        .build()
+        .unwrap();
+    let events = events(&ics);
+    assert_eq!(events.len(), 2);
+
+    assert!(events[0][0].starts_with("UID:test-"));
+    assert!(events[0].contains(&"SUMMARY:第1回小テスト（線形代数）".to_string()));
+    assert!(events[0].contains(&"DTSTART;TZID=Asia/Tokyo:20250501T090000".to_string()));
+    assert!(events[0].contains(&"DTEND;TZID=Asia/Tokyo:20250508T235900".to_string()));
+    assert!(events[0].contains(&"DESCRIPTION:制限時間: 30分".to_string()));
+
+    assert!(events[1][0].starts_with("UID:assignment-"));
+    assert!(events[1].contains(&"DTSTART;TZID=Asia/Tokyo:20250510T170000".to_string()));
+    assert!(events[1].contains(&"CATEGORIES:課題".to_string()));
+}
+
+#[test]
+fn text_is_escaped_and_long_lines_are_folded() {
+    let mut long = class("K00001", "データ構造とアルゴリズム", "月1");
+    long.classroom = Some("講義棟A;101,102\\別館".to_string());
+    long.subject_name = "データ構造とアルゴリズム（応用編）".repeat(3);
+
+    let ics = IcalBuilder::new()
+        .with_term_dates(term())
+        .with_timestamp(TIMESTAMP)
+        .add_class(long)
+        .build()
+        .unwrap();
+
+    for line in ics.split("\r\n") {
+        assert!(line.len() <= 75, "{line}");
+    }
+    assert!(lines(&ics).contains(&"LOCATION:講義棟A\\;101\\,102\\\\別館".to_string()));
+    assert!(lines(&ics)
+        .iter()
+        .any(|line| line.starts_with("DESCRIPTION:月1限\\n担当: 山田 太郎\\n")));
+}
@@ -0,0 +1,86 @@
+use scraper::Html;
+use unipa_ex::common::outcome::ParseWarning;
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::grades::grade_inquiry::GradeInquiryParserBuilder;
+use unipa_ex::parsers::grades::promotion_prediction::PromotionPredictionParserBuilder;
+use unipa_ex::parsers::page::PageKind;
+
+const GRADE_HTML: &str = r#"
+<table class="listTable">
+  <tbody>
+    <tr><td>Programming I</td><td>2</td><td>秀</td><td>Prof. A</td><td>-</td></tr>
+    <tr><td>Seminar</td><td>認定</td><td>??</td><td>Prof. B</td><td>-</td></tr>
+  </tbody>
+</table>
+"#;
+
+#[test]
+fn grade_inquiry_reports_fallbacks_and_unparseable_cells() {
+    let document = Html::parse_document(GRADE_HTML);
+    let outcome = GradeInquiryParserBuilder::new()
+        .parse_with_warnings(&document)
+        .unwrap();
+
+    assert_eq!(outcome.data.subjects.len(), 2);
+    assert!(!outcome.is_complete());
+    assert!(outcome.warnings.contains(&ParseWarning::UnparseableCell {
+        section: "subjects".to_string(),
+        row: 1,
+        column: 1,
+        value: "認定".to_string(),
+    }));
+    assert!(outcome.warnings.contains(&ParseWarning::UnparseableCell {
+        section: "subjects".to_string(),
+        row: 1,
+        column: 2,
+        value: "??".to_string(),
+    }));
+    assert!(outcome.warnings.iter().any(|w| matches!(
+        w,
+        ParseWarning::MissingSection { section, .. } if section == "credit_summary"
+    )));
+    assert!(outcome.warnings.iter().any(|w| matches!(
+        w,
+        ParseWarning::FallbackUsed { field, .. } if field == "gpa_score"
+    )));
+    // 科目一覧から計算した単位状況には卒業要件単位数を補わない
+    assert_eq!(
+        outcome.data.credit_summary.overall.required_for_graduation,
+        None
+    );
+}
+
+#[test]
+fn placeholder_message_is_reported_as_fallback() {
+    let html = r#"
+    <input type="hidden" name="lastSearchStudentId" value="S123">
+    <input type="hidden" name="academicYear" value="2025">
+    <input type="hidden" name="semester" value="1">
+    "#;
+
+    let outcome = PromotionPredictionParserBuilder::new()
+        .parse_with_warnings(&Html::parse_document(html))
+        .unwrap();
+
+    assert_eq!(outcome.data.last_search_student_id, "S123");
+    assert_eq!(
+        outcome.warnings,
+        vec![ParseWarning::FallbackUsed {
+            field: "judgement_message".to_string(),
+            fallback: "判定結果を取得できませんでした。".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn warnings_are_kept_through_page_dispatch_and_serialized() {
+    let document = Html::parse_document(GRADE_HTML);
+    let outcome = PageKind::GradeInquiry
+        .parse_with_warnings(&document)
+        .unwrap();
+    let json = serde_json::to_value(&outcome).unwrap();
+
+    assert!(!outcome.warnings.is_empty());
+    assert_eq!(json["warnings"][0]["kind"], "unparseable_cell");
+    assert_eq!(json["data"]["subjects"][0]["name"], "Programming I");
+}
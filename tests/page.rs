@@ -0,0 +1,51 @@
+use scraper::Html;
+use unipa_ex::parsers::page::{parse_page, PageData, PageKind};
+use unipa_ex::utils::error::ParseError;
+
+#[test]
+fn detect_and_parse_grade_inquiry() {
+    let html = Html::parse_document(
+        r#"
+        <div class="gpaScore">GPA: 3.50</div>
+        <table class="listTable">
+          <tbody>
+            <tr><td>Programming I</td><td>2</td><td>秀</td><td>Prof. A</td><td>-</td></tr>
+          </tbody>
+        </table>
+        "#,
+    );
+
+    let (kind, data) = parse_page(&html).unwrap();
+    assert_eq!(kind, PageKind::GradeInquiry);
+    assert!(matches!(data, PageData::GradeInquiry(ref g) if g.subjects.len() == 1));
+}
+
+#[test]
+fn unknown_page_is_rejected() {
+    let html = Html::parse_document("<html><body><p>hello</p></body></html>");
+
+    assert_eq!(PageKind::detect(&html), None);
+    assert!(parse_page(&html).is_err());
+}
+
+#[test]
+fn page_kind_round_trips_through_str() {
+    for kind in PageKind::ALL {
+        assert_eq!(kind.as_str().parse::<PageKind>().unwrap(), kind);
+    }
+    assert!("timetable".parse::<PageKind>().is_err());
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn every_page_kind_has_a_parser_in_full_build() {
+    let html = Html::parse_document("<html><body></body></html>");
+
+    for kind in PageKind::ALL {
+        assert!(
+            !matches!(kind.parse(&html), Err(ParseError::NotImplemented { .. })),
+            "{kind} requires the {} feature",
+            kind.feature()
+        );
+    }
+}
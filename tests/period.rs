@@ -0,0 +1,172 @@
+use scraper::Html;
+use unipa_ex::common::datetime::ClockTime;
+use unipa_ex::common::options::ParseOptions;
+use unipa_ex::common::outcome::ParseWarning;
+use unipa_ex::common::period::{ClassSlot, ClassTime, PeriodTable, PeriodTime, Weekday};
+use unipa_ex::parsers::page::{PageData, PageKind};
+use unipa_ex::utils::error::ParseError;
+
+fn slots(text: &str) -> Vec<(Option<Weekday>, u32)> {
+    ClassTime::parse(text, &PeriodTable::default())
+        .unwrap()
+        .slots
+        .iter()
+        .map(|slot| (slot.weekday, slot.period))
+        .collect()
+}
+
+fn time(text: &str) -> ClockTime {
+    ClockTime::parse(text).unwrap()
+}
+
+#[test]
+fn weekday_and_period_notations_are_recognized() {
+    use Weekday::*;
+
+    for (text, expected) in [
+        ("月3", vec![(Some(Monday), 3)]),
+        ("月 3", vec![(Some(Monday), 3)]),
+        ("火曜3限", vec![(Some(Tuesday), 3)]),
+        ("水曜日 ２時限", vec![(Some(Wednesday), 2)]),
+        ("月3-4", vec![(Some(Monday), 3), (Some(Monday), 4)]),
+        (
+            "金1〜3",
+            vec![(Some(Friday), 1), (Some(Friday), 2), (Some(Friday), 3)],
+        ),
+        ("木3・4", vec![(Some(Thursday), 3), (Some(Thursday), 4)]),
+        (
+            "火曜3限 火曜4限",
+            vec![(Some(Tuesday), 3), (Some(Tuesday), 4)],
+        ),
+        ("月1, 水2", vec![(Some(Monday), 1), (Some(Wednesday), 2)]),
+        ("月3月4", vec![(Some(Monday), 3), (Some(Monday), 4)]),
+        ("4/14(月) 3限", vec![(Some(Monday), 3)]),
+        ("1限", vec![(None, 1)]),
+        ("5", vec![(None, 5)]),
+    ] {
+        assert_eq!(slots(text), expected, "{text}");
+    }
+}
+
+#[test]
+fn intensive_classes_may_have_no_slots() {
+    let intensive = ClassTime::parse("集中講義", &PeriodTable::default()).unwrap();
+    assert!(intensive.is_intensive);
+    assert!(intensive.slots.is_empty());
+
+    let with_slot = ClassTime::parse("集中 月1", &PeriodTable::default()).unwrap();
+    assert!(with_slot.is_intensive);
+    assert_eq!(with_slot.slots.len(), 1);
+
+    for text in ["未定", "2025/04/01", ""] {
+        assert!(
+            matches!(
+                ClassTime::parse(text, &PeriodTable::default()),
+                Err(ParseError::InvalidDataFormat { .. })
+            ),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn start_and_end_times_come_from_the_period_table() {
+    let class_time = ClassTime::parse("月1-2", &PeriodTable::default()).unwrap();
+    assert_eq!(
+        class_time.slots,
+        vec![
+            ClassSlot {
+                weekday: Some(Weekday::Monday),
+                period: 1,
+                start: Some(time("09:00")),
+                end: Some(time("10:30")),
+            },
+            ClassSlot {
+                weekday: Some(Weekday::Monday),
+                period: 2,
+                start: Some(time("10:40")),
+                end: Some(time("12:10")),
+            },
+        ]
+    );
+
+    // 時限表にない時限は時刻なし
+    let late = ClassTime::parse("土8", &PeriodTable::default()).unwrap();
+    assert_eq!(late.slots[0].start, None);
+
+    let table = PeriodTable::new(vec![PeriodTime {
+        period: 1,
+        start: time("08:50"),
+        end: time("10:20"),
+    }]);
+    let custom = ClassTime::parse("月1", &table).unwrap();
+    assert_eq!(custom.slots[0].start, Some(time("08:50")));
+    assert_eq!(custom.slots[0].to_string(), "月1限");
+}
+
+#[test]
+fn clock_times_are_serialized_as_hours_and_minutes() {
+    assert_eq!(time("9:05").to_string(), "09:05");
+    assert_eq!(time("９：０５").hour(), 9);
+    assert!(ClockTime::parse("24:00").is_err());
+    assert!(ClockTime::parse("09:00:30").is_err());
+    assert_eq!(
+        serde_json::to_value(PeriodTable::default().get(3).unwrap()).unwrap(),
+        serde_json::json!({ "period": 3, "start": "13:00", "end": "14:30" })
+    );
+}
+
+#[test]
+fn syllabus_rows_use_the_period_table_from_the_options() {
+    let html = r#"
+<div id="form1:htmlKensakuJyoken">開講年度／学期：2025年度　前期</div>
+<table id="form1:htmlKekkatable"><tbody>
+  <tr class="rowClass1">
+    <td class="yobi"><span>月3<br>月4</span></td>
+    <td class="kamokuName"><a id="link0" onclick="return false;"><span>K00001 情報科学特論</span></a></td>
+  </tr>
+  <tr class="rowClass1">
+    <td class="yobi"><span>随時</span></td>
+    <td class="kamokuName"><a id="link1" onclick="return false;"><span>K00002 情報理論</span></a></td>
+  </tr>
+</tbody></table>
+<span id="form1:htmlKekkatable:htmlGokeiKensu">2件</span>
+<span id="form1:htmlKekkatable:deluxe1__pagerText">1 / 1 ページ</span>
+<form id="form1"></form>
+"#;
+    let table = PeriodTable::new(vec![
+        PeriodTime {
+            period: 3,
+            start: time("13:10"),
+            end: time("14:40"),
+        },
+        PeriodTime {
+            period: 4,
+            start: time("14:50"),
+            end: time("16:20"),
+        },
+    ]);
+    let outcome = PageKind::SyllabusSearchResult
+        .parse_with_options(
+            &Html::parse_document(html),
+            ParseOptions::new().with_period_table(table),
+        )
+        .unwrap();
+    let PageData::SyllabusSearchResult(page) = &outcome.data else {
+        panic!("unexpected page data");
+    };
+
+    let class_time = page.course_entries[0].class_time.as_ref().unwrap();
+    assert_eq!(page.course_entries[0].schedule_day_period, "月3月4");
+    assert_eq!(class_time.slots.len(), 2);
+    assert_eq!(class_time.slots[1].start, Some(time("14:50")));
+    assert_eq!(class_time.slots[1].end, Some(time("16:20")));
+
+    assert_eq!(page.course_entries[1].class_time, None);
+    assert!(outcome.warnings.contains(&ParseWarning::UnparseableCell {
+        section: "course_entries".to_string(),
+        row: 1,
+        column: 0,
+        value: "随時".to_string(),
+    }));
+}
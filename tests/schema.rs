@@ -0,0 +1,107 @@
+use scraper::Html;
+use serde_json::{json, Value};
+use unipa_ex::parsers::page::PageKind;
+use unipa_ex::parsers::schema::{json_schema, json_schemas};
+use unipa_ex::utils::error::ParseError;
+
+#[path = "../benches/support/fixtures.rs"]
+mod fixtures;
+
+/// スキーマに照らしてJSONを検証し、違反箇所の一覧を返す
+fn violations(kind: PageKind, instance: &Value) -> Vec<String> {
+    let schema = json_schema(kind).unwrap();
+    let validator = jsonschema::validator_for(schema.as_value()).unwrap();
+    validator
+        .iter_errors(instance)
+        .map(|error| format!("{}: {error}", error.instance_path))
+        .collect()
+}
+
+fn assert_matches_schema(kind: PageKind, html: &str) {
+    let data = kind.parse(&Html::parse_document(html)).unwrap();
+    let instance = serde_json::to_value(&data).unwrap();
+
+    let violations = violations(kind, &instance);
+    assert!(violations.is_empty(), "{kind}: {violations:#?}");
+}
+
+#[test]
+fn parsed_fixtures_match_their_schemas() {
+    assert_matches_schema(
+        PageKind::PortalAllNotifications,
+        &fixtures::all_notifications(3),
+    );
+    assert_matches_schema(
+        PageKind::SyllabusSearchResult,
+        &fixtures::syllabus_search_result(3),
+    );
+    assert_matches_schema(PageKind::GradeInquiry, &fixtures::grade_inquiry(1));
+    assert_matches_schema(PageKind::AssignmentList, &fixtures::assignment_list(3));
+    assert_matches_schema(
+        PageKind::QuestionnaireList,
+        &fixtures::questionnaire_list(3),
+    );
+    assert_matches_schema(
+        PageKind::GraduationPrediction,
+        &fixtures::graduation_prediction(3),
+    );
+    assert_matches_schema(
+        PageKind::NotificationDetail,
+        r#"<h1>休講のお知らせ</h1><p>本日の講義は休講です。</p>
+           <a href="/files/download?id=1">資料.pdf</a>"#,
+    );
+}
+
+#[test]
+fn empty_pages_match_their_schemas() {
+    // 空のページでは既定値で補われた結果になるため、省略可能なフィールドの扱いを確認できる
+    let document = Html::parse_document("<html><body></body></html>");
+
+    for kind in PageKind::ALL {
+        let Ok(data) = kind.parse(&document) else {
+            continue;
+        };
+        let instance = serde_json::to_value(&data).unwrap();
+
+        let violations = violations(kind, &instance);
+        assert!(violations.is_empty(), "{kind}: {violations:#?}");
+    }
+}
+
+#[test]
+fn schemas_reject_mismatched_data() {
+    let instance = json!({ "subjects": "Programming I" });
+
+    assert!(!violations(PageKind::GradeInquiry, &instance).is_empty());
+}
+
+#[test]
+fn schemas_carry_field_docs_as_descriptions() {
+    let schema = json_schema(PageKind::GradeInquiry).unwrap();
+    let schema = schema.as_value();
+
+    assert_eq!(schema["title"], "GradeInquiry");
+    assert_eq!(
+        schema["properties"]["gpa_score"]["description"],
+        "GPA得点（GPAの表示がなく、GPA対象の科目もない場合はNone）"
+    );
+}
+
+#[test]
+fn every_page_kind_has_a_schema_in_full_build() {
+    let kinds: Vec<_> = json_schemas().into_iter().map(|(kind, _)| kind).collect();
+
+    assert_eq!(kinds, PageKind::ALL);
+}
+
+#[test]
+fn error_schema_requires_the_display_message() {
+    let schema = serde_json::to_value(schemars::schema_for!(ParseError)).unwrap();
+    assert_eq!(schema["required"], json!(["message"]));
+    let validator = jsonschema::validator_for(&schema).unwrap();
+
+    let mut error = serde_json::to_value(ParseError::element_not_found("#form1", "page")).unwrap();
+    assert!(validator.is_valid(&error), "{error}");
+    error.as_object_mut().unwrap().remove("message");
+    assert!(!validator.is_valid(&error));
+}
@@ -0,0 +1,105 @@
+use scraper::Html;
+use unipa_ex::common::options::ParseOptions;
+use unipa_ex::common::term::{AcademicTerm, Term};
+use unipa_ex::common::traits::{PageParser, SectionParser};
+use unipa_ex::parsers::portal::builder::PortalParserBuilder;
+use unipa_ex::parsers::portal::sections::{
+    CalendarSectionParser, FavoriteLinksSectionParser, NotificationsSectionParser,
+    ScheduleSectionParser,
+};
+use unipa_ex::utils::error::ParseError;
+
+const PORTAL_HTML: &str = r#"
+<html><body><form id="form1">
+  <span class="style24">2025春学期</span>
+  <table id="form1:Poa00101A:htmlCalendarTable">
+    <tbody><tr><td class="todayColor">1</td><td>2</td></tr></tbody>
+  </table>
+  <table id="form1:Poa00401A:htmlTodayJikanTable">
+    <tr><td class="jigen">1限</td><td class="jugyo">情報処理</td></tr>
+  </table>
+  <table id="form1:Poa00201A:htmlParentTable"><tr><td>
+    <table id="form1:Poa00201A:htmlParentTable:0:htmlDetailTbl">
+      <tr><td></td><td>健康診断</td><td>保健室</td><td>2025/04/01</td></tr>
+    </table>
+  </td></tr></table>
+</form></body></html>
+"#;
+
+#[test]
+fn sections_are_extracted_independently_from_one_document() {
+    let document = Html::parse_document(PORTAL_HTML);
+
+    let calendar = CalendarSectionParser::new()
+        .parse_section(&document)
+        .unwrap();
+    assert_eq!(calendar.term, Some(AcademicTerm::new(2025, Term::Spring)));
+    assert!(calendar.days[0][0].is_today);
+
+    let schedule = ScheduleSectionParser::new()
+        .parse_section(&document)
+        .unwrap();
+    assert_eq!(schedule.entries[0].class_content, "情報処理");
+
+    let notifications = NotificationsSectionParser::new()
+        .parse_section(&document)
+        .unwrap();
+    assert_eq!(
+        notifications.sections[0].entries[0].title.as_deref(),
+        Some("健康診断")
+    );
+}
+
+#[test]
+fn section_results_match_the_whole_page_parser() {
+    let document = Html::parse_document(PORTAL_HTML);
+    let portal = PortalParserBuilder::new()
+        .build()
+        .unwrap()
+        .parse_document(&document)
+        .unwrap();
+    let notifications = NotificationsSectionParser::new()
+        .parse_section(&document)
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&portal.notifications).unwrap(),
+        serde_json::to_value(&notifications).unwrap()
+    );
+}
+
+#[test]
+fn section_exists_reports_missing_sections() {
+    let document = Html::parse_document(PORTAL_HTML);
+
+    assert!(CalendarSectionParser::new().section_exists(&document));
+    assert!(NotificationsSectionParser::new().section_exists(&document));
+    assert!(!FavoriteLinksSectionParser::new().section_exists(&document));
+
+    let err = FavoriteLinksSectionParser::new()
+        .with_options(ParseOptions::strict())
+        .parse_section(&document)
+        .unwrap_err();
+    assert!(matches!(err, ParseError::ElementNotFound { .. }));
+}
+
+#[test]
+fn debug_mode_collects_section_diagnostics() {
+    let document = Html::parse_document(PORTAL_HTML);
+    let parser =
+        ScheduleSectionParser::new().with_options(ParseOptions::new().with_debug_mode(true));
+
+    let outcome = parser
+        .parse_element_with_warnings(&document.root_element())
+        .unwrap();
+    let diagnostics = outcome.diagnostics.expect("debug_mode records diagnostics");
+    assert!(diagnostics
+        .selectors
+        .iter()
+        .any(|trace| trace.section == "schedule"));
+
+    let outcome = ScheduleSectionParser::new()
+        .parse_element_with_warnings(&document.root_element())
+        .unwrap();
+    assert!(outcome.diagnostics.is_none());
+}
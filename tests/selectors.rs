@@ -0,0 +1,69 @@
+use scraper::Html;
+use unipa_ex::common::selectors::compile;
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::portal::builder::PortalParserBuilder;
+use unipa_ex::parsers::portal::notification_detail::builder::NotificationDetailParserBuilder;
+use unipa_ex::utils::error::ParseError;
+
+#[test]
+fn unsupported_selector_is_reported_as_creation_failure() {
+    let err = compile("a:contains('ダウンロード')").unwrap_err();
+
+    assert!(matches!(err, ParseError::SelectorCreationFailed { .. }));
+}
+
+#[test]
+fn notification_detail_collects_download_links() {
+    let html = r#"
+    <html><body>
+      <h1>休講のお知らせ</h1>
+      <p>本日の講義は休講です。</p>
+      <a href="/files/download?id=1">資料.pdf</a>
+      <a href="/files/get?id=2">ダウンロード</a>
+      <a href="/top">トップへ</a>
+    </body></html>
+    "#;
+    let parser = NotificationDetailParserBuilder::new().build().unwrap();
+    let detail = parser.parse_document(&Html::parse_document(html)).unwrap();
+
+    assert_eq!(detail.title, "休講のお知らせ");
+    let names: Vec<_> = detail
+        .attachments
+        .iter()
+        .map(|a| a.file_name.as_str())
+        .collect();
+    assert_eq!(names, ["資料.pdf", "ダウンロード"]);
+}
+
+#[test]
+fn notification_sections_are_parsed_in_display_order() {
+    let html = r#"
+    <table id="form1:Poa00201A:htmlParentTable"><tr><td>
+      <table id="form1:Poa00201A:htmlParentTable:2:htmlDetailTbl">
+        <tr><td></td><td>補講のお知らせ</td><td>教務課</td><td>2025/04/02</td></tr>
+      </table>
+      <table id="form1:Poa00201A:htmlParentTable:0:htmlDetailTbl">
+        <tr><td></td><td>健康診断</td><td>保健室</td><td>2025/04/01</td></tr>
+      </table>
+    </td></tr></table>
+    "#;
+    let parser = PortalParserBuilder::new().build().unwrap();
+    let document = Html::parse_document(html);
+
+    // 2回目以降の解析でも同じセレクターを再利用して同じ結果になる
+    for _ in 0..2 {
+        let portal = parser.parse_document(&document).unwrap();
+        let sections: Vec<_> = portal
+            .notifications
+            .sections
+            .iter()
+            .map(|section| (section.section_id.as_str(), section.header_title.as_str()))
+            .collect();
+
+        assert_eq!(sections, [("0", "お知らせ"), ("2", "授業連絡")]);
+        assert_eq!(
+            portal.notifications.sections[1].entries[0].title.as_deref(),
+            Some("補講のお知らせ")
+        );
+    }
+}
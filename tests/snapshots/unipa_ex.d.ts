@@ -0,0 +1,2479 @@
+// このファイルは tests/typescript.rs が生成します。直接編集しないでください。
+
+/**
+ * パーサー共通の解析オプション
+ */
+export interface ParseOptions {
+    /**
+     * 厳密モード
+     *
+     * 有効な場合、通常は警告として記録される既定値の使用や期待した要素の欠落を
+     * エラー（`ElementNotFound`・`MissingRequiredField`など）として扱います。
+     */
+    strict_mode?: boolean;
+    /**
+     * デバッグモード
+     *
+     * 有効な場合、警告付きの解析結果に診断情報を含めます。
+     */
+    debug_mode?: boolean;
+    /**
+     * 時限表
+     *
+     * 授業の曜日・時限（`ClassTime`）の開始・終了時刻を
+     * 求めるために使います。省略時は1限 9:00〜10:30から始まる1コマ90分の時限表です。
+     */
+    period_table?: PeriodTable;
+}
+
+/**
+ * 警告付きの解析結果
+ *
+ * `warnings`が空でない場合、`data`の一部は既定値や推定値で補われています。
+ */
+export interface ParseOutcome<T> {
+    /**
+     * 解析されたデータ
+     */
+    data: T;
+    /**
+     * 解析中に発生した警告
+     */
+    warnings: ParseWarning[];
+    /**
+     * 診断モードで解析した場合のセレクターの試行記録
+     */
+    diagnostics?: ParseDiagnostics;
+}
+
+/**
+ * 解析中に発生した警告
+ */
+export type ParseWarning = { kind: "missing_section"; section: string; selector: string } | { kind: "unparseable_cell"; section: string; row: number; column: number; value: string } | { kind: "fallback_used"; field: string; fallback: string };
+
+/**
+ * 1回の解析で記録された診断情報
+ */
+export interface ParseDiagnostics {
+    /**
+     * 試行したセレクター（試行順）
+     */
+    selectors: SelectorTrace[];
+    /**
+     * 読み飛ばした行
+     */
+    skipped_rows: SkippedRow[];
+}
+
+/**
+ * セレクター1件分の試行結果
+ */
+export interface SelectorTrace {
+    /**
+     * 解析中のセクション名
+     */
+    section: string;
+    /**
+     * 試行したセレクター
+     */
+    selector: string;
+    /**
+     * 一致した要素数
+     */
+    match_count: number;
+    /**
+     * 最初に一致した要素に一致する候補（`a, b, c`形式のセレクターのうち実際に使われたもの）
+     *
+     * 一致する要素がない場合は空になります。
+     */
+    matched_alternatives: string[];
+}
+
+/**
+ * 解析対象から外した行
+ */
+export interface SkippedRow {
+    /**
+     * 解析中のセクション名
+     */
+    section: string;
+    /**
+     * 行番号（0始まり）
+     */
+    row: number;
+    /**
+     * 行に含まれていたセル数
+     */
+    cell_count: number;
+    /**
+     * 読み飛ばした理由
+     */
+    reason: string;
+}
+
+/**
+ * Primary error type for parsing operations
+ *
+ * Serialized as an object tagged by `code` with the variant's fields and the
+ * display `message`.
+ */
+export type ParseError = { message: string } & (
+    { code: "SELECTOR_CREATION_FAILED"; selector: string; context: string } |
+    { code: "ELEMENT_NOT_FOUND"; selector: string; context: string } |
+    { code: "ATTRIBUTE_EXTRACTION_FAILED"; attribute: string; context: string } |
+    { code: "DATA_EXTRACTION_FAILED"; data_type: string; reason: string } |
+    { code: "DATA_PARSING_FAILED"; data_type: string; value: string } |
+    { code: "INVALID_DATA_FORMAT"; data_type: string; details: string } |
+    { code: "HTML_PARSING_FAILED"; reason: string } |
+    { code: "INVALID_HTML_STRUCTURE"; expected: string } |
+    { code: "EMPTY_HTML_CONTENT" } |
+    { code: "FILE_NOT_FOUND"; path: string } |
+    { code: "FILE_PERMISSION_DENIED"; path: string } |
+    { code: "INVALID_FILE_ENCODING"; path: string } |
+    { code: "INVALID_SESSION_ID"; session_id: string } |
+    { code: "INVALID_URL"; url: string } |
+    { code: "REQUEST_TIMEOUT" } |
+    { code: "SERVER_ERROR"; status: string } |
+    { code: "MISSING_CONFIGURATION"; config_name: string } |
+    { code: "INVALID_CONFIGURATION"; config_name: string; value: string } |
+    { code: "VALUE_OUT_OF_RANGE"; field: string; value: string; range: string } |
+    { code: "NOT_IMPLEMENTED"; operation: string } |
+    { code: "OPERATION_CANCELLED"; operation: string } |
+    { code: "UNEXPECTED_ERROR"; context: string; reason: string } |
+    { code: "EMPTY_DATA_ENTRY"; data_type: string } |
+    { code: "MISSING_REQUIRED_FIELD"; field_name: string } |
+    { code: "FORM_DATA_EXTRACTION_FAILED"; reason: string } |
+    { code: "FORM_ELEMENT_NOT_FOUND"; form_selector: string } |
+    { code: "TABLE_DATA_EXTRACTION_FAILED"; reason: string } |
+    { code: "TABLE_ROW_PROCESSING_FAILED"; row_context: string } |
+    { code: "NAVIGATION_EXTRACTION_FAILED"; reason: string } |
+    { code: "PAGINATION_PROCESSING_FAILED"; reason: string }
+);
+
+/**
+ * 解析対象のページ種別
+ */
+export type PageKind = "portal" | "portal-all-notifications" | "portal-class-contact" | "portal-all-class-contact" | "notification-detail" | "grade-inquiry" | "graduation-prediction" | "promotion-prediction" | "license-prediction" | "syllabus-search-result" | "syllabus-view" | "assignment-list" | "classroom-reservation-status" | "questionnaire-list" | "student-info" | "test-answer-status";
+
+/**
+ * バージョン情報付きの解析結果
+ */
+export interface Envelope<T> {
+    /**
+     * ページ種別
+     */
+    kind: PageKind;
+    /**
+     * `data`の形を表すスキーマバージョン（ページ種別ごとに管理）
+     */
+    schema_version: number;
+    /**
+     * 解析したパーサーのクレートバージョン（エンベロープ導入前のデータを変換した場合は`null`）
+     */
+    parser_version: string | undefined;
+    /**
+     * 解析日時（UTCのRFC 3339形式、エンベロープ導入前のデータを変換した場合は`null`）
+     */
+    parsed_at: string | undefined;
+    /**
+     * 解析結果
+     */
+    data: T;
+}
+
+/**
+ * 日本標準時の日付（`YYYY-MM-DD`形式）
+ */
+export type JstDate = string;
+
+/**
+ * 日本標準時の日時（`YYYY-MM-DDTHH:MM:SS+09:00`形式）
+ */
+export type JstDateTime = string;
+
+/**
+ * 開始と終了からなる期間（両端を含む）
+ */
+export interface JstRange<T> {
+    /**
+     * 開始
+     */
+    start: T;
+    /**
+     * 終了
+     */
+    end: T;
+}
+
+/**
+ * 時刻（`HH:MM`形式）
+ */
+export type ClockTime = string;
+
+/**
+ * 開講年度と学期
+ *
+ * 年度・学期の順に並ぶため、ソートすると時系列順になります。
+ */
+export interface AcademicTerm {
+    /**
+     * 年度（西暦）
+     */
+    year: number;
+    /**
+     * 学期
+     */
+    term: Term;
+}
+
+/**
+ * 学期の種類
+ */
+export type Term = "FirstHalf" | "Spring" | "Q1" | "Q2" | "SecondHalf" | "Fall" | "Q3" | "Q4" | "FullYear" | "Intensive";
+
+/**
+ * 曜日
+ */
+export type Weekday = "Monday" | "Tuesday" | "Wednesday" | "Thursday" | "Friday" | "Saturday" | "Sunday";
+
+/**
+ * 1コマ分の曜日・時限と、その開始・終了時刻
+ */
+export interface ClassSlot {
+    /**
+     * 曜日（`1限`のように曜日が表記されていない場合は`None`）
+     */
+    weekday: Weekday | undefined;
+    /**
+     * 時限
+     */
+    period: number;
+    /**
+     * 開始時刻（時限表にない時限の場合は`None`）
+     */
+    start: ClockTime | undefined;
+    /**
+     * 終了時刻（時限表にない時限の場合は`None`）
+     */
+    end: ClockTime | undefined;
+}
+
+/**
+ * 授業の曜日・時限
+ */
+export interface ClassTime {
+    /**
+     * 集中講義など、週ごとの曜日・時限を持たない授業か
+     */
+    is_intensive: boolean;
+    /**
+     * 曜日・時限（連続する時限も1コマずつ、表記の順に並ぶ）
+     */
+    slots: ClassSlot[];
+}
+
+/**
+ * 時限ごとの開始・終了時刻
+ */
+export interface PeriodTime {
+    /**
+     * 時限
+     */
+    period: number;
+    /**
+     * 開始時刻
+     */
+    start: ClockTime;
+    /**
+     * 終了時刻
+     */
+    end: ClockTime;
+}
+
+/**
+ * 時限表（各時限の開始・終了時刻）
+ *
+ * 既定は1コマ90分の時限表（1限 9:00〜10:30、2限 10:40〜12:10、3限 13:00〜14:30、
+ * 4限 14:40〜16:10、5限 16:20〜17:50、6限 18:00〜19:30、7限 19:40〜21:10）です。
+ * 大学によって異なる場合は解析オプションの`period_table`で指定します。
+ */
+export interface PeriodTable {
+    /**
+     * 各時限の開始・終了時刻
+     */
+    periods: PeriodTime[];
+}
+
+/**
+ * ポータル画面全体の情報
+ */
+export interface Portal {
+    /**
+     * カレンダーコンポーネント
+     */
+    calendar: Calendar;
+    /**
+     * スケジュールコンポーネント
+     */
+    schedule: Schedule;
+    /**
+     * お気に入りリンクコンポーネント
+     */
+    favorite_links: FavoriteLinks;
+    /**
+     * お知らせコンポーネント
+     */
+    notifications: Notifications;
+}
+
+/**
+ * カレンダーコンポーネント
+ */
+export interface Calendar {
+    /**
+     * 前月ボタン
+     */
+    last_month_button: string;
+    /**
+     * 次月ボタン
+     */
+    next_month_button: string;
+    /**
+     * 本日ボタン
+     */
+    current_day_button: string;
+    /**
+     * 月間スケジュールボタン
+     */
+    month_schedule_button: string;
+    /**
+     * 年度・学期表示（例: \"2025春学期\"）
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * 現在日付（hidden）
+     */
+    current_date: JstDate | undefined;
+    /**
+     * 選択日付（hidden）
+     */
+    selected_day: string;
+    /**
+     * カレンダーの日付グリッド（週のリストで、各週が日のリストを含む）
+     */
+    days: CalendarDay[][];
+}
+
+/**
+ * カレンダーの1日分の情報
+ */
+export interface CalendarDay {
+    /**
+     * 日付の数値（`&nbsp;` の場合はNone）
+     */
+    day_number: number | undefined;
+    /**
+     * その日が今日かどうか（`todayColor` クラスがあるかで判断）
+     */
+    is_today: boolean;
+    /**
+     * その日のリンク先（JavaScript関数呼び出しなど、存在しない場合はNone）
+     */
+    link: string | undefined;
+    /**
+     * CSSクラス（todayColor, notTodayColor等）
+     */
+    css_classes: string[];
+    /**
+     * セルID（form1:Poa00101A:htmlCalendarTable:X:gridY）
+     */
+    cell_id: string | undefined;
+}
+
+/**
+ * スケジュールコンポーネント
+ */
+export interface Schedule {
+    /**
+     * タイトル
+     */
+    title: string;
+    /**
+     * 今日の時限割テーブルのエントリリスト
+     */
+    entries: ScheduleEntry[];
+    /**
+     * 選択授業コード（hidden）
+     */
+    selected_class_code: string;
+}
+
+/**
+ * スケジュールの1エントリ
+ */
+export interface ScheduleEntry {
+    /**
+     * 日付
+     */
+    date: string;
+    /**
+     * 日付・時限の表記から求めた曜日・時限と開始・終了時刻（時限の表記がない場合はNone）
+     */
+    class_time: ClassTime | undefined;
+    /**
+     * 授業内容
+     */
+    class_content: string;
+    /**
+     * 画像パス（区切り線など、存在しない場合はNone）
+     */
+    image_path: string | undefined;
+}
+
+/**
+ * お気に入りリンクコンポーネント
+ */
+export interface FavoriteLinks {
+    /**
+     * タイトル
+     */
+    title: string;
+    /**
+     * 編集ボタン
+     */
+    edit_button: string;
+    /**
+     * お気に入りリンクのリスト
+     */
+    links: FavoriteLink[];
+}
+
+/**
+ * お気に入りリンクの1項目
+ */
+export interface FavoriteLink {
+    /**
+     * リンク名
+     */
+    name: string;
+    /**
+     * リンクURL（hidden）
+     */
+    url: string;
+    /**
+     * リンクパラメータ（hidden）
+     */
+    params: string;
+    /**
+     * リンクメソッド（hidden）
+     */
+    method: string;
+}
+
+/**
+ * お知らせコンポーネント
+ */
+export interface Notifications {
+    /**
+     * お知らせセクションのリスト
+     */
+    sections: NotificationSection[];
+    /**
+     * 全て表示ボタン
+     */
+    all_info_button: string;
+}
+
+/**
+ * お知らせの1セクション
+ */
+export interface NotificationSection {
+    /**
+     * ヘッダータイトル
+     */
+    header_title: string;
+    /**
+     * 関連するコメント（存在しない場合はNone）
+     */
+    comment: string | undefined;
+    /**
+     * 通知エントリのリスト
+     */
+    entries: NotificationEntry[];
+    /**
+     * そのセクションの全件数
+     */
+    total_count: string;
+    /**
+     * セクション識別子
+     */
+    section_id: string;
+    /**
+     * 表示モード（\"summary\" | \"all\"）
+     */
+    display_mode: string;
+    /**
+     * 全て表示ボタンの有無
+     */
+    has_all_button: boolean;
+}
+
+/**
+ * お知らせの1エントリ
+ */
+export interface NotificationEntry {
+    /**
+     * 既読/未読アイコンの画像パス（存在しない場合はNone）
+     */
+    read_status_image: string | undefined;
+    /**
+     * 重要度を示すアイコンの画像パス（存在しない場合はNone）
+     */
+    important_status_image: string | undefined;
+    /**
+     * タイトル（存在しない場合はNone）
+     */
+    title: string | undefined;
+    /**
+     * 情報源（存在しない場合はNone）
+     */
+    source: string | undefined;
+    /**
+     * 掲載日（表記を解釈できない場合はNone）
+     */
+    insert_date: JstDate | undefined;
+}
+
+/**
+ * お知らせ詳細の情報
+ */
+export interface NotificationDetail {
+    /**
+     * タイトル
+     */
+    title: string;
+    /**
+     * 送信者
+     */
+    from: string;
+    /**
+     * メイン本文
+     */
+    main_text: string;
+    /**
+     * 添付ファイルリスト
+     */
+    attachments: AttachmentFile[];
+    /**
+     * 閉じるボタン
+     */
+    close_button: string;
+}
+
+/**
+ * 添付ファイル情報
+ */
+export interface AttachmentFile {
+    /**
+     * ファイル名
+     */
+    file_name: string;
+    /**
+     * ファイルサイズ
+     */
+    file_size: string;
+    /**
+     * ダウンロードボタンID
+     */
+    download_button_id: string;
+}
+
+/**
+ * 成績照会の全体情報
+ */
+export interface GradeInquiry {
+    /**
+     * 表示パターン
+     */
+    display_pattern: DisplayPattern;
+    /**
+     * 表示設定
+     */
+    display_settings: DisplaySettings;
+    /**
+     * 科目リスト
+     */
+    subjects: Subject[];
+    /**
+     * GPA得点（GPAの表示がなく、GPA対象の科目もない場合はNone）
+     */
+    gpa_score: number | undefined;
+    /**
+     * 年度・学期ごとのGPAと単位数（科目の年度・学期の出現順）
+     */
+    term_summaries: TermSummary[];
+    /**
+     * 単位取得状況サマリー
+     */
+    credit_summary: CreditSummary;
+}
+
+/**
+ * 表示パターン設定
+ */
+export interface DisplayPattern {
+    /**
+     * 表示タイプ
+     */
+    view_type: ViewType;
+}
+
+/**
+ * 表示タイプの種類
+ */
+export type ViewType = "Standard" | "BySemester";
+
+/**
+ * 表示設定の詳細
+ */
+export interface DisplaySettings {
+    /**
+     * 評価名称の表示可否
+     */
+    is_grade_label_visible: boolean;
+    /**
+     * 素点の表示可否
+     */
+    is_numeric_score_visible: boolean;
+    /**
+     * 出席率の表示可否
+     */
+    is_attendance_visible: boolean;
+    /**
+     * 不合格科目の表示可否
+     */
+    is_failed_subjects_visible: boolean;
+    /**
+     * 履修中科目の表示可否
+     */
+    is_current_subjects_visible: boolean;
+    /**
+     * GPAの表示可否
+     */
+    is_gpa_visible: boolean;
+    /**
+     * 単位修得状況の表示可否
+     */
+    is_credit_status_visible: boolean;
+}
+
+/**
+ * 科目情報
+ */
+export interface Subject {
+    /**
+     * 科目名
+     */
+    name: string;
+    /**
+     * 単位数
+     */
+    credit_count: number | undefined;
+    /**
+     * 成績評価
+     */
+    grade: Grade | undefined;
+    /**
+     * 素点
+     */
+    numeric_score: number | undefined;
+    /**
+     * 履修年度・学期（画面から読み取れない場合はNone）
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * 担当教員名
+     */
+    instructor_name: string;
+    /**
+     * 現在履修中かどうか
+     */
+    is_currently_enrolled: boolean;
+    /**
+     * 科目カテゴリ
+     */
+    category: SubjectCategory;
+}
+
+/**
+ * 年度・学期ごとの成績の集計
+ *
+ * 年度学期別表示で画面に表示された値を優先し、表示されていない値は科目の成績から計算します。
+ */
+export interface TermSummary {
+    /**
+     * 年度・学期
+     */
+    term: AcademicTerm;
+    /**
+     * 学期GPA（対象となる科目がない場合は`None`）
+     */
+    gpa: number | undefined;
+    /**
+     * 修得単位数
+     */
+    earned_credits: number;
+    /**
+     * 履修単位数
+     */
+    registered_credits: number;
+}
+
+/**
+ * 成績評価の種類
+ */
+export type Grade = "AA" | "A" | "B" | "C" | "D" | "Pass" | "Recognized" | "NoEvaluation";
+
+/**
+ * 科目カテゴリ情報
+ */
+export interface SubjectCategory {
+    /**
+     * カリキュラム名
+     */
+    curriculum_name: string;
+    /**
+     * 大分類名
+     */
+    major_category_name: string;
+    /**
+     * 中分類名
+     */
+    middle_category_name: string;
+    /**
+     * 小分類名
+     */
+    sub_category_name: string;
+    /**
+     * 必修・選択の区分
+     */
+    requirement_type: RequirementType;
+    /**
+     * 階層レベル
+     */
+    hierarchy_level: number;
+}
+
+/**
+ * 必修・選択の区分
+ */
+export type RequirementType = "Required" | "Elective";
+
+/**
+ * 単位取得状況のサマリー
+ */
+export interface CreditSummary {
+    /**
+     * 全体の単位状況
+     */
+    overall: CreditDetails;
+    /**
+     * 共通教育の単位状況
+     */
+    common_education: CreditDetails;
+    /**
+     * 専門教育の単位状況
+     */
+    specialized_education: CreditDetails;
+    /**
+     * カテゴリ別の単位状況
+     */
+    category_breakdown: CategoryCredits[];
+}
+
+/**
+ * 単位の詳細情報
+ */
+export interface CreditDetails {
+    /**
+     * 卒業要件単位数
+     * 単位状況の表に表示されていない場合（科目一覧から計算した場合を含む）は`None`になります。
+     */
+    required_for_graduation: number | undefined;
+    /**
+     * 修得済み単位数
+     */
+    completed_credits: number;
+    /**
+     * 履修中単位数
+     */
+    currently_enrolled_credits: number;
+    /**
+     * 合計単位数
+     */
+    total_credits: number;
+}
+
+/**
+ * カテゴリ別の単位情報
+ */
+export interface CategoryCredits {
+    /**
+     * カテゴリ名
+     */
+    category_name: string;
+    /**
+     * 単位詳細
+     */
+    credit_details: CreditDetails;
+}
+
+/**
+ * 評価ごとのグレードポイント
+ */
+export interface GradePoint {
+    /**
+     * 評価
+     */
+    grade: Grade;
+    /**
+     * グレードポイント
+     */
+    point: number;
+}
+
+/**
+ * 評価からグレードポイントへの対応表
+ *
+ * 対応表にない評価（合格・認定など）の科目はGPAの対象外です。
+ * 既定は秀=4、優=3、良=2、可=1、不可=0です。
+ */
+export interface GradeScale {
+    /**
+     * 評価ごとのグレードポイント
+     */
+    points: GradePoint[];
+}
+
+/**
+ * 素点からグレードポイントへの変換式（f-GPA）
+ *
+ * 合格点以上の素点は`(素点 - base_score) / step`、合格点未満は0になります。
+ * 既定は合格点60点、`(素点 - 55) / 10`（100点で4.5、60点で0.5）です。
+ */
+export interface NumericScale {
+    /**
+     * 合格点
+     */
+    passing_score?: number;
+    /**
+     * 基準点
+     */
+    base_score?: number;
+    /**
+     * グレードポイント1あたりの点数
+     */
+    step?: number;
+    /**
+     * グレードポイントの上限
+     */
+    max_point?: number | undefined;
+}
+
+/**
+ * グレードポイントの求め方
+ */
+export type GpaMethod = "Grade" | "NumericScore";
+
+/**
+ * GPAの計算規則
+ *
+ * 省略した項目は既定値になります。既定では合格・認定・履修中の科目をGPAに含めず、
+ * 不可の科目はGP 0として含めます。
+ */
+export interface GpaRules {
+    /**
+     * グレードポイントの求め方
+     */
+    method?: GpaMethod;
+    /**
+     * 評価からグレードポイントへの対応表
+     */
+    grade_scale?: GradeScale;
+    /**
+     * 素点からグレードポイントへの変換式
+     */
+    numeric_scale?: NumericScale;
+    /**
+     * 合格の科目をGPAに含めない
+     */
+    exclude_pass?: boolean;
+    /**
+     * 認定の科目をGPAに含めない
+     */
+    exclude_recognized?: boolean;
+    /**
+     * 履修中の科目をGPAに含めない
+     */
+    exclude_currently_enrolled?: boolean;
+    /**
+     * 不可の科目をGPAに含めない
+     */
+    exclude_failed?: boolean;
+}
+
+/**
+ * GPAに含めなかった理由
+ */
+export type GpaExclusion = "Pass" | "Recognized" | "CurrentlyEnrolled" | "Failed" | "NoGradePoint" | "NoCredits";
+
+/**
+ * 1科目のGPAへの寄与
+ */
+export interface SubjectGpa {
+    /**
+     * 科目名
+     */
+    name: string;
+    /**
+     * 履修年度・学期（不明な場合は`None`）
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * 単位数
+     */
+    credit_count: number | undefined;
+    /**
+     * 成績評価
+     */
+    grade: Grade | undefined;
+    /**
+     * 素点
+     */
+    numeric_score: number | undefined;
+    /**
+     * グレードポイント
+     */
+    grade_point: number | undefined;
+    /**
+     * グレードポイントを求めた方法
+     */
+    basis: GpaMethod | undefined;
+    /**
+     * GPAの分子に加えた値（GP × 単位数、対象外の場合は0）
+     */
+    quality_points: number;
+    /**
+     * GPAに含めなかった理由（含めた場合は`None`）
+     */
+    excluded: GpaExclusion | undefined;
+}
+
+/**
+ * 学期ごと、または通算のGPA
+ */
+export interface GpaSummary {
+    /**
+     * 対象の年度・学期（通算の場合は`None`）
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * GPA（GPA対象の科目がない場合は`None`）
+     */
+    gpa: number | undefined;
+    /**
+     * GPA対象の単位数の合計
+     */
+    gpa_credits: number;
+    /**
+     * GP × 単位数の合計
+     */
+    quality_points: number;
+}
+
+/**
+ * GPAの計算結果
+ */
+export interface GpaReport {
+    /**
+     * 通算GPA
+     */
+    cumulative: GpaSummary;
+    /**
+     * 学期ごとのGPA（年度・学期の順、年度・学期が不明な科目は通算のみに含める）
+     */
+    terms: GpaSummary[];
+    /**
+     * 科目ごとの内訳（成績照会と同じ順）
+     */
+    subjects: SubjectGpa[];
+}
+
+/**
+ * 卒業見込判定の結果全体を表す構造体。
+ *
+ * 判定結果とメッセージ、卒業要件の階層、複数の要件不足項目リストを含みます。
+ */
+export interface SotsugyoMikonHanteiKekka {
+    /**
+     * 判定結果。
+     * 判定結果欄・判定メッセージから読み取れない場合は`None`になります。
+     */
+    hantei_status: HanteiStatus | undefined;
+    /**
+     * 卒業見込判定のメッセージ。
+     */
+    hantei_message: string;
+    /**
+     * 卒業要件の階層（最上位の要件のリスト）。
+     */
+    yoken_tree: YokenNode[];
+    /**
+     * 要件不足項目のリスト。
+     * 不足がない場合は空のベクターとなります。
+     */
+    fusoku_items: YokenFusokuItem[];
+}
+
+/**
+ * 卒業見込判定における要件不足項目を表す構造体。
+ *
+ * 各フィールドは、要件の具体的な不足内容と、その不足量を明確に示します。
+ */
+export interface YokenFusokuItem {
+    /**
+     * 要件のコード。
+     */
+    joken_code: string;
+    /**
+     * 要件を特定するための要素番号。
+     */
+    yoso_number: number;
+    /**
+     * 要件不足の具体的なメッセージ。
+     */
+    fusoku_message: string;
+    /**
+     * 要件の不足量。
+     * 「4単位」「0.5単位」「2科目」のような表記を解釈できない場合は`None`になります。
+     */
+    fusoku_ryo: FusokuRyo | undefined;
+}
+
+/**
+ * 要件の不足量を表す構造体。
+ */
+export interface FusokuRyo {
+    /**
+     * 不足している量（0.5単位のような端数を含む）。
+     */
+    amount: number;
+    /**
+     * 量の単位。
+     */
+    unit: FusokuUnit;
+}
+
+/**
+ * 不足量の単位。
+ */
+export type FusokuUnit = "Credits" | "Subjects";
+
+/**
+ * 卒業見込判定の判定結果。
+ */
+export type HanteiStatus = "MikomiAri" | "MikomiNashi" | "TaishoGai";
+
+/**
+ * 卒業要件の階層の1つの要件を表す構造体。
+ *
+ * 要件一覧の字下げ（またはクラス名の`level2`などの番号）から親子関係を組み立てます。
+ */
+export interface YokenNode {
+    /**
+     * 要件名（科目区分名）。
+     */
+    yoken_name: string;
+    /**
+     * 必要単位数。
+     * 単位数の定めがない要件（科目の指定のみなど）は`None`になります。
+     */
+    required_credits: number | undefined;
+    /**
+     * 修得済み単位数。
+     * 修得単位数の列がない場合や、値を解釈できない場合は`None`になります。
+     */
+    completed_credits: number | undefined;
+    /**
+     * 履修中単位数。
+     * 履修中単位数の列がない場合や、値を解釈できない場合は`None`になります。
+     */
+    currently_enrolled_credits: number | undefined;
+    /**
+     * 要件を満たしているかどうか。
+     * 判定欄の「○」「×」を優先し、判定欄がない場合は修得済み単位数と必要単位数から求めます。
+     */
+    is_satisfied: boolean | undefined;
+    /**
+     * 下位の要件。
+     */
+    children: YokenNode[];
+}
+
+/**
+ * 進級見込判定データを表す構造体。
+ * この構造体は、HTMLフォームの隠しフィールドと表示メッセージから抽出された情報を保持します。
+ */
+export interface PromotionPredictionData {
+    /**
+     * フォームに表示される進級見込判定メッセージ。
+     */
+    judgement_message: string;
+    /**
+     * 最後に検索された学生コード。
+     * 学生を特定するための隠しフィールドとして使用されます。
+     */
+    last_search_student_id: string;
+    /**
+     * 処理対象の年度・学期。
+     * フォームの隠しフィールド（`academicYear`・`semester`）から組み立てます。
+     * いずれかが見つからないか解釈できない場合は`None`になります。
+     */
+    term: AcademicTerm | undefined;
+}
+
+/**
+ * 免許取得見込判定データを表す構造体。
+ *
+ * 注意: この構造体は現在プレースホルダ実装です。
+ * 実際の要件に応じて構造を変更する必要があります。
+ */
+export interface LicensePredictionData {
+    /**
+     * 免許の種類
+     */
+    license_type: string;
+    /**
+     * 取得見込判定結果
+     */
+    prediction_result: string;
+    /**
+     * 不足要件の詳細
+     */
+    missing_requirements: string[];
+}
+
+/**
+ * シラバス検索フォームの構造体
+ *
+ * UNIVERSAL PASSPORT EXのシラバス検索で使用可能な全ての検索条件を含みます。
+ * すべてのフィールドはオプショナルで、指定されない場合は検索条件から除外されます。
+ */
+export interface SyllabusSearchForm {
+    /**
+     * 管理部署名
+     */
+    kanri_bsyo_name: string | undefined;
+    /**
+     * 年度
+     */
+    nendo: number | undefined;
+    /**
+     * 学期
+     */
+    gakki_no: string | undefined;
+    /**
+     * 科目区分
+     */
+    kamok_jugyo: string | undefined;
+    /**
+     * 科目名
+     */
+    kamok_name: string | undefined;
+    /**
+     * 担当教員
+     */
+    kyoin_simei: string | undefined;
+    /**
+     * 学科・コース／専攻
+     */
+    gakka: string | undefined;
+    /**
+     * 学年
+     */
+    gakunen: string | undefined;
+    /**
+     * 曜日
+     */
+    yobi: string | undefined;
+    /**
+     * 時限
+     */
+    jigen: string | undefined;
+    /**
+     * 集中講義など
+     */
+    syutyu: boolean | undefined;
+    /**
+     * キーワード
+     */
+    keyword: string | undefined;
+    /**
+     * 識別区分
+     */
+    shikibetsu_kbn: number | undefined;
+    /**
+     * 管理番号
+     */
+    kanri_no: number | undefined;
+}
+
+/**
+ * シラバス検索結果のページ全体を表現する構造体
+ */
+export interface SyllabusSearchResultPage {
+    /**
+     * 検索条件の表示情報
+     */
+    search_conditions: SearchConditions;
+    /**
+     * 検索結果のメタ情報
+     */
+    result_metadata: ResultMetadata;
+    /**
+     * 検索結果の科目一覧
+     */
+    course_entries: CourseEntry[];
+    /**
+     * ページネーション情報
+     */
+    pagination: PaginationInfo;
+    /**
+     * フォーム情報（戻るボタンや隠しフィールド用）
+     */
+    form_info: FormInfo;
+}
+
+/**
+ * 検索条件の表示情報
+ */
+export interface SearchConditions {
+    /**
+     * 開講年度／学期（解釈できない場合は`None`）
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * 科目名
+     */
+    subject_name: string | undefined;
+    /**
+     * 学科・コース／専攻
+     */
+    department_course: string | undefined;
+}
+
+/**
+ * 検索結果のメタ情報
+ */
+export interface ResultMetadata {
+    /**
+     * 総件数
+     */
+    total_count: number;
+    /**
+     * 現在のページ番号（1から開始）
+     */
+    current_page: number;
+    /**
+     * 総ページ数
+     */
+    total_pages: number;
+}
+
+/**
+ * 個別の科目エントリ
+ */
+export interface CourseEntry {
+    /**
+     * 開講曜日
+     */
+    schedule_day_period: string;
+    /**
+     * 開講曜日から求めた曜日・時限と開始・終了時刻（空欄または解釈できない場合はNone）
+     */
+    class_time: ClassTime | undefined;
+    /**
+     * 科目コードと科目名
+     */
+    course_code_and_name: string;
+    /**
+     * 教員氏名
+     */
+    instructor_names: string;
+    /**
+     * 開講区分
+     */
+    course_type: string;
+    /**
+     * 学年
+     */
+    target_grade: string | undefined;
+    /**
+     * 開講年度・学期（年度は検索条件の開講年度）
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * 単位数
+     */
+    credits: string;
+    /**
+     * シラバス詳細へのリンク情報
+     */
+    syllabus_link: SyllabusLinkInfo;
+}
+
+/**
+ * シラバス詳細へのリンク情報
+ */
+export interface SyllabusLinkInfo {
+    /**
+     * リンクのID（例：「form1:htmlKekkatable:0:edit」）
+     */
+    link_id: string;
+    /**
+     * JavaScriptのonclick属性値
+     */
+    onclick_action: string;
+    /**
+     * リンクが有効かどうか
+     */
+    is_active: boolean;
+}
+
+/**
+ * ページネーション情報
+ */
+export interface PaginationInfo {
+    /**
+     * 先頭ページへのボタン状態
+     */
+    first_button_enabled: boolean;
+    /**
+     * 前ページへのボタン状態
+     */
+    previous_button_enabled: boolean;
+    /**
+     * 次ページへのボタン状態
+     */
+    next_button_enabled: boolean;
+    /**
+     * 最終ページへのボタン状態
+     */
+    last_button_enabled: boolean;
+    /**
+     * ページ表示テキスト（例：「1/1 ページ」）
+     */
+    page_display_text: string;
+    /**
+     * 現在のページ番号（強調表示用）
+     */
+    current_page_number: number;
+}
+
+/**
+ * フォーム情報（戻るボタンや隠しフィールド用）
+ */
+export interface FormInfo {
+    /**
+     * フォームのaction属性値
+     */
+    form_action: string;
+    /**
+     * フォームのmethod属性値
+     */
+    form_method: string;
+    /**
+     * フォームのenctype属性値
+     */
+    form_enctype: string;
+    /**
+     * 隠しフィールドの値
+     */
+    hidden_fields: HiddenField[];
+}
+
+/**
+ * 隠しフィールド情報
+ */
+export interface HiddenField {
+    /**
+     * フィールド名
+     */
+    field_name: string;
+    /**
+     * フィールド値
+     */
+    field_value: string;
+}
+
+/**
+ * シラバス詳細情報
+ */
+export interface LessonInfo {
+    /**
+     * 授業コード
+     */
+    lesson_code: number;
+    /**
+     * オムニバス
+     */
+    omnibus: string | undefined;
+    /**
+     * 科目名
+     */
+    subject_name: string;
+    /**
+     * 配当学年
+     */
+    assigned_grade: number;
+    /**
+     * 単位数
+     */
+    credits: number;
+    /**
+     * 年度学期（「2025年度 夏期」のように解釈できない場合はNone）
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * 曜日時限
+     */
+    day_period: string;
+    /**
+     * 曜日時限から求めた曜日・時限と開始・終了時刻（解釈できない場合はNone）
+     */
+    class_time: ClassTime | undefined;
+    /**
+     * 対象学科
+     */
+    target_department: string;
+    /**
+     * コース
+     */
+    course: string | undefined;
+    /**
+     * 科目区分
+     */
+    subject_category: string;
+    /**
+     * 必選の別
+     */
+    required_elective_distinction: string;
+    /**
+     * 担当者
+     */
+    instructor: string;
+    /**
+     * 教室
+     */
+    classroom: string | undefined;
+    /**
+     * 実務家教員担当授業
+     */
+    industry_professional_led_class: string | undefined;
+    /**
+     * 授業の目的と進め方
+     */
+    class_objectives_and_approach: string | undefined;
+    /**
+     * 達成目標１
+     */
+    achievement_goal_1: string | undefined;
+    /**
+     * 達成目標２
+     */
+    achievement_goal_2: string | undefined;
+    /**
+     * 達成目標３
+     */
+    achievement_goal_3: string | undefined;
+    /**
+     * 達成目標４
+     */
+    achievement_goal_4: string | undefined;
+    /**
+     * 達成目標５
+     */
+    achievement_goal_5: string | undefined;
+    /**
+     * 達成目標６
+     */
+    achievement_goal_6: string | undefined;
+    /**
+     * 達成目標７
+     */
+    achievement_goal_7: string | undefined;
+    /**
+     * アクティブラーニング
+     */
+    active_learning: ActiveLearningInfo;
+    /**
+     * 授業計画詳細
+     */
+    lesson_plan_details: LessonPlanItem[];
+    /**
+     * 課題等に対するフィードバック
+     */
+    feedback_on_assignments: string | undefined;
+    /**
+     * 評価方法と基準
+     */
+    evaluation_methods_and_criteria: string | undefined;
+    /**
+     * テキスト
+     */
+    textbook: string | undefined;
+    /**
+     * 参考図書
+     */
+    reference_books: string | undefined;
+    /**
+     * 科目の位置づけ（学習・教育目標との対応）
+     */
+    subject_positioning: string | undefined;
+    /**
+     * 履修登録前の準備
+     */
+    preparation_before_registration: string | undefined;
+}
+
+/**
+ * アクティブラーニング情報
+ */
+export interface ActiveLearningInfo {
+    /**
+     * ディスカッション
+     */
+    discussion: boolean;
+    /**
+     * ディベート
+     */
+    debate: boolean;
+    /**
+     * グループワーク
+     */
+    group_work: boolean;
+    /**
+     * プレゼンテーション
+     */
+    presentation: boolean;
+    /**
+     * 実習
+     */
+    practical_training: boolean;
+    /**
+     * フィールドワーク
+     */
+    field_work: boolean;
+    /**
+     * その他課題解決型学習
+     */
+    other_problem_solving_learning: boolean;
+}
+
+/**
+ * 授業計画項目
+ */
+export interface LessonPlanItem {
+    /**
+     * 第X回 (例: 第１回)
+     */
+    session_number: string;
+    /**
+     * 授業計画
+     */
+    lesson_plan: string;
+    /**
+     * 授業時間外課題（予習および復習を含む）
+     */
+    outside_class_tasks: string;
+}
+
+export interface StudentTimetable {}
+
+/**
+ * 教室予約状況ページ全体から抽出されるデータを格納するメイン構造体
+ */
+export interface ClassroomReservationStatus {
+    /**
+     * ページ上部の検索条件
+     */
+    search_params: SearchParams;
+    /**
+     * 教室予約状況表示テーブルのデータ
+     */
+    reservation_table_data: ClassroomReservationTableData[];
+    /**
+     * 予約詳細ポップアップデータ（存在する場合）
+     */
+    popup_reservation_detail: PopupReservationDetail | undefined;
+    /**
+     * 授業詳細ポップアップデータ（存在する場合）
+     */
+    popup_class_detail: PopupClassDetail | undefined;
+    /**
+     * 重複授業詳細ポップアップデータ（存在する場合）
+     */
+    popup_duplicate_class_detail: PopupDuplicateClassDetail | undefined;
+    /**
+     * 教室詳細ポップアップデータ（存在する場合）
+     */
+    popup_classroom_detail: PopupClassroomDetail | undefined;
+}
+
+/**
+ * ページ上部の検索条件を格納する構造体
+ */
+export interface SearchParams {
+    /**
+     * 検索対象の年度・学期（年度と学期の両方が指定されている場合）
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * 検索対象曜日
+     */
+    day_of_week: string;
+    /**
+     * 検索対象時限
+     */
+    period: string;
+    /**
+     * 検索対象棟
+     */
+    building: string;
+    /**
+     * 検索対象教室
+     */
+    classroom: string;
+    /**
+     * 検索対象科目名
+     */
+    subject_name: string;
+    /**
+     * 検索対象担当教員名
+     */
+    instructor_name: string;
+}
+
+/**
+ * 教室予約状況表示テーブルの各行のデータを格納する構造体
+ */
+export interface ClassroomReservationTableData {
+    /**
+     * 時限
+     */
+    period: string;
+    /**
+     * 時限の開始・終了時刻（曜日は各列で表すため`None`）
+     */
+    class_time: ClassTime | undefined;
+    /**
+     * 月曜日の予約状況
+     */
+    monday: ClassroomReservationCell;
+    /**
+     * 火曜日の予約状況
+     */
+    tuesday: ClassroomReservationCell;
+    /**
+     * 水曜日の予約状況
+     */
+    wednesday: ClassroomReservationCell;
+    /**
+     * 木曜日の予約状況
+     */
+    thursday: ClassroomReservationCell;
+    /**
+     * 金曜日の予約状況
+     */
+    friday: ClassroomReservationCell;
+    /**
+     * 土曜日の予約状況
+     */
+    saturday: ClassroomReservationCell;
+    /**
+     * 日曜日の予約状況
+     */
+    sunday: ClassroomReservationCell;
+}
+
+/**
+ * 教室予約状況テーブルの各セル（曜日×時限）の情報
+ */
+export interface ClassroomReservationCell {
+    /**
+     * 予約状況のタイプ
+     */
+    reservation_type: ReservationType;
+    /**
+     * 科目名（予約がある場合）
+     */
+    subject_name: string | undefined;
+    /**
+     * 担当教員名（予約がある場合）
+     */
+    instructor_name: string | undefined;
+    /**
+     * 教室名
+     */
+    classroom_name: string | undefined;
+    /**
+     * 詳細情報へのリンクURL（存在する場合）
+     */
+    detail_link_url: string | undefined;
+    /**
+     * セルの背景色やスタイル情報
+     */
+    cell_style: string | undefined;
+}
+
+/**
+ * 予約状況のタイプを表現する列挙型
+ */
+export type ReservationType = "Available" | "RegularClass" | "IntensiveCourse" | "Other" | "Duplicate" | "Unavailable";
+
+/**
+ * 予約詳細ポップアップから抽出されるデータを格納する構造体
+ */
+export interface PopupReservationDetail {
+    /**
+     * 予約ID
+     */
+    reservation_id: string;
+    /**
+     * 科目名
+     */
+    subject_name: string;
+    /**
+     * 科目コード
+     */
+    subject_code: string;
+    /**
+     * 担当教員名
+     */
+    instructor_name: string;
+    /**
+     * 教室名
+     */
+    classroom_name: string;
+    /**
+     * 開始日時
+     */
+    start_datetime: JstDateTime | undefined;
+    /**
+     * 終了日時
+     */
+    end_datetime: JstDateTime | undefined;
+    /**
+     * 履修者数
+     */
+    enrollment_count: number | undefined;
+    /**
+     * 備考
+     */
+    notes: string | undefined;
+}
+
+/**
+ * 授業詳細ポップアップから抽出されるデータを格納する構造体
+ */
+export interface PopupClassDetail {
+    /**
+     * 授業ID
+     */
+    class_id: string;
+    /**
+     * 科目名
+     */
+    subject_name: string;
+    /**
+     * 科目コード
+     */
+    subject_code: string;
+    /**
+     * 担当教員名
+     */
+    instructor_name: string;
+    /**
+     * 単位数
+     */
+    credit_count: number;
+    /**
+     * 履修者数
+     */
+    enrollment_count: number;
+    /**
+     * 開講年度・学期
+     */
+    term: AcademicTerm | undefined;
+    /**
+     * 曜日・時限
+     */
+    schedule: string;
+    /**
+     * 曜日・時限から求めた開始・終了時刻（解釈できない場合はNone）
+     */
+    class_time: ClassTime | undefined;
+    /**
+     * 教室名
+     */
+    classroom_name: string;
+    /**
+     * 授業概要
+     */
+    course_description: string | undefined;
+}
+
+/**
+ * 重複授業詳細ポップアップから抽出されるデータを格納する構造体
+ */
+export interface PopupDuplicateClassDetail {
+    /**
+     * 重複している授業のリスト
+     */
+    conflicting_classes: ConflictingClass[];
+    /**
+     * 重複の詳細説明
+     */
+    conflict_description: string | undefined;
+    /**
+     * 重複解決の提案
+     */
+    resolution_suggestion: string | undefined;
+}
+
+/**
+ * 重複している個別の授業情報
+ */
+export interface ConflictingClass {
+    /**
+     * 授業ID
+     */
+    class_id: string;
+    /**
+     * 科目名
+     */
+    subject_name: string;
+    /**
+     * 担当教員名
+     */
+    instructor_name: string;
+    /**
+     * 履修者数
+     */
+    enrollment_count: number;
+    /**
+     * 優先度
+     */
+    priority: string | undefined;
+}
+
+/**
+ * 教室詳細ポップアップから抽出されるデータを格納する構造体
+ */
+export interface PopupClassroomDetail {
+    /**
+     * 教室ID
+     */
+    classroom_id: string;
+    /**
+     * 教室名
+     */
+    classroom_name: string;
+    /**
+     * 棟名
+     */
+    building_name: string;
+    /**
+     * フロア
+     */
+    floor: string;
+    /**
+     * 収容人数
+     */
+    capacity: number;
+    /**
+     * 設備情報
+     */
+    equipment: string[];
+    /**
+     * 利用可能時間帯
+     */
+    available_hours: string;
+    /**
+     * 備考
+     */
+    notes: string | undefined;
+}
+
+/**
+ * アンケート一覧の全体情報
+ */
+export interface QuestionnaireList {
+    /**
+     * アンケート項目のリスト
+     */
+    questionnaires: QuestionnaireItem[];
+    /**
+     * ページネーション情報（ある場合）
+     */
+    pagination: PaginationInfo | undefined;
+}
+
+/**
+ * 個別のアンケート項目
+ */
+export interface QuestionnaireItem {
+    /**
+     * アンケートタイトル
+     */
+    title: string;
+    /**
+     * 対象科目名（ある場合）
+     */
+    subject_name: string | undefined;
+    /**
+     * 担当教員名（ある場合）
+     */
+    instructor_name: string | undefined;
+    /**
+     * 回答期限（表記を解釈できない場合はNone）
+     */
+    deadline: JstDate | undefined;
+    /**
+     * 回答状況
+     */
+    response_status: ResponseStatus;
+    /**
+     * アンケート詳細へのリンク情報
+     */
+    questionnaire_link: QuestionnaireLink;
+}
+
+/**
+ * 回答状況の種類
+ */
+export type ResponseStatus = "NotAnswered" | "Answered" | "Expired";
+
+/**
+ * アンケート詳細へのリンク情報
+ */
+export interface QuestionnaireLink {
+    /**
+     * リンクのURL
+     */
+    url: string;
+    /**
+     * リンクパラメータ
+     */
+    parameters: LinkParameter[];
+    /**
+     * リンクが有効かどうか
+     */
+    is_active: boolean;
+}
+
+/**
+ * リンクパラメータ
+ */
+export interface LinkParameter {
+    /**
+     * パラメータ名
+     */
+    name: string;
+    /**
+     * パラメータ値
+     */
+    value: string;
+}
+
+/**
+ * ページネーション情報
+ */
+export interface PaginationInfo {
+    /**
+     * 現在のページ番号
+     */
+    current_page: number;
+    /**
+     * 総ページ数
+     */
+    total_pages: number;
+    /**
+     * 総件数
+     */
+    total_count: number;
+    /**
+     * 次ページへのリンク（ある場合）
+     */
+    next_page_link: string | undefined;
+    /**
+     * 前ページへのリンク（ある場合）
+     */
+    previous_page_link: string | undefined;
+}
+
+/**
+ * 学生情報全体を表現する構造体
+ */
+export interface StudentInfo {
+    /**
+     * 基本情報セクション
+     */
+    basic_info: BasicInfo;
+    /**
+     * 所属情報セクション
+     */
+    affiliation_info: AffiliationInfo;
+    /**
+     * 指導教員情報セクション
+     */
+    advisor_info: AdvisorInfo;
+    /**
+     * 学籍変更情報セクション
+     */
+    status_change_info: StatusChangeInfo;
+}
+
+/**
+ * 学生の基本情報を表現する構造体
+ */
+export interface BasicInfo {
+    /**
+     * 学籍番号
+     */
+    student_id: string;
+    /**
+     * 学生氏名
+     */
+    student_name: string;
+    /**
+     * 学生氏名（カタカナ）
+     */
+    kana_name: string;
+    /**
+     * 性別
+     */
+    gender: string;
+    /**
+     * 生年月日（例：「2003年01月10日」→「2003-01-10」）
+     */
+    date_of_birth: JstDate | undefined;
+    /**
+     * 国籍（記載がない場合はNone）
+     */
+    nationality: string | undefined;
+    /**
+     * PCメールアドレス（記載がない場合はNone）
+     */
+    pc_email_address: string | undefined;
+    /**
+     * 入学区分
+     */
+    enrollment_type: string;
+    /**
+     * 学生身分種別
+     */
+    student_status_type: string;
+    /**
+     * 入学年度
+     */
+    enrollment_year: number;
+    /**
+     * 入学期番号
+     */
+    enrollment_term_no: number;
+    /**
+     * カリキュラム対象年度
+     */
+    curriculum_target_year: number;
+    /**
+     * カリキュラム対象期
+     */
+    curriculum_target_term: number;
+    /**
+     * 入学年月日（例：「2021年04月01日」→「2021-04-01」）
+     */
+    enrollment_date: JstDate | undefined;
+    /**
+     * 退学年月日（記載がない場合はNone）
+     */
+    withdrawal_date: JstDate | undefined;
+    /**
+     * 卒業予定年月（例：「2026年03月」→「2026-03」）
+     */
+    expected_graduation_month_year: string;
+    /**
+     * 修了年月日（記載がない場合はNone）
+     */
+    completion_date: JstDate | undefined;
+}
+
+/**
+ * 学生の所属情報を表現する構造体
+ */
+export interface AffiliationInfo {
+    /**
+     * 所属学部・組織
+     */
+    affiliated_department_organization: string;
+    /**
+     * カリキュラム学部・組織
+     */
+    curriculum_department_organization: string;
+    /**
+     * 学年
+     */
+    grade_level: number;
+    /**
+     * セメスター
+     */
+    semester: number;
+    /**
+     * 主専攻コース（記載がない場合はNone）
+     */
+    major_course: string | undefined;
+    /**
+     * クラス種別＋クラス（例：「2クラス割\u{3000}A<BR>3クラス割\u{3000}Ⅰ<BR>」→複数行データ）
+     */
+    class_type_class: string;
+}
+
+/**
+ * 学生の指導教員情報を表現する構造体
+ */
+export interface AdvisorInfo {
+    /**
+     * 指導教員氏名
+     */
+    advisor_name: string;
+    /**
+     * 指導開始日（例：「2025年04月01日」→「2025-04-01」）
+     */
+    advisor_start_date: JstDate | undefined;
+    /**
+     * 指導終了日（例：「2026年03月31日」→「2026-03-31」）
+     */
+    advisor_end_date: JstDate | undefined;
+}
+
+/**
+ * 学生の学籍変更情報を表現する構造体
+ */
+export interface StatusChangeInfo {
+    /**
+     * 学籍変更履歴のリスト（例：「セメスター進行（2025年04月01日）」→「Semester progression (2025-04-01)」）
+     */
+    academic_status_history: string[];
+}
+
+/**
+ * 課題提出一覧全体を表現する構造体
+ */
+export interface AssignmentList {
+    /**
+     * 課題一覧
+     */
+    assignments: Assignment[];
+    /**
+     * ページネーション情報
+     */
+    pagination: PaginationInfo | undefined;
+}
+
+/**
+ * 個別の課題情報を表現する構造体
+ */
+export interface Assignment {
+    /**
+     * 科目名
+     */
+    subject_name: string;
+    /**
+     * 課題タイトル
+     */
+    assignment_title: string;
+    /**
+     * 提出期限（表記を解釈できない場合はNone）
+     */
+    due_date: JstDateTime | undefined;
+    /**
+     * 提出状況
+     */
+    submission_status: SubmissionStatus;
+    /**
+     * 課題の詳細説明
+     */
+    description: string | undefined;
+    /**
+     * ファイル添付の有無
+     */
+    has_attachment: boolean;
+    /**
+     * 提出済みファイル名（提出済みの場合）
+     */
+    submitted_file_name: string | undefined;
+    /**
+     * 提出日時（提出済みの場合）
+     */
+    submission_date: JstDateTime | undefined;
+    /**
+     * 教員からのコメント（評価済みの場合）
+     */
+    teacher_comment: string | undefined;
+    /**
+     * 評価点（評価済みの場合）
+     */
+    score: number | undefined;
+}
+
+/**
+ * 提出状況を表現する列挙型
+ */
+export type SubmissionStatus = "NotSubmitted" | "Submitted" | "Overdue" | "Evaluated";
+
+/**
+ * ページネーション情報
+ */
+export interface PaginationInfo {
+    /**
+     * 現在のページ番号
+     */
+    current_page: number;
+    /**
+     * 総ページ数
+     */
+    total_pages: number;
+    /**
+     * 前のページがあるか
+     */
+    has_previous: boolean;
+    /**
+     * 次のページがあるか
+     */
+    has_next: boolean;
+}
+
+/**
+ * テスト解答状況の全体情報
+ */
+export interface TestAnswerStatus {
+    /**
+     * 年度
+     */
+    academic_year: string;
+    /**
+     * タブ情報（未実施、実施中、実施済の件数）
+     */
+    tab_info: TabInfo;
+    /**
+     * 現在表示中のタブ
+     */
+    current_tab: TestStatus;
+    /**
+     * テスト一覧
+     */
+    tests: TestItem[];
+}
+
+/**
+ * タブ情報（各状態の件数）
+ */
+export interface TabInfo {
+    /**
+     * 未実施のテスト件数
+     */
+    not_implemented_count: number;
+    /**
+     * 実施中のテスト件数
+     */
+    in_progress_count: number;
+    /**
+     * 実施済のテスト件数
+     */
+    completed_count: number;
+}
+
+/**
+ * テストの実施状況
+ */
+export type TestStatus = "NotImplemented" | "InProgress" | "Completed";
+
+/**
+ * テスト項目の情報
+ */
+export interface TestItem {
+    /**
+     * テストタイトル
+     */
+    title: string;
+    /**
+     * 授業名
+     */
+    course_name: string;
+    /**
+     * 状態固有の詳細情報
+     */
+    details: TestDetails;
+}
+
+/**
+ * テストの詳細情報（状態によって異なる）
+ */
+export type TestDetails = { NotImplemented: NotImplementedDetails } | { InProgress: InProgressDetails } | { Completed: CompletedDetails };
+
+/**
+ * 未実施テストの詳細情報
+ */
+export interface NotImplementedDetails {
+    /**
+     * 開始日時
+     */
+    start_date: JstDateTime | undefined;
+    /**
+     * 終了日時
+     */
+    end_date: JstDateTime | undefined;
+    /**
+     * 制限時間（分）
+     */
+    time_limit_minutes: number | undefined;
+    /**
+     * 再解答可能かどうか
+     */
+    can_reanswer: boolean;
+}
+
+/**
+ * 実施中テストの詳細情報
+ */
+export interface InProgressDetails {
+    /**
+     * 実施回数
+     */
+    attempt_count: number;
+    /**
+     * 最新実施日時
+     */
+    latest_attempt_date: JstDateTime | undefined;
+    /**
+     * 実施時間（分）
+     */
+    duration_minutes: number | undefined;
+    /**
+     * 点数
+     */
+    score: string | undefined;
+}
+
+/**
+ * 実施済テストの詳細情報
+ */
+export interface CompletedDetails {
+    /**
+     * 実施回数
+     */
+    attempt_count: number;
+    /**
+     * 最新実施日時
+     */
+    latest_attempt_date: JstDateTime | undefined;
+    /**
+     * 実施時間（分）
+     */
+    duration_minutes: number | undefined;
+    /**
+     * 点数
+     */
+    score: string | undefined;
+}
+
+/**
+ * iCalendar出力の設定
+ */
+export interface IcalOptions {
+    /**
+     * 授業期間（毎週の授業を繰り返す最初と最後の日）
+     *
+     * 時間割の授業を含める場合は必須です。
+     */
+    term_dates?: JstRange<JstDate> | undefined;
+    /**
+     * カレンダー名（`X-WR-CALNAME`）
+     */
+    calendar_name?: string | undefined;
+}
+
+/**
+ * 毎週の予定として出力する授業
+ */
+export interface TimetableClass {
+    /**
+     * 授業コード（`UID`に使用）
+     */
+    class_code: string;
+    /**
+     * 科目名
+     */
+    subject_name: string;
+    /**
+     * 担当教員名
+     */
+    teacher_name: string | undefined;
+    /**
+     * 教室名
+     */
+    classroom: string | undefined;
+    /**
+     * 曜日・時限と開始・終了時刻
+     */
+    class_time: ClassTime;
+}
+
+/**
+ * 区切り文字の形式
+ */
+export type CsvFormat = "csv" | "tsv";
+
+/**
+ * 科目シートの列
+ */
+export type SubjectColumn = "year" | "term" | "curriculum_name" | "major_category_name" | "middle_category_name" | "sub_category_name" | "requirement_type" | "name" | "credit_count" | "grade" | "numeric_score" | "instructor_name" | "is_currently_enrolled";
+
+/**
+ * 単位シートの列
+ */
+export type CreditColumn = "category_name" | "required_for_graduation" | "completed_credits" | "currently_enrolled_credits" | "total_credits";
+
+/**
+ * CSV・TSV出力の設定
+ *
+ * 省略した項目は既定値（CSV、BOMあり、すべての列）になります。
+ */
+export interface CsvOptions {
+    /**
+     * 区切り文字の形式
+     */
+    format?: CsvFormat;
+    /**
+     * 先頭にUTF-8のBOMを付けるかどうか
+     */
+    byte_order_mark?: boolean;
+    /**
+     * 科目シートに出力する列（この順で出力）
+     */
+    subject_columns?: SubjectColumn[];
+    /**
+     * 単位シートに出力する列（この順で出力）
+     */
+    credit_columns?: CreditColumn[];
+}
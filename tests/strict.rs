@@ -0,0 +1,103 @@
+use scraper::Html;
+use unipa_ex::common::options::ParseOptions;
+use unipa_ex::common::outcome::ParseWarning;
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::grades::grade_inquiry::GradeInquiryParserBuilder;
+use unipa_ex::parsers::page::PageKind;
+use unipa_ex::parsers::questionnaire::questionnaire_list::QuestionnaireListParserBuilder;
+use unipa_ex::parsers::syllabus::view::SyllabusViewParser;
+use unipa_ex::utils::error::ParseError;
+
+const GRADE_HTML: &str = r#"
+<table class="listTable">
+  <tbody>
+    <tr><td>Programming I</td><td>2</td><td>秀</td><td>Prof. A</td><td>-</td></tr>
+  </tbody>
+</table>
+"#;
+
+#[test]
+fn lenient_mode_keeps_partial_results() {
+    let document = Html::parse_document(GRADE_HTML);
+    let outcome = GradeInquiryParserBuilder::new()
+        .parse_with_warnings(&document)
+        .unwrap();
+
+    assert_eq!(outcome.data.subjects.len(), 1);
+    assert!(!outcome.is_complete());
+}
+
+#[test]
+fn strict_mode_turns_warnings_into_errors() {
+    let document = Html::parse_document(GRADE_HTML);
+    let err = GradeInquiryParserBuilder::new()
+        .with_options(ParseOptions::strict())
+        .parse_document(&document)
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ParseError::ElementNotFound { .. } | ParseError::MissingRequiredField { .. }
+    ));
+}
+
+#[test]
+fn strict_mode_reports_missing_table() {
+    let document = Html::parse_document("<html><body><p>no table</p></body></html>");
+    let err = QuestionnaireListParserBuilder::new()
+        .with_options(ParseOptions::strict())
+        .parse_document(&document)
+        .unwrap_err();
+
+    assert!(matches!(err, ParseError::ElementNotFound { .. }));
+}
+
+#[test]
+fn page_kind_honours_options() {
+    let document = Html::parse_document(GRADE_HTML);
+
+    assert!(PageKind::GradeInquiry
+        .parse_with_options(&document, ParseOptions::new())
+        .is_ok());
+    assert!(PageKind::GradeInquiry
+        .parse_with_options(&document, ParseOptions::strict())
+        .is_err());
+}
+
+#[test]
+fn unreadable_syllabus_terms_are_warnings_unless_strict() {
+    let document = Html::parse_document(
+        r#"<table>
+          <tr><th>授業コード</th><td>12345</td><th>オムニバス</th><td></td></tr>
+          <tr><th>科目名</th><td>情報科学特論</td></tr>
+          <tr><th>配当学年</th><td>3</td><th>単位数</th><td>2</td></tr>
+          <tr><th>年度学期</th><td>2025年度 夏期</td><th>曜日時限</th><td>集中</td></tr>
+          <tr><th>対象学科</th><td>情報工学科</td></tr>
+          <tr><th>科目区分</th><td>専門</td><th>必選の別</th><td>選択</td></tr>
+          <tr><th>担当者</th><td>教員 A</td></tr>
+        </table>"#,
+    );
+
+    let outcome = SyllabusViewParser::new()
+        .unwrap()
+        .parse_with_warnings(&document)
+        .unwrap();
+    assert_eq!(outcome.data.subject_name, "情報科学特論");
+    assert_eq!(outcome.data.term, None);
+    assert_eq!(
+        outcome.warnings,
+        vec![ParseWarning::UnparseableCell {
+            section: "term".to_string(),
+            row: 0,
+            column: 0,
+            value: "2025年度 夏期".to_string(),
+        }]
+    );
+
+    let err = SyllabusViewParser::new()
+        .unwrap()
+        .with_options(ParseOptions::strict())
+        .parse_document(&document)
+        .unwrap_err();
+    assert!(matches!(err, ParseError::DataParsingFailed { .. }));
+}
@@ -0,0 +1,140 @@
+use scraper::Html;
+use unipa_ex::common::outcome::ParseWarning;
+use unipa_ex::common::term::{AcademicTerm, Term};
+use unipa_ex::parsers::page::{PageData, PageKind};
+use unipa_ex::utils::error::ParseError;
+
+fn term(text: &str) -> AcademicTerm {
+    AcademicTerm::parse(text).unwrap()
+}
+
+#[test]
+fn terms_in_every_notation_are_recognized() {
+    for (text, expected) in [
+        ("2025年度 前期", Term::FirstHalf),
+        ("2025年度　後期", Term::SecondHalf),
+        ("2025春学期", Term::Spring),
+        ("2025年度 秋学期", Term::Fall),
+        ("令和7年度 前学期", Term::FirstHalf),
+        ("R7 2学期", Term::SecondHalf),
+        ("２０２５年度　通年", Term::FullYear),
+        ("2025年度 前期集中", Term::Intensive),
+        ("2025年度 第1クォーター", Term::Q1),
+        ("2025/2Q", Term::Q2),
+        ("2025 Q3", Term::Q3),
+        ("2025年度 ４ターム", Term::Q4),
+    ] {
+        assert_eq!(term(text), AcademicTerm::new(2025, expected), "{text}");
+    }
+}
+
+#[test]
+fn term_only_notations_take_the_given_year() {
+    assert_eq!(
+        AcademicTerm::parse_in_year("後期", 2024).unwrap(),
+        AcademicTerm::new(2024, Term::SecondHalf)
+    );
+    assert_eq!(
+        AcademicTerm::parse_in_year("1", 2024).unwrap(),
+        AcademicTerm::new(2024, Term::FirstHalf)
+    );
+    // 表記に年度があればそちらを使う
+    assert_eq!(
+        AcademicTerm::parse_in_year("2025春学期", 2024).unwrap(),
+        AcademicTerm::new(2025, Term::Spring)
+    );
+
+    assert!(matches!(
+        AcademicTerm::parse("前期"),
+        Err(ParseError::InvalidDataFormat { .. })
+    ));
+    assert!(matches!(
+        AcademicTerm::parse("2025年度 未定"),
+        Err(ParseError::InvalidDataFormat { .. })
+    ));
+}
+
+#[test]
+fn display_round_trips_and_sorts_chronologically() {
+    let first = AcademicTerm::new(2025, Term::FirstHalf);
+    assert_eq!(first.to_string(), "2025年度 前期");
+    assert_eq!(term(&first.to_string()), first);
+    assert_eq!(
+        term("2025年度 第3クォーター").to_string(),
+        "2025年度 第3クォーター"
+    );
+
+    let mut terms = vec![
+        term("2025年度 後期"),
+        term("2024年度 後期"),
+        term("2025年度 前期"),
+    ];
+    terms.sort();
+    assert_eq!(
+        terms,
+        vec![
+            AcademicTerm::new(2024, Term::SecondHalf),
+            AcademicTerm::new(2025, Term::FirstHalf),
+            AcademicTerm::new(2025, Term::SecondHalf),
+        ]
+    );
+}
+
+#[test]
+fn overlapping_terms_can_be_joined_across_notations() {
+    let spring = term("2025春学期");
+    assert!(spring.overlaps(&term("2025年度 前期")));
+    assert!(spring.overlaps(&term("2025年度 2Q")));
+    assert!(!spring.overlaps(&term("2025年度 3Q")));
+    assert!(!spring.overlaps(&term("2025年度 後期")));
+    assert!(spring.overlaps(&term("2025年度 通年")));
+    assert!(!spring.overlaps(&term("2024年度 春学期")));
+}
+
+#[test]
+fn serialized_as_year_and_variant_name() {
+    let value = serde_json::to_value(AcademicTerm::new(2025, Term::Q1)).unwrap();
+    assert_eq!(value, serde_json::json!({ "year": 2025, "term": "Q1" }));
+}
+
+#[test]
+fn syllabus_rows_take_the_year_from_the_search_conditions() {
+    let html = r#"
+<div id="form1:htmlKensakuJyoken">開講年度／学期：2025年度　前期<br>科目名：<br>学科・コース／専攻：情報工学科</div>
+<table id="form1:htmlKekkatable"><tbody>
+  <tr class="rowClass1">
+    <td class="kamokuName"><a id="link0" onclick="return false;"><span>K00001 情報科学特論</span></a></td>
+    <td class="gakki"><span>後期<br></span></td>
+  </tr>
+  <tr class="rowClass1">
+    <td class="kamokuName"><a id="link1" onclick="return false;"><span>K00002 情報理論</span></a></td>
+    <td class="gakki"><span>随時</span></td>
+  </tr>
+</tbody></table>
+<span id="form1:htmlKekkatable:htmlGokeiKensu">2件</span>
+<span id="form1:htmlKekkatable:deluxe1__pagerText">1 / 1 ページ</span>
+<form id="form1"></form>
+"#;
+    let outcome = PageKind::SyllabusSearchResult
+        .parse_with_warnings(&Html::parse_document(html))
+        .unwrap();
+    let PageData::SyllabusSearchResult(page) = &outcome.data else {
+        panic!("unexpected page data");
+    };
+
+    assert_eq!(
+        page.search_conditions.term,
+        Some(AcademicTerm::new(2025, Term::FirstHalf))
+    );
+    assert_eq!(
+        page.course_entries[0].term,
+        Some(AcademicTerm::new(2025, Term::SecondHalf))
+    );
+    assert_eq!(page.course_entries[1].term, None);
+    assert!(outcome.warnings.contains(&ParseWarning::UnparseableCell {
+        section: "course_entries".to_string(),
+        row: 1,
+        column: 5,
+        value: "随時".to_string(),
+    }));
+}
@@ -0,0 +1,255 @@
+//! WASMパッケージの`.d.ts`に出力されるモデルの型定義のスナップショットテスト
+//!
+//! モデルの変更でTypeScript側の型が変わると`tests/snapshots/unipa_ex.d.ts`との差分で失敗します。
+//! 意図した変更の場合は`UPDATE_SNAPSHOTS=1 cargo test --test typescript`でスナップショットを更新し、
+//! 差分をレビューに含めてください。
+
+use std::fs;
+use std::path::Path;
+
+use tsify::Tsify;
+
+/// スナップショットに含める型と、その型定義の組を列挙する
+macro_rules! declarations {
+    ($($ty:ty),* $(,)?) => {
+        vec![$((type_name(stringify!($ty)), <$ty as Tsify>::DECL)),*]
+    };
+}
+
+/// `path::to::Type<()>`から`Type`を取り出す
+fn type_name(path: &'static str) -> &'static str {
+    let path = path.split('<').next().unwrap_or(path).trim();
+    path.rsplit("::").next().unwrap_or(path).trim()
+}
+
+fn declarations() -> Vec<(&'static str, &'static str)> {
+    use unipa_ex::common::{datetime, diagnostics, options, outcome, period, term};
+    use unipa_ex::export::{csv, ical};
+    use unipa_ex::parsers::grades::{
+        gpa, grade_inquiry, graduation_prediction, license_prediction, promotion_prediction,
+    };
+    use unipa_ex::parsers::portal::notification_detail;
+    use unipa_ex::parsers::syllabus::{search, search_result, view};
+    use unipa_ex::parsers::timetable::student_timetable;
+    use unipa_ex::parsers::{
+        assignment_submit, classroom_reservation_status, envelope, page, portal, questionnaire,
+        student_info_inquiry, test_answer_status,
+    };
+    use unipa_ex::utils::error;
+
+    declarations![
+        // 共通
+        options::ParseOptions,
+        outcome::ParseOutcome<()>,
+        outcome::ParseWarning,
+        diagnostics::ParseDiagnostics,
+        diagnostics::SelectorTrace,
+        diagnostics::SkippedRow,
+        error::ParseError,
+        page::PageKind,
+        envelope::Envelope<()>,
+        datetime::JstDate,
+        datetime::JstDateTime,
+        datetime::JstRange<()>,
+        datetime::ClockTime,
+        term::AcademicTerm,
+        term::Term,
+        period::Weekday,
+        period::ClassSlot,
+        period::ClassTime,
+        period::PeriodTime,
+        period::PeriodTable,
+        // ポータル
+        portal::model::Portal,
+        portal::model::Calendar,
+        portal::model::CalendarDay,
+        portal::model::Schedule,
+        portal::model::ScheduleEntry,
+        portal::model::FavoriteLinks,
+        portal::model::FavoriteLink,
+        portal::model::Notifications,
+        portal::model::NotificationSection,
+        portal::model::NotificationEntry,
+        notification_detail::model::NotificationDetail,
+        notification_detail::model::AttachmentFile,
+        // 成績
+        grade_inquiry::model::GradeInquiry,
+        grade_inquiry::model::DisplayPattern,
+        grade_inquiry::model::ViewType,
+        grade_inquiry::model::DisplaySettings,
+        grade_inquiry::model::Subject,
+        grade_inquiry::model::TermSummary,
+        grade_inquiry::model::Grade,
+        grade_inquiry::model::SubjectCategory,
+        grade_inquiry::model::RequirementType,
+        grade_inquiry::model::CreditSummary,
+        grade_inquiry::model::CreditDetails,
+        grade_inquiry::model::CategoryCredits,
+        gpa::GradePoint,
+        gpa::GradeScale,
+        gpa::NumericScale,
+        gpa::GpaMethod,
+        gpa::GpaRules,
+        gpa::GpaExclusion,
+        gpa::SubjectGpa,
+        gpa::GpaSummary,
+        gpa::GpaReport,
+        graduation_prediction::model::SotsugyoMikonHanteiKekka,
+        graduation_prediction::model::YokenFusokuItem,
+        graduation_prediction::model::FusokuRyo,
+        graduation_prediction::model::FusokuUnit,
+        graduation_prediction::model::HanteiStatus,
+        graduation_prediction::model::YokenNode,
+        promotion_prediction::model::PromotionPredictionData,
+        license_prediction::model::LicensePredictionData,
+        // シラバス
+        search::model::SyllabusSearchForm,
+        search_result::model::SyllabusSearchResultPage,
+        search_result::model::SearchConditions,
+        search_result::model::ResultMetadata,
+        search_result::model::CourseEntry,
+        search_result::model::SyllabusLinkInfo,
+        search_result::model::PaginationInfo,
+        search_result::model::FormInfo,
+        search_result::model::HiddenField,
+        view::model::LessonInfo,
+        view::model::ActiveLearningInfo,
+        view::model::LessonPlanItem,
+        // 時間割
+        student_timetable::StudentTimetable,
+        // 教室予約状況
+        classroom_reservation_status::model::ClassroomReservationStatus,
+        classroom_reservation_status::model::SearchParams,
+        classroom_reservation_status::model::ClassroomReservationTableData,
+        classroom_reservation_status::model::ClassroomReservationCell,
+        classroom_reservation_status::model::ReservationType,
+        classroom_reservation_status::model::PopupReservationDetail,
+        classroom_reservation_status::model::PopupClassDetail,
+        classroom_reservation_status::model::PopupDuplicateClassDetail,
+        classroom_reservation_status::model::ConflictingClass,
+        classroom_reservation_status::model::PopupClassroomDetail,
+        // アンケート
+        questionnaire::questionnaire_list::model::QuestionnaireList,
+        questionnaire::questionnaire_list::model::QuestionnaireItem,
+        questionnaire::questionnaire_list::model::ResponseStatus,
+        questionnaire::questionnaire_list::model::QuestionnaireLink,
+        questionnaire::questionnaire_list::model::LinkParameter,
+        questionnaire::questionnaire_list::model::PaginationInfo,
+        // 学籍情報
+        student_info_inquiry::model::StudentInfo,
+        student_info_inquiry::model::BasicInfo,
+        student_info_inquiry::model::AffiliationInfo,
+        student_info_inquiry::model::AdvisorInfo,
+        student_info_inquiry::model::StatusChangeInfo,
+        // 課題提出
+        assignment_submit::model::AssignmentList,
+        assignment_submit::model::Assignment,
+        assignment_submit::model::SubmissionStatus,
+        assignment_submit::model::PaginationInfo,
+        // テスト解答状況
+        test_answer_status::model::TestAnswerStatus,
+        test_answer_status::model::TabInfo,
+        test_answer_status::model::TestStatus,
+        test_answer_status::model::TestItem,
+        test_answer_status::model::TestDetails,
+        test_answer_status::model::NotImplementedDetails,
+        test_answer_status::model::InProgressDetails,
+        test_answer_status::model::CompletedDetails,
+        // 書き出し
+        ical::IcalOptions,
+        ical::TimetableClass,
+        csv::CsvFormat,
+        csv::SubjectColumn,
+        csv::CreditColumn,
+        csv::CsvOptions,
+    ]
+}
+
+fn render() -> String {
+    let mut output = String::from(
+        "// このファイルは tests/typescript.rs が生成します。直接編集しないでください。\n",
+    );
+    for (_, decl) in declarations() {
+        output.push('\n');
+        output.push_str(decl.trim());
+        output.push('\n');
+    }
+    output
+}
+
+/// `src`以下で`Tsify`を導出している型の名前
+fn tsify_types_in(dir: &Path, names: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            tsify_types_in(&path, names);
+            continue;
+        }
+        let source = fs::read_to_string(&path).unwrap();
+        let mut derives_tsify = false;
+        for line in source.lines().map(str::trim) {
+            if line.starts_with("#[derive(") && line.contains("Tsify") {
+                derives_tsify = true;
+            } else if derives_tsify
+                && (line.starts_with("pub struct ") || line.starts_with("pub enum "))
+            {
+                let name = line.split_whitespace().nth(2).unwrap();
+                let name = name.split(['<', '{', '(']).next().unwrap();
+                names.push(name.to_string());
+                derives_tsify = false;
+            }
+        }
+    }
+}
+
+#[test]
+fn typescript_declarations_match_snapshot() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots/unipa_ex.d.ts");
+    let actual = render();
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_default();
+    if actual != expected {
+        let first_difference = actual
+            .lines()
+            .zip(expected.lines())
+            .position(|(a, e)| a != e)
+            .unwrap_or_else(|| actual.lines().count().min(expected.lines().count()));
+        panic!(
+            "TypeScriptの型定義がスナップショットと異なります（{}行目付近）。\n\
+             意図した変更であれば UPDATE_SNAPSHOTS=1 cargo test --test typescript で更新してください。\n\
+             --- 現在の出力 ---\n{}",
+            first_difference + 1,
+            actual
+                .lines()
+                .skip(first_difference.saturating_sub(3))
+                .take(10)
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
+
+#[test]
+fn snapshot_covers_every_tsify_type() {
+    let mut names = Vec::new();
+    tsify_types_in(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        &mut names,
+    );
+    let listed: Vec<_> = declarations().into_iter().map(|(name, _)| name).collect();
+
+    let missing: Vec<_> = names
+        .iter()
+        .filter(|name| !listed.contains(&name.as_str()))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "tests/typescript.rs の declarations() に追加してください: {missing:?}"
+    );
+}
@@ -0,0 +1,83 @@
+//! ブラウザ上で実行するテスト（`wasm-pack test --headless --firefox`）
+
+#![cfg(target_arch = "wasm32")]
+
+use scraper::Html;
+use unipa_ex::common::traits::PageParser;
+use unipa_ex::parsers::portal::builder::PortalParserBuilder;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{DomParser, SupportedType};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const PORTAL_HTML: &str = r##"
+<html><body><form id="form1">
+  <span class="style24">2025春学期</span>
+  <input type="image" alt="前月">
+  <input type="hidden" name="form1:Poa00101A:htmlCurDate" value="2025/04/01">
+  <table id="form1:Poa00101A:htmlCalendarTable">
+    <tbody><tr><td class="todayColor" onclick="select(1)">1</td><td>&nbsp;</td></tr></tbody>
+  </table>
+  <table id="form1:Poa00401A:htmlTodayJikanTable">
+    <tr><td class="jigen">1限</td><td class="jugyo">情報処理</td><td><img src="/img/line.gif"></td></tr>
+  </table>
+  <table id="form1:Poa00301A:htmlPrjTable">
+    <tr><td><a href="#">図書館&nbsp;OPAC</a></td></tr>
+  </table>
+  <input type="hidden" name="form1:Poa00301A:htmlPrjTable:0:htmlLinkUrl" value="https://lib.example.jp/">
+  <table id="form1:Poa00201A:htmlParentTable"><tr><td>
+    <table id="form1:Poa00201A:htmlParentTable:0:htmlDetailTbl">
+      <tr><td><img src="/img/unread.gif"></td><td>健康診断</td><td>保健室</td><td>2025/04/01</td></tr>
+    </table>
+  </td></tr></table>
+</form></body></html>
+"##;
+
+#[wasm_bindgen_test]
+fn live_dom_matches_string_parsing() {
+    let parser = PortalParserBuilder::new().build().unwrap();
+    let from_string = parser
+        .parse_document(&Html::parse_document(PORTAL_HTML))
+        .unwrap();
+
+    let document = DomParser::new()
+        .unwrap()
+        .parse_from_string(PORTAL_HTML, SupportedType::TextHtml)
+        .unwrap();
+    let from_dom = parser
+        .parse_element(&document.document_element().unwrap())
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&from_dom).unwrap(),
+        serde_json::to_value(&from_string).unwrap()
+    );
+}
+
+#[wasm_bindgen_test]
+fn facade_classes_share_the_same_shape() {
+    use unipa_ex::common::options::ParseOptions;
+    use unipa_ex::parsers::page::PageKind;
+    use unipa_ex::wasm::{PortalAllNotificationsParser, PortalParser};
+
+    let parser = PortalParser::new(None).unwrap();
+    assert_eq!(parser.page_kind(), PageKind::Portal);
+    assert_eq!(parser.options(), ParseOptions::default());
+
+    let expected = PortalParserBuilder::new()
+        .build()
+        .unwrap()
+        .parse_document(&Html::parse_document(PORTAL_HTML))
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(parser.parse(PORTAL_HTML, None).unwrap()).unwrap(),
+        serde_json::to_value(&expected).unwrap()
+    );
+
+    // 呼び出し時のオプションは構築時のオプションより優先される
+    let outcome = PortalAllNotificationsParser::new(None)
+        .unwrap()
+        .parse_with_warnings(PORTAL_HTML, Some(ParseOptions::new().with_debug_mode(true)))
+        .unwrap();
+    assert!(outcome.diagnostics.is_some());
+}